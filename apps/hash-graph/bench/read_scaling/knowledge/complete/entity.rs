@@ -136,6 +136,7 @@ async fn seed_db(
                             right_entity_id: entity_b_metadata.record_id.entity_id,
                             left_entity_confidence: None,
                             right_entity_confidence: None,
+                            position: None,
                         }),
                         None,
                     )
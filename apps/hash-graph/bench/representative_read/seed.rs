@@ -210,6 +210,7 @@ async fn seed_db(account_id: AccountId, store_wrapper: &mut StoreWrapper) {
                                 right_entity_id: right_entity_metadata.record_id.entity_id,
                                 left_entity_confidence: None,
                                 right_entity_confidence: None,
+                                position: None,
                             }),
                             None,
                         )
@@ -240,6 +240,7 @@ pub async fn seed<D, P, E, C>(
                     }],
                     conflict_behavior: ConflictBehavior::Fail,
                     provenance: ProvidedOntologyEditionProvenanceMetadata::default(),
+                    conversion: None,
                 },
             )
             .await
@@ -259,6 +260,8 @@ pub async fn seed<D, P, E, C>(
                                     level: 0,
                                 }],
                                 provenance: ProvidedOntologyEditionProvenanceMetadata::default(),
+                                conversion: None,
+                                reject_breaking_changes: false,
                             },
                         )
                         .await
@@ -309,6 +312,7 @@ pub async fn seed<D, P, E, C>(
                                     level: 0,
                                 }],
                                 provenance: ProvidedOntologyEditionProvenanceMetadata::default(),
+                                reject_breaking_changes: false,
                             },
                         )
                         .await
@@ -369,6 +373,7 @@ pub async fn seed<D, P, E, C>(
                                     },
                                 ],
                                 provenance: ProvidedOntologyEditionProvenanceMetadata::default(),
+                                reject_breaking_changes: false,
                             },
                         )
                         .await
@@ -15,13 +15,18 @@ use clap::Parser;
 use error_stack::{Report, Result, ResultExt};
 use graph::{
     ontology::domain_validator::DomainValidator,
-    store::{DatabaseConnectionInfo, FetchingPool, PostgresStorePool, StorePool},
+    store::{DatabaseConnectionInfo, EntityStore, FetchingPool, PostgresStorePool, StorePool},
+};
+use graph_api::rest::{
+    rest_api_router, OntologyTypeUpdatePolicy, OpenApiDocumentation, RestRouterDependencies,
 };
-use graph_api::rest::{rest_api_router, OpenApiDocumentation, RestRouterDependencies};
 use regex::Regex;
 use reqwest::{Client, Url};
 use temporal_client::TemporalClientConfig;
-use tokio::{net::TcpListener, time::timeout};
+use tokio::{
+    net::TcpListener,
+    time::{interval, timeout},
+};
 use tokio_postgres::NoTls;
 
 use crate::{
@@ -102,6 +107,21 @@ pub struct ServerArgs {
     #[clap(long, default_value_t = false)]
     pub offline: bool,
 
+    /// Rejects updates of ontology types which are not backwards compatible unless the update is
+    /// forced.
+    #[clap(
+        long,
+        default_value_t = false,
+        env = "HASH_GRAPH_REJECT_BREAKING_TYPE_UPDATES"
+    )]
+    pub reject_breaking_type_updates: bool,
+
+    /// The number of days changes to entities are kept in the entity change feed.
+    ///
+    /// If not set, changes are kept indefinitely.
+    #[clap(long, env = "HASH_GRAPH_ENTITY_CHANGE_RETENTION_DAYS")]
+    pub entity_change_retention_days: Option<u64>,
+
     /// The host the Spice DB server is listening at.
     #[clap(long, env = "HASH_SPICEDB_HOST")]
     pub spicedb_host: String,
@@ -184,6 +204,14 @@ pub async fn server(args: ServerArgs) -> Result<(), GraphError> {
         )
     };
 
+    let pool = Arc::new(pool);
+    if let Some(retention_days) = args.entity_change_retention_days {
+        tokio::spawn(prune_entity_changes(
+            Arc::clone(&pool),
+            Duration::from_secs(retention_days * 24 * 60 * 60),
+        ));
+    }
+
     let mut spicedb_client = SpiceDbOpenApi::new(
         format!("{}:{}", args.spicedb_host, args.spicedb_http_port),
         args.spicedb_grpc_preshared_key.as_deref(),
@@ -200,9 +228,14 @@ pub async fn server(args: ServerArgs) -> Result<(), GraphError> {
     zanzibar_client.seed().await.change_context(GraphError)?;
 
     let router = rest_api_router(RestRouterDependencies {
-        store: Arc::new(pool),
+        store: pool,
         authorization_api: Arc::new(zanzibar_client),
         domain_regex: DomainValidator::new(args.allowed_url_domain),
+        ontology_type_update_policy: if args.reject_breaking_type_updates {
+            OntologyTypeUpdatePolicy::RejectBreakingChanges
+        } else {
+            OntologyTypeUpdatePolicy::AllowBreakingChanges
+        },
         temporal_client: if let Some(host) = args.temporal_host {
             Some(
                 TemporalClientConfig::new(
@@ -231,6 +264,25 @@ pub async fn server(args: ServerArgs) -> Result<(), GraphError> {
     Ok(())
 }
 
+async fn prune_entity_changes<P: StorePool + Send + Sync>(pool: Arc<P>, retention: Duration) {
+    let mut interval = interval(Duration::from_secs(60 * 60));
+    loop {
+        interval.tick().await;
+
+        let mut store = match pool.acquire().await {
+            Ok(store) => store,
+            Err(report) => {
+                tracing::error!(error = ?report, "Failed to acquire store to prune entity changes");
+                continue;
+            }
+        };
+        match store.prune_entity_changes(retention).await {
+            Ok(pruned) => tracing::info!(pruned, "Pruned entity changes"),
+            Err(report) => tracing::error!(error = ?report, "Failed to prune entity changes"),
+        }
+    }
+}
+
 pub async fn healthcheck(address: ApiAddress) -> Result<(), HealthcheckError> {
     let request_url = format!("http://{address}/api-doc/openapi.json");
 
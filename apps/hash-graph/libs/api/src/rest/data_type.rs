@@ -16,7 +16,7 @@ use authorization::{
 use axum::{
     extract::{OriginalUri, Path, Query},
     http::{header::LINK, HeaderMap, StatusCode},
-    response::Response,
+    response::{IntoResponse, Response},
     routing::{get, post, put},
     Extension, Router,
 };
@@ -27,22 +27,29 @@ use graph::{
         patch_id_and_parse, DataTypeQueryToken,
     },
     store::{
-        error::VersionedUrlAlreadyExists,
+        error::{BreakingOntologyTypeChanges, VersionedUrlAlreadyExists},
         ontology::{
             ArchiveDataTypeParams, CreateDataTypeParams, GetDataTypesParams,
             UnarchiveDataTypeParams, UpdateDataTypeEmbeddingParams, UpdateDataTypesParams,
         },
+        query::Filter,
         BaseUrlAlreadyExists, ConflictBehavior, DataTypeStore, OntologyVersionDoesNotExist,
         StorePool,
     },
     subgraph::{
+        edges::GraphResolveDepths,
         identifier::DataTypeVertexId,
         query::{DataTypeStructuralQuery, StructuralQuery},
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
+            VariableTemporalAxisUnresolved,
+        },
     },
 };
 use graph_types::{
+    account::AccountId,
     ontology::{
-        DataTypeMetadata, DataTypeWithMetadata, OntologyTemporalMetadata,
+        DataTypeConversion, DataTypeMetadata, DataTypeWithMetadata, OntologyTemporalMetadata,
         OntologyTypeClassificationMetadata, OntologyTypeMetadata, OntologyTypeReference,
         ProvidedOntologyEditionProvenanceMetadata,
     },
@@ -51,6 +58,7 @@ use graph_types::{
 use hash_status::Status;
 use serde::{Deserialize, Serialize};
 use temporal_client::TemporalClient;
+use temporal_versioning::TemporalBound;
 use time::OffsetDateTime;
 use type_system::{
     url::{OntologyTypeVersion, VersionedUrl},
@@ -63,7 +71,8 @@ use crate::rest::{
     json::Json,
     status::{report_to_response, status_to_response},
     utoipa_typedef::{subgraph::Subgraph, ListOrValue, MaybeListOfDataType},
-    AuthenticatedUserHeader, Cursor, Pagination, PermissionResponse, RestApiStore,
+    AuthenticatedUserHeader, Cursor, OntologyTypeUpdatePolicy, OntologyTypeUpdatePreview,
+    Pagination, PermissionResponse, RestApiStore,
 };
 
 #[derive(OpenApi)]
@@ -77,6 +86,7 @@ use crate::rest::{
         load_external_data_type,
         get_data_types_by_query,
         update_data_type,
+        preview_data_type_update,
         update_data_type_embeddings,
         archive_data_type,
         unarchive_data_type,
@@ -84,6 +94,7 @@ use crate::rest::{
     components(
         schemas(
             DataTypeWithMetadata,
+            DataTypeConversion,
 
             DataTypeOwnerSubject,
             DataTypeViewerSubject,
@@ -94,6 +105,7 @@ use crate::rest::{
             CreateDataTypeRequest,
             LoadExternalDataTypeRequest,
             UpdateDataTypeRequest,
+            PreviewDataTypeUpdateRequest,
             UpdateDataTypeEmbeddingParams,
             DataTypeQueryToken,
             DataTypeStructuralQuery,
@@ -141,6 +153,7 @@ impl RoutedResource for DataTypeResource {
                 )
                 .route("/query", post(get_data_types_by_query::<S, A>))
                 .route("/load", post(load_external_data_type::<S, A>))
+                .route("/preview-update", post(preview_data_type_update::<S, A>))
                 .route("/archive", put(archive_data_type::<S, A>))
                 .route("/unarchive", put(unarchive_data_type::<S, A>))
                 .route("/embeddings", post(update_data_type_embeddings::<S, A>)),
@@ -155,6 +168,9 @@ struct CreateDataTypeRequest {
     schema: MaybeListOfDataType,
     owned_by_id: OwnedById,
     relationships: Vec<DataTypeRelationAndSubject>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    conversion: Option<DataTypeConversion>,
     #[serde(
         default,
         skip_serializing_if = "ProvidedOntologyEditionProvenanceMetadata::is_empty"
@@ -209,6 +225,7 @@ where
         schema,
         owned_by_id,
         relationships,
+        conversion,
         provenance,
     }) = body;
 
@@ -230,6 +247,7 @@ where
                     classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
                     relationships: relationships.clone(),
                     conflict_behavior: ConflictBehavior::Fail,
+                    conversion: conversion.clone(),
                     provenance: provenance.clone()
                 })
             }).collect::<Result<Vec<_>, StatusCode>>()?
@@ -360,6 +378,7 @@ where
                             },
                             relationships,
                             conflict_behavior: ConflictBehavior::Fail,
+                            conversion: None,
                             provenance,
                         },
                     )
@@ -420,7 +439,7 @@ where
         .filter
         .convert_parameters()
         .map_err(report_to_response)?;
-    let subgraph = store
+    let (subgraph, cursor) = store
         .get_data_type(
             actor_id,
             &authorization_api,
@@ -433,10 +452,9 @@ where
         .await
         .map_err(report_to_response)?;
 
-    let cursor = subgraph.roots.iter().last().map(Cursor);
     let mut headers = HeaderMap::new();
     if let (Some(cursor), Some(limit)) = (cursor, pagination.limit) {
-        headers.insert(LINK, cursor.link_header("next", uri, limit)?);
+        headers.insert(LINK, Cursor(cursor).link_header("next", uri, limit)?);
     }
     Ok((headers, Json(Subgraph::from(subgraph))))
 }
@@ -448,6 +466,12 @@ struct UpdateDataTypeRequest {
     schema: serde_json::Value,
     type_to_update: VersionedUrl,
     relationships: Vec<DataTypeRelationAndSubject>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    conversion: Option<DataTypeConversion>,
+    /// Applies the update even if it contains breaking changes.
+    #[serde(default)]
+    force: bool,
     #[serde(
         default,
         skip_serializing_if = "ProvidedOntologyEditionProvenanceMetadata::is_empty"
@@ -469,6 +493,7 @@ struct UpdateDataTypeRequest {
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
         (status = 404, description = "Base data type ID was not found"),
+        (status = 409, content_type = "application/json", description = "The update contains breaking changes and was not forced. The breaking changes are part of the response", body = OntologyTypeUpdatePreview),
         (status = 500, description = "Store error occurred"),
     ),
     request_body = UpdateDataTypeRequest,
@@ -479,8 +504,9 @@ async fn update_data_type<S, A>(
     store_pool: Extension<Arc<S>>,
     authorization_api_pool: Extension<Arc<A>>,
     temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    ontology_type_update_policy: Extension<OntologyTypeUpdatePolicy>,
     body: Json<UpdateDataTypeRequest>,
-) -> Result<Json<DataTypeMetadata>, StatusCode>
+) -> Result<Json<DataTypeMetadata>, Response>
 where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
@@ -489,6 +515,8 @@ where
         schema,
         mut type_to_update,
         relationships,
+        conversion,
+        force,
         provenance,
     }) = body;
 
@@ -496,19 +524,19 @@ where
 
     let data_type = patch_id_and_parse(&type_to_update, schema).map_err(|report| {
         tracing::error!(error=?report, "Couldn't patch schema and convert to Data Type");
-        StatusCode::UNPROCESSABLE_ENTITY
+        StatusCode::UNPROCESSABLE_ENTITY.into_response()
         // TODO - We should probably return more information to the client
         //  https://app.asana.com/0/1201095311341924/1202574350052904/f
     })?;
 
     let mut store = store_pool.acquire().await.map_err(|report| {
         tracing::error!(error=?report, "Could not acquire store");
-        StatusCode::INTERNAL_SERVER_ERROR
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
     })?;
 
     let mut authorization_api = authorization_api_pool.acquire().await.map_err(|error| {
         tracing::error!(?error, "Could not acquire access to the authorization API");
-        StatusCode::INTERNAL_SERVER_ERROR
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
     })?;
 
     store
@@ -519,6 +547,8 @@ where
             UpdateDataTypesParams {
                 schema: data_type,
                 relationships,
+                conversion,
+                reject_breaking_changes: ontology_type_update_policy.checks_changes(force),
                 provenance,
             },
         )
@@ -526,19 +556,143 @@ where
         .map_err(|report| {
             tracing::error!(error=?report, "Could not update data type");
 
+            if let Some(breaking_changes) = report.downcast_ref::<BreakingOntologyTypeChanges>() {
+                // The breaking changes are returned, so the client can decide whether to force the
+                // update.
+                return (
+                    StatusCode::CONFLICT,
+                    Json(OntologyTypeUpdatePreview::from(
+                        breaking_changes.changes.clone(),
+                    )),
+                )
+                    .into_response();
+            }
+
             if report.contains::<PermissionAssertion>() {
-                return StatusCode::FORBIDDEN;
+                return StatusCode::FORBIDDEN.into_response();
             }
             if report.contains::<OntologyVersionDoesNotExist>() {
-                return StatusCode::NOT_FOUND;
+                return StatusCode::NOT_FOUND.into_response();
             }
 
             // Insertion/update errors are considered internal server errors.
-            StatusCode::INTERNAL_SERVER_ERROR
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
         })
         .map(Json)
 }
 
+async fn read_data_type<S, A>(
+    store: &S,
+    authorization_api: &A,
+    actor_id: AccountId,
+    data_type_id: &VersionedUrl,
+) -> Result<DataType, StatusCode>
+where
+    S: DataTypeStore + Sync,
+    A: AuthorizationApi + Sync,
+{
+    store
+        .get_data_type(
+            actor_id,
+            authorization_api,
+            GetDataTypesParams {
+                query: StructuralQuery {
+                    filter: Filter::for_versioned_url(data_type_id),
+                    graph_resolve_depths: GraphResolveDepths::default(),
+                    temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                        pinned: PinnedTemporalAxisUnresolved::new(None),
+                        variable: VariableTemporalAxisUnresolved::new(
+                            Some(TemporalBound::Unbounded),
+                            None,
+                        ),
+                    },
+                    include_drafts: false,
+                },
+                after: None,
+                limit: None,
+            },
+        )
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not read data type");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .0
+        .vertices
+        .data_types
+        .remove(&DataTypeVertexId::from(data_type_id.clone()))
+        .map(|data_type| data_type.schema)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct PreviewDataTypeUpdateRequest {
+    #[schema(value_type = VAR_UPDATE_DATA_TYPE)]
+    schema: serde_json::Value,
+    type_to_update: VersionedUrl,
+}
+
+#[utoipa::path(
+    post,
+    path = "/data-types/preview-update",
+    tag = "DataType",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The changes the update would introduce", body = OntologyTypeUpdatePreview),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 404, description = "Base data type ID was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = PreviewDataTypeUpdateRequest,
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn preview_data_type_update<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    body: Json<PreviewDataTypeUpdateRequest>,
+) -> Result<Json<OntologyTypeUpdatePreview>, StatusCode>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let Json(PreviewDataTypeUpdateRequest {
+        schema,
+        type_to_update,
+    }) = body;
+
+    let updated_data_type_id = VersionedUrl {
+        base_url: type_to_update.base_url.clone(),
+        version: OntologyTypeVersion::new(type_to_update.version.inner() + 1),
+    };
+    let data_type: DataType =
+        patch_id_and_parse(&updated_data_type_id, schema).map_err(|report| {
+            tracing::error!(error=?report, "Couldn't patch schema and convert to Data Type");
+            StatusCode::UNPROCESSABLE_ENTITY
+        })?;
+
+    let store = store_pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let authorization_api = authorization_api_pool.acquire().await.map_err(|error| {
+        tracing::error!(?error, "Could not acquire access to the authorization API");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let previous_data_type =
+        read_data_type(&store, &authorization_api, actor_id, &type_to_update).await?;
+
+    Ok(Json(OntologyTypeUpdatePreview::from(
+        previous_data_type.changes_to(&data_type),
+    )))
+}
+
 #[utoipa::path(
     post,
     path = "/data-types/embeddings",
@@ -2,7 +2,7 @@
 
 #![expect(clippy::str_to_string)]
 
-use std::sync::Arc;
+use std::{collections::VecDeque, sync::Arc, time::Duration};
 
 use authorization::{
     backend::{ModifyRelationshipOperation, PermissionAssertion},
@@ -15,58 +15,96 @@ use authorization::{
     AuthorizationApi, AuthorizationApiPool,
 };
 use axum::{
-    extract::Path,
-    http::StatusCode,
-    response::Response,
+    extract::{Path, Query},
+    http::{HeaderMap, StatusCode},
+    response::{
+        sse::{Event, KeepAlive},
+        Response, Sse,
+    },
     routing::{get, post},
     Extension, Router,
 };
 use error_stack::{Report, ResultExt};
+use futures::{stream, Stream};
 use graph::{
     knowledge::{EntityQueryPath, EntityQuerySortingToken, EntityQueryToken},
     store::{
-        error::{EntityDoesNotExist, RaceConditionOnUpdate},
+        error::{
+            EntityDoesNotExist, EntityEditionMismatch, RaceConditionOnUpdate,
+            UnpublishedLinkEndpoints,
+        },
         knowledge::{
-            CreateEntityRequest, GetEntityParams, PatchEntityParams, UpdateEntityEmbeddingsParams,
-            ValidateEntityParams,
+            CorrectEntityParams, CountEntitiesParams, CreateEntityRequest, DeleteEntitiesParams,
+            DiffEntityDraftResult, DiffEntityParams, DiffEntityResult, DiffEntitySubgraphParams,
+            EntityChange, EntityChangeCursor, EntityChangeKind, EntityCount, EntityCountGroup,
+            EntityCountGroupBy, EntityCountGroupKey, EntityDraft, EntityEditionSelector,
+            EntityTypeIdDiff, GetEntityChangesParams, GetEntityParams, LinkDataDiff,
+            MergeEntitiesParams, MigrateEntitiesParams, MoveLinkParams, PatchEntityParams,
+            UpdateEntityEmbeddingsParams, ValidateEntityParams,
         },
+        query::Filter,
         AccountStore, EntityQueryCursor, EntityQuerySorting, EntityQuerySortingRecord, EntityStore,
-        EntityValidationType, NullOrdering, Ordering, StorePool,
+        EntityValidationType, InsertionError, NullOrdering, Ordering, QueryError, StorePool,
+        UpdateError,
+    },
+    subgraph::{
+        diff::{EdgeDiff, GraphEdge, GraphEdgeKind, GraphElementId, SubgraphDiff, VertexDiff},
+        query::EntityStructuralQuery,
+        temporal_axes::QueryTemporalAxesUnresolved,
     },
-    subgraph::{query::EntityStructuralQuery, temporal_axes::QueryTemporalAxesUnresolved},
 };
 use graph_types::{
+    account::AccountId,
     knowledge::{
         entity::{
-            Entity, EntityEditionId, EntityEditionProvenanceMetadata, EntityEmbedding, EntityId,
-            EntityMetadata, EntityProvenanceMetadata, EntityRecordId, EntityTemporalMetadata,
-            EntityUuid, ProvidedEntityEditionProvenanceMetadata,
+            ActorType, Entity, EntityEditionId, EntityEditionProvenanceMetadata, EntityEmbedding,
+            EntityId, EntityMetadata, EntityProvenanceMetadata, EntityRecordId,
+            EntityTemporalMetadata, EntityUuid, OriginProvenance, PropertyMergeStrategy,
+            PropertyMigration, ProvidedEntityEditionProvenanceMetadata, SourceProvenance,
         },
-        link::LinkData,
-        Confidence, Property, PropertyConfidence, PropertyObject, PropertyPatchOperation,
-        PropertyPath,
+        link::{LinkData, LinkPosition},
+        Confidence, Property, PropertyConfidence, PropertyDataTypes, PropertyDiff,
+        PropertyMergeConflict, PropertyObject, PropertyPatchOperation, PropertyPath,
+        PropertyProvenance, PropertyProvenances,
     },
     owned_by_id::OwnedById,
     Embedding,
 };
+use hash_status::Status;
 use serde::{Deserialize, Serialize};
 use temporal_client::TemporalClient;
 use utoipa::{OpenApi, ToSchema};
 use validation::ValidateEntityComponents;
 
 use crate::rest::{
-    api_resource::RoutedResource, json::Json, status::report_to_response,
-    utoipa_typedef::subgraph::Subgraph, AuthenticatedUserHeader, PermissionResponse,
+    api_resource::RoutedResource,
+    json::Json,
+    status::{report_to_response, report_to_status, status_to_response},
+    utoipa_typedef::subgraph::Subgraph,
+    AuthenticatedUserHeader, PermissionResponse,
 };
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         create_entity,
+        create_entities,
         validate_entity,
         check_entity_permission,
         get_entities_by_query,
+        count_entities,
+        diff_entity_subgraph,
         patch_entity,
+        correct_entity,
+        merge_entities,
+        migrate_entities,
+        move_link,
+        delete_entities,
+        get_entity_history,
+        get_entity_drafts,
+        diff_entity,
+        diff_entity_draft,
+        get_entity_changes,
         update_entity_embeddings,
 
         get_entity_authorization_relationships,
@@ -80,6 +118,7 @@ use crate::rest::{
     components(
         schemas(
             CreateEntityRequest,
+            CreateEntitiesResponseItem,
             ValidateEntityParams,
             EntityValidationType,
             ValidateEntityComponents,
@@ -91,6 +130,26 @@ use crate::rest::{
 
             PatchEntityParams,
             PropertyPatchOperation,
+            CorrectEntityParams,
+            MergeEntitiesParams,
+            PropertyMergeStrategy,
+            MigrateEntitiesParams,
+            PropertyMigration,
+            MigrateEntitiesResponseItem,
+            MoveLinkParams,
+            DeleteEntitiesParams,
+            DiffEntityParams,
+            EntityEditionSelector,
+            DiffEntityResult,
+            EntityDraft,
+            DiffEntityDraftResult,
+            PropertyMergeConflict,
+            PropertyDiff,
+            EntityTypeIdDiff,
+            LinkDataDiff,
+            EntityChange,
+            EntityChangeKind,
+            EntityChangeCursor,
 
             EntityRelationAndSubject,
             EntityPermission,
@@ -110,11 +169,25 @@ use crate::rest::{
             EntityQuerySortingRecord,
             EntityQuerySortingToken,
             GetEntityByQueryResponse,
+            CountEntitiesParams,
+            EntityCount,
+            EntityCountGroup,
+            EntityCountGroupBy,
+            EntityCountGroupKey,
+            DiffEntitySubgraphParams,
+            SubgraphDiff,
+            VertexDiff,
+            EdgeDiff,
+            GraphEdge,
+            GraphEdgeKind,
+            GraphElementId,
 
             Entity,
             Property,
             PropertyObject,
             PropertyConfidence,
+            PropertyDataTypes,
+            PropertyProvenances,
             EntityUuid,
             EntityId,
             EntityEditionId,
@@ -122,10 +195,15 @@ use crate::rest::{
             EntityProvenanceMetadata,
             EntityEditionProvenanceMetadata,
             ProvidedEntityEditionProvenanceMetadata,
+            ActorType,
+            OriginProvenance,
+            SourceProvenance,
+            PropertyProvenance,
             EntityRecordId,
             EntityTemporalMetadata,
             EntityQueryToken,
             LinkData,
+            LinkPosition,
 
             PropertyPath,
             Confidence,
@@ -148,16 +226,31 @@ impl RoutedResource for EntityResource {
         Router::new().nest(
             "/entities",
             Router::new()
-                .route("/", post(create_entity::<S, A>).patch(patch_entity::<S, A>))
+                .route(
+                    "/",
+                    post(create_entity::<S, A>)
+                        .patch(patch_entity::<S, A>)
+                        .delete(delete_entities::<S, A>),
+                )
+                .route("/bulk", post(create_entities::<S, A>))
                 .route(
                     "/relationships",
                     post(modify_entity_authorization_relationships::<A>),
                 )
                 .route("/validate", post(validate_entity::<S, A>))
                 .route("/embeddings", post(update_entity_embeddings::<S, A>))
+                .route("/corrections", post(correct_entity::<S, A>))
+                .route("/merge", post(merge_entities::<S, A>))
+                .route("/migrate", post(migrate_entities::<S, A>))
+                .route("/move-link", post(move_link::<S, A>))
+                .route("/diff", post(diff_entity::<S, A>))
+                .route("/changes", get(get_entity_changes::<S, A>))
                 .nest(
                     "/:entity_id",
                     Router::new()
+                        .route("/history", get(get_entity_history::<S, A>))
+                        .route("/drafts", get(get_entity_drafts::<S, A>))
+                        .route("/draft-diff", get(diff_entity_draft::<S, A>))
                         .route(
                             "/relationships",
                             get(get_entity_authorization_relationships::<A>),
@@ -176,7 +269,9 @@ impl RoutedResource for EntityResource {
                             get(check_entity_permission::<A>),
                         ),
                 )
-                .route("/query", post(get_entities_by_query::<S, A>)),
+                .route("/query", post(get_entities_by_query::<S, A>))
+                .route("/query/count", post(count_entities::<S, A>))
+                .route("/query/diff", post(diff_entity_subgraph::<S, A>)),
         )
     }
 }
@@ -192,6 +287,7 @@ impl RoutedResource for EntityResource {
     responses(
         (status = 200, content_type = "application/json", description = "The metadata of the created entity", body = EntityMetadata),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 400, content_type = "application/json", description = "The entity is a published link but its endpoints only exist as drafts. The unpublished endpoints are part of the response"),
 
         (status = 404, description = "Entity Type URL was not found"),
         (status = 500, description = "Store error occurred"),
@@ -228,10 +324,96 @@ where
             params,
         )
         .await
-        .map_err(report_to_response)
+        .map_err(|report| {
+            if let Some(endpoints) = report.downcast_ref::<UnpublishedLinkEndpoints>() {
+                return status_to_response(Status::new(
+                    hash_status::StatusCode::FailedPrecondition,
+                    Some(report.to_string()),
+                    vec![endpoints],
+                ));
+            }
+
+            report_to_response(report)
+        })
         .map(Json)
 }
 
+#[derive(Serialize, ToSchema)]
+#[serde(untagged)]
+enum CreateEntitiesResponseItem {
+    Created {
+        metadata: Box<EntityMetadata>,
+    },
+    Failed {
+        #[schema(value_type = Object)]
+        error: Status<Report<InsertionError>>,
+    },
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/bulk",
+    request_body = [CreateEntityRequest],
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The result of the creation of each entity, in the same order as the request", body = [CreateEntitiesResponseItem]),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 500, description = "Store error occurred"),
+    ),
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client, body)
+)]
+async fn create_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(body): Json<serde_json::Value>,
+) -> Result<Json<Vec<CreateEntitiesResponseItem>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let params = Vec::<CreateEntityRequest>::deserialize(&body).map_err(report_to_response)?;
+
+    let mut store = store_pool.acquire().await.map_err(report_to_response)?;
+    let mut authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .create_entities(
+            actor_id,
+            &mut authorization_api,
+            temporal_client.as_deref(),
+            params,
+        )
+        .await
+        .map_err(report_to_response)
+        .map(|results| {
+            Json(
+                results
+                    .into_iter()
+                    .map(|result| match result {
+                        Ok(metadata) => CreateEntitiesResponseItem::Created {
+                            metadata: Box::new(metadata),
+                        },
+                        Err(report) => CreateEntitiesResponseItem::Failed {
+                            error: report_to_status(report),
+                        },
+                    })
+                    .collect(),
+            )
+        })
+}
+
 #[utoipa::path(
     post,
     path = "/entities/validate",
@@ -275,8 +457,17 @@ where
             params,
         )
         .await
-        .attach(hash_status::StatusCode::InvalidArgument)
-        .map_err(report_to_response)?;
+        .map_err(|report| {
+            if let Some(endpoints) = report.downcast_ref::<UnpublishedLinkEndpoints>() {
+                return status_to_response(Status::new(
+                    hash_status::StatusCode::FailedPrecondition,
+                    Some(report.to_string()),
+                    vec![endpoints],
+                ));
+            }
+
+            report_to_response(report.attach(hash_status::StatusCode::InvalidArgument))
+        })?;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -463,6 +654,106 @@ where
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/entities/query/count",
+    request_body = CountEntitiesParams,
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/json",
+            body = EntityCount,
+            description = "The number of entities that satisfy the given query, optionally grouped.",
+        ),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool, request))]
+async fn count_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Json<EntityCount>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let store = store_pool.acquire().await.map_err(report_to_response)?;
+
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut params = CountEntitiesParams::deserialize(&request).map_err(report_to_response)?;
+    params
+        .filter
+        .convert_parameters()
+        .map_err(report_to_response)?;
+
+    store
+        .count_entities(actor_id, &authorization_api, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/query/diff",
+    request_body = DiffEntitySubgraphParams,
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/json",
+            body = SubgraphDiff,
+            description = "The vertices and edges which were added, removed, or changed between the subgraphs resolved at the old and the new temporal axes.",
+        ),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool, request))]
+async fn diff_entity_subgraph<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Json<SubgraphDiff>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let store = store_pool.acquire().await.map_err(report_to_response)?;
+
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut params = DiffEntitySubgraphParams::deserialize(&request).map_err(report_to_response)?;
+    params
+        .filter
+        .convert_parameters()
+        .map_err(report_to_response)?;
+
+    store
+        .diff_entity_subgraph(actor_id, &authorization_api, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
 #[utoipa::path(
     patch,
     path = "/entities",
@@ -474,6 +765,8 @@ where
         (status = 200, content_type = "application/json", description = "The metadata of the updated entity", body = EntityMetadata),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
         (status = 423, content_type = "text/plain", description = "The entity that should be updated was unexpectedly updated at the same time"),
+        (status = 409, content_type = "application/json", description = "The entity is not at the expected edition anymore, or a published draft conflicts with changes made to the live entity. The editions or the conflicting property paths are part of the response"),
+        (status = 400, content_type = "application/json", description = "A published link would point at entities which only exist as drafts. The unpublished endpoints are part of the response"),
 
         (status = 404, description = "Entity ID or Entity Type URL was not found"),
         (status = 500, description = "Store error occurred"),
@@ -510,14 +803,641 @@ where
         )
         .await
         .map_err(|report| {
+            if let Some(mismatch) = report.downcast_ref::<EntityEditionMismatch>() {
+                // The current edition is returned, so the client can decide how to resolve the
+                // conflict.
+                return status_to_response(Status::new(
+                    hash_status::StatusCode::Aborted,
+                    Some(report.to_string()),
+                    vec![mismatch],
+                ));
+            }
+
+            if let Some(conflict) = report.downcast_ref::<PropertyMergeConflict>() {
+                return status_to_response(Status::new(
+                    hash_status::StatusCode::Aborted,
+                    Some(report.to_string()),
+                    vec![conflict],
+                ));
+            }
+
+            if let Some(endpoints) = report.downcast_ref::<UnpublishedLinkEndpoints>() {
+                // Publishing the link requires its endpoints to be published first.
+                return status_to_response(Status::new(
+                    hash_status::StatusCode::FailedPrecondition,
+                    Some(report.to_string()),
+                    vec![endpoints],
+                ));
+            }
+
             if report.contains::<EntityDoesNotExist>() {
-                report.attach(hash_status::StatusCode::NotFound)
+                report_to_response(report.attach(hash_status::StatusCode::NotFound))
             } else if report.contains::<RaceConditionOnUpdate>() {
-                report.attach(hash_status::StatusCode::Cancelled)
+                report_to_response(report.attach(hash_status::StatusCode::Cancelled))
             } else {
-                report
+                report_to_response(report)
             }
         })
+        .map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/corrections",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The metadata of the corrected editions", body = [EntityMetadata]),
+        (status = 400, content_type = "text/plain", description = "The decision time interval is empty or a corrected edition is invalid"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "Insufficient permissions to update the entity"),
+        (status = 404, description = "The entity does not exist within the decision time interval"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = CorrectEntityParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn correct_entity<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<CorrectEntityParams>,
+) -> Result<Json<Vec<EntityMetadata>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let mut store = store_pool.acquire().await.map_err(report_to_response)?;
+    let mut authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .correct_entity(
+            actor_id,
+            &mut authorization_api,
+            temporal_client.as_deref(),
+            params,
+        )
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/merge",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The metadata of the new edition of the surviving entity", body = EntityMetadata),
+        (status = 400, content_type = "text/plain", description = "The entities cannot be merged or the merged entity is invalid"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "Insufficient permissions to update the entities or the links pointing to the duplicate"),
+        (status = 404, description = "One of the entities does not exist"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = MergeEntitiesParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn merge_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<MergeEntitiesParams>,
+) -> Result<Json<EntityMetadata>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let mut store = store_pool.acquire().await.map_err(report_to_response)?;
+    let mut authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .merge_entities(
+            actor_id,
+            &mut authorization_api,
+            temporal_client.as_deref(),
+            params,
+        )
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(untagged)]
+enum MigrateEntitiesResponseItem {
+    #[serde(rename_all = "camelCase")]
+    Migrated {
+        entity_id: EntityId,
+        metadata: Box<EntityMetadata>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Valid { entity_id: EntityId },
+    #[serde(rename_all = "camelCase")]
+    Failed {
+        entity_id: EntityId,
+        #[schema(value_type = Object)]
+        error: Status<Report<UpdateError>>,
+    },
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/migrate",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The result of the migration of each entity. In a dry run, entities which could be migrated are reported as valid", body = [MigrateEntitiesResponseItem]),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "Insufficient permissions to instantiate the target entity type"),
+        (status = 404, description = "The target entity type does not exist"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = MigrateEntitiesParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn migrate_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<MigrateEntitiesParams>,
+) -> Result<Json<Vec<MigrateEntitiesResponseItem>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let mut store = store_pool.acquire().await.map_err(report_to_response)?;
+    let mut authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .migrate_entities(
+            actor_id,
+            &mut authorization_api,
+            temporal_client.as_deref(),
+            params,
+        )
+        .await
+        .map_err(report_to_response)
+        .map(|migrations| {
+            Json(
+                migrations
+                    .into_iter()
+                    .map(|migration| match migration.result {
+                        Ok(Some(metadata)) => MigrateEntitiesResponseItem::Migrated {
+                            entity_id: migration.entity_id,
+                            metadata: Box::new(metadata),
+                        },
+                        Ok(None) => MigrateEntitiesResponseItem::Valid {
+                            entity_id: migration.entity_id,
+                        },
+                        Err(report) => MigrateEntitiesResponseItem::Failed {
+                            entity_id: migration.entity_id,
+                            error: report_to_status(report),
+                        },
+                    })
+                    .collect(),
+            )
+        })
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/move-link",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The new position of the link", body = LinkPosition),
+        (status = 400, content_type = "text/plain", description = "The entity is not a link, the sibling is not ordered like the link, or the link type is not ordered"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "Insufficient permissions to update the link"),
+        (status = 404, description = "The link or the sibling does not exist"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = MoveLinkParams,
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn move_link<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    Json(params): Json<MoveLinkParams>,
+) -> Result<Json<LinkPosition>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let mut store = store_pool.acquire().await.map_err(report_to_response)?;
+    let mut authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .move_link(actor_id, &mut authorization_api, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/entities",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The IDs of all deleted entities including deleted links", body = [EntityId]),
+        (status = 400, content_type = "text/plain", description = "The entities are still linked and deletion was not cascaded"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "The actor does not have full access to the entities or does not administrate their webs"),
+        (status = 404, description = "Entity ID was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = DeleteEntitiesParams,
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn delete_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    Json(params): Json<DeleteEntitiesParams>,
+) -> Result<Json<Vec<EntityId>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let mut store = store_pool.acquire().await.map_err(report_to_response)?;
+    let mut authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .delete_entities(actor_id, &mut authorization_api, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    get,
+    path = "/entities/{entity_id}/history",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("entity_id" = EntityId, Path, description = "The ID of the entity to read the editions of"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The metadata of every edition of the entity ordered by transaction and decision time", body = [EntityMetadata]),
+
+        (status = 403, description = "Insufficient permissions to view the entity"),
+        (status = 404, description = "Entity ID was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn get_entity_history<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    Path(entity_id): Path<EntityId>,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+) -> Result<Json<Vec<EntityMetadata>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let store = store_pool.acquire().await.map_err(report_to_response)?;
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .get_entity_history(actor_id, &authorization_api, entity_id)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    get,
+    path = "/entities/{entity_id}/drafts",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("entity_id" = EntityId, Path, description = "The ID of the entity to read the drafts of"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The current edition of every unpublished draft of the entity", body = [EntityDraft]),
+
+        (status = 403, description = "Insufficient permissions to view the entity"),
+        (status = 500, description = "Store error occurred"),
+    ),
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn get_entity_drafts<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    Path(entity_id): Path<EntityId>,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+) -> Result<Json<Vec<EntityDraft>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let store = store_pool.acquire().await.map_err(report_to_response)?;
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .get_entity_drafts(actor_id, &authorization_api, entity_id)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    get,
+    path = "/entities/{entity_id}/draft-diff",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("entity_id" = EntityId, Path, description = "The ID of the draft to compare to the live entity"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The changes publishing the draft would make to the live entity", body = DiffEntityDraftResult),
+        (status = 400, content_type = "text/plain", description = "The entity ID does not refer to a draft"),
+
+        (status = 403, description = "Insufficient permissions to view the entity"),
+        (status = 404, description = "The draft or the live entity was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn diff_entity_draft<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    Path(entity_id): Path<EntityId>,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+) -> Result<Json<DiffEntityDraftResult<'static>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let store = store_pool.acquire().await.map_err(report_to_response)?;
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .diff_entity_draft(actor_id, &authorization_api, entity_id)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+/// The time to wait before polling for new changes if the change feed is exhausted.
+const ENTITY_CHANGES_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// The maximum number of changes read from the store at once.
+const ENTITY_CHANGES_BATCH_SIZE: usize = 100;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct GetEntityChangesQuery {
+    after: Option<String>,
+    filter: Option<String>,
+}
+
+/// Reads changes from the store and buffers them until they are sent to the client.
+struct EntityChangeFeed<S, A> {
+    store_pool: Arc<S>,
+    authorization_api_pool: Arc<A>,
+    actor_id: AccountId,
+    // The filter borrows from its source, so it's stored as JSON and deserialized for every read.
+    filter: serde_json::Value,
+    cursor: Option<EntityChangeCursor>,
+    pending: VecDeque<EntityChange>,
+}
+
+impl<S, A> EntityChangeFeed<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    /// Reads the next batch of changes after the current cursor.
+    ///
+    /// Returns `false` if no changes were committed after the cursor.
+    async fn read_changes(&mut self) -> Result<bool, Report<QueryError>> {
+        let store = self.store_pool.acquire().await.change_context(QueryError)?;
+        let authorization_api = self
+            .authorization_api_pool
+            .acquire()
+            .await
+            .change_context(QueryError)?;
+
+        let mut filter = Filter::<Entity>::deserialize(&self.filter).change_context(QueryError)?;
+        filter.convert_parameters().change_context(QueryError)?;
+
+        let (changes, cursor) = store
+            .get_entity_changes(
+                self.actor_id,
+                &authorization_api,
+                GetEntityChangesParams {
+                    filter,
+                    after: self.cursor,
+                    limit: ENTITY_CHANGES_BATCH_SIZE,
+                },
+            )
+            .await?;
+
+        let Some(cursor) = cursor else {
+            return Ok(false);
+        };
+        self.cursor = Some(cursor);
+        self.pending.extend(changes);
+        Ok(true)
+    }
+
+    /// Returns the next change, waiting for new changes to be committed if necessary.
+    async fn next_change(&mut self) -> Result<EntityChange, Report<QueryError>> {
+        loop {
+            if let Some(change) = self.pending.pop_front() {
+                return Ok(change);
+            }
+
+            if !self.read_changes().await? {
+                tokio::time::sleep(ENTITY_CHANGES_POLL_INTERVAL).await;
+            }
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/entities/changes",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("Last-Event-ID" = Option<String>, Header, description = "The ID of the last received event to continue the feed after"),
+        ("after" = Option<String>, Query, description = "The cursor to continue the feed after. Takes precedence over `Last-Event-ID`"),
+        ("filter" = Option<String>, Query, description = "A JSON encoded `Filter` which the changed entities have to match"),
+    ),
+    responses(
+        (status = 200, content_type = "text/event-stream", description = "A stream of changes made to entities. Every event contains one change as JSON and uses its cursor as event ID", body = EntityChange),
+        (status = 422, content_type = "text/plain", description = "Provided cursor or filter is invalid"),
+        (status = 500, description = "Store error occurred"),
+    ),
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool, headers))]
+async fn get_entity_changes<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    headers: HeaderMap,
+    Query(query): Query<GetEntityChangesQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, Response>
+where
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
+{
+    let cursor = query
+        .after
+        .as_deref()
+        .or_else(|| {
+            headers
+                .get("Last-Event-ID")
+                .and_then(|value| value.to_str().ok())
+        })
+        .map(str::parse::<EntityChangeCursor>)
+        .transpose()
+        .map_err(|error| {
+            report_to_response(Report::new(error).attach(hash_status::StatusCode::InvalidArgument))
+        })?;
+
+    let filter = query.filter.map_or_else(
+        || Ok(serde_json::json!({ "all": [] })),
+        |filter| serde_json::from_str(&filter),
+    );
+    let filter = filter.map_err(|error| {
+        report_to_response(Report::new(error).attach(hash_status::StatusCode::InvalidArgument))
+    })?;
+    // Validate the filter once, so an invalid filter is reported before the stream starts.
+    Filter::<Entity>::deserialize(&filter)
+        .map_err(|error| {
+            report_to_response(Report::new(error).attach(hash_status::StatusCode::InvalidArgument))
+        })?
+        .convert_parameters()
+        .map_err(|report| {
+            report_to_response(report.attach(hash_status::StatusCode::InvalidArgument))
+        })?;
+
+    let mut feed = EntityChangeFeed {
+        store_pool: store_pool.0,
+        authorization_api_pool: authorization_api_pool.0,
+        actor_id,
+        filter,
+        cursor,
+        pending: VecDeque::new(),
+    };
+    // The first batch is read before the stream starts, so errors are returned as a response
+    // instead of silently ending the stream.
+    feed.read_changes().await.map_err(report_to_response)?;
+
+    let stream = stream::unfold(Some(feed), |feed| async move {
+        let mut feed = feed?;
+        match feed.next_change().await {
+            Ok(change) => Some((
+                Event::default()
+                    .id(change.cursor.to_string())
+                    .json_data(&change),
+                Some(feed),
+            )),
+            Err(report) => {
+                // Ending the stream lets the client reconnect with the last received event ID.
+                tracing::error!(error = ?report, "Could not read entity changes");
+                None
+            }
+        }
+    });
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/diff",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The differences between the two editions", body = DiffEntityResult),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "Insufficient permissions to view one of the entities"),
+        (status = 404, description = "One of the editions was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = DiffEntityParams,
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn diff_entity<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    Json(params): Json<DiffEntityParams>,
+) -> Result<Json<DiffEntityResult<'static>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let store = store_pool.acquire().await.map_err(report_to_response)?;
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .diff_entity(actor_id, &authorization_api, params)
+        .await
         .map_err(report_to_response)
         .map(Json)
 }
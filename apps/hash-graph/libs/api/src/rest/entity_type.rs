@@ -17,7 +17,7 @@ use authorization::{
 use axum::{
     extract::{OriginalUri, Path, Query},
     http::{header::LINK, HeaderMap, StatusCode},
-    response::Response,
+    response::{IntoResponse, Response},
     routing::{get, post, put},
     Extension, Router,
 };
@@ -28,19 +28,29 @@ use graph::{
         patch_id_and_parse, EntityTypeQueryToken,
     },
     store::{
-        error::{BaseUrlAlreadyExists, OntologyVersionDoesNotExist, VersionedUrlAlreadyExists},
+        error::{
+            BaseUrlAlreadyExists, BreakingOntologyTypeChanges, OntologyVersionDoesNotExist,
+            VersionedUrlAlreadyExists,
+        },
         ontology::{
             ArchiveEntityTypeParams, CreateEntityTypeParams, GetEntityTypesParams,
             UnarchiveEntityTypeParams, UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams,
         },
+        query::Filter,
         ConflictBehavior, EntityTypeStore, StorePool,
     },
     subgraph::{
+        edges::GraphResolveDepths,
         identifier::EntityTypeVertexId,
         query::{EntityTypeStructuralQuery, StructuralQuery},
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
+            VariableTemporalAxisUnresolved,
+        },
     },
 };
 use graph_types::{
+    account::AccountId,
     ontology::{
         EntityTypeEmbedding, EntityTypeMetadata, EntityTypeWithMetadata, OntologyTemporalMetadata,
         OntologyTypeClassificationMetadata, OntologyTypeMetadata, OntologyTypeReference,
@@ -51,6 +61,7 @@ use graph_types::{
 use hash_map::HashMap;
 use serde::{Deserialize, Serialize};
 use temporal_client::TemporalClient;
+use temporal_versioning::TemporalBound;
 use time::OffsetDateTime;
 use type_system::{
     url::{BaseUrl, OntologyTypeVersion, VersionedUrl},
@@ -65,7 +76,8 @@ use crate::{
         json::Json,
         status::{report_to_response, status_to_response},
         utoipa_typedef::{subgraph::Subgraph, ListOrValue, MaybeListOfEntityType},
-        AuthenticatedUserHeader, Cursor, Pagination, PermissionResponse, RestApiStore,
+        AuthenticatedUserHeader, Cursor, OntologyTypeUpdatePolicy, OntologyTypeUpdatePreview,
+        Pagination, PermissionResponse, RestApiStore,
     },
 };
 
@@ -80,6 +92,7 @@ use crate::{
         load_external_entity_type,
         get_entity_types_by_query,
         update_entity_type,
+        preview_entity_type_update,
         update_entity_type_embeddings,
         archive_entity_type,
         unarchive_entity_type,
@@ -102,6 +115,7 @@ use crate::{
             CreateEntityTypeRequest,
             LoadExternalEntityTypeRequest,
             UpdateEntityTypeRequest,
+            PreviewEntityTypeUpdateRequest,
             UpdateEntityTypeEmbeddingParams,
             EntityTypeQueryToken,
             EntityTypeStructuralQuery,
@@ -149,6 +163,7 @@ impl RoutedResource for EntityTypeResource {
                 )
                 .route("/query", post(get_entity_types_by_query::<S, A>))
                 .route("/load", post(load_external_entity_type::<S, A>))
+                .route("/preview-update", post(preview_entity_type_update::<S, A>))
                 .route("/archive", put(archive_entity_type::<S, A>))
                 .route("/unarchive", put(unarchive_entity_type::<S, A>))
                 .route("/embeddings", post(update_entity_type_embeddings::<S, A>)),
@@ -724,7 +739,7 @@ where
         .convert_parameters()
         .map_err(report_to_response)?;
 
-    let subgraph = store
+    let (subgraph, cursor) = store
         .get_entity_type(
             actor_id,
             &authorization_api,
@@ -737,10 +752,9 @@ where
         .await
         .map_err(report_to_response)?;
 
-    let cursor = subgraph.roots.iter().last().map(Cursor);
     let mut headers = HeaderMap::new();
     if let (Some(cursor), Some(limit)) = (cursor, pagination.limit) {
-        headers.insert(LINK, cursor.link_header("next", uri, limit)?);
+        headers.insert(LINK, Cursor(cursor).link_header("next", uri, limit)?);
     }
     Ok((headers, Json(Subgraph::from(subgraph))))
 }
@@ -756,6 +770,9 @@ struct UpdateEntityTypeRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     icon: Option<String>,
     relationships: Vec<EntityTypeRelationAndSubject>,
+    /// Applies the update even if it contains breaking changes.
+    #[serde(default)]
+    force: bool,
     #[serde(
         default,
         skip_serializing_if = "ProvidedOntologyEditionProvenanceMetadata::is_empty"
@@ -777,6 +794,7 @@ struct UpdateEntityTypeRequest {
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
         (status = 404, description = "Base entity type ID was not found"),
+        (status = 409, content_type = "application/json", description = "The update contains breaking changes and was not forced. The breaking changes are part of the response", body = OntologyTypeUpdatePreview),
         (status = 500, description = "Store error occurred"),
     ),
     request_body = UpdateEntityTypeRequest,
@@ -787,8 +805,9 @@ async fn update_entity_type<S, A>(
     store_pool: Extension<Arc<S>>,
     authorization_api_pool: Extension<Arc<A>>,
     temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    ontology_type_update_policy: Extension<OntologyTypeUpdatePolicy>,
     body: Json<UpdateEntityTypeRequest>,
-) -> Result<Json<EntityTypeMetadata>, StatusCode>
+) -> Result<Json<EntityTypeMetadata>, Response>
 where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
@@ -799,6 +818,7 @@ where
         label_property,
         icon,
         relationships,
+        force,
         provenance,
     }) = body;
 
@@ -807,19 +827,19 @@ where
     let entity_type = patch_id_and_parse(&type_to_update, schema).map_err(|report| {
         tracing::error!(error=?report, "Couldn't convert schema to Entity Type");
         // Shame there isn't an UNPROCESSABLE_ENTITY_TYPE code :D
-        StatusCode::UNPROCESSABLE_ENTITY
+        StatusCode::UNPROCESSABLE_ENTITY.into_response()
         // TODO - We should probably return more information to the client
         //  https://app.asana.com/0/1201095311341924/1202574350052904/f
     })?;
 
     let mut store = store_pool.acquire().await.map_err(|report| {
         tracing::error!(error=?report, "Could not acquire store");
-        StatusCode::INTERNAL_SERVER_ERROR
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
     })?;
 
     let mut authorization_api = authorization_api_pool.acquire().await.map_err(|error| {
         tracing::error!(?error, "Could not acquire access to the authorization API");
-        StatusCode::INTERNAL_SERVER_ERROR
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
     })?;
 
     store
@@ -832,6 +852,7 @@ where
                 label_property,
                 icon,
                 relationships,
+                reject_breaking_changes: ontology_type_update_policy.checks_changes(force),
                 provenance,
             },
         )
@@ -839,19 +860,143 @@ where
         .map_err(|report| {
             tracing::error!(error=?report, "Could not update entity type");
 
+            if let Some(breaking_changes) = report.downcast_ref::<BreakingOntologyTypeChanges>() {
+                // The breaking changes are returned, so the client can decide whether to force the
+                // update.
+                return (
+                    StatusCode::CONFLICT,
+                    Json(OntologyTypeUpdatePreview::from(
+                        breaking_changes.changes.clone(),
+                    )),
+                )
+                    .into_response();
+            }
+
             if report.contains::<PermissionAssertion>() {
-                return StatusCode::FORBIDDEN;
+                return StatusCode::FORBIDDEN.into_response();
             }
             if report.contains::<OntologyVersionDoesNotExist>() {
-                return StatusCode::NOT_FOUND;
+                return StatusCode::NOT_FOUND.into_response();
             }
 
             // Insertion/update errors are considered internal server errors.
-            StatusCode::INTERNAL_SERVER_ERROR
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
         })
         .map(Json)
 }
 
+async fn read_entity_type<S, A>(
+    store: &S,
+    authorization_api: &A,
+    actor_id: AccountId,
+    entity_type_id: &VersionedUrl,
+) -> Result<EntityType, StatusCode>
+where
+    S: EntityTypeStore + Sync,
+    A: AuthorizationApi + Sync,
+{
+    store
+        .get_entity_type(
+            actor_id,
+            authorization_api,
+            GetEntityTypesParams {
+                query: StructuralQuery {
+                    filter: Filter::for_versioned_url(entity_type_id),
+                    graph_resolve_depths: GraphResolveDepths::default(),
+                    temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                        pinned: PinnedTemporalAxisUnresolved::new(None),
+                        variable: VariableTemporalAxisUnresolved::new(
+                            Some(TemporalBound::Unbounded),
+                            None,
+                        ),
+                    },
+                    include_drafts: false,
+                },
+                after: None,
+                limit: None,
+            },
+        )
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not read entity type");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .0
+        .vertices
+        .entity_types
+        .remove(&EntityTypeVertexId::from(entity_type_id.clone()))
+        .map(|entity_type| entity_type.schema)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct PreviewEntityTypeUpdateRequest {
+    #[schema(value_type = VAR_UPDATE_ENTITY_TYPE)]
+    schema: serde_json::Value,
+    type_to_update: VersionedUrl,
+}
+
+#[utoipa::path(
+    post,
+    path = "/entity-types/preview-update",
+    tag = "EntityType",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The changes the update would introduce", body = OntologyTypeUpdatePreview),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 404, description = "Base entity type ID was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = PreviewEntityTypeUpdateRequest,
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn preview_entity_type_update<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    body: Json<PreviewEntityTypeUpdateRequest>,
+) -> Result<Json<OntologyTypeUpdatePreview>, StatusCode>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let Json(PreviewEntityTypeUpdateRequest {
+        schema,
+        type_to_update,
+    }) = body;
+
+    let updated_entity_type_id = VersionedUrl {
+        base_url: type_to_update.base_url.clone(),
+        version: OntologyTypeVersion::new(type_to_update.version.inner() + 1),
+    };
+    let entity_type: EntityType =
+        patch_id_and_parse(&updated_entity_type_id, schema).map_err(|report| {
+            tracing::error!(error=?report, "Couldn't patch schema and convert to Entity Type");
+            StatusCode::UNPROCESSABLE_ENTITY
+        })?;
+
+    let store = store_pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let authorization_api = authorization_api_pool.acquire().await.map_err(|error| {
+        tracing::error!(?error, "Could not acquire access to the authorization API");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let previous_entity_type =
+        read_entity_type(&store, &authorization_api, actor_id, &type_to_update).await?;
+
+    Ok(Json(OntologyTypeUpdatePreview::from(
+        previous_entity_type.changes_to(&entity_type),
+    )))
+}
+
 #[utoipa::path(
     post,
     path = "/entity-types/embeddings",
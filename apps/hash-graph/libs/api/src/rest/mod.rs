@@ -33,7 +33,7 @@ use base64::Engine;
 use error_stack::{Report, ResultExt};
 use graph::{
     ontology::{domain_validator::DomainValidator, Selector},
-    store::{error::VersionedUrlAlreadyExists, Store, StorePool, TypeFetcher},
+    store::{error::VersionedUrlAlreadyExists, query::LinkPathHop, Store, StorePool, TypeFetcher},
     subgraph::{
         edges::{
             EdgeResolveDepths, GraphResolveDepths, KnowledgeGraphEdgeKind, OntologyEdgeKind,
@@ -69,7 +69,10 @@ use temporal_versioning::{
     ClosedTemporalBound, DecisionTime, LeftClosedTemporalInterval, LimitedTemporalBound,
     OpenTemporalBound, RightBoundedTemporalInterval, TemporalBound, Timestamp, TransactionTime,
 };
-use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+use type_system::{
+    url::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+    Compatibility, JsonSchemaValueType, OntologyTypeChange, OntologyTypeChangeKind,
+};
 use utoipa::{
     openapi::{
         self, schema, ArrayBuilder, KnownFormat, Object, ObjectBuilder, OneOfBuilder, Ref, RefOr,
@@ -122,6 +125,39 @@ pub struct PermissionResponse {
     has_permission: bool,
 }
 
+/// Whether updates of ontology types may introduce changes which are not backwards compatible.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OntologyTypeUpdatePolicy {
+    /// Updates are applied regardless of their changes.
+    AllowBreakingChanges,
+    /// Updates with breaking changes are rejected unless they are forced.
+    RejectBreakingChanges,
+}
+
+impl OntologyTypeUpdatePolicy {
+    /// Returns if the changes of an update have to be checked before it's applied.
+    const fn checks_changes(self, force: bool) -> bool {
+        matches!(self, Self::RejectBreakingChanges) && !force
+    }
+}
+
+/// The changes an update of an ontology type would introduce compared to the type it updates.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OntologyTypeUpdatePreview {
+    changes: Vec<OntologyTypeChange>,
+    breaking: bool,
+}
+
+impl From<Vec<OntologyTypeChange>> for OntologyTypeUpdatePreview {
+    fn from(changes: Vec<OntologyTypeChange>) -> Self {
+        Self {
+            breaking: changes.iter().any(OntologyTypeChange::is_breaking),
+            changes,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Cursor<T>(pub T);
 
@@ -270,6 +306,7 @@ where
     pub authorization_api: Arc<A>,
     pub temporal_client: Option<TemporalClient>,
     pub domain_regex: DomainValidator,
+    pub ontology_type_update_policy: OntologyTypeUpdatePolicy,
 }
 
 /// A [`Router`] that only serves the `OpenAPI` specification (JSON, and necessary subschemas) for
@@ -307,6 +344,7 @@ where
         .layer(Extension(dependencies.authorization_api))
         .layer(Extension(dependencies.temporal_client.map(Arc::new)))
         .layer(Extension(dependencies.domain_regex))
+        .layer(Extension(dependencies.ontology_type_update_policy))
         .layer(span_trace_layer())
         .merge(openapi_only_router())
 }
@@ -344,6 +382,11 @@ async fn serve_static_schema(Path(path): Path<String>) -> Result<Response, Statu
     components(
         schemas(
             PermissionResponse,
+            OntologyTypeUpdatePreview,
+            OntologyTypeChange,
+            OntologyTypeChangeKind,
+            Compatibility,
+            JsonSchemaValueType,
 
             BaseUrl,
             VersionedUrl,
@@ -659,6 +702,66 @@ impl Modify for FilterSchemaAddon {
                                 )
                                 .required("notEqual"),
                         )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("GreaterFilter"))
+                                .property(
+                                    "greater",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("greater"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("GreaterOrEqualFilter"))
+                                .property(
+                                    "greaterOrEqual",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("greaterOrEqual"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("LessFilter"))
+                                .property(
+                                    "less",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("less"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("LessOrEqualFilter"))
+                                .property(
+                                    "lessOrEqual",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("lessOrEqual"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("BetweenFilter"))
+                                .property(
+                                    "between",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(3))
+                                        .max_items(Some(3)),
+                                )
+                                .required("between"),
+                        )
                         .item(
                             ObjectBuilder::new()
                                 .title(Some("CosineDistanceFilter"))
@@ -707,10 +810,60 @@ impl Modify for FilterSchemaAddon {
                                 )
                                 .required("containsSegment"),
                         )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("MatchesFilter"))
+                                .property(
+                                    "matches",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("matches"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("LinkPathFilter"))
+                                .property("linkPath", Ref::from_schema_name("LinkPath"))
+                                .required("linkPath"),
+                        )
                         .build(),
                 )
                 .into(),
             );
+            components.schemas.insert(
+                "LinkPath".to_owned(),
+                ObjectBuilder::new()
+                    .property(
+                        "hops",
+                        ArrayBuilder::new().items(Ref::from_schema_name("LinkPathHop")),
+                    )
+                    .required("hops")
+                    .property("filter", Ref::from_schema_name("Filter"))
+                    .into(),
+            );
+            components.schemas.insert(
+                "LinkPathHop".to_owned(),
+                ObjectBuilder::new()
+                    .property(
+                        "direction",
+                        ObjectBuilder::new()
+                            .schema_type(SchemaType::String)
+                            .enum_values(Some(["outgoing", "incoming"])),
+                    )
+                    .required("direction")
+                    .property("linkFilter", Ref::from_schema_name("Filter"))
+                    .property("entityFilter", Ref::from_schema_name("Filter"))
+                    .property(
+                        "maxDepth",
+                        ObjectBuilder::new()
+                            .schema_type(SchemaType::Integer)
+                            .minimum(Some(1.0))
+                            .maximum(Some(f64::from(LinkPathHop::MAX_DEPTH))),
+                    )
+                    .into(),
+            );
             components.schemas.insert(
                 "FilterExpression".to_owned(),
                 schema::Schema::OneOf(
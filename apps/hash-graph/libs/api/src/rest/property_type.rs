@@ -17,7 +17,7 @@ use authorization::{
 use axum::{
     extract::{OriginalUri, Path, Query},
     http::{header::LINK, HeaderMap, StatusCode},
-    response::Response,
+    response::{IntoResponse, Response},
     routing::{get, post, put},
     Extension, Router,
 };
@@ -28,21 +28,28 @@ use graph::{
         patch_id_and_parse, PropertyTypeQueryToken,
     },
     store::{
-        error::VersionedUrlAlreadyExists,
+        error::{BreakingOntologyTypeChanges, VersionedUrlAlreadyExists},
         ontology::{
             ArchivePropertyTypeParams, CreatePropertyTypeParams, GetPropertyTypesParams,
             UnarchivePropertyTypeParams, UpdatePropertyTypeEmbeddingParams,
             UpdatePropertyTypesParams,
         },
+        query::Filter,
         BaseUrlAlreadyExists, ConflictBehavior, OntologyVersionDoesNotExist, PropertyTypeStore,
         StorePool,
     },
     subgraph::{
+        edges::GraphResolveDepths,
         identifier::PropertyTypeVertexId,
         query::{PropertyTypeStructuralQuery, StructuralQuery},
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
+            VariableTemporalAxisUnresolved,
+        },
     },
 };
 use graph_types::{
+    account::AccountId,
     ontology::{
         OntologyTemporalMetadata, OntologyTypeClassificationMetadata, OntologyTypeMetadata,
         OntologyTypeReference, PropertyTypeEmbedding, PropertyTypeMetadata,
@@ -53,6 +60,7 @@ use graph_types::{
 use hash_status::Status;
 use serde::{Deserialize, Serialize};
 use temporal_client::TemporalClient;
+use temporal_versioning::TemporalBound;
 use time::OffsetDateTime;
 use type_system::{
     url::{OntologyTypeVersion, VersionedUrl},
@@ -65,7 +73,8 @@ use crate::rest::{
     json::Json,
     status::{report_to_response, status_to_response},
     utoipa_typedef::{subgraph::Subgraph, ListOrValue, MaybeListOfPropertyType},
-    AuthenticatedUserHeader, Cursor, Pagination, PermissionResponse, RestApiStore,
+    AuthenticatedUserHeader, Cursor, OntologyTypeUpdatePolicy, OntologyTypeUpdatePreview,
+    Pagination, PermissionResponse, RestApiStore,
 };
 
 #[derive(OpenApi)]
@@ -79,6 +88,7 @@ use crate::rest::{
         load_external_property_type,
         get_property_types_by_query,
         update_property_type,
+        preview_property_type_update,
         update_property_type_embeddings,
         archive_property_type,
         unarchive_property_type,
@@ -100,6 +110,7 @@ use crate::rest::{
             CreatePropertyTypeRequest,
             LoadExternalPropertyTypeRequest,
             UpdatePropertyTypeRequest,
+            PreviewPropertyTypeUpdateRequest,
             UpdatePropertyTypeEmbeddingParams,
             PropertyTypeQueryToken,
             PropertyTypeStructuralQuery,
@@ -147,6 +158,10 @@ impl RoutedResource for PropertyTypeResource {
                 )
                 .route("/query", post(get_property_types_by_query::<S, A>))
                 .route("/load", post(load_external_property_type::<S, A>))
+                .route(
+                    "/preview-update",
+                    post(preview_property_type_update::<S, A>),
+                )
                 .route("/archive", put(archive_property_type::<S, A>))
                 .route("/unarchive", put(unarchive_property_type::<S, A>))
                 .route("/embeddings", post(update_property_type_embeddings::<S, A>)),
@@ -425,7 +440,7 @@ where
         .filter
         .convert_parameters()
         .map_err(report_to_response)?;
-    let subgraph = store
+    let (subgraph, cursor) = store
         .get_property_type(
             actor_id,
             &authorization_api,
@@ -438,10 +453,9 @@ where
         .await
         .map_err(report_to_response)?;
 
-    let cursor = subgraph.roots.iter().last().map(Cursor);
     let mut headers = HeaderMap::new();
     if let (Some(cursor), Some(limit)) = (cursor, pagination.limit) {
-        headers.insert(LINK, cursor.link_header("next", uri, limit)?);
+        headers.insert(LINK, Cursor(cursor).link_header("next", uri, limit)?);
     }
     Ok((headers, Json(Subgraph::from(subgraph))))
 }
@@ -453,6 +467,9 @@ struct UpdatePropertyTypeRequest {
     schema: serde_json::Value,
     type_to_update: VersionedUrl,
     relationships: Vec<PropertyTypeRelationAndSubject>,
+    /// Applies the update even if it contains breaking changes.
+    #[serde(default)]
+    force: bool,
     #[serde(
         default,
         skip_serializing_if = "ProvidedOntologyEditionProvenanceMetadata::is_empty"
@@ -474,6 +491,7 @@ struct UpdatePropertyTypeRequest {
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
         (status = 404, description = "Base property type ID was not found"),
+        (status = 409, content_type = "application/json", description = "The update contains breaking changes and was not forced. The breaking changes are part of the response", body = OntologyTypeUpdatePreview),
         (status = 500, description = "Store error occurred"),
     ),
     request_body = UpdatePropertyTypeRequest,
@@ -484,8 +502,9 @@ async fn update_property_type<S, A>(
     store_pool: Extension<Arc<S>>,
     authorization_api_pool: Extension<Arc<A>>,
     temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    ontology_type_update_policy: Extension<OntologyTypeUpdatePolicy>,
     body: Json<UpdatePropertyTypeRequest>,
-) -> Result<Json<PropertyTypeMetadata>, StatusCode>
+) -> Result<Json<PropertyTypeMetadata>, Response>
 where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
@@ -494,6 +513,7 @@ where
         schema,
         mut type_to_update,
         relationships,
+        force,
         provenance,
     }) = body;
 
@@ -501,19 +521,19 @@ where
 
     let property_type = patch_id_and_parse(&type_to_update, schema).map_err(|report| {
         tracing::error!(error=?report, "Couldn't patch schema and convert to Property Type");
-        StatusCode::UNPROCESSABLE_ENTITY
+        StatusCode::UNPROCESSABLE_ENTITY.into_response()
         // TODO - We should probably return more information to the client
         //  https://app.asana.com/0/1201095311341924/1202574350052904/f
     })?;
 
     let mut store = store_pool.acquire().await.map_err(|report| {
         tracing::error!(error=?report, "Could not acquire store");
-        StatusCode::INTERNAL_SERVER_ERROR
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
     })?;
 
     let mut authorization_api = authorization_api_pool.acquire().await.map_err(|error| {
         tracing::error!(?error, "Could not acquire access to the authorization API");
-        StatusCode::INTERNAL_SERVER_ERROR
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
     })?;
 
     store
@@ -524,6 +544,7 @@ where
             UpdatePropertyTypesParams {
                 schema: property_type,
                 relationships,
+                reject_breaking_changes: ontology_type_update_policy.checks_changes(force),
                 provenance,
             },
         )
@@ -531,19 +552,143 @@ where
         .map_err(|report| {
             tracing::error!(error=?report, "Could not update property type");
 
+            if let Some(breaking_changes) = report.downcast_ref::<BreakingOntologyTypeChanges>() {
+                // The breaking changes are returned, so the client can decide whether to force the
+                // update.
+                return (
+                    StatusCode::CONFLICT,
+                    Json(OntologyTypeUpdatePreview::from(
+                        breaking_changes.changes.clone(),
+                    )),
+                )
+                    .into_response();
+            }
+
             if report.contains::<PermissionAssertion>() {
-                return StatusCode::FORBIDDEN;
+                return StatusCode::FORBIDDEN.into_response();
             }
             if report.contains::<OntologyVersionDoesNotExist>() {
-                return StatusCode::NOT_FOUND;
+                return StatusCode::NOT_FOUND.into_response();
             }
 
             // Insertion/update errors are considered internal server errors.
-            StatusCode::INTERNAL_SERVER_ERROR
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
         })
         .map(Json)
 }
 
+async fn read_property_type<S, A>(
+    store: &S,
+    authorization_api: &A,
+    actor_id: AccountId,
+    property_type_id: &VersionedUrl,
+) -> Result<PropertyType, StatusCode>
+where
+    S: PropertyTypeStore + Sync,
+    A: AuthorizationApi + Sync,
+{
+    store
+        .get_property_type(
+            actor_id,
+            authorization_api,
+            GetPropertyTypesParams {
+                query: StructuralQuery {
+                    filter: Filter::for_versioned_url(property_type_id),
+                    graph_resolve_depths: GraphResolveDepths::default(),
+                    temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                        pinned: PinnedTemporalAxisUnresolved::new(None),
+                        variable: VariableTemporalAxisUnresolved::new(
+                            Some(TemporalBound::Unbounded),
+                            None,
+                        ),
+                    },
+                    include_drafts: false,
+                },
+                after: None,
+                limit: None,
+            },
+        )
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not read property type");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .0
+        .vertices
+        .property_types
+        .remove(&PropertyTypeVertexId::from(property_type_id.clone()))
+        .map(|property_type| property_type.schema)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct PreviewPropertyTypeUpdateRequest {
+    #[schema(value_type = VAR_UPDATE_PROPERTY_TYPE)]
+    schema: serde_json::Value,
+    type_to_update: VersionedUrl,
+}
+
+#[utoipa::path(
+    post,
+    path = "/property-types/preview-update",
+    tag = "PropertyType",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The changes the update would introduce", body = OntologyTypeUpdatePreview),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 404, description = "Base property type ID was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = PreviewPropertyTypeUpdateRequest,
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn preview_property_type_update<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    body: Json<PreviewPropertyTypeUpdateRequest>,
+) -> Result<Json<OntologyTypeUpdatePreview>, StatusCode>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let Json(PreviewPropertyTypeUpdateRequest {
+        schema,
+        type_to_update,
+    }) = body;
+
+    let updated_property_type_id = VersionedUrl {
+        base_url: type_to_update.base_url.clone(),
+        version: OntologyTypeVersion::new(type_to_update.version.inner() + 1),
+    };
+    let property_type: PropertyType = patch_id_and_parse(&updated_property_type_id, schema)
+        .map_err(|report| {
+            tracing::error!(error=?report, "Couldn't patch schema and convert to Property Type");
+            StatusCode::UNPROCESSABLE_ENTITY
+        })?;
+
+    let store = store_pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let authorization_api = authorization_api_pool.acquire().await.map_err(|error| {
+        tracing::error!(?error, "Could not acquire access to the authorization API");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let previous_property_type =
+        read_property_type(&store, &authorization_api, actor_id, &type_to_update).await?;
+
+    Ok(Json(OntologyTypeUpdatePreview::from(
+        previous_property_type.changes_to(&property_type),
+    )))
+}
+
 #[utoipa::path(
     post,
     path = "/property-types/embeddings",
@@ -19,7 +19,7 @@ where
     response
 }
 
-pub(crate) fn report_to_response<C>(report: impl Into<Report<C>>) -> Response
+pub(crate) fn report_to_status<C>(report: impl Into<Report<C>>) -> Status<Report<C>>
 where
     C: Context,
 {
@@ -35,9 +35,12 @@ where
     // hash_tracing::sentry::capture_report(&report);
     tracing::error!(error = ?report, tags.code = ?status_code.to_http_code());
 
-    status_to_response(Status::new(
-        status_code,
-        Some(report.to_string()),
-        vec![report],
-    ))
+    Status::new(status_code, Some(report.to_string()), vec![report])
+}
+
+pub(crate) fn report_to_response<C>(report: impl Into<Report<C>>) -> Response
+where
+    C: Context,
+{
+    status_to_response(report_to_status(report))
 }
@@ -292,7 +292,7 @@ where
     let mut store = pool.acquire().await.map_err(store_acquisition_error)?;
     let mut authorization_api = (**authorization_api).clone();
 
-    store.delete_entities().await.map_err(|report| {
+    store.delete_all_entities().await.map_err(|report| {
         tracing::error!(error=?report, "Could not delete entities");
         report_to_response(&report, "ENTITY_DELETION_FAILURE")
     })?;
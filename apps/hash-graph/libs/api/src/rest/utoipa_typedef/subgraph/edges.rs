@@ -4,13 +4,13 @@ use std::{
 };
 
 use graph::subgraph::{
-    edges::{KnowledgeGraphEdgeKind, OntologyEdgeKind, OutwardEdge, SharedEdgeKind},
+    edges::{EdgeDirection, KnowledgeGraphEdgeKind, OntologyEdgeKind, OutwardEdge, SharedEdgeKind},
     identifier::{
         DataTypeVertexId, EntityIdWithInterval, EntityTypeVertexId, PropertyTypeVertexId,
     },
     temporal_axes::VariableAxis,
 };
-use graph_types::knowledge::entity::EntityId;
+use graph_types::knowledge::{entity::EntityId, link::LinkPosition};
 use serde::Serialize;
 use temporal_versioning::Timestamp;
 use type_system::url::{BaseUrl, OntologyTypeVersion};
@@ -139,6 +139,30 @@ pub(crate) struct KnowledgeGraphRootedEdges(
     pub(crate) HashMap<EntityId, BTreeMap<Timestamp<VariableAxis>, Vec<KnowledgeGraphOutwardEdge>>>,
 );
 
+impl KnowledgeGraphRootedEdges {
+    /// Sorts the outgoing links of every entity by the position of the link entity.
+    ///
+    /// Links without a position, i.e. links of unordered link types or links which were not
+    /// moved yet, are placed after the positioned links and are ordered by their [`EntityId`]. All
+    /// other edges keep their relative order and are placed before the links.
+    pub(crate) fn sort_outgoing_links(&mut self, positions: &HashMap<EntityId, LinkPosition>) {
+        for edges in self.0.values_mut().flat_map(BTreeMap::values_mut) {
+            edges.sort_by_key(|edge| match edge {
+                KnowledgeGraphOutwardEdge::ToKnowledgeGraph(OutwardEdge {
+                    kind: KnowledgeGraphEdgeKind::HasLeftEntity,
+                    direction: EdgeDirection::Incoming,
+                    right_endpoint,
+                }) => positions
+                    .get(&right_endpoint.entity_id)
+                    .map_or((2, None, Some(right_endpoint.entity_id)), |position| {
+                        (1, Some(position), None)
+                    }),
+                _ => (0, None, None),
+            });
+        }
+    }
+}
+
 #[derive(Default, Debug, Serialize)]
 #[serde(transparent)]
 pub(crate) struct OntologyRootedEdges(
@@ -182,11 +206,21 @@ impl From<graph::subgraph::edges::Edges> for Edges {
                 edges
                     .entity_type_to_entity_type
                     .into_flattened::<OntologyOutwardEdge>()
+                    .chain(
+                        edges
+                            .entity_type_to_entity
+                            .into_flattened::<OntologyOutwardEdge>(),
+                    )
                     .chain(
                         edges
                             .entity_type_to_property_type
                             .into_flattened::<OntologyOutwardEdge>(),
                     )
+                    .chain(
+                        edges
+                            .property_type_to_entity_type
+                            .into_flattened::<OntologyOutwardEdge>(),
+                    )
                     .chain(
                         edges
                             .property_type_to_property_type
@@ -197,6 +231,16 @@ impl From<graph::subgraph::edges::Edges> for Edges {
                             .property_type_to_data_type
                             .into_flattened::<OntologyOutwardEdge>(),
                     )
+                    .chain(
+                        edges
+                            .data_type_to_property_type
+                            .into_flattened::<OntologyOutwardEdge>(),
+                    )
+                    .chain(
+                        edges
+                            .data_type_to_data_type
+                            .into_flattened::<OntologyOutwardEdge>(),
+                    )
                     .fold(HashMap::new(), collect_merge),
             ),
             knowledge_graph: KnowledgeGraphRootedEdges(
@@ -239,12 +283,17 @@ impl ToSchema<'_> for Edges {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use graph::subgraph::{
         edges::{EdgeDirection, KnowledgeGraphEdgeKind, SharedEdgeKind},
         identifier::{EntityIdWithInterval, EntityTypeVertexId, EntityVertexId},
     };
     use graph_types::{
-        knowledge::entity::{EntityId, EntityUuid},
+        knowledge::{
+            entity::{EntityId, EntityUuid},
+            link::LinkPosition,
+        },
         owned_by_id::OwnedById,
     };
     use temporal_versioning::{
@@ -253,7 +302,15 @@ mod tests {
     use type_system::url::{BaseUrl, OntologyTypeVersion};
     use uuid::Uuid;
 
-    use crate::rest::utoipa_typedef::subgraph::Edges;
+    use crate::rest::utoipa_typedef::subgraph::{Edges, KnowledgeGraphOutwardEdge};
+
+    fn entity_id() -> EntityId {
+        EntityId {
+            owned_by_id: OwnedById::new(Uuid::new_v4()),
+            entity_uuid: EntityUuid::new(Uuid::new_v4()),
+            draft_id: None,
+        }
+    }
 
     #[test]
     fn merge_ontology() {
@@ -313,4 +370,93 @@ mod tests {
             .expect("should have at least a single entry");
         assert_eq!(edges.len(), 2);
     }
+
+    #[test]
+    fn sort_outgoing_links() {
+        let vertex_id = EntityVertexId {
+            base_id: entity_id(),
+            revision_id: Timestamp::now(),
+        };
+        let interval = LeftClosedTemporalInterval::new(
+            ClosedTemporalBound::Inclusive(Timestamp::now()),
+            OpenTemporalBound::Unbounded,
+        );
+
+        let links = [
+            entity_id(),
+            entity_id(),
+            entity_id(),
+            entity_id(),
+            entity_id(),
+        ];
+        let positions = HashMap::from([
+            (
+                links[0],
+                LinkPosition::new("V".to_owned()).expect("should be a valid position"),
+            ),
+            (
+                links[1],
+                LinkPosition::new("F".to_owned()).expect("should be a valid position"),
+            ),
+            (
+                links[2],
+                LinkPosition::new("FV".to_owned()).expect("should be a valid position"),
+            ),
+        ]);
+
+        let mut edges = graph::subgraph::edges::Edges::default();
+        for link in links {
+            edges.entity_to_entity.insert(
+                &vertex_id,
+                KnowledgeGraphEdgeKind::HasLeftEntity,
+                EdgeDirection::Incoming,
+                EntityIdWithInterval {
+                    entity_id: link,
+                    interval,
+                },
+            );
+        }
+        edges.entity_to_entity_type.insert(
+            &vertex_id,
+            SharedEdgeKind::IsOfType,
+            EdgeDirection::Outgoing,
+            EntityTypeVertexId {
+                base_id: BaseUrl::new("https://example.com/".to_owned())
+                    .expect("should be valid URL"),
+                revision_id: OntologyTypeVersion::new(0),
+            },
+        );
+
+        let mut edges = Edges::from(edges);
+        edges.knowledge_graph.sort_outgoing_links(&positions);
+
+        let (_, edges) = edges
+            .knowledge_graph
+            .0
+            .values()
+            .next()
+            .and_then(|values| values.first_key_value())
+            .expect("should have at least a single entry");
+        let endpoints = edges
+            .iter()
+            .filter_map(|edge| match edge {
+                KnowledgeGraphOutwardEdge::ToKnowledgeGraph(edge) => {
+                    Some(edge.right_endpoint.entity_id)
+                }
+                KnowledgeGraphOutwardEdge::ToOntology(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert!(matches!(edges[0], KnowledgeGraphOutwardEdge::ToOntology(_)));
+        assert_eq!(
+            endpoints,
+            [
+                links[1],
+                links[2],
+                links[0],
+                links[3].min(links[4]),
+                links[3].max(links[4])
+            ]
+        );
+    }
 }
@@ -1,6 +1,8 @@
 mod edges;
 mod vertices;
 
+use std::collections::HashMap;
+
 use graph::subgraph::{
     edges::GraphResolveDepths, identifier::GraphElementVertexId,
     temporal_axes::SubgraphTemporalAxes,
@@ -28,10 +30,22 @@ pub(crate) struct Subgraph {
 
 impl From<graph::subgraph::Subgraph> for Subgraph {
     fn from(subgraph: graph::subgraph::Subgraph) -> Self {
+        let link_positions = subgraph
+            .vertices
+            .entities
+            .values()
+            .filter_map(|entity| {
+                let position = entity.link_data.as_ref()?.position.clone()?;
+                Some((entity.metadata.record_id.entity_id, position))
+            })
+            .collect::<HashMap<_, _>>();
+        let mut edges = Edges::from(subgraph.edges);
+        edges.knowledge_graph.sort_outgoing_links(&link_positions);
+
         Self {
             roots: subgraph.roots,
             vertices: subgraph.vertices.into(),
-            edges: subgraph.edges.into(),
+            edges,
             depths: subgraph.depths,
             temporal_axes: subgraph.temporal_axes,
         }
@@ -1,6 +1,6 @@
 use std::{borrow::Cow, fmt, str::FromStr};
 
-use graph_types::knowledge::entity::Entity;
+use graph_types::knowledge::{entity::Entity, PropertyPath, PropertyPathElement};
 use serde::{
     de::{self, SeqAccess, Visitor},
     Deserialize, Deserializer,
@@ -128,6 +128,10 @@ pub enum EntityQueryPath<'p> {
     ///
     /// It's currently not possible to query for the entity confidence value directly.
     RightEntityConfidence,
+    /// The position of the [`Entity`] among the outgoing links of its left entity.
+    ///
+    /// It's currently not possible to query for the link position directly.
+    LeftEntityPosition,
     /// The list of all property pointers of an [`Entity`].
     ///
     /// It's currently not possible to query for the list of property pointers directly.
@@ -138,6 +142,67 @@ pub enum EntityQueryPath<'p> {
     ///
     /// [`PropertyPaths`]: Self::PropertyPaths
     PropertyConfidences,
+    /// The confidence value of a single property of the [`Entity`].
+    ///
+    /// If no confidence was specified for the property, the value is `null`. Deserializes from
+    /// `["propertyConfidence", ...]` where `...` is the [`PropertyPath`] of the property:
+    ///
+    /// ```rust
+    /// # use serde::Deserialize;
+    /// # use serde_json::json;
+    /// # use graph::knowledge::EntityQueryPath;
+    /// let path = EntityQueryPath::deserialize(json!([
+    ///     "propertyConfidence",
+    ///     "https://blockprotocol.org/@alice/types/property-type/name/"
+    /// ]))?;
+    /// assert_eq!(
+    ///     path.to_string(),
+    ///     "propertyConfidence(/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1name~1)"
+    /// );
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    PropertyConfidence(PropertyPath<'p>),
+    /// The list of the base URLs of the data types belonging to [`PropertyPaths`]' properties.
+    ///
+    /// It's currently not possible to query for the list of data types directly.
+    ///
+    /// [`PropertyPaths`]: Self::PropertyPaths
+    PropertyDataTypeBaseUrls,
+    /// The list of the versions of the data types belonging to [`PropertyPaths`]' properties.
+    ///
+    /// It's currently not possible to query for the list of data types directly.
+    ///
+    /// [`PropertyPaths`]: Self::PropertyPaths
+    PropertyDataTypeVersions,
+    /// The list of the provenance belonging to [`PropertyPaths`]' properties.
+    ///
+    /// It's currently not possible to query for the list of property provenance directly.
+    ///
+    /// [`PropertyPaths`]: Self::PropertyPaths
+    PropertyProvenances,
+    /// The numeric value of a single property of the [`Entity`] expressed in the canonical unit of
+    /// the data type the value was provided in.
+    ///
+    /// This allows comparing and sorting values which were provided in different units, e.g. a
+    /// length in centimeters and a length in meters. If no data type was specified for the
+    /// property or the value is not a number, the value is `null`. Deserializes from
+    /// `["normalizedProperty", ...]` where `...` is the [`PropertyPath`] of the property:
+    ///
+    /// ```rust
+    /// # use serde::Deserialize;
+    /// # use serde_json::json;
+    /// # use graph::knowledge::EntityQueryPath;
+    /// let path = EntityQueryPath::deserialize(json!([
+    ///     "normalizedProperty",
+    ///     "https://blockprotocol.org/@alice/types/property-type/height/"
+    /// ]))?;
+    /// assert_eq!(
+    ///     path.to_string(),
+    ///     "normalizedProperty(/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1height~1)"
+    /// );
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    NormalizedProperty(PropertyPath<'p>),
     /// Whether or not the [`Entity`] is in a draft state.
     ///
     /// ```rust
@@ -368,6 +433,24 @@ pub enum EntityQueryPath<'p> {
     /// # Ok::<(), serde_json::Error>(())
     /// ```
     Embedding,
+    /// The relevance of the properties of the [`Entity`] for a full-text search query.
+    ///
+    /// This path is only available for sorting and deserializes from
+    /// `["textSearchRank", "<query>"]`:
+    ///
+    /// ```rust
+    /// # use std::borrow::Cow;
+    /// # use serde_json::json;
+    /// # use graph::knowledge::EntityQueryPath;
+    /// let path =
+    ///     EntityQueryPath::deserialize_from_sorting_tokens(json!(["textSearchRank", "hash graph"]))?;
+    /// assert_eq!(
+    ///     path,
+    ///     EntityQueryPath::TextSearchRank(Cow::Borrowed("hash graph"))
+    /// );
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    TextSearchRank(Cow<'p, str>),
 }
 
 impl fmt::Display for EntityQueryPath<'_> {
@@ -389,6 +472,7 @@ impl fmt::Display for EntityQueryPath<'_> {
             Self::EditionProvenance(Some(path)) => write!(fmt, "editionProvenance.{path}"),
             Self::EditionProvenance(None) => fmt.write_str("editionProvenance"),
             Self::Embedding => fmt.write_str("embedding"),
+            Self::TextSearchRank(query) => write!(fmt, r#"textSearchRank("{query}")"#),
             Self::EntityTypeEdge {
                 edge_kind: SharedEdgeKind::IsOfType,
                 path,
@@ -422,8 +506,18 @@ impl fmt::Display for EntityQueryPath<'_> {
             Self::EntityConfidence => fmt.write_str("entityConfidence"),
             Self::LeftEntityConfidence => fmt.write_str("leftEntityConfidence"),
             Self::RightEntityConfidence => fmt.write_str("rightEntityConfidence"),
+            Self::LeftEntityPosition => fmt.write_str("leftEntityPosition"),
             Self::PropertyPaths => fmt.write_str("propertyPaths"),
             Self::PropertyConfidences => fmt.write_str("propertyConfidences"),
+            Self::PropertyConfidence(path) => {
+                write!(fmt, "propertyConfidence({})", path.to_json_pointer())
+            }
+            Self::PropertyDataTypeBaseUrls => fmt.write_str("propertyDataTypeBaseUrls"),
+            Self::PropertyDataTypeVersions => fmt.write_str("propertyDataTypeVersions"),
+            Self::PropertyProvenances => fmt.write_str("propertyProvenances"),
+            Self::NormalizedProperty(path) => {
+                write!(fmt, "normalizedProperty({})", path.to_json_pointer())
+            }
         }
     }
 }
@@ -440,10 +534,21 @@ impl QueryPath for EntityQueryPath<'_> {
             Self::Properties(_) | Self::Provenance(_) | Self::EditionProvenance(_) => {
                 ParameterType::Any
             }
-            Self::EntityConfidence | Self::LeftEntityConfidence | Self::RightEntityConfidence => {
-                ParameterType::F64
-            }
+            Self::EntityConfidence
+            | Self::LeftEntityConfidence
+            | Self::RightEntityConfidence
+            | Self::PropertyConfidence(_)
+            | Self::NormalizedProperty(_)
+            | Self::TextSearchRank(_) => ParameterType::F64,
+            Self::LeftEntityPosition => ParameterType::Text,
             Self::PropertyPaths => ParameterType::Vector(Box::new(ParameterType::Text)),
+            Self::PropertyDataTypeBaseUrls => {
+                ParameterType::Vector(Box::new(ParameterType::BaseUrl))
+            }
+            Self::PropertyDataTypeVersions => {
+                ParameterType::Vector(Box::new(ParameterType::OntologyTypeVersion))
+            }
+            Self::PropertyProvenances => ParameterType::Vector(Box::new(ParameterType::Any)),
             Self::PropertyConfidences | Self::Embedding => {
                 ParameterType::Vector(Box::new(ParameterType::F64))
             }
@@ -467,6 +572,8 @@ pub enum EntityQueryToken {
     OwnedById,
     Type,
     Properties,
+    PropertyConfidence,
+    NormalizedProperty,
     Provenance,
     EditionProvenance,
     Embedding,
@@ -476,6 +583,22 @@ pub enum EntityQueryToken {
     RightEntity,
 }
 
+/// Reads the remaining elements of the sequence as [`PropertyPath`].
+fn visit_property_path<'de, A>(
+    seq: &mut A,
+    position: &mut usize,
+) -> Result<PropertyPath<'de>, A::Error>
+where
+    A: SeqAccess<'de>,
+{
+    let mut path = PropertyPath::default();
+    while let Some(element) = seq.next_element::<PropertyPathElement<'de>>()? {
+        path.push(element);
+        *position += 1;
+    }
+    Ok(path)
+}
+
 /// Deserializes an [`EntityQueryPath`] from a string sequence.
 pub struct EntityQueryPathVisitor {
     /// The current position in the sequence when deserializing.
@@ -483,10 +606,10 @@ pub struct EntityQueryPathVisitor {
 }
 
 impl EntityQueryPathVisitor {
-    pub const EXPECTING: &'static str = "one of `uuid`, `editionId`, `draftId`, `archived`, \
-                                         `ownedById`, `type`, `properties`, `provenance`, \
-                                         `editionProvenance`, `embedding`, `incomingLinks`, \
-                                         `outgoingLinks`, `leftEntity`, `rightEntity`";
+    pub const EXPECTING: &'static str =
+        "one of `uuid`, `editionId`, `draftId`, `archived`, `ownedById`, `type`, `properties`, \
+         `propertyConfidence`, `normalizedProperty`, `provenance`, `editionProvenance`, \
+         `embedding`, `incomingLinks`, `outgoingLinks`, `leftEntity`, `rightEntity`";
 
     #[must_use]
     pub const fn new(position: usize) -> Self {
@@ -540,6 +663,12 @@ impl<'de> Visitor<'de> for EntityQueryPathVisitor {
                     EntityQueryPath::Properties(Some(JsonPath::from_path_tokens(path_tokens)))
                 }
             }
+            EntityQueryToken::PropertyConfidence => EntityQueryPath::PropertyConfidence(
+                visit_property_path(&mut seq, &mut self.position)?,
+            ),
+            EntityQueryToken::NormalizedProperty => EntityQueryPath::NormalizedProperty(
+                visit_property_path(&mut seq, &mut self.position)?,
+            ),
             EntityQueryToken::Provenance => {
                 let mut path_tokens = Vec::new();
                 while let Some(property) = seq.next_element::<PathToken<'de>>()? {
@@ -620,6 +749,9 @@ pub enum EntityQuerySortingToken {
     RecordCreatedAtDecisionTime,
     CreatedAtTransactionTime,
     CreatedAtDecisionTime,
+    TextSearchRank,
+    PropertyConfidence,
+    NormalizedProperty,
 }
 
 /// Deserializes an [`EntityQueryPath`] from a string sequence.
@@ -631,7 +763,8 @@ pub struct EntityQuerySortingVisitor {
 impl EntityQuerySortingVisitor {
     pub const EXPECTING: &'static str =
         "one of `archived`, `properties`, `recordCreatedAtTransactionTime`, \
-         `recordCreatedAtDecisionTime`, `createdAtTransactionTime`, `createdAtDecisionTime`";
+         `recordCreatedAtDecisionTime`, `createdAtTransactionTime`, `createdAtDecisionTime`, \
+         `textSearchRank`, `propertyConfidence`, `normalizedProperty`";
 
     #[must_use]
     pub const fn new(position: usize) -> Self {
@@ -684,6 +817,19 @@ impl<'de> Visitor<'de> for EntityQuerySortingVisitor {
                     EntityQueryPath::Properties(Some(JsonPath::from_path_tokens(path_tokens)))
                 }
             }
+            EntityQuerySortingToken::TextSearchRank => {
+                let query = seq
+                    .next_element::<Cow<'de, str>>()?
+                    .ok_or_else(|| de::Error::invalid_length(self.position, &self))?;
+                self.position += 1;
+                EntityQueryPath::TextSearchRank(query)
+            }
+            EntityQuerySortingToken::PropertyConfidence => EntityQueryPath::PropertyConfidence(
+                visit_property_path(&mut seq, &mut self.position)?,
+            ),
+            EntityQuerySortingToken::NormalizedProperty => EntityQueryPath::NormalizedProperty(
+                visit_property_path(&mut seq, &mut self.position)?,
+            ),
         })
     }
 }
@@ -733,11 +879,24 @@ impl<'de: 'p, 'p> EntityQueryPath<'p> {
             },
             Self::Properties(path) => EntityQueryPath::Properties(path.map(JsonPath::into_owned)),
             Self::Embedding => EntityQueryPath::Embedding,
+            Self::TextSearchRank(query) => {
+                EntityQueryPath::TextSearchRank(Cow::Owned(query.into_owned()))
+            }
             Self::EntityConfidence => EntityQueryPath::EntityConfidence,
             Self::LeftEntityConfidence => EntityQueryPath::LeftEntityConfidence,
             Self::RightEntityConfidence => EntityQueryPath::RightEntityConfidence,
+            Self::LeftEntityPosition => EntityQueryPath::LeftEntityPosition,
             Self::PropertyPaths => EntityQueryPath::PropertyPaths,
             Self::PropertyConfidences => EntityQueryPath::PropertyConfidences,
+            Self::PropertyConfidence(path) => {
+                EntityQueryPath::PropertyConfidence(path.into_owned())
+            }
+            Self::PropertyDataTypeBaseUrls => EntityQueryPath::PropertyDataTypeBaseUrls,
+            Self::PropertyDataTypeVersions => EntityQueryPath::PropertyDataTypeVersions,
+            Self::PropertyProvenances => EntityQueryPath::PropertyProvenances,
+            Self::NormalizedProperty(path) => {
+                EntityQueryPath::NormalizedProperty(path.into_owned())
+            }
             Self::Provenance(path) => EntityQueryPath::Provenance(path.map(JsonPath::into_owned)),
             Self::EditionProvenance(path) => {
                 EntityQueryPath::EditionProvenance(path.map(JsonPath::into_owned))
@@ -833,6 +992,30 @@ mod tests {
                 Cow::Borrowed("https://blockprotocol.org/@alice/types/property-type/name/")
             )])))
         );
+        assert_eq!(
+            deserialize([
+                "propertyConfidence",
+                "https://blockprotocol.org/@alice/types/property-type/name/"
+            ]),
+            EntityQueryPath::PropertyConfidence(
+                PropertyPath::from_json_pointer(
+                    "/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1name~1"
+                )
+                .expect("invalid property path")
+            )
+        );
+        assert_eq!(
+            deserialize([
+                "normalizedProperty",
+                "https://blockprotocol.org/@alice/types/property-type/height/"
+            ]),
+            EntityQueryPath::NormalizedProperty(
+                PropertyPath::from_json_pointer(
+                    "/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1height~1"
+                )
+                .expect("invalid property path")
+            )
+        );
         assert_eq!(
             deserialize(["leftEntity", "uuid"]),
             EntityQueryPath::EntityEdge {
@@ -182,6 +182,37 @@ pub enum DataTypeQueryPath<'p> {
     /// # Ok::<(), serde_json::Error>(())
     /// ```
     Embedding,
+    /// The [`BaseUrl`] of the data type values of the [`DataType`] can be converted into.
+    ///
+    /// Only data types which represent a unit have a canonical unit.
+    ///
+    /// ```rust
+    /// # use serde::Deserialize;
+    /// # use serde_json::json;
+    /// # use graph::ontology::DataTypeQueryPath;
+    /// let path = DataTypeQueryPath::deserialize(json!(["canonicalUnit"]))?;
+    /// assert_eq!(path, DataTypeQueryPath::CanonicalUnit);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    ///
+    /// [`DataType`]: type_system::DataType
+    /// [`BaseUrl`]: type_system::url::BaseUrl
+    CanonicalUnit,
+    /// The factor values of the [`DataType`] are multiplied with to express them in the
+    /// [`CanonicalUnit`].
+    ///
+    /// ```rust
+    /// # use serde::Deserialize;
+    /// # use serde_json::json;
+    /// # use graph::ontology::DataTypeQueryPath;
+    /// let path = DataTypeQueryPath::deserialize(json!(["conversionFactor"]))?;
+    /// assert_eq!(path, DataTypeQueryPath::ConversionFactor);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    ///
+    /// [`DataType`]: type_system::DataType
+    /// [`CanonicalUnit`]: Self::CanonicalUnit
+    ConversionFactor,
     /// Corresponds to the provenance data of the [`DataType`].
     ///
     /// Deserializes from `["editionProvenance", ...]` where `...` is a path to a provenance entry
@@ -215,12 +246,13 @@ impl QueryPath for DataTypeQueryPath<'_> {
         match self {
             Self::OntologyId | Self::OwnedById => ParameterType::Uuid,
             Self::Schema(_) | Self::AdditionalMetadata => ParameterType::Object,
-            Self::BaseUrl => ParameterType::BaseUrl,
+            Self::BaseUrl | Self::CanonicalUnit => ParameterType::BaseUrl,
             Self::VersionedUrl => ParameterType::VersionedUrl,
             Self::TransactionTime => ParameterType::TimeInterval,
             Self::Version => ParameterType::OntologyTypeVersion,
             Self::Description | Self::Title | Self::Type => ParameterType::Text,
             Self::Embedding => ParameterType::Vector(Box::new(ParameterType::F64)),
+            Self::ConversionFactor => ParameterType::F64,
             Self::EditionProvenance(_) => ParameterType::Any,
             Self::PropertyTypeEdge { path, .. } => path.expected_type(),
         }
@@ -245,6 +277,8 @@ impl fmt::Display for DataTypeQueryPath<'_> {
             Self::EditionProvenance(Some(path)) => write!(fmt, "editionProvenance.{path}"),
             Self::EditionProvenance(None) => fmt.write_str("editionProvenance"),
             Self::Embedding => fmt.write_str("embedding"),
+            Self::CanonicalUnit => fmt.write_str("canonicalUnit"),
+            Self::ConversionFactor => fmt.write_str("conversionFactor"),
             Self::PropertyTypeEdge {
                 edge_kind, path, ..
             } => {
@@ -270,6 +304,8 @@ pub enum DataTypeQueryToken {
     Type,
     EditionProvenance,
     Embedding,
+    CanonicalUnit,
+    ConversionFactor,
     #[serde(skip)]
     Schema,
 }
@@ -281,9 +317,9 @@ pub struct DataTypeQueryPathVisitor {
 }
 
 impl DataTypeQueryPathVisitor {
-    pub const EXPECTING: &'static str = "one of `baseUrl`, `version`, `versionedUrl`, \
-                                         `ownedById`, `title`, `description`, `type`, \
-                                         `editionProvenance`, `embedding`";
+    pub const EXPECTING: &'static str =
+        "one of `baseUrl`, `version`, `versionedUrl`, `ownedById`, `title`, `description`, \
+         `type`, `editionProvenance`, `embedding`, `canonicalUnit`, `conversionFactor`";
 
     #[must_use]
     pub const fn new(position: usize) -> Self {
@@ -316,6 +352,8 @@ impl<'de> Visitor<'de> for DataTypeQueryPathVisitor {
             DataTypeQueryToken::Description => DataTypeQueryPath::Description,
             DataTypeQueryToken::Type => DataTypeQueryPath::Type,
             DataTypeQueryToken::Embedding => DataTypeQueryPath::Embedding,
+            DataTypeQueryToken::CanonicalUnit => DataTypeQueryPath::CanonicalUnit,
+            DataTypeQueryToken::ConversionFactor => DataTypeQueryPath::ConversionFactor,
             DataTypeQueryToken::Schema => {
                 let mut path_tokens = Vec::new();
                 while let Some(field) = seq.next_element::<PathToken<'de>>()? {
@@ -373,6 +411,8 @@ impl DataTypeQueryPath<'_> {
             Self::AdditionalMetadata => DataTypeQueryPath::AdditionalMetadata,
             Self::Type => DataTypeQueryPath::Type,
             Self::Embedding => DataTypeQueryPath::Embedding,
+            Self::CanonicalUnit => DataTypeQueryPath::CanonicalUnit,
+            Self::ConversionFactor => DataTypeQueryPath::ConversionFactor,
             Self::EditionProvenance(path) => {
                 DataTypeQueryPath::EditionProvenance(path.map(JsonPath::into_owned))
             }
@@ -409,6 +449,14 @@ mod tests {
         assert_eq!(deserialize(["type"]), DataTypeQueryPath::Type);
         assert_eq!(deserialize(["title"]), DataTypeQueryPath::Title);
         assert_eq!(deserialize(["description"]), DataTypeQueryPath::Description);
+        assert_eq!(
+            deserialize(["canonicalUnit"]),
+            DataTypeQueryPath::CanonicalUnit
+        );
+        assert_eq!(
+            deserialize(["conversionFactor"]),
+            DataTypeQueryPath::ConversionFactor
+        );
 
         assert_eq!(
             DataTypeQueryPath::deserialize(de::value::SeqDeserializer::<_, de::value::Error>::new(
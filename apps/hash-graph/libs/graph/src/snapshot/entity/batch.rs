@@ -294,7 +294,18 @@ impl<C: AsClient> WriteBatch<C> for EntityRowBatch {
                         SELECT * FROM entity_has_right_entity_tmp;
 
                     INSERT INTO entity_property
-                        SELECT * FROM entity_property_tmp;
+                        SELECT
+                            entity_property_tmp.entity_edition_id,
+                            entity_property_tmp.property_path,
+                            entity_property_tmp.confidence,
+                            entity_property_tmp.data_type_ontology_id,
+                            entity_property_tmp.normalized_value
+                                * COALESCE(data_type_conversions.factor, 1),
+                            entity_property_tmp.provenance
+                        FROM entity_property_tmp
+                        LEFT OUTER JOIN data_type_conversions ON
+                            data_type_conversions.source_data_type_ontology_id = \
+                 entity_property_tmp.data_type_ontology_id;
 
                     INSERT INTO entity_embeddings
                         SELECT * FROM entity_embeddings_tmp;
@@ -321,6 +332,7 @@ impl<C: AsClient> WriteBatch<C> for EntityRowBatch {
                 store: postgres_client,
                 cache: StoreCache::default(),
                 authorization: None,
+                pending_entities: None,
             };
 
             for entity in entities {
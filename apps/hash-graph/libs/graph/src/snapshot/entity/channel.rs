@@ -1,16 +1,21 @@
 use std::{
+    collections::HashMap,
     pin::Pin,
     task::{ready, Context, Poll},
 };
 
-use authorization::schema::{EntityRelationAndSubject, EntityTypeId};
+use authorization::schema::{DataTypeId, EntityRelationAndSubject, EntityTypeId};
 use error_stack::{Report, ResultExt};
 use futures::{
     channel::mpsc::{self, Receiver, Sender},
     stream::{select_all, BoxStream, SelectAll},
     Sink, SinkExt, Stream, StreamExt,
 };
-use graph_types::knowledge::entity::{EntityUuid, InferredEntityProvenanceMetadata};
+use graph_types::knowledge::{
+    entity::{EntityUuid, InferredEntityProvenanceMetadata},
+    Property,
+};
+use serde_json::Value as JsonValue;
 
 use crate::snapshot::{
     entity::{
@@ -109,18 +114,62 @@ impl Sink<EntitySnapshotRecord> for EntitySender {
                     web_id: entity.metadata.record_id.entity_id.owned_by_id,
                     entity_uuid: entity.metadata.record_id.entity_id.entity_uuid,
                     draft_id,
+                    // Snapshots do not contain the edition a draft was based on, so restored
+                    // drafts are published without merging.
+                    base_edition_id: None,
                 })
                 .change_context(SnapshotRestoreError::Read)
                 .attach_printable("could not send entity draft id")?;
         }
 
+        let mut properties = HashMap::new();
         for (path, confidence) in entity.metadata.property_confidence {
-            self.property
-                .start_send_unpin(EntityPropertyRow {
+            properties
+                .entry(path.clone())
+                .or_insert_with(|| EntityPropertyRow {
                     entity_edition_id: entity.metadata.record_id.edition_id,
                     property_path: path,
-                    confidence: Some(confidence),
+                    confidence: None,
+                    data_type_ontology_id: None,
+                    normalized_value: None,
+                    provenance: None,
                 })
+                .confidence = Some(confidence);
+        }
+        for (path, data_type_id) in entity.metadata.property_data_types {
+            let normalized_value = match entity.properties.get(&path) {
+                Some(Property::Value(JsonValue::Number(number))) => number.as_f64(),
+                _ => None,
+            };
+            let row = properties
+                .entry(path.clone())
+                .or_insert_with(|| EntityPropertyRow {
+                    entity_edition_id: entity.metadata.record_id.edition_id,
+                    property_path: path,
+                    confidence: None,
+                    data_type_ontology_id: None,
+                    normalized_value: None,
+                    provenance: None,
+                });
+            row.data_type_ontology_id = Some(DataTypeId::from_url(&data_type_id).into_uuid());
+            row.normalized_value = normalized_value;
+        }
+        for (path, provenance) in entity.metadata.property_provenance {
+            properties
+                .entry(path.clone())
+                .or_insert_with(|| EntityPropertyRow {
+                    entity_edition_id: entity.metadata.record_id.edition_id,
+                    property_path: path,
+                    confidence: None,
+                    data_type_ontology_id: None,
+                    normalized_value: None,
+                    provenance: None,
+                })
+                .provenance = Some(provenance);
+        }
+        for property in properties.into_values() {
+            self.property
+                .start_send_unpin(property)
                 .change_context(SnapshotRestoreError::Read)
                 .attach_printable("could not send entity property")?;
         }
@@ -166,6 +215,7 @@ impl Sink<EntitySnapshotRecord> for EntitySender {
                     left_web_id: link_data.left_entity_id.owned_by_id,
                     left_entity_uuid: link_data.left_entity_id.entity_uuid,
                     confidence: link_data.left_entity_confidence,
+                    position: link_data.position,
                 })
                 .change_context(SnapshotRestoreError::Read)
                 .attach_printable("could not send entity link edges")?;
@@ -4,7 +4,8 @@ use graph_types::{
             DraftId, EntityEditionId, EntityEditionProvenanceMetadata, EntityUuid,
             InferredEntityProvenanceMetadata,
         },
-        Confidence, PropertyObject, PropertyPath,
+        link::LinkPosition,
+        Confidence, PropertyObject, PropertyPath, PropertyProvenance,
     },
     owned_by_id::OwnedById,
     Embedding,
@@ -27,6 +28,7 @@ pub struct EntityDraftRow {
     pub web_id: OwnedById,
     pub entity_uuid: EntityUuid,
     pub draft_id: DraftId,
+    pub base_edition_id: Option<EntityEditionId>,
 }
 
 #[derive(Debug, ToSql)]
@@ -45,6 +47,10 @@ pub struct EntityPropertyRow {
     pub entity_edition_id: EntityEditionId,
     pub property_path: PropertyPath<'static>,
     pub confidence: Option<Confidence>,
+    pub data_type_ontology_id: Option<Uuid>,
+    /// The value as provided, it's converted into the canonical unit when committing.
+    pub normalized_value: Option<f64>,
+    pub provenance: Option<PropertyProvenance>,
 }
 
 #[derive(Debug, ToSql)]
@@ -73,6 +79,7 @@ pub struct EntityHasLeftEntityRow {
     pub left_web_id: OwnedById,
     pub left_entity_uuid: EntityUuid,
     pub confidence: Option<Confidence>,
+    pub position: Option<LinkPosition>,
 }
 
 #[derive(Debug, ToSql)]
@@ -10,7 +10,10 @@ use tokio_postgres::GenericClient;
 
 use crate::{
     snapshot::{
-        ontology::{table::DataTypeRow, DataTypeEmbeddingRow},
+        ontology::{
+            table::{DataTypeConversionRow, DataTypeInheritsFromRow, DataTypeRow},
+            DataTypeEmbeddingRow,
+        },
         WriteBatch,
     },
     store::{AsClient, InsertionError, PostgresStore},
@@ -18,6 +21,8 @@ use crate::{
 
 pub enum DataTypeRowBatch {
     Schema(Vec<DataTypeRow>),
+    InheritsFrom(Vec<DataTypeInheritsFromRow>),
+    Conversions(Vec<DataTypeConversionRow>),
     Relations(HashMap<DataTypeId, Vec<DataTypeRelationAndSubject>>),
     Embeddings(Vec<DataTypeEmbeddingRow>),
 }
@@ -34,6 +39,16 @@ impl<C: AsClient> WriteBatch<C> for DataTypeRowBatch {
                         (LIKE data_types INCLUDING ALL)
                         ON COMMIT DROP;
 
+                    CREATE TEMPORARY TABLE data_type_inherits_from_tmp (
+                        source_data_type_ontology_id UUID NOT NULL,
+                        target_data_type_base_url TEXT NOT NULL,
+                        target_data_type_version INT8 NOT NULL
+                    ) ON COMMIT DROP;
+
+                    CREATE TEMPORARY TABLE data_type_conversions_tmp
+                        (LIKE data_type_conversions INCLUDING ALL)
+                        ON COMMIT DROP;
+
                     CREATE TEMPORARY TABLE data_type_embeddings_tmp
                         (LIKE data_type_embeddings INCLUDING ALL)
                         ON COMMIT DROP;
@@ -68,6 +83,38 @@ impl<C: AsClient> WriteBatch<C> for DataTypeRowBatch {
                     tracing::info!("Read {} data type schemas", rows.len());
                 }
             }
+            Self::InheritsFrom(inherits_from) => {
+                let rows = client
+                    .query(
+                        "
+                            INSERT INTO data_type_inherits_from_tmp
+                            SELECT DISTINCT * FROM UNNEST($1::data_type_inherits_from_tmp[])
+                            RETURNING 1;
+                        ",
+                        &[&inherits_from],
+                    )
+                    .await
+                    .change_context(InsertionError)?;
+                if !rows.is_empty() {
+                    tracing::info!("Read {} data type inheritance edges", rows.len());
+                }
+            }
+            Self::Conversions(conversions) => {
+                let rows = client
+                    .query(
+                        "
+                            INSERT INTO data_type_conversions_tmp
+                            SELECT DISTINCT * FROM UNNEST($1::data_type_conversions[])
+                            RETURNING 1;
+                        ",
+                        &[&conversions],
+                    )
+                    .await
+                    .change_context(InsertionError)?;
+                if !rows.is_empty() {
+                    tracing::info!("Read {} data type conversions", rows.len());
+                }
+            }
             #[expect(
                 clippy::needless_collect,
                 reason = "Lifetime error, probably the signatures are wrong"
@@ -116,6 +163,20 @@ impl<C: AsClient> WriteBatch<C> for DataTypeRowBatch {
                 "
                     INSERT INTO data_types SELECT * FROM data_types_tmp;
 
+                    INSERT INTO data_type_inherits_from
+                        SELECT
+                            source_data_type_ontology_id,
+                            ontology_ids_tmp.ontology_id AS target_data_type_ontology_id
+                        FROM data_type_inherits_from_tmp
+                        INNER JOIN ontology_ids_tmp ON
+                            ontology_ids_tmp.base_url = \
+                 data_type_inherits_from_tmp.target_data_type_base_url
+                            AND ontology_ids_tmp.version = \
+                 data_type_inherits_from_tmp.target_data_type_version;
+
+                    INSERT INTO data_type_conversions
+                        SELECT * FROM data_type_conversions_tmp;
+
                     INSERT INTO data_type_embeddings
                         SELECT * FROM data_type_embeddings_tmp;
                 ",
@@ -15,8 +15,9 @@ use uuid::Uuid;
 
 use crate::snapshot::{
     ontology::{
-        data_type::batch::DataTypeRowBatch, table::DataTypeRow, DataTypeEmbeddingRow,
-        DataTypeSnapshotRecord, OntologyTypeMetadataSender,
+        data_type::batch::DataTypeRowBatch,
+        table::{DataTypeConversionRow, DataTypeInheritsFromRow, DataTypeRow},
+        DataTypeEmbeddingRow, DataTypeSnapshotRecord, OntologyTypeMetadataSender,
     },
     SnapshotRestoreError,
 };
@@ -29,6 +30,8 @@ use crate::snapshot::{
 pub struct DataTypeSender {
     metadata: OntologyTypeMetadataSender,
     schema: Sender<DataTypeRow>,
+    inherits_from: Sender<Vec<DataTypeInheritsFromRow>>,
+    conversion: Sender<DataTypeConversionRow>,
     relations: Sender<(DataTypeId, Vec<DataTypeRelationAndSubject>)>,
 }
 
@@ -43,6 +46,12 @@ impl Sink<DataTypeSnapshotRecord> for DataTypeSender {
         ready!(self.schema.poll_ready_unpin(cx))
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not poll schema sender")?;
+        ready!(self.inherits_from.poll_ready_unpin(cx))
+            .change_context(SnapshotRestoreError::Read)
+            .attach_printable("could not poll inherits from edge sender")?;
+        ready!(self.conversion.poll_ready_unpin(cx))
+            .change_context(SnapshotRestoreError::Read)
+            .attach_printable("could not poll conversion sender")?;
         ready!(self.relations.poll_ready_unpin(cx))
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not poll relations sender")?;
@@ -66,6 +75,39 @@ impl Sink<DataTypeSnapshotRecord> for DataTypeSender {
                 data_type.metadata.provenance,
             ))
             .attach_printable("could not send metadata")?;
+
+        if let Some(conversion) = data_type.metadata.conversion {
+            self.conversion
+                .start_send_unpin(DataTypeConversionRow {
+                    source_data_type_ontology_id: ontology_id,
+                    target_data_type_base_url: conversion.canonical_unit,
+                    factor: conversion.factor,
+                })
+                .change_context(SnapshotRestoreError::Read)
+                .attach_printable("could not send conversion")?;
+        }
+
+        let inherits_from: Vec<_> = data_type
+            .schema
+            .inherits_from()
+            .all_of()
+            .iter()
+            .map(|data_type_ref| {
+                let url = data_type_ref.url();
+                DataTypeInheritsFromRow {
+                    source_data_type_ontology_id: ontology_id,
+                    target_data_type_base_url: url.base_url.clone(),
+                    target_data_type_version: url.version,
+                }
+            })
+            .collect();
+        if !inherits_from.is_empty() {
+            self.inherits_from
+                .start_send_unpin(inherits_from)
+                .change_context(SnapshotRestoreError::Read)
+                .attach_printable("could not send inherits from edge")?;
+        }
+
         self.schema
             .start_send_unpin(DataTypeRow {
                 ontology_id,
@@ -88,6 +130,12 @@ impl Sink<DataTypeSnapshotRecord> for DataTypeSender {
         ready!(self.schema.poll_flush_unpin(cx))
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not flush schema sender")?;
+        ready!(self.inherits_from.poll_flush_unpin(cx))
+            .change_context(SnapshotRestoreError::Read)
+            .attach_printable("could not flush inherits from edge sender")?;
+        ready!(self.conversion.poll_flush_unpin(cx))
+            .change_context(SnapshotRestoreError::Read)
+            .attach_printable("could not flush conversion sender")?;
         ready!(self.relations.poll_flush_unpin(cx))
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not flush relations sender")?;
@@ -101,6 +149,12 @@ impl Sink<DataTypeSnapshotRecord> for DataTypeSender {
         ready!(self.schema.poll_close_unpin(cx))
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not close schema sender")?;
+        ready!(self.inherits_from.poll_close_unpin(cx))
+            .change_context(SnapshotRestoreError::Read)
+            .attach_printable("could not close inherits from edge sender")?;
+        ready!(self.conversion.poll_close_unpin(cx))
+            .change_context(SnapshotRestoreError::Read)
+            .attach_printable("could not close conversion sender")?;
         ready!(self.relations.poll_close_unpin(cx))
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not close relations sender")?;
@@ -136,12 +190,16 @@ pub fn data_type_channel(
     embedding_rx: Receiver<DataTypeEmbeddingRow>,
 ) -> (DataTypeSender, DataTypeReceiver) {
     let (schema_tx, schema_rx) = mpsc::channel(chunk_size);
+    let (inherits_from_tx, inherits_from_rx) = mpsc::channel(chunk_size);
+    let (conversion_tx, conversion_rx) = mpsc::channel(chunk_size);
     let (relations_tx, relations_rx) = mpsc::channel(chunk_size);
 
     (
         DataTypeSender {
             metadata: metadata_sender,
             schema: schema_tx,
+            inherits_from: inherits_from_tx,
+            conversion: conversion_tx,
             relations: relations_tx,
         },
         DataTypeReceiver {
@@ -150,6 +208,16 @@ pub fn data_type_channel(
                     .ready_chunks(chunk_size)
                     .map(DataTypeRowBatch::Schema)
                     .boxed(),
+                inherits_from_rx
+                    .ready_chunks(chunk_size)
+                    .map(|values| {
+                        DataTypeRowBatch::InheritsFrom(values.into_iter().flatten().collect())
+                    })
+                    .boxed(),
+                conversion_rx
+                    .ready_chunks(chunk_size)
+                    .map(DataTypeRowBatch::Conversions)
+                    .boxed(),
                 relations_rx
                     .ready_chunks(chunk_size)
                     .map(|relations| DataTypeRowBatch::Relations(relations.into_iter().collect()))
@@ -45,6 +45,22 @@ pub struct DataTypeRow {
     pub schema: Json<DataType>,
 }
 
+#[derive(Debug, ToSql)]
+#[postgres(name = "data_type_inherits_from_tmp")]
+pub struct DataTypeInheritsFromRow {
+    pub source_data_type_ontology_id: Uuid,
+    pub target_data_type_base_url: BaseUrl,
+    pub target_data_type_version: OntologyTypeVersion,
+}
+
+#[derive(Debug, ToSql)]
+#[postgres(name = "data_type_conversions")]
+pub struct DataTypeConversionRow {
+    pub source_data_type_ontology_id: Uuid,
+    pub target_data_type_base_url: BaseUrl,
+    pub factor: f64,
+}
+
 #[derive(Debug, ToSql)]
 #[postgres(name = "data_type_embeddings_tmp")]
 pub struct DataTypeEmbeddingRow {
@@ -1,6 +1,9 @@
 use std::fmt;
 
 use error_stack::Context;
+use graph_types::knowledge::entity::{EntityEditionId, EntityId};
+use serde::Serialize;
+use type_system::OntologyTypeChange;
 
 #[derive(Debug)]
 pub struct StoreError;
@@ -97,6 +100,62 @@ impl fmt::Display for RaceConditionOnUpdate {
 
 impl Context for RaceConditionOnUpdate {}
 
+/// The edition of an entity which was expected to be updated is not the current edition anymore.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[must_use]
+pub struct EntityEditionMismatch {
+    pub expected_edition_id: EntityEditionId,
+    pub current_edition_id: EntityEditionId,
+}
+
+impl fmt::Display for EntityEditionMismatch {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt,
+            "Expected the entity to be at edition `{}` but the current edition is `{}`",
+            self.expected_edition_id.into_uuid(),
+            self.current_edition_id.into_uuid()
+        )
+    }
+}
+
+impl Context for EntityEditionMismatch {}
+
+/// A published link points at entities which only exist as drafts.
+///
+/// The link can only be published after its endpoints were published.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[must_use]
+pub struct UnpublishedLinkEndpoints {
+    /// The existing links which would be published. Links which are about to be created are not
+    /// listed as they don't have an ID yet.
+    pub link_entity_ids: Vec<EntityId>,
+    /// The endpoints of the links which have not been published.
+    pub entity_ids: Vec<EntityId>,
+}
+
+impl fmt::Display for UnpublishedLinkEndpoints {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("The link")?;
+        for (index, link_entity_id) in self.link_entity_ids.iter().enumerate() {
+            fmt.write_str(if index > 0 { ", " } else { " " })?;
+            write!(fmt, "`{link_entity_id}`")?;
+        }
+        fmt.write_str(" points at entities which have not been published: ")?;
+        for (index, entity_id) in self.entity_ids.iter().enumerate() {
+            if index > 0 {
+                fmt.write_str(", ")?;
+            }
+            write!(fmt, "`{entity_id}`")?;
+        }
+        Ok(())
+    }
+}
+
+impl Context for UnpublishedLinkEndpoints {}
+
 #[derive(Debug)]
 #[must_use]
 pub struct VersionedUrlAlreadyExists;
@@ -133,6 +192,26 @@ impl fmt::Display for OntologyTypeIsNotOwned {
 
 impl Context for OntologyTypeIsNotOwned {}
 
+/// An update of an ontology type is not backwards compatible to the version it updates.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[must_use]
+pub struct BreakingOntologyTypeChanges {
+    pub changes: Vec<OntologyTypeChange>,
+}
+
+impl fmt::Display for BreakingOntologyTypeChanges {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt,
+            "The update of the ontology type contains {} breaking change(s)",
+            self.changes.len()
+        )
+    }
+}
+
+impl Context for BreakingOntologyTypeChanges {}
+
 #[derive(Debug)]
 pub struct MigrationError;
 
@@ -1,4 +1,4 @@
-use std::{collections::HashSet, mem};
+use std::{collections::HashSet, mem, time::Duration};
 
 use async_trait::async_trait;
 use authorization::{
@@ -14,8 +14,8 @@ use error_stack::{Report, Result, ResultExt};
 use graph_types::{
     account::AccountId,
     knowledge::{
-        entity::{EntityMetadata, EntityUuid},
-        link::LinkData,
+        entity::{EntityId, EntityMetadata, EntityUuid},
+        link::{LinkData, LinkPosition},
         PropertyObject,
     },
     ontology::{
@@ -42,9 +42,14 @@ use crate::{
     store::{
         account::{InsertAccountGroupIdParams, InsertAccountIdParams, InsertWebIdParams},
         crud::{QueryResult, Read, ReadPaginated, Sorting},
+        error::DeletionError,
         knowledge::{
-            CreateEntityParams, EntityQueryCursor, GetEntityParams, PatchEntityParams,
-            UpdateEntityEmbeddingsParams, ValidateEntityError, ValidateEntityParams,
+            CorrectEntityParams, CountEntitiesParams, CreateEntityParams, DeleteEntitiesParams,
+            DiffEntityDraftResult, DiffEntityParams, DiffEntityResult, DiffEntitySubgraphParams,
+            EntityChange, EntityChangeCursor, EntityCount, EntityDraft, EntityMigration,
+            EntityQueryCursor, GetEntityChangesParams, GetEntityParams, MergeEntitiesParams,
+            MigrateEntitiesParams, MoveLinkParams, PatchEntityParams, UpdateEntityEmbeddingsParams,
+            ValidateEntityError, ValidateEntityParams,
         },
         ontology::{
             ArchiveDataTypeParams, ArchiveEntityTypeParams, ArchivePropertyTypeParams,
@@ -60,8 +65,9 @@ use crate::{
         SubgraphRecord, UpdateError,
     },
     subgraph::{
+        diff::SubgraphDiff,
         edges::GraphResolveDepths,
-        identifier::VertexId,
+        identifier::{DataTypeVertexId, EntityTypeVertexId, PropertyTypeVertexId, VertexId},
         query::StructuralQuery,
         temporal_axes::{
             PinnedTemporalAxisUnresolved, QueryTemporalAxes, QueryTemporalAxesUnresolved,
@@ -265,45 +271,45 @@ where
         }
 
         match ontology_type_reference {
-            OntologyTypeReference::DataTypeReference(_) => {
-                self.store
-                    .get_data_type(
-                        actor_id,
-                        authorization_api,
-                        GetDataTypesParams {
-                            query: create_query(url),
-                            after: None,
-                            limit: None,
-                        },
-                    )
-                    .await
-            }
-            OntologyTypeReference::PropertyTypeReference(_) => {
-                self.store
-                    .get_property_type(
-                        actor_id,
-                        authorization_api,
-                        GetPropertyTypesParams {
-                            query: create_query(url),
-                            after: None,
-                            limit: None,
-                        },
-                    )
-                    .await
-            }
-            OntologyTypeReference::EntityTypeReference(_) => {
-                self.store
-                    .get_entity_type(
-                        actor_id,
-                        authorization_api,
-                        GetEntityTypesParams {
-                            query: create_query(url),
-                            after: None,
-                            limit: None,
-                        },
-                    )
-                    .await
-            }
+            OntologyTypeReference::DataTypeReference(_) => self
+                .store
+                .get_data_type(
+                    actor_id,
+                    authorization_api,
+                    GetDataTypesParams {
+                        query: create_query(url),
+                        after: None,
+                        limit: None,
+                    },
+                )
+                .await
+                .map(|(subgraph, _)| subgraph),
+            OntologyTypeReference::PropertyTypeReference(_) => self
+                .store
+                .get_property_type(
+                    actor_id,
+                    authorization_api,
+                    GetPropertyTypesParams {
+                        query: create_query(url),
+                        after: None,
+                        limit: None,
+                    },
+                )
+                .await
+                .map(|(subgraph, _)| subgraph),
+            OntologyTypeReference::EntityTypeReference(_) => self
+                .store
+                .get_entity_type(
+                    actor_id,
+                    authorization_api,
+                    GetEntityTypesParams {
+                        query: create_query(url),
+                        after: None,
+                        limit: None,
+                    },
+                )
+                .await
+                .map(|(subgraph, _)| subgraph),
         }
         .change_context(StoreError)
         .attach_printable("Could not check if ontology type exists")
@@ -550,6 +556,7 @@ where
                             classification: metadata.classification,
                             relationships: DATA_TYPE_RELATIONSHIPS,
                             conflict_behavior: ConflictBehavior::Skip,
+                            conversion: None,
                             provenance: ProvidedOntologyEditionProvenanceMetadata::default(),
                         }),
                 )
@@ -649,6 +656,7 @@ where
                                 classification: metadata.classification,
                                 relationships: DATA_TYPE_RELATIONSHIPS,
                                 conflict_behavior: ConflictBehavior::Skip,
+                                conversion: None,
                                 provenance: ProvidedOntologyEditionProvenanceMetadata::default(),
                             }),
                     )
@@ -920,7 +928,7 @@ where
         actor_id: AccountId,
         authorization_api: &Au,
         params: GetDataTypesParams<'_>,
-    ) -> Result<Subgraph, QueryError> {
+    ) -> Result<(Subgraph, Option<DataTypeVertexId>), QueryError> {
         self.store
             .get_data_type(actor_id, authorization_api, params)
             .await
@@ -1041,7 +1049,7 @@ where
         actor_id: AccountId,
         authorization_api: &Au,
         params: GetPropertyTypesParams<'_>,
-    ) -> Result<Subgraph, QueryError> {
+    ) -> Result<(Subgraph, Option<PropertyTypeVertexId>), QueryError> {
         self.store
             .get_property_type(actor_id, authorization_api, params)
             .await
@@ -1162,7 +1170,7 @@ where
         actor_id: AccountId,
         authorization_api: &Au,
         params: GetEntityTypesParams<'_>,
-    ) -> Result<Subgraph, QueryError> {
+    ) -> Result<(Subgraph, Option<EntityTypeVertexId>), QueryError> {
         self.store
             .get_entity_type(actor_id, authorization_api, params)
             .await
@@ -1262,6 +1270,43 @@ where
             .await
     }
 
+    async fn create_entities<Au: AuthorizationApi + Send + Sync, P, R>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut Au,
+        temporal_client: Option<&TemporalClient>,
+        params: P,
+    ) -> Result<Vec<Result<EntityMetadata, InsertionError>>, InsertionError>
+    where
+        P: IntoIterator<Item = CreateEntityParams<R>, IntoIter: Send> + Send,
+        R: IntoIterator<Item = EntityRelationAndSubject> + Send,
+    {
+        let params = params.into_iter().collect::<Vec<_>>();
+        let entity_type_ids = params
+            .iter()
+            .flat_map(|params| &params.entity_type_ids)
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        for entity_type_id in entity_type_ids {
+            let entity_type_reference = EntityTypeReference::new(entity_type_id);
+            self.insert_external_types_by_reference(
+                actor_id,
+                authorization_api,
+                temporal_client,
+                OntologyTypeReference::EntityTypeReference(&entity_type_reference),
+                ConflictBehavior::Skip,
+                FetchBehavior::ExcludeProvidedReferences,
+                &HashSet::new(),
+            )
+            .await?;
+        }
+
+        self.store
+            .create_entities(actor_id, authorization_api, temporal_client, params)
+            .await
+    }
+
     async fn validate_entity<Au: AuthorizationApi + Sync>(
         &self,
         actor_id: AccountId,
@@ -1318,6 +1363,17 @@ where
             .await
     }
 
+    async fn count_entities<Au: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &Au,
+        params: CountEntitiesParams<'_>,
+    ) -> Result<EntityCount, QueryError> {
+        self.store
+            .count_entities(actor_id, authorization_api, params)
+            .await
+    }
+
     async fn patch_entity<Au: AuthorizationApi + Send + Sync>(
         &mut self,
         actor_id: AccountId,
@@ -1346,6 +1402,53 @@ where
             .await
     }
 
+    async fn correct_entity<Au: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut Au,
+        temporal_client: Option<&TemporalClient>,
+        params: CorrectEntityParams,
+    ) -> Result<Vec<EntityMetadata>, UpdateError> {
+        self.store
+            .correct_entity(actor_id, authorization_api, temporal_client, params)
+            .await
+    }
+
+    async fn merge_entities<Au: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut Au,
+        temporal_client: Option<&TemporalClient>,
+        params: MergeEntitiesParams,
+    ) -> Result<EntityMetadata, UpdateError> {
+        self.store
+            .merge_entities(actor_id, authorization_api, temporal_client, params)
+            .await
+    }
+
+    async fn migrate_entities<Au: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut Au,
+        temporal_client: Option<&TemporalClient>,
+        params: MigrateEntitiesParams,
+    ) -> Result<Vec<EntityMigration>, UpdateError> {
+        self.store
+            .migrate_entities(actor_id, authorization_api, temporal_client, params)
+            .await
+    }
+
+    async fn move_link<Au: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut Au,
+        params: MoveLinkParams,
+    ) -> Result<LinkPosition, UpdateError> {
+        self.store
+            .move_link(actor_id, authorization_api, params)
+            .await
+    }
+
     async fn update_entity_embeddings<Au: AuthorizationApi + Send + Sync>(
         &mut self,
         actor_id: AccountId,
@@ -1356,4 +1459,85 @@ where
             .update_entity_embeddings(actor_id, authorization_api, params)
             .await
     }
+
+    async fn delete_entities<Au: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut Au,
+        params: DeleteEntitiesParams,
+    ) -> Result<Vec<EntityId>, DeletionError> {
+        self.store
+            .delete_entities(actor_id, authorization_api, params)
+            .await
+    }
+
+    async fn get_entity_history<Au: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &Au,
+        entity_id: EntityId,
+    ) -> Result<Vec<EntityMetadata>, QueryError> {
+        self.store
+            .get_entity_history(actor_id, authorization_api, entity_id)
+            .await
+    }
+
+    async fn diff_entity_subgraph<Au: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &Au,
+        params: DiffEntitySubgraphParams<'_>,
+    ) -> Result<SubgraphDiff, QueryError> {
+        self.store
+            .diff_entity_subgraph(actor_id, authorization_api, params)
+            .await
+    }
+
+    async fn diff_entity<Au: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &Au,
+        params: DiffEntityParams,
+    ) -> Result<DiffEntityResult<'static>, QueryError> {
+        self.store
+            .diff_entity(actor_id, authorization_api, params)
+            .await
+    }
+
+    async fn get_entity_drafts<Au: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &Au,
+        entity_id: EntityId,
+    ) -> Result<Vec<EntityDraft>, QueryError> {
+        self.store
+            .get_entity_drafts(actor_id, authorization_api, entity_id)
+            .await
+    }
+
+    async fn diff_entity_draft<Au: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &Au,
+        entity_id: EntityId,
+    ) -> Result<DiffEntityDraftResult<'static>, QueryError> {
+        self.store
+            .diff_entity_draft(actor_id, authorization_api, entity_id)
+            .await
+    }
+
+    async fn get_entity_changes<Au: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &Au,
+        params: GetEntityChangesParams<'_>,
+    ) -> Result<(Vec<EntityChange>, Option<EntityChangeCursor>), QueryError> {
+        self.store
+            .get_entity_changes(actor_id, authorization_api, params)
+            .await
+    }
+
+    async fn prune_entity_changes(&mut self, retention: Duration) -> Result<u64, DeletionError> {
+        self.store.prune_entity_changes(retention).await
+    }
 }
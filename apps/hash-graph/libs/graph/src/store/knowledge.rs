@@ -1,4 +1,11 @@
-use std::{borrow::Cow, error::Error, fmt};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt,
+    str::FromStr,
+    time::Duration,
+};
 
 use authorization::{schema::EntityRelationAndSubject, zanzibar::Consistency, AuthorizationApi};
 use error_stack::Report;
@@ -6,17 +13,19 @@ use graph_types::{
     account::AccountId,
     knowledge::{
         entity::{
-            Entity, EntityEmbedding, EntityId, EntityMetadata, EntityUuid,
-            ProvidedEntityEditionProvenanceMetadata,
+            Entity, EntityEditionId, EntityEmbedding, EntityId, EntityMetadata, EntityUuid,
+            PropertyMergeStrategy, PropertyMigration, ProvidedEntityEditionProvenanceMetadata,
         },
-        link::LinkData,
-        Confidence, PropertyConfidence, PropertyObject, PropertyPatchOperation,
+        link::{LinkData, LinkPosition},
+        Confidence, PropertyConfidence, PropertyDataTypes, PropertyDiff, PropertyObject,
+        PropertyPatchOperation, PropertyPath, PropertyProvenances,
     },
     owned_by_id::OwnedById,
 };
+use postgres_types::{FromSql, ToSql};
 use serde::{Deserialize, Serialize};
 use temporal_client::TemporalClient;
-use temporal_versioning::{DecisionTime, Timestamp, TransactionTime};
+use temporal_versioning::{DecisionTime, LeftClosedTemporalInterval, Timestamp, TransactionTime};
 use type_system::{url::VersionedUrl, ClosedEntityType, EntityType};
 #[cfg(feature = "utoipa")]
 use utoipa::{
@@ -29,10 +38,13 @@ use validation::ValidateEntityComponents;
 use crate::{
     knowledge::EntityQueryPath,
     store::{
-        crud, crud::Sorting, postgres::CursorField, InsertionError, NullOrdering, Ordering,
-        QueryError, UpdateError,
+        crud, crud::Sorting, error::DeletionError, postgres::CursorField, query::Filter,
+        InsertionError, NullOrdering, Ordering, QueryError, UpdateError,
+    },
+    subgraph::{
+        diff::SubgraphDiff, edges::GraphResolveDepths, query::EntityStructuralQuery,
+        temporal_axes::QueryTemporalAxesUnresolved, Subgraph,
     },
-    subgraph::{query::EntityStructuralQuery, Subgraph},
 };
 
 #[derive(Debug, Clone, Deserialize)]
@@ -190,6 +202,12 @@ pub struct CreateEntityParams<R> {
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     #[serde(default, skip_serializing_if = "PropertyConfidence::is_empty")]
     pub property_confidence: PropertyConfidence<'static>,
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default, skip_serializing_if = "PropertyDataTypes::is_empty")]
+    pub property_data_types: PropertyDataTypes<'static>,
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default, skip_serializing_if = "PropertyProvenances::is_empty")]
+    pub property_provenance: PropertyProvenances<'static>,
     #[serde(default)]
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     pub link_data: Option<LinkData>,
@@ -210,6 +228,12 @@ pub struct ValidateEntityParams<'a> {
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     #[serde(borrow, default, skip_serializing_if = "PropertyConfidence::is_empty")]
     pub property_confidence: Cow<'a, PropertyConfidence<'a>>,
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(borrow, default, skip_serializing_if = "PropertyDataTypes::is_empty")]
+    pub property_data_types: Cow<'a, PropertyDataTypes<'a>>,
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(borrow, default, skip_serializing_if = "PropertyProvenances::is_empty")]
+    pub property_provenance: Cow<'a, PropertyProvenances<'a>>,
     #[serde(borrow, default)]
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     pub link_data: Option<Cow<'a, LinkData>>,
@@ -229,11 +253,147 @@ pub struct GetEntityParams<'a> {
     pub limit: Option<usize>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CountEntitiesParams<'a> {
+    #[serde(borrow)]
+    pub filter: Filter<'a, Entity>,
+    pub temporal_axes: QueryTemporalAxesUnresolved,
+    pub include_drafts: bool,
+    #[serde(default)]
+    pub group_by: Option<EntityCountGroupBy>,
+}
+
+#[cfg(feature = "utoipa")]
+impl ToSchema<'_> for CountEntitiesParams<'_> {
+    fn schema() -> (&'static str, RefOr<Schema>) {
+        (
+            "CountEntitiesParams",
+            Schema::Object(
+                schema::ObjectBuilder::new()
+                    .property("filter", Ref::from_schema_name("Filter"))
+                    .required("filter")
+                    .property(
+                        "temporalAxes",
+                        Ref::from_schema_name(QueryTemporalAxesUnresolved::schema().0),
+                    )
+                    .required("temporalAxes")
+                    .property(
+                        "includeDrafts",
+                        schema::ObjectBuilder::new().schema_type(schema::SchemaType::Boolean),
+                    )
+                    .required("includeDrafts")
+                    .property("groupBy", Ref::from_schema_name("EntityCountGroupBy"))
+                    .build(),
+            )
+            .into(),
+        )
+    }
+}
+
+/// Parameters to compare the subgraphs resolved for the same query at two temporal axes.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DiffEntitySubgraphParams<'a> {
+    #[serde(borrow)]
+    pub filter: Filter<'a, Entity>,
+    pub graph_resolve_depths: GraphResolveDepths,
+    /// The temporal axes the old subgraph is resolved at, e.g. "as of last Monday".
+    pub old_temporal_axes: QueryTemporalAxesUnresolved,
+    /// The temporal axes the new subgraph is resolved at, e.g. "now".
+    pub new_temporal_axes: QueryTemporalAxesUnresolved,
+    pub include_drafts: bool,
+}
+
+#[cfg(feature = "utoipa")]
+impl ToSchema<'_> for DiffEntitySubgraphParams<'_> {
+    fn schema() -> (&'static str, RefOr<Schema>) {
+        (
+            "DiffEntitySubgraphParams",
+            Schema::Object(
+                schema::ObjectBuilder::new()
+                    .property("filter", Ref::from_schema_name("Filter"))
+                    .required("filter")
+                    .property(
+                        "graphResolveDepths",
+                        Ref::from_schema_name(GraphResolveDepths::schema().0),
+                    )
+                    .required("graphResolveDepths")
+                    .property(
+                        "oldTemporalAxes",
+                        Ref::from_schema_name(QueryTemporalAxesUnresolved::schema().0),
+                    )
+                    .required("oldTemporalAxes")
+                    .property(
+                        "newTemporalAxes",
+                        Ref::from_schema_name(QueryTemporalAxesUnresolved::schema().0),
+                    )
+                    .required("newTemporalAxes")
+                    .property(
+                        "includeDrafts",
+                        schema::ObjectBuilder::new().schema_type(schema::SchemaType::Boolean),
+                    )
+                    .required("includeDrafts")
+                    .build(),
+            )
+            .into(),
+        )
+    }
+}
+
+/// The property used to group entities when counting them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum EntityCountGroupBy {
+    /// Groups entities by their entity types.
+    ///
+    /// An entity with multiple entity types is counted once for each of its types. Inherited
+    /// entity types are not taken into account.
+    EntityType,
+    /// Groups entities by the web they belong to.
+    Web,
+    /// Groups entities by whether they are archived.
+    Archived,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum EntityCountGroupKey {
+    EntityType(VersionedUrl),
+    Web(OwnedById),
+    Archived(bool),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityCountGroup {
+    pub key: EntityCountGroupKey,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityCount {
+    /// The number of distinct entities matching the filter.
+    pub count: usize,
+    /// The number of entities per group if a grouping was requested.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<EntityCountGroup>,
+}
+
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct PatchEntityParams {
     pub entity_id: EntityId,
+    /// If set, the patch is only applied if this is still the current edition of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub expected_edition_id: Option<EntityEditionId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     pub decision_time: Option<Timestamp<DecisionTime>>,
@@ -256,6 +416,229 @@ pub struct PatchEntityParams {
     pub provenance: ProvidedEntityEditionProvenanceMetadata,
 }
 
+/// Retroactively corrects the properties of an entity over a decision-time interval.
+///
+/// Unlike [`PatchEntityParams`], this does not create a new edition which is valid from a point in
+/// decision time onwards, but rewrites the history of the entity within the given interval.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CorrectEntityParams {
+    pub entity_id: EntityId,
+    /// The decision-time interval the correction is applied to.
+    pub decision_time: LeftClosedTemporalInterval<DecisionTime>,
+    pub properties: Vec<PropertyPatchOperation>,
+    #[serde(default, skip_serializing_if = "UserDefinedProvenanceData::is_empty")]
+    pub provenance: ProvidedEntityEditionProvenanceMetadata,
+}
+
+/// Merges a duplicate entity into the entity which is kept.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MergeEntitiesParams {
+    /// The entity which is kept.
+    pub survivor: EntityId,
+    /// The entity which is merged into the survivor and archived afterwards.
+    pub duplicate: EntityId,
+    /// The strategy used for conflicting properties without a strategy in `strategies`.
+    #[serde(default)]
+    pub default_strategy: PropertyMergeStrategy,
+    /// The strategies used for the properties at the given paths.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub strategies: HashMap<PropertyPath<'static>, PropertyMergeStrategy>,
+    #[serde(default, skip_serializing_if = "UserDefinedProvenanceData::is_empty")]
+    pub provenance: ProvidedEntityEditionProvenanceMetadata,
+}
+
+/// The outcome of migrating a single entity with [`EntityStore::migrate_entities`].
+#[derive(Debug)]
+pub struct EntityMigration {
+    pub entity_id: EntityId,
+    /// The metadata of the migrated entity, or `None` in a dry run.
+    pub result: Result<Option<EntityMetadata>, Report<UpdateError>>,
+}
+
+/// Migrates all entities of an entity type to another entity type.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MigrateEntitiesParams {
+    /// The entity type the entities are migrated from.
+    pub from: VersionedUrl,
+    /// The entity type replacing `from` in the entity types of the migrated entities.
+    pub to: VersionedUrl,
+    /// The changes applied to the properties of each entity, in the given order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub properties: Vec<PropertyMigration>,
+    /// Only validates the migrated entities without storing them.
+    #[serde(default)]
+    pub dry_run: bool,
+    #[serde(default, skip_serializing_if = "UserDefinedProvenanceData::is_empty")]
+    pub provenance: ProvidedEntityEditionProvenanceMetadata,
+}
+
+/// Moves a link entity directly after one of its siblings, i.e. the link entities with the same
+/// left entity.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MoveLinkParams {
+    /// The link entity which is moved.
+    pub link_entity_id: EntityId,
+    /// The sibling the link is placed after, if not specified the link is placed first.
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub after: Option<EntityId>,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DeleteEntitiesParams {
+    /// The entities to delete.
+    ///
+    /// The whole entity is deleted including all of its drafts, so the draft id is ignored.
+    pub entity_ids: Vec<EntityId>,
+    /// Also deletes all link entities which have one of the deleted entities as left or right
+    /// entity.
+    ///
+    /// If this is not set, the deletion fails if any of the entities is still linked.
+    #[serde(default)]
+    pub cascade_links: bool,
+}
+
+/// Selects a single edition of an entity.
+#[derive(Debug, Copy, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum EntityEditionSelector {
+    /// The edition with the given ID.
+    EditionId(EntityEditionId),
+    /// The edition which was valid at the given points in time.
+    ///
+    /// Omitted timestamps default to the current time.
+    #[serde(rename_all = "camelCase")]
+    Timestamp {
+        #[serde(default)]
+        decision_time: Option<Timestamp<DecisionTime>>,
+        #[serde(default)]
+        transaction_time: Option<Timestamp<TransactionTime>>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DiffEntityParams {
+    pub first_entity_id: EntityId,
+    pub first_edition: EntityEditionSelector,
+    pub second_entity_id: EntityId,
+    pub second_edition: EntityEditionSelector,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", tag = "op")]
+pub enum EntityTypeIdDiff {
+    Added { added: VersionedUrl },
+    Removed { removed: VersionedUrl },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct LinkDataDiff {
+    pub old: Option<LinkData>,
+    pub new: Option<LinkData>,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DiffEntityResult<'e> {
+    pub properties: Vec<PropertyDiff<'e>>,
+    pub entity_types: Vec<EntityTypeIdDiff>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_data: Option<LinkDataDiff>,
+}
+
+impl DiffEntityResult<'static> {
+    /// Compares the properties, entity types, and link data of two entities.
+    #[must_use]
+    pub fn from_entities(first: &Entity, second: &Entity) -> Self {
+        let first_entity_types = first
+            .metadata
+            .entity_type_ids
+            .iter()
+            .collect::<HashSet<_>>();
+        let second_entity_types = second
+            .metadata
+            .entity_type_ids
+            .iter()
+            .collect::<HashSet<_>>();
+
+        Self {
+            properties: first
+                .properties
+                .diff(&second.properties, &mut PropertyPath::default())
+                .map(PropertyDiff::into_owned)
+                .collect(),
+            entity_types: first_entity_types
+                .difference(&second_entity_types)
+                .map(|removed| EntityTypeIdDiff::Removed {
+                    removed: (*removed).clone(),
+                })
+                .chain(
+                    second_entity_types
+                        .difference(&first_entity_types)
+                        .map(|added| EntityTypeIdDiff::Added {
+                            added: (*added).clone(),
+                        }),
+                )
+                .collect(),
+            link_data: (first.link_data != second.link_data).then_some(LinkDataDiff {
+                old: first.link_data.clone(),
+                new: second.link_data.clone(),
+            }),
+        }
+    }
+}
+
+/// A draft of an entity together with the live edition it was created from.
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityDraft {
+    pub metadata: EntityMetadata,
+    /// The live edition the draft was created from.
+    ///
+    /// This is `None` if the draft was not created from a live edition, e.g. if the entity was
+    /// created as a draft.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_edition_id: Option<EntityEditionId>,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DiffEntityDraftResult<'e> {
+    /// The live edition the draft was created from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_edition_id: Option<EntityEditionId>,
+    /// The current live edition the draft is compared to.
+    pub live_edition_id: EntityEditionId,
+    /// The changes publishing the draft would make to the current live edition.
+    ///
+    /// If there are conflicts, the draft is compared as it is.
+    pub diff: DiffEntityResult<'e>,
+    /// The properties which were changed differently in the draft and in the live entity since
+    /// the draft was created. The draft cannot be published as long as there are conflicts.
+    pub conflicts: Vec<PropertyPath<'e>>,
+}
+
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -267,6 +650,101 @@ pub struct UpdateEntityEmbeddingsParams<'e> {
     pub reset: bool,
 }
 
+/// The kind of change which was made to an entity.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSql, FromSql)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "entity_change_kind", rename_all = "snake_case")]
+pub enum EntityChangeKind {
+    /// The entity was created.
+    Create,
+    /// A new edition of the entity was created.
+    Patch,
+    /// The entity was archived.
+    Archive,
+    /// A draft of the entity was published.
+    Publish,
+    /// The link was moved among the outgoing links of its left entity.
+    Move,
+    /// The entity was permanently deleted.
+    Delete,
+}
+
+/// The position of a change in the entity change feed.
+///
+/// The string representation is used as event ID in the change feed, so it can be passed back to
+/// continue reading after that change.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityChangeCursor {
+    pub transaction_id: i64,
+    pub sequence_id: i64,
+}
+
+impl fmt::Display for EntityChangeCursor {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}-{}", self.transaction_id, self.sequence_id)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ParseEntityChangeCursorError;
+
+impl fmt::Display for ParseEntityChangeCursorError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("Invalid entity change cursor")
+    }
+}
+
+impl Error for ParseEntityChangeCursorError {}
+
+impl FromStr for EntityChangeCursor {
+    type Err = ParseEntityChangeCursorError;
+
+    fn from_str(cursor: &str) -> Result<Self, Self::Err> {
+        let (transaction_id, sequence_id) =
+            cursor.split_once('-').ok_or(ParseEntityChangeCursorError)?;
+        let (Ok(transaction_id), Ok(sequence_id)) = (transaction_id.parse(), sequence_id.parse())
+        else {
+            return Err(ParseEntityChangeCursorError);
+        };
+        Ok(Self {
+            transaction_id,
+            sequence_id,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityChange {
+    pub cursor: EntityChangeCursor,
+    pub kind: EntityChangeKind,
+    pub changed_at: Timestamp<TransactionTime>,
+    /// The entity which was changed.
+    pub entity_id: EntityId,
+    /// The metadata of the edition which was created by the change.
+    ///
+    /// This is not set for [`EntityChangeKind::Delete`] as the entity does not exist anymore.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub metadata: Option<EntityMetadata>,
+}
+
+#[derive(Debug)]
+pub struct GetEntityChangesParams<'p> {
+    /// Only changes to entities matching this filter are returned.
+    pub filter: Filter<'p, Entity>,
+    /// Only changes after this cursor are returned.
+    ///
+    /// If this is not set, the feed is read from the beginning.
+    pub after: Option<EntityChangeCursor>,
+    /// The maximum number of changes to scan.
+    pub limit: usize,
+}
+
 /// Describes the API of a store implementation for [Entities].
 ///
 /// [Entities]: Entity
@@ -291,6 +769,35 @@ pub trait EntityStore: crud::ReadPaginated<Entity> {
     where
         R: IntoIterator<Item = EntityRelationAndSubject> + Send;
 
+    /// Creates multiple new [`Entities`] in a single transaction.
+    ///
+    /// Each entity is checked and validated individually. Entities which fail these checks are
+    /// not inserted and the corresponding error is returned at the same position as the input
+    /// parameters, while all other entities are inserted. Links may refer to entities which are
+    /// created in the same batch, in which case they are rejected together with these entities.
+    ///
+    /// # Errors:
+    ///
+    /// - if the store is not able to insert the batch as a whole, e.g. because the account referred
+    ///   to by `owned_by_id` does not exist
+    ///
+    /// [Entities]: Entity
+    fn create_entities<A: AuthorizationApi + Send + Sync, P, R>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        temporal_client: Option<&TemporalClient>,
+        params: P,
+    ) -> impl Future<
+        Output = Result<
+            Vec<Result<EntityMetadata, Report<InsertionError>>>,
+            Report<InsertionError>,
+        >,
+    > + Send
+    where
+        P: IntoIterator<Item = CreateEntityParams<R>, IntoIter: Send> + Send,
+        R: IntoIterator<Item = EntityRelationAndSubject> + Send;
+
     /// Validates an [`Entity`].
     ///
     /// # Errors:
@@ -310,8 +817,9 @@ pub trait EntityStore: crud::ReadPaginated<Entity> {
     /// [`EntityType`]s is stored in a different table and would need to be queried for each,
     /// this would be a lot less efficient.
     ///
-    /// This is not supposed to be used outside of benchmarking as in the long term we need to
-    /// figure out how to deal with batch inserting.
+    /// This is not supposed to be used outside of benchmarking, use [`create_entities`] instead.
+    ///
+    /// [`create_entities`]: Self::create_entities
     ///
     /// # Errors
     ///
@@ -354,6 +862,27 @@ pub trait EntityStore: crud::ReadPaginated<Entity> {
         Output = Result<(Subgraph, Option<EntityQueryCursor<'static>>), Report<QueryError>>,
     > + Send;
 
+    /// Counts the [`Entity`]s matching the [`Filter`] of the [`CountEntitiesParams`].
+    ///
+    /// Only entities the actor is allowed to view are counted. If a grouping is requested, the
+    /// count is additionally reported for each group.
+    ///
+    /// The permission of every entity matching the filter is checked. The checks are sent to the
+    /// authorization backend in concurrent batches, but the cost of counting still grows with the
+    /// number of matching entities rather than the number of groups. Callers counting large parts
+    /// of the graph should narrow the filter, e.g. to the webs they are interested in.
+    ///
+    /// # Errors
+    ///
+    /// - if the query could not be executed
+    /// - if the permissions of the entities could not be checked
+    fn count_entities<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        params: CountEntitiesParams<'_>,
+    ) -> impl Future<Output = Result<EntityCount, Report<QueryError>>> + Send;
+
     fn patch_entity<A: AuthorizationApi + Send + Sync>(
         &mut self,
         actor_id: AccountId,
@@ -362,10 +891,246 @@ pub trait EntityStore: crud::ReadPaginated<Entity> {
         params: PatchEntityParams,
     ) -> impl Future<Output = Result<EntityMetadata, Report<UpdateError>>> + Send;
 
+    /// Applies the correction in the [`CorrectEntityParams`] to the history of an [`Entity`].
+    ///
+    /// Every edition which is valid within the decision-time interval is patched, and the patched
+    /// edition replaces the original one for the overlapping part of the interval. The parts of
+    /// the original editions outside of the interval are kept. Adjacent corrected editions with
+    /// identical contents are merged into a single edition. The transaction-time history is not
+    /// altered, so the state before the correction can still be queried.
+    ///
+    /// Returns the metadata of the corrected editions. Parts of the interval in which the entity
+    /// did not exist are not corrected.
+    ///
+    /// # Errors
+    ///
+    /// - if the interval is empty
+    /// - if the entity does not exist within the interval
+    /// - if the actor is not allowed to update the entity
+    /// - if a corrected edition is not valid
+    fn correct_entity<A: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        temporal_client: Option<&TemporalClient>,
+        params: CorrectEntityParams,
+    ) -> impl Future<Output = Result<Vec<EntityMetadata>, Report<UpdateError>>> + Send;
+
+    /// Merges the duplicate [`Entity`] in the [`MergeEntitiesParams`] into the surviving one.
+    ///
+    /// The properties of both entities are merged according to the merge strategies and stored
+    /// in a new edition of the survivor, which is of the entity types of both entities. Every link
+    /// entity pointing to the duplicate is re-pointed to the survivor, and the relations of the
+    /// duplicate are added to the survivor. Afterwards, the duplicate is archived with an edition
+    /// recording the survivor it was merged into.
+    ///
+    /// Link endpoints are not temporal, so previous editions of a re-pointed link entity point to
+    /// the survivor as well. To record the re-point, a new edition is created for every re-pointed
+    /// link entity and its drafts, which appears as [`EntityChangeKind::Patch`] in the change feed.
+    ///
+    /// Returns the metadata of the new edition of the survivor.
+    ///
+    /// # Errors
+    ///
+    /// - if the survivor and the duplicate are the same entity or one of them is a draft
+    /// - if one of the entities does not exist
+    /// - if the actor is not allowed to update one of the entities or a link entity pointing to the
+    ///   duplicate
+    /// - if the merged properties are not valid for the survivor
+    fn merge_entities<A: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        temporal_client: Option<&TemporalClient>,
+        params: MergeEntitiesParams,
+    ) -> impl Future<Output = Result<EntityMetadata, Report<UpdateError>>> + Send;
+
+    /// Migrates all current [`Entity`]s of an entity type according to the
+    /// [`MigrateEntitiesParams`].
+    ///
+    /// Each entity, including drafts, is migrated individually: the property migrations are
+    /// applied, the entity type is replaced, and the result is validated against the new closed
+    /// entity type. Entities which fail are not updated and the error is returned for the
+    /// corresponding entity, while all other entities are updated. In a dry run, no entity is
+    /// updated and the result of every entity which could be migrated is `None`.
+    ///
+    /// # Errors
+    ///
+    /// - if the actor is not allowed to instantiate the target entity type
+    /// - if the target entity type does not exist
+    /// - if the store is not able to update the entities as a whole
+    fn migrate_entities<A: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        temporal_client: Option<&TemporalClient>,
+        params: MigrateEntitiesParams,
+    ) -> impl Future<Output = Result<Vec<EntityMigration>, Report<UpdateError>>> + Send;
+
+    /// Moves a link entity according to the [`MoveLinkParams`] and returns its new position.
+    ///
+    /// The link is positioned between the sibling it is placed after and the sibling which
+    /// directly followed that sibling, so the positions of all other links are left unchanged.
+    /// Positions are not temporal, the move applies to all editions of the link entity and is
+    /// recorded as [`EntityChangeKind::Move`] in the entity change feed instead.
+    ///
+    /// Links without a position are ordered after all positioned links by their [`EntityId`]. If
+    /// such a link is used as sibling, positions are assigned to all unpositioned links sharing a
+    /// type with the moved link, keeping their current order. As this rewrites the positions of
+    /// these links, the actor has to be allowed to update all of them.
+    ///
+    /// # Errors
+    ///
+    /// - if the link entity or the sibling does not exist
+    /// - if the sibling does not share a type with the link entity and has no position
+    /// - if the actor is not allowed to update the link entity
+    /// - if the actor is not allowed to update a link which is assigned a position
+    /// - if the link type is not ordered for the left entity
+    fn move_link<A: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        params: MoveLinkParams,
+    ) -> impl Future<Output = Result<LinkPosition, Report<UpdateError>>> + Send;
+
     fn update_entity_embeddings<A: AuthorizationApi + Send + Sync>(
         &mut self,
         actor_id: AccountId,
         authorization_api: &mut A,
         params: UpdateEntityEmbeddingsParams<'_>,
     ) -> impl Future<Output = Result<(), Report<UpdateError>>> + Send;
+
+    /// Permanently deletes the [`Entity`]s specified in the [`DeleteEntitiesParams`].
+    ///
+    /// Unlike archiving, this removes every edition, draft, embedding, link edge, change event, and
+    /// authorization relationship of the entities. Only a [`EntityChangeKind::Delete`] event is
+    /// kept for every deleted entity. The IDs of all deleted entities, including cascaded link
+    /// entities, are returned.
+    ///
+    /// # Errors
+    ///
+    /// - if one of the entities does not exist
+    /// - if the actor does not have full access to all of the deleted entities or is not an
+    ///   administrator of all webs the deleted entities belong to
+    /// - if one of the entities is still linked and `cascade_links` is not set
+    fn delete_entities<A: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        params: DeleteEntitiesParams,
+    ) -> impl Future<Output = Result<Vec<EntityId>, Report<DeletionError>>> + Send;
+
+    /// Returns the metadata of every edition of the [`Entity`] specified by `entity_id`.
+    ///
+    /// The editions are ordered by the start of their transaction time and then by the start of
+    /// their decision time. If the entity ID contains a draft ID, the editions of that draft are
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// - if the entity does not exist
+    /// - if the actor is not allowed to view the entity
+    fn get_entity_history<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        entity_id: EntityId,
+    ) -> impl Future<Output = Result<Vec<EntityMetadata>, Report<QueryError>>> + Send;
+
+    /// Compares the two editions selected in the [`DiffEntityParams`].
+    ///
+    /// # Errors
+    ///
+    /// - if one of the editions does not exist
+    /// - if the actor is not allowed to view one of the entities
+    fn diff_entity<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        params: DiffEntityParams,
+    ) -> impl Future<Output = Result<DiffEntityResult<'static>, Report<QueryError>>> + Send;
+
+    /// Compares the subgraphs resolved for the same query at the two temporal axes of the
+    /// [`DiffEntitySubgraphParams`].
+    ///
+    /// See [`Subgraph::diff`] for how the subgraphs are compared.
+    ///
+    /// # Errors
+    ///
+    /// - if reading one of the subgraphs fails
+    fn diff_entity_subgraph<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        params: DiffEntitySubgraphParams<'_>,
+    ) -> impl Future<Output = Result<SubgraphDiff, Report<QueryError>>> + Send;
+
+    /// Returns all drafts of the [`Entity`] specified by `entity_id`.
+    ///
+    /// The draft ID of `entity_id` is ignored. Drafts which were already published are not
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// - if the actor is not allowed to view the entity
+    /// - if reading the drafts from the store fails
+    fn get_entity_drafts<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        entity_id: EntityId,
+    ) -> impl Future<Output = Result<Vec<EntityDraft>, Report<QueryError>>> + Send;
+
+    /// Compares the draft specified by `entity_id` to the current live edition of the entity.
+    ///
+    /// If the live entity was updated since the draft was created, the draft is merged into the
+    /// current live edition the same way it would be when it is published.
+    ///
+    /// # Errors
+    ///
+    /// - if `entity_id` does not refer to a draft
+    /// - if the draft or the live entity does not exist
+    /// - if the actor is not allowed to view the entity
+    fn diff_entity_draft<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        entity_id: EntityId,
+    ) -> impl Future<Output = Result<DiffEntityDraftResult<'static>, Report<QueryError>>> + Send;
+
+    /// Reads the changes made to [`Entity`]s after the cursor in the [`GetEntityChangesParams`].
+    ///
+    /// At most `limit` changes are scanned. Of those, only the changes to entities which match the
+    /// filter and which the actor is allowed to view are returned. Deleted entities cannot be
+    /// matched against the filter, so deletions are returned for every web the actor is allowed to
+    /// view entities in. The returned cursor points to
+    /// the last scanned change, so reading can be continued from there even if none of the scanned
+    /// changes were returned. If no changes were scanned, `None` is returned instead.
+    ///
+    /// # Errors
+    ///
+    /// - if reading the changes from the store fails
+    /// - if the edition created by a change does not exist
+    fn get_entity_changes<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        params: GetEntityChangesParams<'_>,
+    ) -> impl Future<
+        Output = Result<(Vec<EntityChange>, Option<EntityChangeCursor>), Report<QueryError>>,
+    > + Send;
+
+    /// Removes the changes which are older than `retention` from the entity change feed.
+    ///
+    /// Consumers continuing the feed from a cursor before the removed changes will not receive
+    /// them, so the retention has to be longer than consumers are expected to lag behind. The
+    /// number of removed changes is returned.
+    ///
+    /// # Errors
+    ///
+    /// - if removing the changes from the store fails
+    fn prune_entity_changes(
+        &mut self,
+        retention: Duration,
+    ) -> impl Future<Output = Result<u64, Report<DeletionError>>> + Send;
 }
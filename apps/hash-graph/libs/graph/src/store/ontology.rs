@@ -10,9 +10,9 @@ use error_stack::Result;
 use graph_types::{
     account::AccountId,
     ontology::{
-        DataTypeMetadata, DataTypeWithMetadata, EntityTypeMetadata, EntityTypeWithMetadata,
-        OntologyTemporalMetadata, OntologyTypeClassificationMetadata, PropertyTypeMetadata,
-        PropertyTypeWithMetadata, ProvidedOntologyEditionProvenanceMetadata,
+        DataTypeConversion, DataTypeMetadata, DataTypeWithMetadata, EntityTypeMetadata,
+        EntityTypeWithMetadata, OntologyTemporalMetadata, OntologyTypeClassificationMetadata,
+        PropertyTypeMetadata, PropertyTypeWithMetadata, ProvidedOntologyEditionProvenanceMetadata,
     },
     Embedding,
 };
@@ -45,6 +45,8 @@ pub struct CreateDataTypeParams<R> {
     pub classification: OntologyTypeClassificationMetadata,
     pub relationships: R,
     pub conflict_behavior: ConflictBehavior,
+    #[serde(default)]
+    pub conversion: Option<DataTypeConversion>,
     #[serde(default, skip_serializing_if = "UserDefinedProvenanceData::is_empty")]
     pub provenance: ProvidedOntologyEditionProvenanceMetadata,
 }
@@ -65,8 +67,16 @@ pub struct GetDataTypesParams<'p> {
 pub struct UpdateDataTypesParams<R> {
     pub schema: DataType,
     pub relationships: R,
+    #[serde(default)]
+    pub conversion: Option<DataTypeConversion>,
     #[serde(default, skip_serializing_if = "UserDefinedProvenanceData::is_empty")]
     pub provenance: ProvidedOntologyEditionProvenanceMetadata,
+    /// Rejects the update with [`BreakingOntologyTypeChanges`] if it is not backwards compatible
+    /// to the previous version.
+    ///
+    /// [`BreakingOntologyTypeChanges`]: crate::store::error::BreakingOntologyTypeChanges
+    #[serde(default)]
+    pub reject_breaking_changes: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -154,6 +164,9 @@ pub trait DataTypeStore {
 
     /// Get the [`Subgraph`] specified by the [`StructuralQuery`].
     ///
+    /// If a limit is provided and more data types are available, a cursor is returned which can be
+    /// passed as `after` to read the next page.
+    ///
     /// # Errors
     ///
     /// - if the requested [`DataType`] doesn't exist.
@@ -162,13 +175,17 @@ pub trait DataTypeStore {
         actor_id: AccountId,
         authorization_api: &A,
         params: GetDataTypesParams<'_>,
-    ) -> impl Future<Output = Result<Subgraph, QueryError>> + Send;
+    ) -> impl Future<Output = Result<(Subgraph, Option<DataTypeVertexId>), QueryError>> + Send;
 
     /// Update the definition of an existing [`DataType`].
     ///
     /// # Errors
     ///
     /// - if the [`DataType`] doesn't exist.
+    /// - [`BreakingOntologyTypeChanges`] if `reject_breaking_changes` is set and the update is not
+    ///   backwards compatible
+    ///
+    /// [`BreakingOntologyTypeChanges`]: crate::store::error::BreakingOntologyTypeChanges
     fn update_data_type<A: AuthorizationApi + Send + Sync, R>(
         &mut self,
         actor_id: AccountId,
@@ -245,6 +262,12 @@ pub struct UpdatePropertyTypesParams<R> {
     pub relationships: R,
     #[serde(default, skip_serializing_if = "UserDefinedProvenanceData::is_empty")]
     pub provenance: ProvidedOntologyEditionProvenanceMetadata,
+    /// Rejects the update with [`BreakingOntologyTypeChanges`] if it is not backwards compatible
+    /// to the previous version.
+    ///
+    /// [`BreakingOntologyTypeChanges`]: crate::store::error::BreakingOntologyTypeChanges
+    #[serde(default)]
+    pub reject_breaking_changes: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -332,6 +355,9 @@ pub trait PropertyTypeStore {
 
     /// Get the [`Subgraph`] specified by the [`StructuralQuery`].
     ///
+    /// If a limit is provided and more property types are available, a cursor is returned which can
+    /// be passed as `after` to read the next page.
+    ///
     /// # Errors
     ///
     /// - if the requested [`PropertyType`] doesn't exist.
@@ -340,13 +366,17 @@ pub trait PropertyTypeStore {
         actor_id: AccountId,
         authorization_api: &A,
         params: GetPropertyTypesParams<'_>,
-    ) -> impl Future<Output = Result<Subgraph, QueryError>> + Send;
+    ) -> impl Future<Output = Result<(Subgraph, Option<PropertyTypeVertexId>), QueryError>> + Send;
 
     /// Update the definition of an existing [`PropertyType`].
     ///
     /// # Errors
     ///
     /// - if the [`PropertyType`] doesn't exist.
+    /// - [`BreakingOntologyTypeChanges`] if `reject_breaking_changes` is set and the update is not
+    ///   backwards compatible
+    ///
+    /// [`BreakingOntologyTypeChanges`]: crate::store::error::BreakingOntologyTypeChanges
     fn update_property_type<A: AuthorizationApi + Send + Sync, R>(
         &mut self,
         actor_id: AccountId,
@@ -427,6 +457,12 @@ pub struct UpdateEntityTypesParams<R> {
     pub relationships: R,
     #[serde(default, skip_serializing_if = "UserDefinedProvenanceData::is_empty")]
     pub provenance: ProvidedOntologyEditionProvenanceMetadata,
+    /// Rejects the update with [`BreakingOntologyTypeChanges`] if it is not backwards compatible
+    /// to the previous version.
+    ///
+    /// [`BreakingOntologyTypeChanges`]: crate::store::error::BreakingOntologyTypeChanges
+    #[serde(default)]
+    pub reject_breaking_changes: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -514,6 +550,9 @@ pub trait EntityTypeStore {
 
     /// Get the [`Subgraph`]s specified by the [`StructuralQuery`].
     ///
+    /// If a limit is provided and more entity types are available, a cursor is returned which can
+    /// be passed as `after` to read the next page.
+    ///
     /// # Errors
     ///
     /// - if the requested [`EntityType`] doesn't exist.
@@ -522,13 +561,17 @@ pub trait EntityTypeStore {
         actor_id: AccountId,
         authorization_api: &A,
         params: GetEntityTypesParams<'_>,
-    ) -> impl Future<Output = Result<Subgraph, QueryError>> + Send;
+    ) -> impl Future<Output = Result<(Subgraph, Option<EntityTypeVertexId>), QueryError>> + Send;
 
     /// Update the definition of an existing [`EntityType`].
     ///
     /// # Errors
     ///
     /// - if the [`EntityType`] doesn't exist.
+    /// - [`BreakingOntologyTypeChanges`] if `reject_breaking_changes` is set and the update is not
+    ///   backwards compatible
+    ///
+    /// [`BreakingOntologyTypeChanges`]: crate::store::error::BreakingOntologyTypeChanges
     fn update_entity_type<A: AuthorizationApi + Send + Sync, R>(
         &mut self,
         actor_id: AccountId,
@@ -3,43 +3,50 @@ mod read;
 
 use std::{
     borrow::Cow,
-    collections::{HashMap, HashSet},
+    collections::{hash_map::Entry, HashMap, HashSet},
     iter::once,
+    mem,
+    time::Duration,
 };
 
 use authorization::{
-    backend::ModifyRelationshipOperation,
+    backend::{ModifyRelationshipOperation, PermissionAssertion},
     schema::{
-        EntityOwnerSubject, EntityPermission, EntityRelationAndSubject, EntityTypeId,
+        DataTypeId, EntityOwnerSubject, EntityPermission, EntityRelationAndSubject, EntityTypeId,
         EntityTypePermission, WebPermission,
     },
     zanzibar::{Consistency, Zookie},
     AuthorizationApi,
 };
 use error_stack::{bail, ensure, Report, Result, ResultExt};
-use futures::TryStreamExt;
+use futures::{future, TryStreamExt};
 use graph_types::{
     account::{AccountId, CreatedById, EditionArchivedById, EditionCreatedById},
     knowledge::{
         entity::{
             DraftId, Entity, EntityEditionId, EntityEditionProvenanceMetadata, EntityEmbedding,
             EntityId, EntityMetadata, EntityProvenanceMetadata, EntityRecordId,
-            EntityTemporalMetadata, EntityUuid, ProvidedEntityEditionProvenanceMetadata,
+            EntityTemporalMetadata, EntityUuid, InferredEntityProvenanceMetadata,
+            ProvidedEntityEditionProvenanceMetadata,
         },
-        link::LinkData,
-        Confidence, PropertyConfidence, PropertyObject, PropertyPath,
+        link::{LinkData, LinkPosition},
+        Confidence, Property, PropertyConfidence, PropertyDataTypes, PropertyMergeConflict,
+        PropertyObject, PropertyPath, PropertyProvenance, PropertyProvenances,
     },
     owned_by_id::OwnedById,
     Embedding,
 };
 use hash_status::StatusCode;
 use postgres_types::{Json, ToSql};
+use serde_json::Value as JsonValue;
 use temporal_client::TemporalClient;
 use temporal_versioning::{
     ClosedTemporalBound, DecisionTime, LeftClosedTemporalInterval, LimitedTemporalBound,
-    RightBoundedTemporalInterval, TemporalBound, Timestamp, TransactionTime,
+    OpenTemporalBound, TemporalBound, TemporalTagged, Timestamp, TransactionTime,
+};
+use tokio_postgres::{
+    binary_copy::BinaryCopyInWriter, error::SqlState, types::Type, GenericClient, Row,
 };
-use tokio_postgres::{error::SqlState, GenericClient, Row};
 use type_system::{url::VersionedUrl, ClosedEntityType};
 use uuid::Uuid;
 use validation::{Validate, ValidateEntityComponents};
@@ -49,15 +56,25 @@ use crate::{
     ontology::EntityTypeQueryPath,
     store::{
         crud::{QueryResult, Read, ReadPaginated, Sorting},
-        error::{DeletionError, EntityDoesNotExist, RaceConditionOnUpdate},
+        error::{
+            DeletionError, EntityDoesNotExist, EntityEditionMismatch, RaceConditionOnUpdate,
+            UnpublishedLinkEndpoints,
+        },
         knowledge::{
-            CreateEntityParams, EntityQueryCursor, EntityQuerySorting, EntityValidationType,
-            GetEntityParams, PatchEntityParams, UpdateEntityEmbeddingsParams, ValidateEntityError,
+            CorrectEntityParams, CountEntitiesParams, CreateEntityParams, DeleteEntitiesParams,
+            DiffEntityDraftResult, DiffEntityParams, DiffEntityResult, DiffEntitySubgraphParams,
+            EntityChange, EntityChangeCursor, EntityChangeKind, EntityCount, EntityCountGroup,
+            EntityCountGroupBy, EntityCountGroupKey, EntityDraft, EntityEditionSelector,
+            EntityMigration, EntityQueryCursor, EntityQuerySorting, EntityValidationType,
+            GetEntityChangesParams, GetEntityParams, MergeEntitiesParams, MigrateEntitiesParams,
+            MoveLinkParams, PatchEntityParams, UpdateEntityEmbeddingsParams, ValidateEntityError,
             ValidateEntityParams,
         },
         postgres::{
-            knowledge::entity::read::EntityEdgeTraversalData, ontology::OntologyId,
-            query::ReferenceTable, TraversalContext,
+            knowledge::entity::read::EntityEdgeTraversalData,
+            ontology::OntologyId,
+            query::{ReferenceTable, SelectCompiler},
+            TraversalContext, TraversalQueue,
         },
         query::{Filter, FilterExpression, Parameter, ParameterList},
         validation::StoreProvider,
@@ -65,12 +82,13 @@ use crate::{
         SubgraphRecord, UpdateError,
     },
     subgraph::{
-        edges::{EdgeDirection, GraphResolveDepths, KnowledgeGraphEdgeKind, SharedEdgeKind},
-        identifier::{EntityIdWithInterval, EntityVertexId},
+        diff::SubgraphDiff,
+        edges::{EdgeDirection, KnowledgeGraphEdgeKind, SharedEdgeKind},
+        identifier::EntityIdWithInterval,
+        query::StructuralQuery,
         temporal_axes::{
             PinnedTemporalAxis, PinnedTemporalAxisUnresolved, QueryTemporalAxes,
-            QueryTemporalAxesUnresolved, VariableAxis, VariableTemporalAxis,
-            VariableTemporalAxisUnresolved,
+            QueryTemporalAxesUnresolved, VariableTemporalAxis, VariableTemporalAxisUnresolved,
         },
         Subgraph,
     },
@@ -82,15 +100,11 @@ impl<C: AsClient> PostgresStore<C> {
     /// This is used to recursively resolve a type, so the result can be reused.
     #[tracing::instrument(
         level = "info",
-        skip(self, traversal_context, subgraph, authorization_api, zookie)
+        skip(self, queue, traversal_context, subgraph, authorization_api, zookie)
     )]
     pub(crate) async fn traverse_entities<A>(
         &self,
-        mut entity_queue: Vec<(
-            EntityVertexId,
-            GraphResolveDepths,
-            RightBoundedTemporalInterval<VariableAxis>,
-        )>,
+        queue: &mut TraversalQueue,
         traversal_context: &mut TraversalContext,
         actor_id: AccountId,
         authorization_api: &A,
@@ -102,7 +116,7 @@ impl<C: AsClient> PostgresStore<C> {
     {
         let variable_axis = subgraph.temporal_axes.resolved.variable_time_axis();
 
-        let mut entity_type_queue = Vec::new();
+        let mut entity_queue = mem::take(&mut queue.entities);
 
         while !entity_queue.is_empty() {
             let mut shared_edges_to_traverse = Option::<EntityEdgeTraversalData>::None;
@@ -175,7 +189,7 @@ impl<C: AsClient> PostgresStore<C> {
             }
 
             if let Some(traversal_data) = shared_edges_to_traverse.take() {
-                entity_type_queue.extend(
+                queue.entity_types.extend(
                     Self::filter_entity_types_by_permission(
                         self.read_shared_edges(&traversal_data, Some(0)).await?,
                         actor_id,
@@ -265,25 +279,16 @@ impl<C: AsClient> PostgresStore<C> {
             }
         }
 
-        self.traverse_entity_types(
-            entity_type_queue,
-            traversal_context,
-            actor_id,
-            authorization_api,
-            zookie,
-            subgraph,
-        )
-        .await?;
-
         Ok(())
     }
 
     #[tracing::instrument(level = "info", skip(self))]
-    pub async fn delete_entities(&mut self) -> Result<(), DeletionError> {
+    pub async fn delete_all_entities(&mut self) -> Result<(), DeletionError> {
         self.as_client()
             .client()
             .simple_query(
                 "
+                    DELETE FROM entity_change_events;
                     DELETE FROM entity_has_left_entity;
                     DELETE FROM entity_has_right_entity;
                     DELETE FROM entity_property;
@@ -300,6 +305,259 @@ impl<C: AsClient> PostgresStore<C> {
 
         Ok(())
     }
+
+    /// Reads the edition of an entity specified by the [`EntityEditionSelector`].
+    ///
+    /// If the selector matches more than one temporal version, the one with the latest
+    /// transaction time is returned.
+    #[tracing::instrument(level = "trace", skip(self))]
+    async fn read_entity_edition(
+        &self,
+        entity_id: EntityId,
+        edition: EntityEditionSelector,
+    ) -> Result<Entity, QueryError> {
+        let entities = match edition {
+            EntityEditionSelector::EditionId(edition_id) => {
+                Read::<Entity>::read_vec(
+                    self,
+                    &Filter::All(vec![
+                        Filter::for_entity_by_entity_id(entity_id),
+                        Filter::Equal(
+                            Some(FilterExpression::Path(EntityQueryPath::EditionId)),
+                            Some(FilterExpression::Parameter(Parameter::Uuid(
+                                edition_id.into_uuid(),
+                            ))),
+                        ),
+                    ]),
+                    None,
+                    true,
+                )
+                .await?
+            }
+            EntityEditionSelector::Timestamp {
+                decision_time,
+                transaction_time,
+            } => {
+                Read::<Entity>::read_vec(
+                    self,
+                    &Filter::for_entity_by_entity_id(entity_id),
+                    Some(
+                        &QueryTemporalAxesUnresolved::DecisionTime {
+                            pinned: PinnedTemporalAxisUnresolved::new(transaction_time),
+                            variable: VariableTemporalAxisUnresolved::new(
+                                decision_time.map(TemporalBound::Inclusive),
+                                decision_time.map(LimitedTemporalBound::Inclusive),
+                            ),
+                        }
+                        .resolve(),
+                    ),
+                    entity_id.draft_id.is_some(),
+                )
+                .await?
+            }
+        };
+
+        entities
+            .into_iter()
+            .max_by_key(|entity| {
+                let ClosedTemporalBound::Inclusive(transaction_time) =
+                    *entity.metadata.temporal_versioning.transaction_time.start();
+                transaction_time
+            })
+            .ok_or_else(|| {
+                Report::new(EntityDoesNotExist)
+                    .attach(StatusCode::NotFound)
+                    .attach_printable(entity_id)
+                    .change_context(QueryError)
+            })
+    }
+
+    /// Validates an entity in the same way as [`EntityStore::validate_entity`].
+    ///
+    /// Link endpoints are looked up in `pending_entities` before they are read from the store, so
+    /// links between entities which are not inserted yet can be validated.
+    async fn validate_entity_with_pending<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        consistency: Consistency<'_>,
+        params: ValidateEntityParams<'_>,
+        pending_entities: Option<&HashMap<(OwnedById, EntityUuid), Entity>>,
+    ) -> Result<(), ValidateEntityError> {
+        let schema = match params.entity_types {
+            EntityValidationType::ClosedSchema(schema) => schema,
+            EntityValidationType::Schema(schemas) => Cow::Owned(schemas.into_iter().collect()),
+            EntityValidationType::Id(entity_type_url) => {
+                let (ontology_type_ids, ontology_type_uuids): (Vec<_>, Vec<_>) = entity_type_url
+                    .as_ref()
+                    .iter()
+                    .map(|url| {
+                        let id = EntityTypeId::from_url(url);
+                        (id, id.into_uuid())
+                    })
+                    .unzip();
+
+                if !authorization_api
+                    .check_entity_types_permission(
+                        actor_id,
+                        EntityTypePermission::View,
+                        ontology_type_ids.iter().copied(),
+                        consistency,
+                    )
+                    .await
+                    .change_context(ValidateEntityError)?
+                    .0
+                    .into_iter()
+                    .all(|(_, permission)| permission)
+                {
+                    bail!(Report::new(ValidateEntityError).attach(StatusCode::PermissionDenied));
+                }
+
+                let mut closed_schemas = self
+                    .read_closed_schemas(
+                        &Filter::In(
+                            FilterExpression::Path(EntityTypeQueryPath::OntologyId),
+                            ParameterList::Uuid(&ontology_type_uuids),
+                        ),
+                        Some(
+                            &QueryTemporalAxesUnresolved::DecisionTime {
+                                pinned: PinnedTemporalAxisUnresolved::new(None),
+                                variable: VariableTemporalAxisUnresolved::new(None, None),
+                            }
+                            .resolve(),
+                        ),
+                    )
+                    .await
+                    .change_context(ValidateEntityError)?
+                    .map_ok(|(_, raw_type)| raw_type)
+                    .try_collect::<Vec<ClosedEntityType>>()
+                    .await
+                    .change_context(ValidateEntityError)?;
+
+                ensure!(
+                    closed_schemas.len() <= 1,
+                    Report::new(ValidateEntityError).attach_printable(format!(
+                        "Expected exactly one closed schema to be returned from the query but {} \
+                         were returned",
+                        closed_schemas.len(),
+                    ))
+                );
+                Cow::Owned(closed_schemas.pop().ok_or_else(|| {
+                    Report::new(ValidateEntityError).attach_printable(
+                        "Expected exactly one closed schema to be returned from the query but \
+                         none was returned",
+                    )
+                })?)
+            }
+        };
+
+        let mut status: Result<(), validation::EntityValidationError> = if schema.schemas.is_empty()
+        {
+            Err(Report::new(
+                validation::EntityValidationError::EmptyEntityTypes,
+            ))
+        } else {
+            Ok(())
+        };
+
+        let validator_provider = StoreProvider {
+            store: self,
+            cache: StoreCache::default(),
+            authorization: Some((authorization_api, actor_id, Consistency::FullyConsistent)),
+            pending_entities,
+        };
+
+        let property_status = match validation::normalize_properties(
+            params.properties.as_ref(),
+            params.property_data_types.as_ref(),
+            &schema,
+            params.components,
+            &validator_provider,
+        )
+        .await
+        {
+            Ok(properties) => {
+                properties
+                    .validate(&schema, params.components, &validator_provider)
+                    .await
+            }
+            Err(error) => Err(error),
+        };
+        if let Err(error) = property_status {
+            if let Err(ref mut report) = status {
+                report.extend_one(error);
+            } else {
+                status = Err(error);
+            }
+        }
+
+        if let Err(error) = params
+            .property_confidence
+            .validate(
+                params.properties.as_ref(),
+                params.components,
+                &validator_provider,
+            )
+            .await
+        {
+            if let Err(ref mut report) = status {
+                report.extend_one(error);
+            } else {
+                status = Err(error);
+            }
+        }
+
+        if let Err(error) = params
+            .property_provenance
+            .validate(
+                params.properties.as_ref(),
+                params.components,
+                &validator_provider,
+            )
+            .await
+        {
+            if let Err(ref mut report) = status {
+                report.extend_one(error);
+            } else {
+                status = Err(error);
+            }
+        }
+
+        if let Err(error) = params
+            .link_data
+            .as_deref()
+            .validate(&schema, params.components, &validator_provider)
+            .await
+        {
+            let unpublished_endpoints = error
+                .frames()
+                .filter_map(|frame| match frame.downcast_ref() {
+                    Some(validation::EntityValidationError::UnpublishedLinkEndpoint { id }) => {
+                        Some(*id)
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            let error = if unpublished_endpoints.is_empty() {
+                error
+            } else {
+                error.attach(UnpublishedLinkEndpoints {
+                    link_entity_ids: Vec::new(),
+                    entity_ids: unpublished_endpoints,
+                })
+            };
+
+            if let Err(ref mut report) = status {
+                report.extend_one(error);
+            } else {
+                status = Err(error);
+            }
+        }
+
+        status
+            .change_context(ValidateEntityError)
+            .attach(StatusCode::InvalidArgument)
+    }
 }
 
 impl<C: AsClient> EntityStore for PostgresStore<C> {
@@ -500,7 +758,7 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                 .change_context(InsertionError)?;
         }
 
-        if let Some(link_data) = params.link_data {
+        if let Some(link_data) = &params.link_data {
             transaction
                 .as_client()
                 .query(
@@ -510,8 +768,9 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                             entity_uuid,
                             left_web_id,
                             left_entity_uuid,
-                            confidence
-                        ) VALUES ($1, $2, $3, $4, $5);
+                            confidence,
+                            position
+                        ) VALUES ($1, $2, $3, $4, $5, $6);
                     ",
                     &[
                         &entity_id.owned_by_id,
@@ -519,6 +778,7 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                         &link_data.left_entity_id.owned_by_id,
                         &link_data.left_entity_id.entity_uuid,
                         &link_data.left_entity_confidence,
+                        &link_data.position,
                     ],
                 )
                 .await
@@ -551,6 +811,7 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
         let edition_provenance = EntityEditionProvenanceMetadata {
             created_by_id: EditionCreatedById::new(actor_id),
             archived_by_id: None,
+            merged_into: None,
             user_defined: params.provenance,
         };
         let (edition_id, closed_schema) = transaction
@@ -564,12 +825,21 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
             .await?;
 
         transaction
-            .insert_properties(edition_id, &params.property_confidence)
+            .insert_properties(
+                edition_id,
+                &params.properties,
+                &params.property_confidence,
+                &params.property_data_types,
+                &params.property_provenance,
+            )
             .await?;
 
         let temporal_versioning = transaction
             .insert_temporal_metadata(entity_id, edition_id, params.decision_time)
             .await?;
+        transaction
+            .insert_entity_changes([(entity_id, edition_id, EntityChangeKind::Create)])
+            .await?;
 
         authorization_api
             .modify_entity_relations(relationships.clone().into_iter().map(
@@ -593,13 +863,11 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                     entity_types: EntityValidationType::ClosedSchema(Cow::Owned(closed_schema)),
                     properties: Cow::Borrowed(&params.properties),
                     property_confidence: Cow::Borrowed(&params.property_confidence),
+                    property_data_types: Cow::Borrowed(&params.property_data_types),
+                    property_provenance: Cow::Borrowed(&params.property_provenance),
                     link_data: params.link_data.as_ref().map(Cow::Borrowed),
                     components: if params.draft {
-                        ValidateEntityComponents {
-                            num_items: false,
-                            required_properties: false,
-                            ..ValidateEntityComponents::full()
-                        }
+                        ValidateEntityComponents::draft()
                     } else {
                         ValidateEntityComponents::full()
                     },
@@ -658,6 +926,8 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                 archived: false,
                 confidence: params.confidence,
                 property_confidence: params.property_confidence,
+                property_data_types: params.property_data_types,
+                property_provenance: params.property_provenance,
             };
             if let Some(temporal_client) = temporal_client {
                 temporal_client
@@ -676,144 +946,467 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
         }
     }
 
-    // TODO: Relax constraints on entity validation for draft entities
-    //   see https://linear.app/hash/issue/H-1449
-    // TODO: Restrict non-draft links to non-draft entities
-    //   see https://linear.app/hash/issue/H-1450
-    #[tracing::instrument(level = "info", skip(self, authorization_api))]
-    async fn validate_entity<A: AuthorizationApi + Sync>(
-        &self,
+    #[tracing::instrument(level = "info", skip(self, authorization_api, temporal_client, params))]
+    async fn create_entities<A: AuthorizationApi + Send + Sync, P, R>(
+        &mut self,
         actor_id: AccountId,
-        authorization_api: &A,
-        consistency: Consistency<'_>,
-        params: ValidateEntityParams<'_>,
-    ) -> Result<(), ValidateEntityError> {
-        let schema = match params.entity_types {
-            EntityValidationType::ClosedSchema(schema) => schema,
-            EntityValidationType::Schema(schemas) => Cow::Owned(schemas.into_iter().collect()),
-            EntityValidationType::Id(entity_type_url) => {
-                let (ontology_type_ids, ontology_type_uuids): (Vec<_>, Vec<_>) = entity_type_url
-                    .as_ref()
-                    .iter()
-                    .map(|url| {
-                        let id = EntityTypeId::from_url(url);
-                        (id, id.into_uuid())
-                    })
-                    .unzip();
-
-                if !authorization_api
-                    .check_entity_types_permission(
-                        actor_id,
-                        EntityTypePermission::View,
-                        ontology_type_ids.iter().copied(),
-                        consistency,
-                    )
-                    .await
-                    .change_context(ValidateEntityError)?
-                    .0
+        authorization_api: &mut A,
+        temporal_client: Option<&TemporalClient>,
+        params: P,
+    ) -> Result<Vec<Result<EntityMetadata, InsertionError>>, InsertionError>
+    where
+        P: IntoIterator<Item = CreateEntityParams<R>, IntoIter: Send> + Send,
+        R: IntoIterator<Item = EntityRelationAndSubject> + Send,
+    {
+        let params = params
+            .into_iter()
+            .map(|params| CreateEntityParams {
+                relationships: params
+                    .relationships
                     .into_iter()
-                    .all(|(_, permission)| permission)
-                {
-                    bail!(Report::new(ValidateEntityError).attach(StatusCode::PermissionDenied));
-                }
+                    .chain(once(EntityRelationAndSubject::Owner {
+                        subject: EntityOwnerSubject::Web {
+                            id: params.owned_by_id,
+                        },
+                        level: 0,
+                    }))
+                    .collect::<Vec<_>>(),
+                owned_by_id: params.owned_by_id,
+                entity_uuid: params.entity_uuid,
+                decision_time: params.decision_time,
+                entity_type_ids: params.entity_type_ids,
+                properties: params.properties,
+                confidence: params.confidence,
+                property_confidence: params.property_confidence,
+                property_data_types: params.property_data_types,
+                property_provenance: params.property_provenance,
+                link_data: params.link_data,
+                draft: params.draft,
+                provenance: params.provenance,
+            })
+            .collect::<Vec<_>>();
 
-                let mut closed_schemas = self
-                    .read_closed_schemas(
-                        &Filter::In(
-                            FilterExpression::Path(EntityTypeQueryPath::OntologyId),
-                            ParameterList::Uuid(&ontology_type_uuids),
-                        ),
-                        Some(
-                            &QueryTemporalAxesUnresolved::DecisionTime {
-                                pinned: PinnedTemporalAxisUnresolved::new(None),
-                                variable: VariableTemporalAxisUnresolved::new(None, None),
-                            }
-                            .resolve(),
-                        ),
-                    )
-                    .await
-                    .change_context(ValidateEntityError)?
-                    .map_ok(|(_, raw_type)| raw_type)
-                    .try_collect::<Vec<ClosedEntityType>>()
-                    .await
-                    .change_context(ValidateEntityError)?;
+        let entity_type_ids = params
+            .iter()
+            .flat_map(|params| &params.entity_type_ids)
+            .map(EntityTypeId::from_url)
+            .collect::<HashSet<_>>();
+        let (instantiate_permissions, _) = authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::Instantiate,
+                entity_type_ids.clone(),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(InsertionError)?;
 
-                ensure!(
-                    closed_schemas.len() <= 1,
-                    Report::new(ValidateEntityError).attach_printable(format!(
-                        "Expected exactly one closed schema to be returned from the query but {} \
-                         were returned",
-                        closed_schemas.len(),
-                    ))
+        let webs = params
+            .iter()
+            .filter(|params| {
+                Some(params.owned_by_id.into_uuid())
+                    != params.entity_uuid.map(EntityUuid::into_uuid)
+            })
+            .map(|params| params.owned_by_id)
+            .collect::<HashSet<_>>();
+        let (web_permissions, _) = authorization_api
+            .check_webs_permission(
+                actor_id,
+                WebPermission::CreateEntity,
+                webs,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(InsertionError)?;
+
+        let entity_type_uuids = entity_type_ids
+            .into_iter()
+            .map(EntityTypeId::into_uuid)
+            .collect::<Vec<_>>();
+        let closed_schemas = self
+            .read_closed_schemas(
+                &Filter::In(
+                    FilterExpression::Path(EntityTypeQueryPath::OntologyId),
+                    ParameterList::Uuid(&entity_type_uuids),
+                ),
+                None,
+            )
+            .await
+            .change_context(InsertionError)?
+            .try_collect::<HashMap<_, _>>()
+            .await
+            .change_context(InsertionError)?;
+
+        let (requested_web_ids, requested_entity_uuids): (Vec<_>, Vec<_>) = params
+            .iter()
+            .filter_map(|params| {
+                params
+                    .entity_uuid
+                    .map(|entity_uuid| (params.owned_by_id, entity_uuid))
+            })
+            .unzip();
+        let existing_entities = self
+            .as_client()
+            .query(
+                "
+                    SELECT web_id, entity_uuid
+                    FROM entity_ids
+                    JOIN unnest($1::UUID[], $2::UUID[]) AS requested(web_id, entity_uuid)
+                    USING (web_id, entity_uuid);
+                ",
+                &[&requested_web_ids, &requested_entity_uuids],
+            )
+            .await
+            .change_context(InsertionError)?
+            .into_iter()
+            .map(|row| (row.get::<_, OwnedById>(0), row.get::<_, EntityUuid>(1)))
+            .collect::<HashSet<_>>();
+
+        let mut errors = HashMap::new();
+        let mut requested_entities = HashSet::new();
+        let mut pending = Vec::with_capacity(params.len());
+        for (index, params) in params.iter().enumerate() {
+            if !params.entity_type_ids.iter().all(|entity_type_id| {
+                instantiate_permissions
+                    .get(&EntityTypeId::from_url(entity_type_id))
+                    .copied()
+                    .unwrap_or(false)
+            }) {
+                errors.insert(
+                    index,
+                    Report::new(PermissionAssertion)
+                        .change_context(InsertionError)
+                        .attach(StatusCode::PermissionDenied),
                 );
-                Cow::Owned(closed_schemas.pop().ok_or_else(|| {
-                    Report::new(ValidateEntityError).attach_printable(
-                        "Expected exactly one closed schema to be returned from the query but \
-                         none was returned",
-                    )
-                })?)
+                continue;
             }
-        };
 
-        let mut status: Result<(), validation::EntityValidationError> = if schema.schemas.is_empty()
-        {
-            Err(Report::new(
-                validation::EntityValidationError::EmptyEntityTypes,
-            ))
-        } else {
-            Ok(())
-        };
+            if !web_permissions
+                .get(&params.owned_by_id)
+                .copied()
+                .unwrap_or(true)
+            {
+                errors.insert(
+                    index,
+                    Report::new(PermissionAssertion)
+                        .change_context(InsertionError)
+                        .attach(StatusCode::PermissionDenied),
+                );
+                continue;
+            }
 
-        let validator_provider = StoreProvider {
-            store: self,
-            cache: StoreCache::default(),
-            authorization: Some((authorization_api, actor_id, Consistency::FullyConsistent)),
-        };
+            let Some(closed_schema) = params
+                .entity_type_ids
+                .iter()
+                .map(|entity_type_id| {
+                    closed_schemas
+                        .get(&EntityTypeId::from_url(entity_type_id))
+                        .cloned()
+                })
+                .collect::<Option<ClosedEntityType>>()
+            else {
+                errors.insert(
+                    index,
+                    Report::new(InsertionError)
+                        .attach_printable("Not all entity types could be found")
+                        .attach(StatusCode::NotFound),
+                );
+                continue;
+            };
 
-        if let Err(error) = params
-            .properties
-            .validate(&schema, params.components, &validator_provider)
-            .await
-        {
-            if let Err(ref mut report) = status {
-                report.extend_one(error);
-            } else {
-                status = Err(error);
+            let entity_id = EntityId {
+                owned_by_id: params.owned_by_id,
+                entity_uuid: params
+                    .entity_uuid
+                    .unwrap_or_else(|| EntityUuid::new(Uuid::new_v4())),
+                draft_id: params.draft.then(|| DraftId::new(Uuid::new_v4())),
+            };
+            if existing_entities.contains(&(entity_id.owned_by_id, entity_id.entity_uuid))
+                || !requested_entities.insert((entity_id.owned_by_id, entity_id.entity_uuid))
+            {
+                errors.insert(
+                    index,
+                    Report::new(InsertionError)
+                        .attach_printable(entity_id.entity_uuid)
+                        .attach(StatusCode::AlreadyExists),
+                );
+                continue;
             }
+
+            pending.push((
+                index,
+                entity_id,
+                EntityEditionId::new(Uuid::new_v4()),
+                closed_schema,
+            ));
         }
 
-        if let Err(error) = params
-            .property_confidence
-            .validate(
-                params.properties.as_ref(),
-                params.components,
-                &validator_provider,
-            )
+        let transaction = self.transaction().await.change_context(InsertionError)?;
+
+        let now = transaction
+            .as_client()
+            .query_one("SELECT now();", &[])
             .await
-        {
-            if let Err(ref mut report) = status {
-                report.extend_one(error);
-            } else {
-                status = Err(error);
+            .change_context(InsertionError)?
+            .get::<_, Timestamp<()>>(0);
+
+        // The entities are built before anything is inserted, so links between entities of the
+        // same batch are validated against the entities in memory.
+        let mut pending_entities = pending
+            .iter()
+            .map(|(index, entity_id, edition_id, _)| {
+                let params = &params[*index];
+                let decision_time = params.decision_time.unwrap_or_else(|| now.cast());
+                let transaction_time = now.cast();
+                let entity = Entity {
+                    properties: params.properties.clone(),
+                    link_data: params.link_data.clone(),
+                    metadata: EntityMetadata {
+                        record_id: EntityRecordId {
+                            entity_id: *entity_id,
+                            edition_id: *edition_id,
+                        },
+                        entity_type_ids: params.entity_type_ids.clone(),
+                        provenance: EntityProvenanceMetadata {
+                            created_by_id: CreatedById::new(actor_id),
+                            created_at_decision_time: decision_time,
+                            created_at_transaction_time: transaction_time,
+                            first_non_draft_created_at_decision_time: (!params.draft)
+                                .then_some(decision_time),
+                            first_non_draft_created_at_transaction_time: (!params.draft)
+                                .then_some(transaction_time),
+                            edition: EntityEditionProvenanceMetadata {
+                                created_by_id: EditionCreatedById::new(actor_id),
+                                archived_by_id: None,
+                                merged_into: None,
+                                user_defined: params.provenance.clone(),
+                            },
+                        },
+                        temporal_versioning: EntityTemporalMetadata {
+                            decision_time: LeftClosedTemporalInterval::new(
+                                ClosedTemporalBound::Inclusive(decision_time),
+                                OpenTemporalBound::Unbounded,
+                            ),
+                            transaction_time: LeftClosedTemporalInterval::new(
+                                ClosedTemporalBound::Inclusive(transaction_time),
+                                OpenTemporalBound::Unbounded,
+                            ),
+                        },
+                        archived: false,
+                        confidence: params.confidence,
+                        property_confidence: params.property_confidence.clone(),
+                        property_data_types: params.property_data_types.clone(),
+                        property_provenance: params.property_provenance.clone(),
+                    },
+                };
+                ((entity_id.owned_by_id, entity_id.entity_uuid), entity)
+            })
+            .collect::<HashMap<_, _>>();
+
+        // Rejecting an entity invalidates the links of the batch pointing to it, so these links
+        // are validated again until no further entity is rejected.
+        let mut unvalidated = pending
+            .iter()
+            .map(|(index, ..)| *index)
+            .collect::<HashSet<_>>();
+        while !unvalidated.is_empty() {
+            let mut rejected = HashMap::new();
+            for (index, _, _, closed_schema) in &pending {
+                if !unvalidated.contains(index) {
+                    continue;
+                }
+
+                let params = &params[*index];
+                if let Err(error) = transaction
+                    .validate_entity_with_pending(
+                        actor_id,
+                        authorization_api,
+                        Consistency::FullyConsistent,
+                        ValidateEntityParams {
+                            entity_types: EntityValidationType::ClosedSchema(Cow::Borrowed(
+                                closed_schema,
+                            )),
+                            properties: Cow::Borrowed(&params.properties),
+                            property_confidence: Cow::Borrowed(&params.property_confidence),
+                            property_data_types: Cow::Borrowed(&params.property_data_types),
+                            property_provenance: Cow::Borrowed(&params.property_provenance),
+                            link_data: params.link_data.as_ref().map(Cow::Borrowed),
+                            components: if params.draft {
+                                ValidateEntityComponents::draft()
+                            } else {
+                                ValidateEntityComponents::full()
+                            },
+                        },
+                        Some(&pending_entities),
+                    )
+                    .await
+                {
+                    rejected.insert(
+                        *index,
+                        error
+                            .change_context(InsertionError)
+                            .attach(StatusCode::InvalidArgument),
+                    );
+                }
             }
+
+            let rejected_entities = pending
+                .iter()
+                .filter(|(index, ..)| rejected.contains_key(index))
+                .map(|(_, entity_id, ..)| (entity_id.owned_by_id, entity_id.entity_uuid))
+                .collect::<HashSet<_>>();
+            pending.retain(|(index, ..)| !rejected.contains_key(index));
+            pending_entities.retain(|entity, _| !rejected_entities.contains(entity));
+            errors.extend(rejected);
+
+            unvalidated = pending
+                .iter()
+                .filter(|(index, ..)| {
+                    params[*index].link_data.as_ref().is_some_and(|link_data| {
+                        [link_data.left_entity_id, link_data.right_entity_id]
+                            .iter()
+                            .any(|endpoint| {
+                                rejected_entities
+                                    .contains(&(endpoint.owned_by_id, endpoint.entity_uuid))
+                            })
+                    })
+                })
+                .map(|(index, ..)| *index)
+                .collect();
         }
 
-        if let Err(error) = params
-            .link_data
-            .as_deref()
-            .validate(&schema, params.components, &validator_provider)
-            .await
-        {
-            if let Err(ref mut report) = status {
-                report.extend_one(error);
-            } else {
-                status = Err(error);
+        if !pending.is_empty() {
+            let entities = pending
+                .iter()
+                .map(|(_, entity_id, ..)| {
+                    &pending_entities[&(entity_id.owned_by_id, entity_id.entity_uuid)]
+                })
+                .collect::<Vec<_>>();
+            transaction
+                .insert_entity_batch(
+                    &entities
+                        .iter()
+                        .map(|entity| {
+                            (
+                                &entity.metadata,
+                                &entity.properties,
+                                entity.link_data.as_ref(),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .await?;
+            transaction
+                .insert_entity_changes(entities.iter().map(|entity| {
+                    (
+                        entity.metadata.record_id.entity_id,
+                        entity.metadata.record_id.edition_id,
+                        EntityChangeKind::Create,
+                    )
+                }))
+                .await?;
+
+            let relationships = pending
+                .iter()
+                .flat_map(|(index, entity_id, ..)| {
+                    params[*index]
+                        .relationships
+                        .iter()
+                        .map(|relation_and_subject| (*entity_id, *relation_and_subject))
+                })
+                .collect::<Vec<_>>();
+            authorization_api
+                .modify_entity_relations(relationships.clone().into_iter().map(
+                    |(entity_id, relation_and_subject)| {
+                        (
+                            ModifyRelationshipOperation::Create,
+                            entity_id,
+                            relation_and_subject,
+                        )
+                    },
+                ))
+                .await
+                .change_context(InsertionError)?;
+
+            let commit_result = {
+                let span = tracing::trace_span!("committing entities");
+                let _enter = span.enter();
+                transaction.commit().await.change_context(InsertionError)
+            };
+            if let Err(mut error) = commit_result {
+                if let Err(auth_error) = authorization_api
+                    .modify_entity_relations(relationships.into_iter().map(
+                        |(entity_id, relation_and_subject)| {
+                            (
+                                ModifyRelationshipOperation::Delete,
+                                entity_id,
+                                relation_and_subject,
+                            )
+                        },
+                    ))
+                    .await
+                    .change_context(InsertionError)
+                {
+                    // TODO: Use `add_child`
+                    //   see https://linear.app/hash/issue/GEN-105/add-ability-to-add-child-errors
+                    error.extend_one(auth_error);
+                }
+
+                return Err(error);
             }
         }
 
-        status
-            .change_context(ValidateEntityError)
-            .attach(StatusCode::InvalidArgument)
+        let mut entities = pending
+            .into_iter()
+            .map(|(index, entity_id, ..)| {
+                let entity = pending_entities
+                    .remove(&(entity_id.owned_by_id, entity_id.entity_uuid))
+                    .expect("pending entity was not built");
+                (index, entity)
+            })
+            .collect::<HashMap<_, _>>();
+        let entities = (0..params.len())
+            .map(|index| {
+                entities.remove(&index).ok_or_else(|| {
+                    errors
+                        .remove(&index)
+                        .expect("entity was neither created nor rejected")
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(temporal_client) = temporal_client {
+            let created_entities = entities
+                .iter()
+                .filter_map(|entity| entity.as_ref().ok())
+                .cloned()
+                .collect::<Vec<_>>();
+            if !created_entities.is_empty() {
+                temporal_client
+                    .start_update_entity_embeddings_workflow(actor_id, &created_entities)
+                    .await
+                    .change_context(InsertionError)?;
+            }
+        }
+
+        Ok(entities
+            .into_iter()
+            .map(|entity| entity.map(|entity| entity.metadata))
+            .collect())
+    }
+
+    // TODO: Relax constraints on entity validation for draft entities
+    //   see https://linear.app/hash/issue/H-1449
+    #[tracing::instrument(level = "info", skip(self, authorization_api))]
+    async fn validate_entity<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        consistency: Consistency<'_>,
+        params: ValidateEntityParams<'_>,
+    ) -> Result<(), ValidateEntityError> {
+        self.validate_entity_with_pending(actor_id, authorization_api, consistency, params, None)
+            .await
     }
 
     #[tracing::instrument(level = "info", skip(self, authorization_api, entities))]
@@ -922,6 +1515,13 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                     }),
             )
             .await?;
+        transaction
+            .insert_entity_changes(entity_ids.iter().zip(&entity_edition_ids).map(
+                |((entity_id, ..), entity_edition_id)| {
+                    (*entity_id, *entity_edition_id, EntityChangeKind::Create)
+                },
+            ))
+            .await?;
 
         transaction
             .insert_entity_is_of_type(entity_edition_ids.iter().copied(), entity_type_ontology_id)
@@ -957,6 +1557,7 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                         edition: EntityEditionProvenanceMetadata {
                             created_by_id: EditionCreatedById::new(actor_id),
                             archived_by_id: None,
+                            merged_into: None,
                             user_defined: ProvidedEntityEditionProvenanceMetadata::default(),
                         },
                     },
@@ -964,6 +1565,8 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                     archived: false,
                     confidence: None,
                     property_confidence: PropertyConfidence::default(),
+                    property_data_types: PropertyDataTypes::default(),
+                    property_provenance: PropertyProvenances::default(),
                 },
             )
             .collect())
@@ -1083,24 +1686,28 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
 
         // TODO: We currently pass in the subgraph as mutable reference, thus we cannot borrow the
         //       vertices and have to `.collect()` the keys.
-        self.traverse_entities(
-            subgraph
-                .vertices
-                .entities
-                .keys()
-                .map(|id| {
-                    (
-                        *id,
-                        subgraph.depths,
-                        subgraph.temporal_axes.resolved.variable_interval(),
-                    )
-                })
-                .collect(),
+        self.traverse(
+            TraversalQueue {
+                entities: subgraph
+                    .vertices
+                    .entities
+                    .keys()
+                    .map(|id| {
+                        (
+                            *id,
+                            subgraph.depths,
+                            subgraph.temporal_axes.resolved.variable_interval(),
+                        )
+                    })
+                    .collect(),
+                ..TraversalQueue::default()
+            },
             &mut traversal_context,
             actor_id,
             authorization_api,
             &latest_zookie,
             &mut subgraph,
+            query.include_drafts,
         )
         .await?;
 
@@ -1111,27 +1718,156 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
         Ok((subgraph, last))
     }
 
-    #[tracing::instrument(level = "info", skip(self, authorization_api, temporal_client, params))]
-    async fn patch_entity<A: AuthorizationApi + Send + Sync>(
-        &mut self,
+    #[tracing::instrument(level = "info", skip(self, authorization_api, params))]
+    async fn count_entities<A: AuthorizationApi + Sync>(
+        &self,
         actor_id: AccountId,
-        authorization_api: &mut A,
-        temporal_client: Option<&TemporalClient>,
-        mut params: PatchEntityParams,
-    ) -> Result<EntityMetadata, UpdateError> {
-        let entity_type_ids = params
-            .entity_type_ids
-            .iter()
-            .map(EntityTypeId::from_url)
-            .collect::<Vec<_>>();
+        authorization_api: &A,
+        params: CountEntitiesParams<'_>,
+    ) -> Result<EntityCount, QueryError> {
+        let temporal_axes = params.temporal_axes.resolve();
 
-        if !authorization_api
-            .check_entity_types_permission(
-                actor_id,
-                EntityTypePermission::Instantiate,
-                entity_type_ids,
-                Consistency::FullyConsistent,
-            )
+        let entity_type_base_url_path = EntityQueryPath::EntityTypeEdge {
+            edge_kind: SharedEdgeKind::IsOfType,
+            path: EntityTypeQueryPath::BaseUrl,
+            inheritance_depth: Some(0),
+        };
+        let entity_type_version_path = EntityQueryPath::EntityTypeEdge {
+            edge_kind: SharedEdgeKind::IsOfType,
+            path: EntityTypeQueryPath::Version,
+            inheritance_depth: Some(0),
+        };
+
+        let mut compiler = SelectCompiler::new(Some(&temporal_axes), params.include_drafts);
+        let group_indices = match params.group_by {
+            None => Vec::new(),
+            Some(EntityCountGroupBy::EntityType) => vec![
+                compiler.add_grouped_selection_path(&entity_type_base_url_path),
+                compiler.add_grouped_selection_path(&entity_type_version_path),
+            ],
+            Some(EntityCountGroupBy::Web) => {
+                vec![compiler.add_grouped_selection_path(&EntityQueryPath::OwnedById)]
+            }
+            Some(EntityCountGroupBy::Archived) => {
+                vec![compiler.add_grouped_selection_path(&EntityQueryPath::Archived)]
+            }
+        };
+        let owned_by_ids_index =
+            compiler.add_aggregated_selection_path(&EntityQueryPath::OwnedById);
+        let entity_uuids_index = compiler.add_aggregated_selection_path(&EntityQueryPath::Uuid);
+        compiler.add_filter(&params.filter);
+
+        let (statement, parameters) = compiler.compile();
+        let groups = self
+            .as_client()
+            .query(&statement, parameters)
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| {
+                // Without a grouping, a single row is returned. The aggregated arrays are `NULL` if
+                // no entity matches the filter.
+                let entity_ids = row
+                    .get::<_, Option<Vec<OwnedById>>>(owned_by_ids_index)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .zip(
+                        row.get::<_, Option<Vec<EntityUuid>>>(entity_uuids_index)
+                            .unwrap_or_default(),
+                    )
+                    .map(|(owned_by_id, entity_uuid)| EntityId {
+                        owned_by_id,
+                        entity_uuid,
+                        draft_id: None,
+                    })
+                    .collect::<Vec<_>>();
+
+                let key = match (params.group_by, group_indices.as_slice()) {
+                    (Some(EntityCountGroupBy::EntityType), &[base_url_index, version_index]) => {
+                        Some(EntityCountGroupKey::EntityType(VersionedUrl {
+                            base_url: row.get(base_url_index),
+                            version: row.get(version_index),
+                        }))
+                    }
+                    (Some(EntityCountGroupBy::Web), &[owned_by_id_index]) => {
+                        Some(EntityCountGroupKey::Web(row.get(owned_by_id_index)))
+                    }
+                    (Some(EntityCountGroupBy::Archived), &[archived_index]) => {
+                        Some(EntityCountGroupKey::Archived(row.get(archived_index)))
+                    }
+                    _ => None,
+                };
+
+                (key, entity_ids)
+            })
+            .collect::<Vec<_>>();
+
+        // The permissions are stored per entity, so there is no way to restrict the aggregation to
+        // viewable entities in the database. Instead, the matching entities are checked in batches
+        // of bounded size, so a single request to the authorization backend does not grow with
+        // the number of matches. The batches are checked concurrently. Drafts and entities with
+        // multiple types may occur multiple times.
+        let entity_ids = groups
+            .iter()
+            .flat_map(|(_, entity_ids)| entity_ids.iter().copied())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let checks = entity_ids.chunks(PERMISSION_CHECK_BATCH_SIZE).map(|batch| {
+            authorization_api.check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                batch.iter().copied(),
+                Consistency::FullyConsistent,
+            )
+        });
+        let permitted_ids = future::try_join_all(checks)
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .flat_map(|(permissions, _)| permissions)
+            .filter_map(|(entity_uuid, has_permission)| has_permission.then_some(entity_uuid))
+            .collect::<HashSet<_>>();
+
+        Ok(EntityCount {
+            count: permitted_ids.len(),
+            groups: groups
+                .into_iter()
+                .filter_map(|(key, entity_ids)| {
+                    let key = key?;
+                    let count = entity_ids
+                        .into_iter()
+                        .map(|entity_id| entity_id.entity_uuid)
+                        .filter(|entity_uuid| permitted_ids.contains(entity_uuid))
+                        .collect::<HashSet<_>>()
+                        .len();
+                    (count > 0).then_some(EntityCountGroup { key, count })
+                })
+                .collect(),
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip(self, authorization_api, temporal_client, params))]
+    async fn patch_entity<A: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        temporal_client: Option<&TemporalClient>,
+        mut params: PatchEntityParams,
+    ) -> Result<EntityMetadata, UpdateError> {
+        let entity_type_ids = params
+            .entity_type_ids
+            .iter()
+            .map(EntityTypeId::from_url)
+            .collect::<Vec<_>>();
+
+        if !authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::Instantiate,
+                entity_type_ids,
+                Consistency::FullyConsistent,
+            )
             .await
             .change_context(UpdateError)?
             .0
@@ -1164,6 +1900,17 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                     .attach_printable(params.entity_id)
                     .change_context(UpdateError)
             })?;
+        if let Some(expected_edition_id) = params.expected_edition_id {
+            ensure!(
+                locked_row.entity_edition_id == expected_edition_id,
+                Report::new(EntityEditionMismatch {
+                    expected_edition_id,
+                    current_edition_id: locked_row.entity_edition_id,
+                })
+                .attach(StatusCode::Aborted)
+                .change_context(UpdateError)
+            );
+        }
         let ClosedTemporalBound::Inclusive(locked_transaction_time) =
             *locked_row.transaction_time.start();
         let ClosedTemporalBound::Inclusive(locked_decision_time) =
@@ -1192,11 +1939,15 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
 
         let previous_properties = previous_entity.properties.clone();
         let previous_property_confidence = previous_entity.metadata.property_confidence.clone();
+        let previous_property_data_types = previous_entity.metadata.property_data_types.clone();
+        let previous_property_provenance = previous_entity.metadata.property_provenance.clone();
         previous_entity
             .patch(&params.properties)
             .change_context(UpdateError)?;
         let properties = previous_entity.properties;
         let property_confidence = previous_entity.metadata.property_confidence;
+        let property_data_types = previous_entity.metadata.property_data_types;
+        let property_provenance = previous_entity.metadata.property_provenance;
 
         let mut first_non_draft_created_at_decision_time = previous_entity
             .metadata
@@ -1263,6 +2014,8 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
             && archived == previous_entity.metadata.archived
             && !entity_types_updated
             && previous_property_confidence == property_confidence
+            && previous_property_data_types == property_data_types
+            && previous_property_provenance == property_provenance
             && params.confidence == previous_entity.metadata.confidence
         {
             // No changes were made to the entity.
@@ -1274,14 +2027,38 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                 archived,
                 confidence: previous_entity.metadata.confidence,
                 property_confidence,
+                property_data_types,
+                property_provenance,
             });
         }
 
         let link_data = previous_entity.link_data;
 
+        let (properties, property_confidence, property_data_types, property_provenance) =
+            if was_draft_before && !draft {
+                transaction
+                    .merge_draft_properties(
+                        params.entity_id,
+                        params.decision_time,
+                        properties,
+                        property_confidence,
+                        property_data_types,
+                        property_provenance,
+                    )
+                    .await?
+            } else {
+                (
+                    properties,
+                    property_confidence,
+                    property_data_types,
+                    property_provenance,
+                )
+            };
+
         let edition_provenance = EntityEditionProvenanceMetadata {
             created_by_id: EditionCreatedById::new(actor_id),
             archived_by_id: None,
+            merged_into: None,
             user_defined: params.provenance,
         };
         let (edition_id, closed_schema) = transaction
@@ -1296,7 +2073,13 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
             .change_context(UpdateError)?;
 
         transaction
-            .insert_properties(edition_id, &property_confidence)
+            .insert_properties(
+                edition_id,
+                &properties,
+                &property_confidence,
+                &property_data_types,
+                &property_provenance,
+            )
             .await
             .change_context(UpdateError)?;
 
@@ -1316,12 +2099,14 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                         INSERT INTO entity_drafts (
                             web_id,
                             entity_uuid,
-                            draft_id
-                        ) VALUES ($1, $2, $3);",
+                            draft_id,
+                            base_edition_id
+                        ) VALUES ($1, $2, $3, $4);",
                         &[
                             &params.entity_id.owned_by_id,
                             &params.entity_id.entity_uuid,
                             &draft_id,
+                            &locked_row.entity_edition_id,
                         ],
                     )
                     .await
@@ -1377,6 +2162,18 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
             }
         };
 
+        let change_kind = if was_draft_before && !draft {
+            EntityChangeKind::Publish
+        } else if archived && !previous_entity.metadata.archived {
+            EntityChangeKind::Archive
+        } else {
+            EntityChangeKind::Patch
+        };
+        transaction
+            .insert_entity_changes([(params.entity_id, edition_id, change_kind)])
+            .await
+            .change_context(UpdateError)?;
+
         let validation_components = if draft {
             ValidateEntityComponents::draft()
         } else {
@@ -1392,11 +2189,14 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                     entity_types: EntityValidationType::ClosedSchema(Cow::Borrowed(&closed_schema)),
                     properties: Cow::Borrowed(&properties),
                     property_confidence: Cow::Borrowed(&property_confidence),
+                    property_data_types: Cow::Borrowed(&property_data_types),
+                    property_provenance: Cow::Borrowed(&property_provenance),
                     link_data: link_data.as_ref().map(Cow::Borrowed),
                     components: validation_components,
                 },
             )
             .await
+            .map_err(|report| attach_link_entity_id(report, params.entity_id))
             .change_context(UpdateError)
             .attach(StatusCode::InvalidArgument)?;
 
@@ -1425,6 +2225,8 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
             },
             confidence: params.confidence,
             property_confidence,
+            property_data_types,
+            property_provenance,
             archived,
         };
         if let Some(temporal_client) = temporal_client {
@@ -1443,183 +2245,2351 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
         Ok(entity_metadata)
     }
 
-    #[tracing::instrument(level = "info", skip(self, params))]
-    async fn update_entity_embeddings<A: AuthorizationApi + Send + Sync>(
+    #[tracing::instrument(level = "info", skip(self, authorization_api, temporal_client, params))]
+    async fn correct_entity<A: AuthorizationApi + Send + Sync>(
         &mut self,
-        _: AccountId,
-        _: &mut A,
-        params: UpdateEntityEmbeddingsParams<'_>,
-    ) -> Result<(), UpdateError> {
-        #[derive(Debug, ToSql)]
-        #[postgres(name = "entity_embeddings")]
-        pub struct EntityEmbeddingsRow<'a> {
-            web_id: OwnedById,
-            entity_uuid: EntityUuid,
-            draft_id: Option<DraftId>,
-            property: Option<String>,
-            embedding: Embedding<'a>,
-            updated_at_transaction_time: Timestamp<TransactionTime>,
-            updated_at_decision_time: Timestamp<DecisionTime>,
-        }
-        let entity_embeddings = params
-            .embeddings
-            .into_iter()
-            .map(|embedding: EntityEmbedding<'_>| EntityEmbeddingsRow {
-                web_id: params.entity_id.owned_by_id,
-                entity_uuid: params.entity_id.entity_uuid,
-                draft_id: params.entity_id.draft_id,
-                property: embedding.property.as_ref().map(ToString::to_string),
-                embedding: embedding.embedding,
-                updated_at_transaction_time: params.updated_at_transaction_time,
-                updated_at_decision_time: params.updated_at_decision_time,
-            })
-            .collect::<Vec<_>>();
-
-        // TODO: Add permission to allow updating embeddings
-        //   see https://linear.app/hash/issue/H-1870
-        // let permissions = authorization_api
-        //     .check_entities_permission(
-        //         actor_id,
-        //         EntityPermission::UpdateEmbeddings,
-        //         entity_ids.iter().copied(),
-        //         Consistency::FullyConsistent,
-        //     )
-        //     .await
-        //     .change_context(UpdateError)?
-        //     .0
-        //     .into_iter()
-        //     .filter_map(|(entity_id, has_permission)| (!has_permission).then_some(entity_id))
-        //     .collect::<Vec<_>>();
-        // if !permissions.is_empty() {
-        //     let mut status = Report::new(PermissionAssertion);
-        //     for entity_id in permissions {
-        //         status = status.attach(format!("Permission denied for entity {entity_id}"));
-        //     }
-        //     return Err(status.change_context(UpdateError));
-        // }
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        temporal_client: Option<&TemporalClient>,
+        params: CorrectEntityParams,
+    ) -> Result<Vec<EntityMetadata>, UpdateError> {
+        let ClosedTemporalBound::Inclusive(correction_start) = *params.decision_time.start();
+        ensure!(
+            params.decision_time.contains_point(&correction_start),
+            Report::new(UpdateError)
+                .attach(StatusCode::InvalidArgument)
+                .attach_printable("The decision time interval of the correction is empty")
+        );
 
-        if params.reset {
-            if let Some(draft_id) = params.entity_id.draft_id {
-                self.as_client()
-                    .query(
-                        "
-                        DELETE FROM entity_embeddings
-                        WHERE web_id = $1
-                          AND entity_uuid = $2
-                          AND draft_id = $3
-                          AND updated_at_transaction_time <= $4
-                          AND updated_at_decision_time <= $5;
-                    ",
-                        &[
-                            &params.entity_id.owned_by_id,
-                            &params.entity_id.entity_uuid,
-                            &draft_id,
-                            &params.updated_at_transaction_time,
-                            &params.updated_at_decision_time,
-                        ],
-                    )
-                    .await
-                    .change_context(UpdateError)?;
-            } else {
-                self.as_client()
-                    .query(
-                        "
-                        DELETE FROM entity_embeddings
-                        WHERE web_id = $1
-                          AND entity_uuid = $2
-                          AND draft_id IS NULL
-                          AND updated_at_transaction_time <= $3
-                          AND updated_at_decision_time <= $4;
-                    ",
-                        &[
-                            &params.entity_id.owned_by_id,
-                            &params.entity_id.entity_uuid,
-                            &params.updated_at_transaction_time,
-                            &params.updated_at_decision_time,
-                        ],
-                    )
-                    .await
-                    .change_context(UpdateError)?;
-            }
-        }
-        self.as_client()
-            .query(
-                "
-                    INSERT INTO entity_embeddings
-                    SELECT * FROM UNNEST($1::entity_embeddings[])
-                    ON CONFLICT (web_id, entity_uuid, property) DO UPDATE
-                    SET
-                        embedding = EXCLUDED.embedding,
-                        updated_at_transaction_time = EXCLUDED.updated_at_transaction_time,
-                        updated_at_decision_time = EXCLUDED.updated_at_decision_time
-                    WHERE entity_embeddings.updated_at_transaction_time <= \
-                 EXCLUDED.updated_at_transaction_time
-                    AND entity_embeddings.updated_at_decision_time <= \
-                 EXCLUDED.updated_at_decision_time;
-                ",
-                &[&entity_embeddings],
+        authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::Update,
+                params.entity_id,
+                Consistency::FullyConsistent,
             )
             .await
+            .change_context(UpdateError)?
+            .assert_permission()
             .change_context(UpdateError)?;
 
-        Ok(())
-    }
-}
-
-#[derive(Debug)]
-#[must_use]
-struct LockedEntityEdition {
-    entity_id: EntityId,
-    entity_edition_id: EntityEditionId,
-    decision_time: LeftClosedTemporalInterval<DecisionTime>,
-    transaction_time: LeftClosedTemporalInterval<TransactionTime>,
-    updated_at_decision_time: Timestamp<DecisionTime>,
-}
+        let transaction = self.transaction().await.change_context(UpdateError)?;
 
-impl PostgresStore<tokio_postgres::Transaction<'_>> {
-    #[tracing::instrument(level = "trace", skip(self))]
-    async fn insert_entity_edition(
-        &self,
-        archived: bool,
-        entity_type_ids: &[VersionedUrl],
-        properties: &PropertyObject,
-        confidence: Option<Confidence>,
-        provenance: &EntityEditionProvenanceMetadata,
-    ) -> Result<(EntityEditionId, ClosedEntityType), InsertionError> {
-        let edition_id: EntityEditionId = self
+        let locked_rows = transaction
             .as_client()
-            .query_one(
+            .query(
                 "
-                    INSERT INTO entity_editions (
-                        entity_edition_id,
-                        archived,
-                        properties,
-                        confidence,
-                        provenance
-                    ) VALUES (gen_random_uuid(), $1, $2, $3, $4)
-                    RETURNING entity_edition_id;
-                ",
-                &[&archived, &properties, &confidence, provenance],
+                SELECT
+                    entity_edition_id,
+                    decision_time
+                FROM entity_temporal_metadata
+                WHERE web_id = $1
+                  AND entity_uuid = $2
+                  AND draft_id IS NOT DISTINCT FROM $3
+                  AND decision_time && $4
+                  AND transaction_time @> now()
+                ORDER BY lower(decision_time)
+                FOR UPDATE;",
+                &[
+                    &params.entity_id.owned_by_id,
+                    &params.entity_id.entity_uuid,
+                    &params.entity_id.draft_id,
+                    &params.decision_time,
+                ],
             )
             .await
-            .change_context(InsertionError)?
-            .get(0);
+            .change_context(UpdateError)?;
+        ensure!(
+            !locked_rows.is_empty(),
+            Report::new(EntityDoesNotExist)
+                .attach(StatusCode::NotFound)
+                .attach_printable(params.entity_id)
+                .change_context(UpdateError)
+        );
 
-        let entity_type_ontology_ids = entity_type_ids
-            .iter()
-            .map(|entity_type_id| OntologyId::from(EntityTypeId::from_url(entity_type_id)))
-            .collect::<Vec<_>>();
+        // The temporal versions which are superseded by the correction and the corrected entities
+        // with the part of the interval they are valid in. The locked rows are ordered by decision
+        // time, so adjacent corrections with identical contents can be merged on the fly.
+        let mut replaced_versions = Vec::new();
+        let mut corrections = Vec::<(LeftClosedTemporalInterval<DecisionTime>, Entity)>::new();
+        for row in locked_rows {
+            let edition_id: EntityEditionId = row.get(0);
+            let decision_time: LeftClosedTemporalInterval<DecisionTime> = row.get(1);
 
-        self.as_client()
-            .query(
-                "
-                    INSERT INTO entity_is_of_type (
-                        entity_edition_id,
-                        entity_type_ontology_id
-                    ) SELECT $1, UNNEST($2::UUID[]);
-                ",
-                &[&edition_id, &entity_type_ontology_ids],
-            )
+            let mut entity = transaction
+                .read_entity_edition(
+                    params.entity_id,
+                    EntityEditionSelector::EditionId(edition_id),
+                )
+                .await
+                .change_context(UpdateError)?;
+            let previous_properties = entity.properties.clone();
+            let previous_property_confidence = entity.metadata.property_confidence.clone();
+            let previous_property_data_types = entity.metadata.property_data_types.clone();
+            let previous_property_provenance = entity.metadata.property_provenance.clone();
+            entity
+                .patch(&params.properties)
+                .change_context(UpdateError)
+                .attach(StatusCode::InvalidArgument)?;
+            if entity.properties == previous_properties
+                && entity.metadata.property_confidence == previous_property_confidence
+                && entity.metadata.property_data_types == previous_property_data_types
+                && entity.metadata.property_provenance == previous_property_provenance
+            {
+                continue;
+            }
+
+            let Some(corrected_decision_time) = decision_time.intersect(params.decision_time)
+            else {
+                continue;
+            };
+            replaced_versions.push((edition_id, decision_time));
+
+            if let Some((last_decision_time, last_entity)) = corrections.last_mut()
+                && last_entity.properties == entity.properties
+                && last_entity.metadata.property_confidence == entity.metadata.property_confidence
+                && last_entity.metadata.property_data_types == entity.metadata.property_data_types
+                && last_entity.metadata.property_provenance == entity.metadata.property_provenance
+                && last_entity.metadata.entity_type_ids == entity.metadata.entity_type_ids
+                && last_entity.metadata.archived == entity.metadata.archived
+                && last_entity.metadata.confidence == entity.metadata.confidence
+            {
+                let mut union = last_decision_time.union(corrected_decision_time);
+                if let (Some(merged), None) = (union.next(), union.next()) {
+                    *last_decision_time = merged;
+                    continue;
+                }
+            }
+            corrections.push((corrected_decision_time, entity));
+        }
+
+        if corrections.is_empty() {
+            // The correction does not change any edition.
+            return Ok(Vec::new());
+        }
+
+        for (edition_id, decision_time) in &replaced_versions {
+            transaction
+                .as_client()
+                .query(
+                    "
+                    UPDATE entity_temporal_metadata
+                    SET transaction_time = tstzrange(lower(transaction_time), now(), '[)')
+                    WHERE web_id = $1
+                      AND entity_uuid = $2
+                      AND draft_id IS NOT DISTINCT FROM $3
+                      AND entity_edition_id = $4
+                      AND decision_time = $5
+                      AND transaction_time @> now();",
+                    &[
+                        &params.entity_id.owned_by_id,
+                        &params.entity_id.entity_uuid,
+                        &params.entity_id.draft_id,
+                        edition_id,
+                        decision_time,
+                    ],
+                )
+                .await
+                .change_context(UpdateError)?;
+        }
+
+        // The parts of the superseded versions outside of the correction are kept unchanged.
+        for (edition_id, decision_time) in replaced_versions {
+            for remaining_decision_time in decision_time.difference(params.decision_time) {
+                transaction
+                    .insert_temporal_slice(params.entity_id, edition_id, remaining_decision_time)
+                    .await
+                    .change_context(UpdateError)?;
+            }
+        }
+
+        let validation_components = if params.entity_id.draft_id.is_some() {
+            ValidateEntityComponents::draft()
+        } else {
+            ValidateEntityComponents::full()
+        };
+
+        let mut corrected_entities = Vec::with_capacity(corrections.len());
+        for (decision_time, mut entity) in corrections {
+            let edition_provenance = EntityEditionProvenanceMetadata {
+                created_by_id: EditionCreatedById::new(actor_id),
+                archived_by_id: None,
+                merged_into: None,
+                user_defined: params.provenance.clone(),
+            };
+            let (edition_id, closed_schema) = transaction
+                .insert_entity_edition(
+                    entity.metadata.archived,
+                    &entity.metadata.entity_type_ids,
+                    &entity.properties,
+                    entity.metadata.confidence,
+                    &edition_provenance,
+                )
+                .await
+                .change_context(UpdateError)?;
+            transaction
+                .insert_properties(
+                    edition_id,
+                    &entity.properties,
+                    &entity.metadata.property_confidence,
+                    &entity.metadata.property_data_types,
+                    &entity.metadata.property_provenance,
+                )
+                .await
+                .change_context(UpdateError)?;
+            let temporal_versioning = transaction
+                .insert_temporal_slice(params.entity_id, edition_id, decision_time)
+                .await
+                .change_context(UpdateError)?;
+
+            transaction
+                .validate_entity(
+                    actor_id,
+                    authorization_api,
+                    Consistency::FullyConsistent,
+                    ValidateEntityParams {
+                        entity_types: EntityValidationType::ClosedSchema(Cow::Borrowed(
+                            &closed_schema,
+                        )),
+                        properties: Cow::Borrowed(&entity.properties),
+                        property_confidence: Cow::Borrowed(&entity.metadata.property_confidence),
+                        property_data_types: Cow::Borrowed(&entity.metadata.property_data_types),
+                        property_provenance: Cow::Borrowed(&entity.metadata.property_provenance),
+                        link_data: entity.link_data.as_ref().map(Cow::Borrowed),
+                        components: validation_components,
+                    },
+                )
+                .await
+                .map_err(|report| attach_link_entity_id(report, params.entity_id))
+                .change_context(UpdateError)
+                .attach(StatusCode::InvalidArgument)?;
+
+            entity.metadata.record_id.edition_id = edition_id;
+            entity.metadata.temporal_versioning = temporal_versioning;
+            entity.metadata.provenance.edition = edition_provenance;
+            corrected_entities.push(entity);
+        }
+
+        transaction
+            .insert_entity_changes(corrected_entities.iter().map(|entity| {
+                (
+                    params.entity_id,
+                    entity.metadata.record_id.edition_id,
+                    EntityChangeKind::Patch,
+                )
+            }))
+            .await
+            .change_context(UpdateError)?;
+
+        transaction.commit().await.change_context(UpdateError)?;
+
+        if let Some(temporal_client) = temporal_client {
+            temporal_client
+                .start_update_entity_embeddings_workflow(actor_id, &corrected_entities)
+                .await
+                .change_context(UpdateError)?;
+        }
+
+        Ok(corrected_entities
+            .into_iter()
+            .map(|entity| entity.metadata)
+            .collect())
+    }
+
+    #[tracing::instrument(level = "info", skip(self, authorization_api, temporal_client, params))]
+    async fn merge_entities<A: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        temporal_client: Option<&TemporalClient>,
+        params: MergeEntitiesParams,
+    ) -> Result<EntityMetadata, UpdateError> {
+        ensure!(
+            params.survivor.draft_id.is_none() && params.duplicate.draft_id.is_none(),
+            Report::new(UpdateError)
+                .attach(StatusCode::InvalidArgument)
+                .attach_printable("Drafts cannot be merged")
+        );
+        ensure!(
+            params.survivor != params.duplicate,
+            Report::new(UpdateError)
+                .attach(StatusCode::InvalidArgument)
+                .attach_printable("An entity cannot be merged into itself")
+        );
+
+        authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::Update,
+                params.survivor,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)?;
+        authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::Update,
+                params.duplicate,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)?;
+
+        let transaction = self.transaction().await.change_context(UpdateError)?;
+
+        // The entities are always locked in the same order, so concurrent merges of the same
+        // entities cannot deadlock.
+        let mut entity_ids = [params.survivor, params.duplicate];
+        entity_ids.sort_by_key(|entity_id| entity_id.entity_uuid);
+        let mut locked_rows = HashMap::with_capacity(2);
+        for entity_id in entity_ids {
+            let locked_row = transaction
+                .lock_entity_edition(entity_id, None)
+                .await?
+                .ok_or_else(|| {
+                    Report::new(EntityDoesNotExist)
+                        .attach(StatusCode::NotFound)
+                        .attach_printable(entity_id)
+                        .change_context(UpdateError)
+                })?;
+            let entity = transaction
+                .read_entity_edition(
+                    entity_id,
+                    EntityEditionSelector::EditionId(locked_row.entity_edition_id),
+                )
+                .await
+                .change_context(UpdateError)?;
+            locked_rows.insert(entity_id, (locked_row, entity));
+        }
+        let (duplicate_row, duplicate) = locked_rows
+            .remove(&params.duplicate)
+            .expect("duplicate should be locked");
+        let (survivor_row, survivor) = locked_rows
+            .remove(&params.survivor)
+            .expect("survivor should be locked");
+
+        let (properties, property_confidence, property_data_types, property_provenance) = survivor
+            .merge_properties(&duplicate, &params.default_strategy, &params.strategies)
+            .change_context(UpdateError)
+            .attach(StatusCode::InvalidArgument)?;
+        let mut entity_type_ids = survivor.metadata.entity_type_ids.clone();
+        for entity_type_id in &duplicate.metadata.entity_type_ids {
+            if !entity_type_ids.contains(entity_type_id) {
+                entity_type_ids.push(entity_type_id.clone());
+            }
+        }
+
+        let linking_entities = transaction
+            .read_linking_entities(&[params.duplicate])
+            .await
+            .change_context(UpdateError)?;
+        if !linking_entities.is_empty() {
+            let (permissions, _) = authorization_api
+                .check_entities_permission(
+                    actor_id,
+                    EntityPermission::Update,
+                    linking_entities.iter().copied(),
+                    Consistency::FullyConsistent,
+                )
+                .await
+                .change_context(UpdateError)?;
+            let forbidden_entities = permissions
+                .into_iter()
+                .filter_map(|(entity_uuid, has_permission)| {
+                    (!has_permission).then_some(entity_uuid)
+                })
+                .collect::<Vec<_>>();
+            if !forbidden_entities.is_empty() {
+                let mut status =
+                    Report::new(PermissionAssertion).attach(StatusCode::PermissionDenied);
+                for entity_uuid in forbidden_entities {
+                    status = status
+                        .attach_printable(format!("Permission denied for entity {entity_uuid}"));
+                }
+                bail!(status.change_context(UpdateError));
+            }
+        }
+
+        for statement in [
+            "
+                UPDATE entity_has_left_entity
+                SET left_web_id = $3,
+                    left_entity_uuid = $4
+                WHERE left_web_id = $1
+                  AND left_entity_uuid = $2;
+            ",
+            "
+                UPDATE entity_has_right_entity
+                SET right_web_id = $3,
+                    right_entity_uuid = $4
+                WHERE right_web_id = $1
+                  AND right_entity_uuid = $2;
+            ",
+        ] {
+            transaction
+                .as_client()
+                .query(
+                    statement,
+                    &[
+                        &params.duplicate.owned_by_id,
+                        &params.duplicate.entity_uuid,
+                        &params.survivor.owned_by_id,
+                        &params.survivor.entity_uuid,
+                    ],
+                )
+                .await
+                .change_context(UpdateError)?;
+        }
+
+        // Link endpoints are not temporal, so re-pointing a link changes all of its editions. To
+        // make the change visible in the history of the link, a new edition is created for the
+        // published link and each of its drafts.
+        let (linking_web_ids, linking_entity_uuids): (Vec<_>, Vec<_>) = linking_entities
+            .iter()
+            .filter(|entity_id| **entity_id != params.survivor && **entity_id != params.duplicate)
+            .map(|entity_id| (entity_id.owned_by_id, entity_id.entity_uuid))
+            .unzip();
+        let link_entity_ids = transaction
+            .as_client()
+            .query(
+                "
+                    SELECT DISTINCT web_id, entity_uuid, draft_id
+                    FROM entity_temporal_metadata
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT * FROM unnest($1::UUID[], $2::UUID[])
+                    )
+                      AND transaction_time @> now()
+                      AND decision_time @> now()
+                    ORDER BY entity_uuid, draft_id NULLS FIRST;
+                ",
+                &[&linking_web_ids, &linking_entity_uuids],
+            )
+            .await
+            .change_context(UpdateError)?
+            .into_iter()
+            .map(|row| EntityId {
+                owned_by_id: row.get(0),
+                entity_uuid: row.get(1),
+                draft_id: row.get(2),
+            })
+            .collect::<Vec<_>>();
+        let mut link_changes = Vec::with_capacity(link_entity_ids.len());
+        for link_entity_id in link_entity_ids {
+            let Some(locked_row) = transaction
+                .lock_entity_edition(link_entity_id, None)
+                .await?
+            else {
+                continue;
+            };
+            let link = transaction
+                .read_entity_edition(
+                    link_entity_id,
+                    EntityEditionSelector::EditionId(locked_row.entity_edition_id),
+                )
+                .await
+                .change_context(UpdateError)?;
+            let (link_edition_id, _) = transaction
+                .insert_entity_edition(
+                    link.metadata.archived,
+                    &link.metadata.entity_type_ids,
+                    &link.properties,
+                    link.metadata.confidence,
+                    &EntityEditionProvenanceMetadata {
+                        created_by_id: EditionCreatedById::new(actor_id),
+                        archived_by_id: link.metadata.provenance.edition.archived_by_id,
+                        merged_into: None,
+                        user_defined: params.provenance.clone(),
+                    },
+                )
+                .await
+                .change_context(UpdateError)?;
+            transaction
+                .insert_properties(
+                    link_edition_id,
+                    &link.properties,
+                    &link.metadata.property_confidence,
+                    &link.metadata.property_data_types,
+                    &link.metadata.property_provenance,
+                )
+                .await
+                .change_context(UpdateError)?;
+            transaction
+                .update_temporal_metadata(locked_row, link_edition_id, false)
+                .await?;
+            link_changes.push((link_entity_id, link_edition_id, EntityChangeKind::Patch));
+        }
+
+        let edition_provenance = EntityEditionProvenanceMetadata {
+            created_by_id: EditionCreatedById::new(actor_id),
+            archived_by_id: None,
+            merged_into: None,
+            user_defined: params.provenance.clone(),
+        };
+        let (edition_id, closed_schema) = transaction
+            .insert_entity_edition(
+                survivor.metadata.archived,
+                &entity_type_ids,
+                &properties,
+                survivor.metadata.confidence,
+                &edition_provenance,
+            )
+            .await
+            .change_context(UpdateError)?;
+        transaction
+            .insert_properties(
+                edition_id,
+                &properties,
+                &property_confidence,
+                &property_data_types,
+                &property_provenance,
+            )
+            .await
+            .change_context(UpdateError)?;
+        let temporal_versioning = transaction
+            .update_temporal_metadata(survivor_row, edition_id, false)
+            .await?;
+
+        let (archived_edition_id, _) = transaction
+            .insert_entity_edition(
+                true,
+                &duplicate.metadata.entity_type_ids,
+                &duplicate.properties,
+                duplicate.metadata.confidence,
+                &EntityEditionProvenanceMetadata {
+                    created_by_id: EditionCreatedById::new(actor_id),
+                    archived_by_id: Some(EditionArchivedById::new(actor_id)),
+                    merged_into: Some(params.survivor),
+                    user_defined: params.provenance.clone(),
+                },
+            )
+            .await
+            .change_context(UpdateError)?;
+        transaction
+            .insert_properties(
+                archived_edition_id,
+                &duplicate.properties,
+                &duplicate.metadata.property_confidence,
+                &duplicate.metadata.property_data_types,
+                &duplicate.metadata.property_provenance,
+            )
+            .await
+            .change_context(UpdateError)?;
+        transaction
+            .update_temporal_metadata(duplicate_row, archived_edition_id, false)
+            .await?;
+
+        // Drafts of the duplicate would otherwise be left behind and could be published again, so
+        // they are archived together with the duplicate.
+        let duplicate_draft_ids = transaction
+            .as_client()
+            .query(
+                "
+                    SELECT draft_id
+                    FROM entity_temporal_metadata
+                    WHERE web_id = $1
+                      AND entity_uuid = $2
+                      AND draft_id IS NOT NULL
+                      AND transaction_time @> now()
+                      AND decision_time @> now()
+                    ORDER BY draft_id;
+                ",
+                &[&params.duplicate.owned_by_id, &params.duplicate.entity_uuid],
+            )
+            .await
+            .change_context(UpdateError)?
+            .into_iter()
+            .map(|row| EntityId {
+                draft_id: row.get(0),
+                ..params.duplicate
+            })
+            .collect::<Vec<_>>();
+        let mut draft_changes = Vec::with_capacity(duplicate_draft_ids.len());
+        for draft_entity_id in duplicate_draft_ids {
+            let Some(locked_row) = transaction
+                .lock_entity_edition(draft_entity_id, None)
+                .await?
+            else {
+                continue;
+            };
+            let draft = transaction
+                .read_entity_edition(
+                    draft_entity_id,
+                    EntityEditionSelector::EditionId(locked_row.entity_edition_id),
+                )
+                .await
+                .change_context(UpdateError)?;
+            let (draft_edition_id, _) = transaction
+                .insert_entity_edition(
+                    true,
+                    &draft.metadata.entity_type_ids,
+                    &draft.properties,
+                    draft.metadata.confidence,
+                    &EntityEditionProvenanceMetadata {
+                        created_by_id: EditionCreatedById::new(actor_id),
+                        archived_by_id: Some(EditionArchivedById::new(actor_id)),
+                        merged_into: Some(params.survivor),
+                        user_defined: params.provenance.clone(),
+                    },
+                )
+                .await
+                .change_context(UpdateError)?;
+            transaction
+                .insert_properties(
+                    draft_edition_id,
+                    &draft.properties,
+                    &draft.metadata.property_confidence,
+                    &draft.metadata.property_data_types,
+                    &draft.metadata.property_provenance,
+                )
+                .await
+                .change_context(UpdateError)?;
+            transaction
+                .update_temporal_metadata(locked_row, draft_edition_id, false)
+                .await?;
+            draft_changes.push((draft_entity_id, draft_edition_id, EntityChangeKind::Archive));
+        }
+
+        transaction
+            .insert_entity_changes(
+                [
+                    (params.survivor, edition_id, EntityChangeKind::Patch),
+                    (
+                        params.duplicate,
+                        archived_edition_id,
+                        EntityChangeKind::Archive,
+                    ),
+                ]
+                .into_iter()
+                .chain(draft_changes)
+                .chain(link_changes),
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        transaction
+            .validate_entity(
+                actor_id,
+                authorization_api,
+                Consistency::FullyConsistent,
+                ValidateEntityParams {
+                    entity_types: EntityValidationType::ClosedSchema(Cow::Borrowed(&closed_schema)),
+                    properties: Cow::Borrowed(&properties),
+                    property_confidence: Cow::Borrowed(&property_confidence),
+                    property_data_types: Cow::Borrowed(&property_data_types),
+                    property_provenance: Cow::Borrowed(&property_provenance),
+                    link_data: survivor.link_data.as_ref().map(Cow::Borrowed),
+                    components: ValidateEntityComponents::full(),
+                },
+            )
+            .await
+            .map_err(|report| attach_link_entity_id(report, params.survivor))
+            .change_context(UpdateError)
+            .attach(StatusCode::InvalidArgument)?;
+
+        let survivor_relations = authorization_api
+            .get_entity_relations(params.survivor, Consistency::FullyConsistent)
+            .await
+            .change_context(UpdateError)?;
+        let relationships = authorization_api
+            .get_entity_relations(params.duplicate, Consistency::FullyConsistent)
+            .await
+            .change_context(UpdateError)?
+            .into_iter()
+            .filter(|relation_and_subject| {
+                // The owner and setting of the survivor define its web and permission model, so
+                // only the relations granted on the duplicate itself are carried over.
+                matches!(
+                    relation_and_subject,
+                    EntityRelationAndSubject::Administrator { .. }
+                        | EntityRelationAndSubject::Editor { .. }
+                        | EntityRelationAndSubject::Viewer { .. }
+                ) && !survivor_relations.contains(relation_and_subject)
+            })
+            .collect::<Vec<_>>();
+        authorization_api
+            .modify_entity_relations(relationships.clone().into_iter().map(
+                |relation_and_subject| {
+                    (
+                        ModifyRelationshipOperation::Create,
+                        params.survivor,
+                        relation_and_subject,
+                    )
+                },
+            ))
+            .await
+            .change_context(UpdateError)?;
+
+        if let Err(mut error) = transaction.commit().await.change_context(UpdateError) {
+            if let Err(auth_error) = authorization_api
+                .modify_entity_relations(relationships.into_iter().map(|relation_and_subject| {
+                    (
+                        ModifyRelationshipOperation::Delete,
+                        params.survivor,
+                        relation_and_subject,
+                    )
+                }))
+                .await
+                .change_context(UpdateError)
+            {
+                // TODO: Use `add_child`
+                //   see https://linear.app/hash/issue/GEN-105/add-ability-to-add-child-errors
+                error.extend_one(auth_error);
+            }
+
+            return Err(error);
+        }
+
+        let entity_metadata = EntityMetadata {
+            record_id: EntityRecordId {
+                entity_id: params.survivor,
+                edition_id,
+            },
+            temporal_versioning,
+            entity_type_ids,
+            provenance: EntityProvenanceMetadata {
+                edition: edition_provenance,
+                ..survivor.metadata.provenance
+            },
+            archived: survivor.metadata.archived,
+            confidence: survivor.metadata.confidence,
+            property_confidence,
+            property_data_types,
+            property_provenance,
+        };
+        if let Some(temporal_client) = temporal_client {
+            temporal_client
+                .start_update_entity_embeddings_workflow(
+                    actor_id,
+                    &[Entity {
+                        properties,
+                        link_data: survivor.link_data,
+                        metadata: entity_metadata.clone(),
+                    }],
+                )
+                .await
+                .change_context(UpdateError)?;
+        }
+        Ok(entity_metadata)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, authorization_api, temporal_client, params))]
+    async fn migrate_entities<A: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        temporal_client: Option<&TemporalClient>,
+        params: MigrateEntitiesParams,
+    ) -> Result<Vec<EntityMigration>, UpdateError> {
+        authorization_api
+            .check_entity_type_permission(
+                actor_id,
+                EntityTypePermission::Instantiate,
+                EntityTypeId::from_url(&params.to),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)?;
+
+        let transaction = self.transaction().await.change_context(UpdateError)?;
+
+        let entity_ids = transaction
+            .as_client()
+            .query(
+                "
+                    SELECT
+                        entity_temporal_metadata.web_id,
+                        entity_temporal_metadata.entity_uuid,
+                        entity_temporal_metadata.draft_id
+                    FROM entity_temporal_metadata
+                    JOIN entity_is_of_type USING (entity_edition_id)
+                    WHERE entity_is_of_type.entity_type_ontology_id = $1
+                      AND entity_temporal_metadata.decision_time @> now()
+                      AND entity_temporal_metadata.transaction_time @> now()
+                    ORDER BY web_id, entity_uuid, draft_id NULLS FIRST;
+                ",
+                &[&OntologyId::from(EntityTypeId::from_url(&params.from))],
+            )
+            .await
+            .change_context(UpdateError)?
+            .into_iter()
+            .map(|row| EntityId {
+                owned_by_id: row.get(0),
+                entity_uuid: row.get(1),
+                draft_id: row.get(2),
+            })
+            .collect::<Vec<_>>();
+
+        let (permissions, _) = authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::Update,
+                entity_ids.clone(),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        let mut results = Vec::with_capacity(entity_ids.len());
+        let mut pending = Vec::with_capacity(entity_ids.len());
+        for entity_id in entity_ids {
+            if !permissions
+                .get(&entity_id.entity_uuid)
+                .copied()
+                .unwrap_or(false)
+            {
+                results.push(EntityMigration {
+                    entity_id,
+                    result: Err(Report::new(PermissionAssertion)
+                        .change_context(UpdateError)
+                        .attach(StatusCode::PermissionDenied)),
+                });
+                continue;
+            }
+
+            let Some(locked_row) = transaction.lock_entity_edition(entity_id, None).await? else {
+                results.push(EntityMigration {
+                    entity_id,
+                    result: Err(Report::new(EntityDoesNotExist)
+                        .attach(StatusCode::NotFound)
+                        .attach_printable(entity_id)
+                        .change_context(UpdateError)),
+                });
+                continue;
+            };
+            let mut entity = transaction
+                .read_entity_edition(
+                    entity_id,
+                    EntityEditionSelector::EditionId(locked_row.entity_edition_id),
+                )
+                .await
+                .change_context(UpdateError)?;
+
+            if let Err(report) = entity.migrate(&params.properties) {
+                results.push(EntityMigration {
+                    entity_id,
+                    result: Err(report
+                        .change_context(UpdateError)
+                        .attach(StatusCode::InvalidArgument)),
+                });
+                continue;
+            }
+            let mut entity_type_ids = Vec::with_capacity(entity.metadata.entity_type_ids.len());
+            for entity_type_id in mem::take(&mut entity.metadata.entity_type_ids) {
+                let entity_type_id = if entity_type_id == params.from {
+                    params.to.clone()
+                } else {
+                    entity_type_id
+                };
+                if !entity_type_ids.contains(&entity_type_id) {
+                    entity_type_ids.push(entity_type_id);
+                }
+            }
+            entity.metadata.entity_type_ids = entity_type_ids;
+
+            pending.push((locked_row, entity));
+        }
+
+        let entity_type_uuids = pending
+            .iter()
+            .flat_map(|(_, entity)| &entity.metadata.entity_type_ids)
+            .map(|entity_type_id| EntityTypeId::from_url(entity_type_id).into_uuid())
+            .chain(once(EntityTypeId::from_url(&params.to).into_uuid()))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let closed_schemas = transaction
+            .read_closed_schemas(
+                &Filter::In(
+                    FilterExpression::Path(EntityTypeQueryPath::OntologyId),
+                    ParameterList::Uuid(&entity_type_uuids),
+                ),
+                None,
+            )
+            .await
+            .change_context(UpdateError)?
+            .try_collect::<HashMap<_, _>>()
+            .await
+            .change_context(UpdateError)?;
+        ensure!(
+            closed_schemas.contains_key(&EntityTypeId::from_url(&params.to)),
+            Report::new(UpdateError)
+                .attach(StatusCode::NotFound)
+                .attach_printable(params.to.clone())
+        );
+
+        let edition_provenance = EntityEditionProvenanceMetadata {
+            created_by_id: EditionCreatedById::new(actor_id),
+            archived_by_id: None,
+            merged_into: None,
+            user_defined: params.provenance,
+        };
+        let mut migrated_entities = Vec::with_capacity(pending.len());
+        for (locked_row, mut entity) in pending {
+            let entity_id = entity.metadata.record_id.entity_id;
+            let Some(closed_schema) = entity
+                .metadata
+                .entity_type_ids
+                .iter()
+                .map(|entity_type_id| {
+                    closed_schemas
+                        .get(&EntityTypeId::from_url(entity_type_id))
+                        .cloned()
+                })
+                .collect::<Option<ClosedEntityType>>()
+            else {
+                results.push(EntityMigration {
+                    entity_id,
+                    result: Err(Report::new(UpdateError)
+                        .attach_printable("Not all entity types could be found")
+                        .attach(StatusCode::NotFound)),
+                });
+                continue;
+            };
+
+            if let Err(report) = transaction
+                .validate_entity(
+                    actor_id,
+                    authorization_api,
+                    Consistency::FullyConsistent,
+                    ValidateEntityParams {
+                        entity_types: EntityValidationType::ClosedSchema(Cow::Borrowed(
+                            &closed_schema,
+                        )),
+                        properties: Cow::Borrowed(&entity.properties),
+                        property_confidence: Cow::Borrowed(&entity.metadata.property_confidence),
+                        property_data_types: Cow::Borrowed(&entity.metadata.property_data_types),
+                        property_provenance: Cow::Borrowed(&entity.metadata.property_provenance),
+                        link_data: entity.link_data.as_ref().map(Cow::Borrowed),
+                        components: if entity_id.draft_id.is_some() {
+                            ValidateEntityComponents::draft()
+                        } else {
+                            ValidateEntityComponents::full()
+                        },
+                    },
+                )
+                .await
+            {
+                results.push(EntityMigration {
+                    entity_id,
+                    result: Err(attach_link_entity_id(report, entity_id)
+                        .change_context(UpdateError)
+                        .attach(StatusCode::InvalidArgument)),
+                });
+                continue;
+            }
+
+            if params.dry_run {
+                results.push(EntityMigration {
+                    entity_id,
+                    result: Ok(None),
+                });
+                continue;
+            }
+
+            let (edition_id, _) = transaction
+                .insert_entity_edition(
+                    entity.metadata.archived,
+                    &entity.metadata.entity_type_ids,
+                    &entity.properties,
+                    entity.metadata.confidence,
+                    &edition_provenance,
+                )
+                .await
+                .change_context(UpdateError)?;
+            transaction
+                .insert_properties(
+                    edition_id,
+                    &entity.properties,
+                    &entity.metadata.property_confidence,
+                    &entity.metadata.property_data_types,
+                    &entity.metadata.property_provenance,
+                )
+                .await
+                .change_context(UpdateError)?;
+            entity.metadata.temporal_versioning = transaction
+                .update_temporal_metadata(locked_row, edition_id, false)
+                .await?;
+            entity.metadata.record_id.edition_id = edition_id;
+            entity.metadata.provenance.edition = edition_provenance.clone();
+
+            results.push(EntityMigration {
+                entity_id,
+                result: Ok(Some(entity.metadata.clone())),
+            });
+            migrated_entities.push(entity);
+        }
+
+        if params.dry_run {
+            // Dropping the transaction rolls back the locks.
+            return Ok(results);
+        }
+
+        transaction
+            .insert_entity_changes(migrated_entities.iter().map(|entity| {
+                (
+                    entity.metadata.record_id.entity_id,
+                    entity.metadata.record_id.edition_id,
+                    EntityChangeKind::Patch,
+                )
+            }))
+            .await
+            .change_context(UpdateError)?;
+
+        transaction.commit().await.change_context(UpdateError)?;
+
+        if let Some(temporal_client) = temporal_client {
+            temporal_client
+                .start_update_entity_embeddings_workflow(actor_id, &migrated_entities)
+                .await
+                .change_context(UpdateError)?;
+        }
+
+        Ok(results)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, authorization_api))]
+    async fn move_link<A: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        params: MoveLinkParams,
+    ) -> Result<LinkPosition, UpdateError> {
+        authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::Update,
+                params.link_entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)?;
+
+        let transaction = self.transaction().await.change_context(UpdateError)?;
+
+        let locked_row = transaction
+            .lock_entity_edition(params.link_entity_id, None)
+            .await?
+            .ok_or_else(|| {
+                Report::new(EntityDoesNotExist)
+                    .attach(StatusCode::NotFound)
+                    .attach_printable(params.link_entity_id)
+                    .change_context(UpdateError)
+            })?;
+        let link_entity = transaction
+            .read_entity_edition(
+                params.link_entity_id,
+                EntityEditionSelector::EditionId(locked_row.entity_edition_id),
+            )
+            .await
+            .change_context(UpdateError)?;
+        let Some(link_data) = link_entity.link_data else {
+            bail!(
+                Report::new(UpdateError)
+                    .attach(StatusCode::InvalidArgument)
+                    .attach_printable("Only link entities can be moved")
+            );
+        };
+
+        // The outgoing links of the left entity are locked in a fixed order, so concurrent moves
+        // neither deadlock nor generate the same position.
+        let mut siblings = transaction
+            .as_client()
+            .query(
+                "
+                    SELECT web_id, entity_uuid, position
+                    FROM entity_has_left_entity
+                    WHERE left_web_id = $1
+                      AND left_entity_uuid = $2
+                    ORDER BY web_id, entity_uuid
+                    FOR UPDATE;
+                ",
+                &[
+                    &link_data.left_entity_id.owned_by_id,
+                    &link_data.left_entity_id.entity_uuid,
+                ],
+            )
+            .await
+            .change_context(UpdateError)?
+            .into_iter()
+            .map(|row| {
+                (
+                    (row.get::<_, OwnedById>(0), row.get::<_, EntityUuid>(1)),
+                    row.get::<_, Option<LinkPosition>>(2),
+                )
+            })
+            .filter(|(sibling, _)| {
+                *sibling
+                    != (
+                        params.link_entity_id.owned_by_id,
+                        params.link_entity_id.entity_uuid,
+                    )
+            })
+            .collect::<Vec<_>>();
+
+        let mut changed_positions = Vec::new();
+        if let Some(after) = params.after {
+            let anchor_position = siblings
+                .iter()
+                .find(|(sibling, _)| *sibling == (after.owned_by_id, after.entity_uuid))
+                .ok_or_else(|| {
+                    Report::new(EntityDoesNotExist)
+                        .attach(StatusCode::NotFound)
+                        .attach_printable(after)
+                        .change_context(UpdateError)
+                })?
+                .1
+                .clone();
+
+            if anchor_position.is_none() {
+                // Links without a position are placed after the positioned links, ordered by their
+                // ID. They are assigned positions lazily in that order when one of them is used as
+                // anchor, so the order of the links doesn't change. Only links sharing a type
+                // with the moved link are assigned a position, as only these are known to be
+                // ordered.
+                let same_type_siblings = transaction
+                    .as_client()
+                    .query(
+                        "
+                            SELECT DISTINCT
+                                entity_temporal_metadata.web_id,
+                                entity_temporal_metadata.entity_uuid
+                            FROM entity_has_left_entity
+                            JOIN entity_temporal_metadata
+                              ON entity_temporal_metadata.web_id = entity_has_left_entity.web_id
+                             AND entity_temporal_metadata.entity_uuid
+                               = entity_has_left_entity.entity_uuid
+                             AND entity_temporal_metadata.draft_id IS NULL
+                             AND entity_temporal_metadata.transaction_time @> now()
+                             AND entity_temporal_metadata.decision_time @> now()
+                            JOIN entity_is_of_type
+                              ON entity_is_of_type.entity_edition_id
+                               = entity_temporal_metadata.entity_edition_id
+                            WHERE entity_has_left_entity.left_web_id = $1
+                              AND entity_has_left_entity.left_entity_uuid = $2
+                              AND entity_has_left_entity.position IS NULL
+                              AND entity_is_of_type.entity_type_ontology_id IN (
+                                  SELECT entity_type_ontology_id
+                                  FROM entity_is_of_type
+                                  WHERE entity_edition_id = $3
+                              );
+                        ",
+                        &[
+                            &link_data.left_entity_id.owned_by_id,
+                            &link_data.left_entity_id.entity_uuid,
+                            &locked_row.entity_edition_id,
+                        ],
+                    )
+                    .await
+                    .change_context(UpdateError)?
+                    .into_iter()
+                    .map(|row| (row.get::<_, OwnedById>(0), row.get::<_, EntityUuid>(1)))
+                    .collect::<HashSet<_>>();
+
+                let mut last_position = siblings
+                    .iter()
+                    .filter_map(|(_, position)| position.clone())
+                    .max();
+                for (sibling, position) in &mut siblings {
+                    if position.is_none() && same_type_siblings.contains(sibling) {
+                        let new_position = LinkPosition::between(last_position.as_ref(), None)
+                            .change_context(UpdateError)?;
+                        changed_positions.push((*sibling, new_position.clone()));
+                        last_position = Some(new_position.clone());
+                        *position = Some(new_position);
+                    }
+                }
+            }
+        }
+
+        if !changed_positions.is_empty() {
+            let (permissions, _) = authorization_api
+                .check_entities_permission(
+                    actor_id,
+                    EntityPermission::Update,
+                    changed_positions
+                        .iter()
+                        .map(|((owned_by_id, entity_uuid), _)| EntityId {
+                            owned_by_id: *owned_by_id,
+                            entity_uuid: *entity_uuid,
+                            draft_id: None,
+                        }),
+                    Consistency::FullyConsistent,
+                )
+                .await
+                .change_context(UpdateError)?;
+            let forbidden_entities = permissions
+                .into_iter()
+                .filter_map(|(entity_uuid, has_permission)| {
+                    (!has_permission).then_some(entity_uuid)
+                })
+                .collect::<Vec<_>>();
+            if !forbidden_entities.is_empty() {
+                let mut status =
+                    Report::new(PermissionAssertion).attach(StatusCode::PermissionDenied);
+                for entity_uuid in forbidden_entities {
+                    status = status
+                        .attach_printable(format!("Permission denied for entity {entity_uuid}"));
+                }
+                bail!(status.change_context(UpdateError));
+            }
+        }
+
+        let lower = params
+            .after
+            .map(|after| {
+                siblings
+                    .iter()
+                    .find(|(sibling, _)| *sibling == (after.owned_by_id, after.entity_uuid))
+                    .and_then(|(_, position)| position.clone())
+                    .ok_or_else(|| {
+                        Report::new(UpdateError)
+                            .attach(StatusCode::InvalidArgument)
+                            .attach_printable(format!(
+                                "The link {after} is not ordered like the moved link"
+                            ))
+                    })
+            })
+            .transpose()?;
+        let upper = siblings
+            .iter()
+            .filter_map(|(_, position)| position.as_ref())
+            .filter(|position| lower.as_ref().map_or(true, |lower| *position > lower))
+            .min();
+        let position = LinkPosition::between(lower.as_ref(), upper)
+            .change_context(UpdateError)
+            .attach(StatusCode::InvalidArgument)?;
+
+        transaction
+            .validate_entity(
+                actor_id,
+                authorization_api,
+                Consistency::FullyConsistent,
+                ValidateEntityParams {
+                    entity_types: EntityValidationType::Id(Cow::Borrowed(
+                        &link_entity.metadata.entity_type_ids,
+                    )),
+                    properties: Cow::Borrowed(&link_entity.properties),
+                    property_confidence: Cow::Borrowed(&link_entity.metadata.property_confidence),
+                    property_data_types: Cow::Borrowed(&link_entity.metadata.property_data_types),
+                    property_provenance: Cow::Borrowed(&link_entity.metadata.property_provenance),
+                    link_data: Some(Cow::Owned(LinkData {
+                        position: Some(position.clone()),
+                        ..link_data
+                    })),
+                    components: ValidateEntityComponents::draft(),
+                },
+            )
+            .await
+            .map_err(|report| attach_link_entity_id(report, params.link_entity_id))
+            .change_context(UpdateError)
+            .attach(StatusCode::InvalidArgument)?;
+
+        changed_positions.push((
+            (
+                params.link_entity_id.owned_by_id,
+                params.link_entity_id.entity_uuid,
+            ),
+            position.clone(),
+        ));
+        let mut web_ids = Vec::with_capacity(changed_positions.len());
+        let mut entity_uuids = Vec::with_capacity(changed_positions.len());
+        let mut positions = Vec::with_capacity(changed_positions.len());
+        for ((web_id, entity_uuid), position) in changed_positions {
+            web_ids.push(web_id);
+            entity_uuids.push(entity_uuid);
+            positions.push(position);
+        }
+        transaction
+            .as_client()
+            .query(
+                "
+                    UPDATE entity_has_left_entity
+                    SET position = changes.position
+                    FROM UNNEST($1::UUID[], $2::UUID[], $3::TEXT[])
+                        AS changes(web_id, entity_uuid, position)
+                    WHERE entity_has_left_entity.web_id = changes.web_id
+                      AND entity_has_left_entity.entity_uuid = changes.entity_uuid;
+                ",
+                &[&web_ids, &entity_uuids, &positions],
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        // Positions are not temporal, so the move is recorded in the change feed only.
+        transaction
+            .insert_entity_changes([(
+                params.link_entity_id,
+                locked_row.entity_edition_id,
+                EntityChangeKind::Move,
+            )])
+            .await
+            .change_context(UpdateError)?;
+
+        transaction.commit().await.change_context(UpdateError)?;
+
+        Ok(position)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_entity_embeddings<A: AuthorizationApi + Send + Sync>(
+        &mut self,
+        _: AccountId,
+        _: &mut A,
+        params: UpdateEntityEmbeddingsParams<'_>,
+    ) -> Result<(), UpdateError> {
+        #[derive(Debug, ToSql)]
+        #[postgres(name = "entity_embeddings")]
+        pub struct EntityEmbeddingsRow<'a> {
+            web_id: OwnedById,
+            entity_uuid: EntityUuid,
+            draft_id: Option<DraftId>,
+            property: Option<String>,
+            embedding: Embedding<'a>,
+            updated_at_transaction_time: Timestamp<TransactionTime>,
+            updated_at_decision_time: Timestamp<DecisionTime>,
+        }
+        let entity_embeddings = params
+            .embeddings
+            .into_iter()
+            .map(|embedding: EntityEmbedding<'_>| EntityEmbeddingsRow {
+                web_id: params.entity_id.owned_by_id,
+                entity_uuid: params.entity_id.entity_uuid,
+                draft_id: params.entity_id.draft_id,
+                property: embedding.property.as_ref().map(ToString::to_string),
+                embedding: embedding.embedding,
+                updated_at_transaction_time: params.updated_at_transaction_time,
+                updated_at_decision_time: params.updated_at_decision_time,
+            })
+            .collect::<Vec<_>>();
+
+        // TODO: Add permission to allow updating embeddings
+        //   see https://linear.app/hash/issue/H-1870
+        // let permissions = authorization_api
+        //     .check_entities_permission(
+        //         actor_id,
+        //         EntityPermission::UpdateEmbeddings,
+        //         entity_ids.iter().copied(),
+        //         Consistency::FullyConsistent,
+        //     )
+        //     .await
+        //     .change_context(UpdateError)?
+        //     .0
+        //     .into_iter()
+        //     .filter_map(|(entity_id, has_permission)| (!has_permission).then_some(entity_id))
+        //     .collect::<Vec<_>>();
+        // if !permissions.is_empty() {
+        //     let mut status = Report::new(PermissionAssertion);
+        //     for entity_id in permissions {
+        //         status = status.attach(format!("Permission denied for entity {entity_id}"));
+        //     }
+        //     return Err(status.change_context(UpdateError));
+        // }
+
+        if params.reset {
+            if let Some(draft_id) = params.entity_id.draft_id {
+                self.as_client()
+                    .query(
+                        "
+                        DELETE FROM entity_embeddings
+                        WHERE web_id = $1
+                          AND entity_uuid = $2
+                          AND draft_id = $3
+                          AND updated_at_transaction_time <= $4
+                          AND updated_at_decision_time <= $5;
+                    ",
+                        &[
+                            &params.entity_id.owned_by_id,
+                            &params.entity_id.entity_uuid,
+                            &draft_id,
+                            &params.updated_at_transaction_time,
+                            &params.updated_at_decision_time,
+                        ],
+                    )
+                    .await
+                    .change_context(UpdateError)?;
+            } else {
+                self.as_client()
+                    .query(
+                        "
+                        DELETE FROM entity_embeddings
+                        WHERE web_id = $1
+                          AND entity_uuid = $2
+                          AND draft_id IS NULL
+                          AND updated_at_transaction_time <= $3
+                          AND updated_at_decision_time <= $4;
+                    ",
+                        &[
+                            &params.entity_id.owned_by_id,
+                            &params.entity_id.entity_uuid,
+                            &params.updated_at_transaction_time,
+                            &params.updated_at_decision_time,
+                        ],
+                    )
+                    .await
+                    .change_context(UpdateError)?;
+            }
+        }
+        self.as_client()
+            .query(
+                "
+                    INSERT INTO entity_embeddings
+                    SELECT * FROM UNNEST($1::entity_embeddings[])
+                    ON CONFLICT (web_id, entity_uuid, property) DO UPDATE
+                    SET
+                        embedding = EXCLUDED.embedding,
+                        updated_at_transaction_time = EXCLUDED.updated_at_transaction_time,
+                        updated_at_decision_time = EXCLUDED.updated_at_decision_time
+                    WHERE entity_embeddings.updated_at_transaction_time <= \
+                 EXCLUDED.updated_at_transaction_time
+                    AND entity_embeddings.updated_at_decision_time <= \
+                 EXCLUDED.updated_at_decision_time;
+                ",
+                &[&entity_embeddings],
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "info", skip(self, authorization_api))]
+    async fn delete_entities<A: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        params: DeleteEntitiesParams,
+    ) -> Result<Vec<EntityId>, DeletionError> {
+        let transaction = self.transaction().await.change_context(DeletionError)?;
+
+        let mut entity_ids = params
+            .entity_ids
+            .into_iter()
+            .map(|entity_id| EntityId {
+                draft_id: None,
+                ..entity_id
+            })
+            .collect::<Vec<_>>();
+        entity_ids.sort_unstable_by_key(|entity_id| entity_id.entity_uuid);
+        entity_ids.dedup();
+
+        let (owned_by_ids, entity_uuids): (Vec<_>, Vec<_>) = entity_ids
+            .iter()
+            .map(|entity_id| (entity_id.owned_by_id, entity_id.entity_uuid))
+            .unzip();
+        let existing_entity_uuids = transaction
+            .as_client()
+            .query(
+                "
+                    SELECT entity_uuid
+                    FROM entity_ids
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT * FROM unnest($1::UUID[], $2::UUID[])
+                    );
+                ",
+                &[&owned_by_ids, &entity_uuids],
+            )
+            .await
+            .change_context(DeletionError)?
+            .into_iter()
+            .map(|row| row.get::<_, EntityUuid>(0))
+            .collect::<HashSet<_>>();
+        if let Some(entity_id) = entity_ids
+            .iter()
+            .find(|entity_id| !existing_entity_uuids.contains(&entity_id.entity_uuid))
+        {
+            bail!(
+                Report::new(EntityDoesNotExist)
+                    .attach(StatusCode::NotFound)
+                    .attach_printable(*entity_id)
+                    .change_context(DeletionError)
+            );
+        }
+
+        // Link entities may be linked by other link entities as well, so we have to collect them
+        // until no further links are found.
+        let mut deleted_entity_uuids = existing_entity_uuids;
+        let mut linked_entity_ids = entity_ids.clone();
+        loop {
+            linked_entity_ids = transaction
+                .read_linking_entities(&linked_entity_ids)
+                .await?
+                .into_iter()
+                .filter(|entity_id| deleted_entity_uuids.insert(entity_id.entity_uuid))
+                .collect();
+            if linked_entity_ids.is_empty() {
+                break;
+            }
+            ensure!(
+                params.cascade_links,
+                Report::new(DeletionError)
+                    .attach(StatusCode::FailedPrecondition)
+                    .attach_printable("The entities are still linked by other entities")
+                    .attach_printable(format!("{linked_entity_ids:?}"))
+            );
+            entity_ids.extend_from_slice(&linked_entity_ids);
+        }
+
+        let (permissions, _) = authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::FullAccess,
+                entity_ids.clone(),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(DeletionError)?;
+        let forbidden_entities = permissions
+            .into_iter()
+            .filter_map(|(entity_uuid, has_permission)| (!has_permission).then_some(entity_uuid))
+            .collect::<Vec<_>>();
+        if !forbidden_entities.is_empty() {
+            let mut status = Report::new(PermissionAssertion).attach(StatusCode::PermissionDenied);
+            for entity_uuid in forbidden_entities {
+                status =
+                    status.attach_printable(format!("Permission denied for entity {entity_uuid}"));
+            }
+            bail!(status.change_context(DeletionError));
+        }
+
+        // Full access to an entity may be granted to non-administrators, but deleting removes the
+        // entity including its history, so the actor has to administrate the webs as well.
+        let (web_permissions, _) = authorization_api
+            .check_webs_permission(
+                actor_id,
+                WebPermission::ChangePermission,
+                entity_ids
+                    .iter()
+                    .map(|entity_id| entity_id.owned_by_id)
+                    .collect::<HashSet<_>>(),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(DeletionError)?;
+        let forbidden_webs = web_permissions
+            .into_iter()
+            .filter_map(|(web_id, has_permission)| (!has_permission).then_some(web_id))
+            .collect::<Vec<_>>();
+        if !forbidden_webs.is_empty() {
+            let mut status = Report::new(PermissionAssertion).attach(StatusCode::PermissionDenied);
+            for web_id in forbidden_webs {
+                status = status.attach_printable(format!(
+                    "Only administrators of web {web_id} can delete its entities"
+                ));
+            }
+            bail!(status.change_context(DeletionError));
+        }
+
+        let mut relationships = Vec::new();
+        for entity_id in &entity_ids {
+            relationships.extend(
+                authorization_api
+                    .get_entity_relations(*entity_id, Consistency::FullyConsistent)
+                    .await
+                    .change_context(DeletionError)?
+                    .into_iter()
+                    .map(|relation_and_subject| (*entity_id, relation_and_subject)),
+            );
+        }
+
+        transaction
+            .insert_entity_deletions(&entity_ids)
+            .await
+            .change_context(DeletionError)?;
+        transaction.delete_entity_records(&entity_ids).await?;
+
+        authorization_api
+            .modify_entity_relations(relationships.clone().into_iter().map(
+                |(entity_id, relation_and_subject)| {
+                    (
+                        ModifyRelationshipOperation::Delete,
+                        entity_id,
+                        relation_and_subject,
+                    )
+                },
+            ))
+            .await
+            .change_context(DeletionError)?;
+
+        if let Err(mut error) = transaction.commit().await.change_context(DeletionError) {
+            if let Err(auth_error) = authorization_api
+                .modify_entity_relations(relationships.into_iter().map(
+                    |(entity_id, relation_and_subject)| {
+                        (
+                            ModifyRelationshipOperation::Touch,
+                            entity_id,
+                            relation_and_subject,
+                        )
+                    },
+                ))
+                .await
+                .change_context(DeletionError)
+            {
+                // TODO: Use `add_child`
+                //   see https://linear.app/hash/issue/GEN-105/add-ability-to-add-child-errors
+                error.extend_one(auth_error);
+            }
+
+            Err(error)
+        } else {
+            Ok(entity_ids)
+        }
+    }
+
+    #[tracing::instrument(level = "info", skip(self, authorization_api))]
+    async fn get_entity_history<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        entity_id: EntityId,
+    ) -> Result<Vec<EntityMetadata>, QueryError> {
+        authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .assert_permission()
+            .change_context(QueryError)
+            .attach(StatusCode::PermissionDenied)?;
+
+        let mut editions = Read::<Entity>::read_vec(
+            self,
+            &Filter::for_entity_by_entity_id(entity_id),
+            None,
+            entity_id.draft_id.is_some(),
+        )
+        .await?
+        .into_iter()
+        .map(|entity| entity.metadata)
+        .collect::<Vec<_>>();
+
+        ensure!(
+            !editions.is_empty(),
+            Report::new(EntityDoesNotExist)
+                .attach(StatusCode::NotFound)
+                .attach_printable(entity_id)
+                .change_context(QueryError)
+        );
+
+        editions.sort_by_key(temporal_version_key);
+
+        Ok(editions)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, authorization_api))]
+    async fn diff_entity<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        params: DiffEntityParams,
+    ) -> Result<DiffEntityResult<'static>, QueryError> {
+        for entity_id in [params.first_entity_id, params.second_entity_id] {
+            authorization_api
+                .check_entity_permission(
+                    actor_id,
+                    EntityPermission::View,
+                    entity_id,
+                    Consistency::FullyConsistent,
+                )
+                .await
+                .change_context(QueryError)?
+                .assert_permission()
+                .change_context(QueryError)
+                .attach(StatusCode::PermissionDenied)?;
+        }
+
+        let first = self
+            .read_entity_edition(params.first_entity_id, params.first_edition)
+            .await?;
+        let second = self
+            .read_entity_edition(params.second_entity_id, params.second_edition)
+            .await?;
+
+        Ok(DiffEntityResult::from_entities(&first, &second))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, authorization_api))]
+    async fn diff_entity_subgraph<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        params: DiffEntitySubgraphParams<'_>,
+    ) -> Result<SubgraphDiff, QueryError> {
+        let read_subgraph = |filter, temporal_axes| {
+            self.get_entity(
+                actor_id,
+                authorization_api,
+                GetEntityParams {
+                    query: StructuralQuery {
+                        filter,
+                        graph_resolve_depths: params.graph_resolve_depths,
+                        temporal_axes,
+                        include_drafts: params.include_drafts,
+                    },
+                    sorting: EntityQuerySorting {
+                        paths: Vec::new(),
+                        cursor: None,
+                    },
+                    limit: None,
+                },
+            )
+        };
+
+        let (old, _) = read_subgraph(params.filter.clone(), params.old_temporal_axes).await?;
+        let (new, _) = read_subgraph(params.filter, params.new_temporal_axes).await?;
+        Ok(old.diff(new))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, authorization_api))]
+    async fn get_entity_drafts<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        entity_id: EntityId,
+    ) -> Result<Vec<EntityDraft>, QueryError> {
+        authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .assert_permission()
+            .change_context(QueryError)
+            .attach(StatusCode::PermissionDenied)?;
+
+        let rows = self
+            .as_client()
+            .query(
+                "
+                SELECT
+                    entity_drafts.draft_id,
+                    entity_drafts.base_edition_id
+                FROM entity_drafts
+                WHERE entity_drafts.web_id = $1
+                  AND entity_drafts.entity_uuid = $2
+                  AND EXISTS (
+                      SELECT 1
+                      FROM entity_temporal_metadata
+                      WHERE entity_temporal_metadata.web_id = entity_drafts.web_id
+                        AND entity_temporal_metadata.entity_uuid = entity_drafts.entity_uuid
+                        AND entity_temporal_metadata.draft_id = entity_drafts.draft_id
+                        AND entity_temporal_metadata.decision_time @> now()
+                        AND entity_temporal_metadata.transaction_time @> now()
+                  )
+                ORDER BY entity_drafts.draft_id;",
+                &[&entity_id.owned_by_id, &entity_id.entity_uuid],
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let mut drafts = Vec::with_capacity(rows.len());
+        for row in rows {
+            let draft = self
+                .read_entity_edition(
+                    EntityId {
+                        draft_id: Some(row.get(0)),
+                        ..entity_id
+                    },
+                    EntityEditionSelector::Timestamp {
+                        decision_time: None,
+                        transaction_time: None,
+                    },
+                )
+                .await?;
+            drafts.push(EntityDraft {
+                metadata: draft.metadata,
+                base_edition_id: row.get(1),
+            });
+        }
+
+        Ok(drafts)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, authorization_api))]
+    async fn diff_entity_draft<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        entity_id: EntityId,
+    ) -> Result<DiffEntityDraftResult<'static>, QueryError> {
+        let Some(draft_id) = entity_id.draft_id else {
+            bail!(
+                Report::new(QueryError)
+                    .attach(StatusCode::InvalidArgument)
+                    .attach_printable("The entity ID does not refer to a draft")
+            );
+        };
+
+        authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .assert_permission()
+            .change_context(QueryError)
+            .attach(StatusCode::PermissionDenied)?;
+
+        let base_edition_id: Option<EntityEditionId> = self
+            .as_client()
+            .query_opt(
+                "
+                SELECT base_edition_id
+                FROM entity_drafts
+                WHERE web_id = $1
+                  AND entity_uuid = $2
+                  AND draft_id = $3;",
+                &[&entity_id.owned_by_id, &entity_id.entity_uuid, &draft_id],
+            )
+            .await
+            .change_context(QueryError)?
+            .ok_or_else(|| {
+                Report::new(EntityDoesNotExist)
+                    .attach(StatusCode::NotFound)
+                    .attach_printable(entity_id)
+                    .change_context(QueryError)
+            })?
+            .get(0);
+
+        let live_entity_id = EntityId {
+            draft_id: None,
+            ..entity_id
+        };
+        let current_edition = EntityEditionSelector::Timestamp {
+            decision_time: None,
+            transaction_time: None,
+        };
+        let live = self
+            .read_entity_edition(live_entity_id, current_edition)
+            .await?;
+        let mut draft = self.read_entity_edition(entity_id, current_edition).await?;
+        let live_edition_id = live.metadata.record_id.edition_id;
+
+        let mut conflicts = Vec::new();
+        if let Some(base_edition_id) = base_edition_id
+            && base_edition_id != live_edition_id
+        {
+            let base = self
+                .read_entity_edition(
+                    live_entity_id,
+                    EntityEditionSelector::EditionId(base_edition_id),
+                )
+                .await?;
+            conflicts = draft
+                .properties
+                .merge_conflicts(&base.properties, &live.properties);
+            if conflicts.is_empty() {
+                draft.properties = draft
+                    .properties
+                    .merge(&base.properties, &live.properties)
+                    .change_context(QueryError)?;
+            }
+        }
+
+        Ok(DiffEntityDraftResult {
+            base_edition_id,
+            live_edition_id,
+            diff: DiffEntityResult::from_entities(&live, &draft),
+            conflicts,
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip(self, authorization_api, params))]
+    async fn get_entity_changes<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        params: GetEntityChangesParams<'_>,
+    ) -> Result<(Vec<EntityChange>, Option<EntityChangeCursor>), QueryError> {
+        let after = params.after.unwrap_or(EntityChangeCursor {
+            transaction_id: 0,
+            sequence_id: 0,
+        });
+        let limit = i64::try_from(params.limit).change_context(QueryError)?;
+
+        // Only events of transactions which are older than the oldest running transaction are
+        // read. Otherwise, a transaction which commits later could still add events before the
+        // returned cursor. Events of the current transaction are always visible.
+        let changes = self
+            .as_client()
+            .query(
+                "
+                    SELECT
+                        transaction_id,
+                        sequence_id,
+                        web_id,
+                        entity_uuid,
+                        draft_id,
+                        entity_edition_id,
+                        kind,
+                        transaction_time
+                    FROM entity_change_events
+                    WHERE (transaction_id, sequence_id) > ($1, $2)
+                      AND (
+                          transaction_id < pg_snapshot_xmin(pg_current_snapshot())::TEXT::BIGINT
+                          OR transaction_id = pg_current_xact_id_if_assigned()::TEXT::BIGINT
+                      )
+                    ORDER BY transaction_id, sequence_id
+                    LIMIT $3;
+                ",
+                &[&after.transaction_id, &after.sequence_id, &limit],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| {
+                (
+                    EntityChangeCursor {
+                        transaction_id: row.get(0),
+                        sequence_id: row.get(1),
+                    },
+                    EntityId {
+                        owned_by_id: row.get(2),
+                        entity_uuid: row.get(3),
+                        draft_id: row.get(4),
+                    },
+                    row.get::<_, Option<EntityEditionId>>(5),
+                    row.get::<_, EntityChangeKind>(6),
+                    row.get::<_, Timestamp<TransactionTime>>(7),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let Some(&(cursor, ..)) = changes.last() else {
+            return Ok((Vec::new(), None));
+        };
+
+        let edition_ids = changes
+            .iter()
+            .filter_map(|(_, _, edition_id, ..)| *edition_id)
+            .collect::<HashSet<_>>();
+        let edition_filter = Filter::Any(
+            edition_ids
+                .iter()
+                .map(|edition_id| {
+                    Filter::Equal(
+                        Some(FilterExpression::Path(EntityQueryPath::EditionId)),
+                        Some(FilterExpression::Parameter(Parameter::Uuid(
+                            edition_id.into_uuid(),
+                        ))),
+                    )
+                })
+                .collect(),
+        );
+
+        // The editions are read on both time axes, so editions which were created for a past or
+        // future decision time, corrected, or superseded afterwards are found as well. An edition
+        // may be split into multiple rows by corrections, the row it was created with is used.
+        let mut editions = HashMap::<_, EntityMetadata>::new();
+        for entity in Read::<Entity>::read_vec(self, &edition_filter, None, true).await? {
+            match editions.entry(entity.metadata.record_id.edition_id) {
+                Entry::Occupied(mut entry) => {
+                    if temporal_version_key(&entity.metadata) < temporal_version_key(entry.get()) {
+                        entry.insert(entity.metadata);
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(entity.metadata);
+                }
+            }
+        }
+        if let Some(edition_id) = edition_ids
+            .iter()
+            .find(|edition_id| !editions.contains_key(edition_id))
+        {
+            return Err(Report::new(QueryError).attach_printable(format!(
+                "The edition `{}` of an entity change does not exist",
+                edition_id.into_uuid()
+            )));
+        }
+
+        let matching_edition_ids = Read::<Entity>::read_vec(
+            self,
+            &Filter::All(vec![edition_filter, params.filter]),
+            None,
+            true,
+        )
+        .await?
+        .into_iter()
+        .map(|entity| entity.metadata.record_id.edition_id)
+        .collect::<HashSet<_>>();
+
+        let entity_ids = editions
+            .values()
+            .map(|metadata| metadata.record_id.entity_id)
+            .collect::<HashSet<_>>();
+        let (permissions, _) = authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        // The relations of deleted entities are removed as well, so deletions are authorized by
+        // the web of the entity instead.
+        let deleted_in_webs = changes
+            .iter()
+            .filter(|(_, _, edition_id, ..)| edition_id.is_none())
+            .map(|(_, entity_id, ..)| entity_id.owned_by_id)
+            .collect::<HashSet<_>>();
+        let (web_permissions, _) = authorization_api
+            .check_webs_permission(
+                actor_id,
+                WebPermission::ViewEntity,
+                deleted_in_webs,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let changes = changes
+            .into_iter()
+            .filter_map(|(cursor, entity_id, edition_id, kind, changed_at)| {
+                let Some(edition_id) = edition_id else {
+                    return web_permissions
+                        .get(&entity_id.owned_by_id)
+                        .copied()
+                        .unwrap_or(false)
+                        .then_some(EntityChange {
+                            cursor,
+                            kind,
+                            changed_at,
+                            entity_id,
+                            metadata: None,
+                        });
+                };
+                let metadata = &editions[&edition_id];
+                let is_visible = matching_edition_ids.contains(&edition_id)
+                    && permissions
+                        .get(&metadata.record_id.entity_id.entity_uuid)
+                        .copied()
+                        .unwrap_or(false);
+                is_visible.then(|| EntityChange {
+                    cursor,
+                    kind,
+                    changed_at,
+                    entity_id: metadata.record_id.entity_id,
+                    metadata: Some(metadata.clone()),
+                })
+            })
+            .collect();
+
+        Ok((changes, Some(cursor)))
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn prune_entity_changes(&mut self, retention: Duration) -> Result<u64, DeletionError> {
+        self.as_client()
+            .execute(
+                "
+                    DELETE FROM entity_change_events
+                    WHERE transaction_time < now() - $1::DOUBLE PRECISION * INTERVAL '1 second';
+                ",
+                &[&retention.as_secs_f64()],
+            )
+            .await
+            .change_context(DeletionError)
+    }
+}
+
+/// The maximum number of entities checked in a single request to the authorization backend.
+const PERMISSION_CHECK_BATCH_SIZE: usize = 1000;
+
+/// Adds the link entity to the [`UnpublishedLinkEndpoints`] of a failed validation, so the caller
+/// knows which link is blocked by the unpublished endpoints.
+fn attach_link_entity_id(
+    mut report: Report<ValidateEntityError>,
+    link_entity_id: EntityId,
+) -> Report<ValidateEntityError> {
+    if let Some(endpoints) = report.downcast_mut::<UnpublishedLinkEndpoints>() {
+        endpoints.link_entity_ids.push(link_entity_id);
+    }
+    report
+}
+
+/// Orders the temporal versions of entities by the start of their transaction and decision time.
+fn temporal_version_key(
+    metadata: &EntityMetadata,
+) -> (Timestamp<TransactionTime>, Timestamp<DecisionTime>) {
+    let ClosedTemporalBound::Inclusive(transaction_time) =
+        *metadata.temporal_versioning.transaction_time.start();
+    let ClosedTemporalBound::Inclusive(decision_time) =
+        *metadata.temporal_versioning.decision_time.start();
+    (transaction_time, decision_time)
+}
+
+#[derive(Debug)]
+#[must_use]
+struct LockedEntityEdition {
+    entity_id: EntityId,
+    entity_edition_id: EntityEditionId,
+    decision_time: LeftClosedTemporalInterval<DecisionTime>,
+    transaction_time: LeftClosedTemporalInterval<TransactionTime>,
+    updated_at_decision_time: Timestamp<DecisionTime>,
+}
+
+/// The rows of `entity_property` to be inserted, stored column by column.
+#[derive(Debug, Default)]
+struct EntityPropertyRows<'p> {
+    entity_edition_ids: Vec<EntityEditionId>,
+    property_paths: Vec<&'p PropertyPath<'p>>,
+    confidences: Vec<Option<&'p Confidence>>,
+    data_type_ontology_ids: Vec<Option<Uuid>>,
+    values: Vec<Option<f64>>,
+    provenances: Vec<Option<&'p PropertyProvenance>>,
+}
+
+impl<'p> EntityPropertyRows<'p> {
+    /// Adds a row for every property which has a confidence, a declared data type, or a
+    /// provenance.
+    ///
+    /// The numeric value of a property with a declared data type is stored as well, so it can be
+    /// converted into the canonical unit of the data type on insertion.
+    fn push(
+        &mut self,
+        entity_edition_id: EntityEditionId,
+        properties: &'p PropertyObject,
+        confidence: &'p PropertyConfidence<'p>,
+        data_types: &'p PropertyDataTypes<'p>,
+        provenance: &'p PropertyProvenances<'p>,
+    ) {
+        let mut rows = HashMap::<
+            _,
+            (
+                Option<&Confidence>,
+                Option<Uuid>,
+                Option<f64>,
+                Option<&PropertyProvenance>,
+            ),
+        >::new();
+        for (path, confidence) in confidence {
+            rows.entry(path).or_default().0 = Some(confidence);
+        }
+        for (path, data_type_id) in data_types {
+            let row = rows.entry(path).or_default();
+            row.1 = Some(DataTypeId::from_url(data_type_id).into_uuid());
+            row.2 = match properties.get(path) {
+                Some(Property::Value(JsonValue::Number(number))) => number.as_f64(),
+                _ => None,
+            };
+        }
+        for (path, provenance) in provenance {
+            rows.entry(path).or_default().3 = Some(provenance);
+        }
+
+        for (path, (confidence, data_type_ontology_id, value, provenance)) in rows {
+            self.entity_edition_ids.push(entity_edition_id);
+            self.property_paths.push(path);
+            self.confidences.push(confidence);
+            self.data_type_ontology_ids.push(data_type_ontology_id);
+            self.values.push(value);
+            self.provenances.push(provenance);
+        }
+    }
+}
+
+impl PostgresStore<tokio_postgres::Transaction<'_>> {
+    /// Returns the link entities which have one of the specified entities as left or right
+    /// entity.
+    #[tracing::instrument(level = "trace", skip(self))]
+    async fn read_linking_entities(
+        &self,
+        entity_ids: &[EntityId],
+    ) -> Result<Vec<EntityId>, DeletionError> {
+        let (owned_by_ids, entity_uuids): (Vec<_>, Vec<_>) = entity_ids
+            .iter()
+            .map(|entity_id| (entity_id.owned_by_id, entity_id.entity_uuid))
+            .unzip();
+
+        Ok(self
+            .as_client()
+            .query(
+                "
+                    SELECT web_id, entity_uuid
+                    FROM entity_has_left_entity
+                    WHERE (left_web_id, left_entity_uuid) IN (
+                        SELECT * FROM unnest($1::UUID[], $2::UUID[])
+                    )
+                    UNION
+                    SELECT web_id, entity_uuid
+                    FROM entity_has_right_entity
+                    WHERE (right_web_id, right_entity_uuid) IN (
+                        SELECT * FROM unnest($1::UUID[], $2::UUID[])
+                    );
+                ",
+                &[&owned_by_ids, &entity_uuids],
+            )
+            .await
+            .change_context(DeletionError)?
+            .into_iter()
+            .map(|row| EntityId {
+                owned_by_id: row.get(0),
+                entity_uuid: row.get(1),
+                draft_id: None,
+            })
+            .collect())
+    }
+
+    /// Removes all records of the specified entities from the database.
+    ///
+    /// This includes all editions, drafts, embeddings, link edges, and change events of the
+    /// entities except for their `delete` events.
+    #[tracing::instrument(level = "trace", skip(self))]
+    async fn delete_entity_records(&self, entity_ids: &[EntityId]) -> Result<(), DeletionError> {
+        let (owned_by_ids, entity_uuids): (Vec<_>, Vec<_>) = entity_ids
+            .iter()
+            .map(|entity_id| (entity_id.owned_by_id, entity_id.entity_uuid))
+            .unzip();
+
+        // The `delete` events are kept, so consumers of the change feed are notified about the
+        // deletion.
+        self.as_client()
+            .execute(
+                "
+                    DELETE FROM entity_change_events
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT * FROM unnest($1::UUID[], $2::UUID[])
+                    )
+                      AND kind <> 'delete';
+                ",
+                &[&owned_by_ids, &entity_uuids],
+            )
+            .await
+            .change_context(DeletionError)?;
+
+        for table in [
+            "entity_embeddings",
+            "entity_has_left_entity",
+            "entity_has_right_entity",
+        ] {
+            self.as_client()
+                .execute(
+                    &format!(
+                        "
+                            DELETE FROM {table}
+                            WHERE (web_id, entity_uuid) IN (
+                                SELECT * FROM unnest($1::UUID[], $2::UUID[])
+                            );
+                        "
+                    ),
+                    &[&owned_by_ids, &entity_uuids],
+                )
+                .await
+                .change_context(DeletionError)?;
+        }
+
+        let edition_ids = self
+            .as_client()
+            .query(
+                "
+                    DELETE FROM entity_temporal_metadata
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT * FROM unnest($1::UUID[], $2::UUID[])
+                    )
+                    RETURNING entity_edition_id;
+                ",
+                &[&owned_by_ids, &entity_uuids],
+            )
+            .await
+            .change_context(DeletionError)?
+            .into_iter()
+            .map(|row| row.get::<_, EntityEditionId>(0))
+            .collect::<Vec<_>>();
+
+        for table in ["entity_property", "entity_is_of_type", "entity_editions"] {
+            self.as_client()
+                .execute(
+                    &format!("DELETE FROM {table} WHERE entity_edition_id = ANY($1::UUID[]);"),
+                    &[&edition_ids],
+                )
+                .await
+                .change_context(DeletionError)?;
+        }
+
+        for table in ["entity_drafts", "entity_ids"] {
+            self.as_client()
+                .execute(
+                    &format!(
+                        "
+                            DELETE FROM {table}
+                            WHERE (web_id, entity_uuid) IN (
+                                SELECT * FROM unnest($1::UUID[], $2::UUID[])
+                            );
+                        "
+                    ),
+                    &[&owned_by_ids, &entity_uuids],
+                )
+                .await
+                .change_context(DeletionError)?;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "trace", skip(self))]
+    async fn insert_entity_edition(
+        &self,
+        archived: bool,
+        entity_type_ids: &[VersionedUrl],
+        properties: &PropertyObject,
+        confidence: Option<Confidence>,
+        provenance: &EntityEditionProvenanceMetadata,
+    ) -> Result<(EntityEditionId, ClosedEntityType), InsertionError> {
+        let edition_id: EntityEditionId = self
+            .as_client()
+            .query_one(
+                "
+                    INSERT INTO entity_editions (
+                        entity_edition_id,
+                        archived,
+                        properties,
+                        confidence,
+                        provenance
+                    ) VALUES (gen_random_uuid(), $1, $2, $3, $4)
+                    RETURNING entity_edition_id;
+                ",
+                &[&archived, &properties, &confidence, provenance],
+            )
+            .await
+            .change_context(InsertionError)?
+            .get(0);
+
+        let entity_type_ontology_ids = entity_type_ids
+            .iter()
+            .map(|entity_type_id| OntologyId::from(EntityTypeId::from_url(entity_type_id)))
+            .collect::<Vec<_>>();
+
+        self.as_client()
+            .query(
+                "
+                    INSERT INTO entity_is_of_type (
+                        entity_edition_id,
+                        entity_type_ontology_id
+                    ) SELECT $1, UNNEST($2::UUID[]);
+                ",
+                &[&edition_id, &entity_type_ontology_ids],
+            )
             .await
             .change_context(InsertionError)?;
 
@@ -1639,26 +4609,421 @@ impl PostgresStore<tokio_postgres::Transaction<'_>> {
         Ok((edition_id, entity_type))
     }
 
-    #[tracing::instrument(level = "trace", skip(self))]
+    #[tracing::instrument(level = "trace", skip(self, properties))]
     async fn insert_properties(
         &self,
         entity_edition_id: EntityEditionId,
+        properties: &PropertyObject,
         confidence: &PropertyConfidence<'_>,
+        data_types: &PropertyDataTypes<'_>,
+        provenance: &PropertyProvenances<'_>,
+    ) -> Result<(), InsertionError> {
+        let mut rows = EntityPropertyRows::default();
+        rows.push(
+            entity_edition_id,
+            properties,
+            confidence,
+            data_types,
+            provenance,
+        );
+        self.insert_property_rows(&rows).await
+    }
+
+    /// Inserts the rows into `entity_property`.
+    ///
+    /// Values with a declared data type are converted into the canonical unit of the data type.
+    /// Values without a conversion are stored as they are.
+    async fn insert_property_rows(
+        &self,
+        rows: &EntityPropertyRows<'_>,
     ) -> Result<(), InsertionError> {
-        let (property_paths, confidences): (Vec<_>, Vec<&Confidence>) = confidence.iter().unzip();
         self.as_client()
             .query(
                 "
                     INSERT INTO entity_property (
                         entity_edition_id,
                         property_path,
-                        confidence
-                    ) VALUES ($1, UNNEST($2::TEXT[]), UNNEST($3::DOUBLE PRECISION[]));
+                        confidence,
+                        data_type_ontology_id,
+                        normalized_value,
+                        provenance
+                    )
+                    SELECT
+                        property.entity_edition_id,
+                        property.property_path,
+                        property.confidence,
+                        property.data_type_ontology_id,
+                        property.value * COALESCE(data_type_conversions.factor, 1),
+                        property.provenance
+                    FROM UNNEST(
+                        $1::UUID[],
+                        $2::TEXT[],
+                        $3::DOUBLE PRECISION[],
+                        $4::UUID[],
+                        $5::DOUBLE PRECISION[],
+                        $6::JSONB[]
+                    ) AS property(
+                        entity_edition_id,
+                        property_path,
+                        confidence,
+                        data_type_ontology_id,
+                        value,
+                        provenance
+                    )
+                    LEFT OUTER JOIN data_type_conversions
+                      ON data_type_conversions.source_data_type_ontology_id
+                       = property.data_type_ontology_id;
+                ",
+                &[
+                    &rows.entity_edition_ids,
+                    &rows.property_paths,
+                    &rows.confidences,
+                    &rows.data_type_ontology_ids,
+                    &rows.values,
+                    &rows.provenances,
+                ],
+            )
+            .await
+            .change_context(InsertionError)?;
+        Ok(())
+    }
+
+    /// Records changes to entities in the change feed.
+    ///
+    /// This has to be called in the same transaction as the change itself, so the change is only
+    /// visible in the feed once it was committed.
+    #[tracing::instrument(level = "trace", skip(self, changes))]
+    async fn insert_entity_changes(
+        &self,
+        changes: impl IntoIterator<Item = (EntityId, EntityEditionId, EntityChangeKind)> + Send,
+    ) -> Result<(), InsertionError> {
+        let mut owned_by_ids = Vec::new();
+        let mut entity_uuids = Vec::new();
+        let mut draft_ids = Vec::new();
+        let mut edition_ids = Vec::new();
+        let mut kinds = Vec::new();
+        for (entity_id, edition_id, kind) in changes {
+            owned_by_ids.push(entity_id.owned_by_id);
+            entity_uuids.push(entity_id.entity_uuid);
+            draft_ids.push(entity_id.draft_id);
+            edition_ids.push(Some(edition_id));
+            kinds.push(kind);
+        }
+
+        self.insert_entity_change_rows(
+            &owned_by_ids,
+            &entity_uuids,
+            &draft_ids,
+            &edition_ids,
+            &kinds,
+        )
+        .await
+    }
+
+    /// Records the deletion of the specified entities in the change feed.
+    ///
+    /// The whole entity is deleted, so the events neither refer to a draft nor to an edition.
+    #[tracing::instrument(level = "trace", skip(self))]
+    async fn insert_entity_deletions(&self, entity_ids: &[EntityId]) -> Result<(), InsertionError> {
+        let (owned_by_ids, entity_uuids): (Vec<_>, Vec<_>) = entity_ids
+            .iter()
+            .map(|entity_id| (entity_id.owned_by_id, entity_id.entity_uuid))
+            .unzip();
+
+        self.insert_entity_change_rows(
+            &owned_by_ids,
+            &entity_uuids,
+            &vec![None; entity_ids.len()],
+            &vec![None; entity_ids.len()],
+            &vec![EntityChangeKind::Delete; entity_ids.len()],
+        )
+        .await
+    }
+
+    async fn insert_entity_change_rows(
+        &self,
+        owned_by_ids: &[OwnedById],
+        entity_uuids: &[EntityUuid],
+        draft_ids: &[Option<DraftId>],
+        edition_ids: &[Option<EntityEditionId>],
+        kinds: &[EntityChangeKind],
+    ) -> Result<(), InsertionError> {
+        self.as_client()
+            .query(
+                "
+                    INSERT INTO entity_change_events (
+                        web_id,
+                        entity_uuid,
+                        draft_id,
+                        entity_edition_id,
+                        kind
+                    ) SELECT * FROM UNNEST(
+                        $1::UUID[],
+                        $2::UUID[],
+                        $3::UUID[],
+                        $4::UUID[],
+                        $5::entity_change_kind[]
+                    );
                 ",
-                &[&entity_edition_id, &property_paths, &confidences],
+                &[
+                    &owned_by_ids,
+                    &entity_uuids,
+                    &draft_ids,
+                    &edition_ids,
+                    &kinds,
+                ],
+            )
+            .await
+            .change_context(InsertionError)?;
+        Ok(())
+    }
+
+    /// Inserts the records of multiple entities by using `COPY` statements.
+    ///
+    /// The metadata is expected to be fully resolved, so the rows are written directly into the
+    /// target tables.
+    #[tracing::instrument(level = "trace", skip(self, entities))]
+    async fn insert_entity_batch(
+        &self,
+        entities: &[(&EntityMetadata, &PropertyObject, Option<&LinkData>)],
+    ) -> Result<(), InsertionError> {
+        let sink = self
+            .client
+            .copy_in(
+                "COPY entity_ids (
+                    web_id,
+                    entity_uuid,
+                    provenance
+                ) FROM STDIN BINARY",
+            )
+            .await
+            .change_context(InsertionError)?;
+        let writer = BinaryCopyInWriter::new(sink, &[Type::UUID, Type::UUID, Type::JSONB]);
+        futures::pin_mut!(writer);
+        for (metadata, ..) in entities {
+            let entity_id = metadata.record_id.entity_id;
+            let provenance = InferredEntityProvenanceMetadata {
+                created_by_id: metadata.provenance.created_by_id,
+                created_at_transaction_time: metadata.provenance.created_at_transaction_time,
+                created_at_decision_time: metadata.provenance.created_at_decision_time,
+                first_non_draft_created_at_transaction_time: metadata
+                    .provenance
+                    .first_non_draft_created_at_transaction_time,
+                first_non_draft_created_at_decision_time: metadata
+                    .provenance
+                    .first_non_draft_created_at_decision_time,
+            };
+            writer
+                .as_mut()
+                .write(&[&entity_id.owned_by_id, &entity_id.entity_uuid, &provenance])
+                .await
+                .change_context(InsertionError)
+                .attach_printable(entity_id.entity_uuid)?;
+        }
+        writer.finish().await.change_context(InsertionError)?;
+
+        let sink = self
+            .client
+            .copy_in(
+                "COPY entity_drafts (
+                    web_id,
+                    entity_uuid,
+                    draft_id
+                ) FROM STDIN BINARY",
+            )
+            .await
+            .change_context(InsertionError)?;
+        let writer = BinaryCopyInWriter::new(sink, &[Type::UUID, Type::UUID, Type::UUID]);
+        futures::pin_mut!(writer);
+        for (metadata, ..) in entities {
+            let entity_id = metadata.record_id.entity_id;
+            if let Some(draft_id) = entity_id.draft_id {
+                writer
+                    .as_mut()
+                    .write(&[&entity_id.owned_by_id, &entity_id.entity_uuid, &draft_id])
+                    .await
+                    .change_context(InsertionError)
+                    .attach_printable(entity_id.entity_uuid)?;
+            }
+        }
+        writer.finish().await.change_context(InsertionError)?;
+
+        let sink = self
+            .client
+            .copy_in(
+                "COPY entity_editions (
+                    entity_edition_id,
+                    properties,
+                    archived,
+                    confidence,
+                    provenance
+                ) FROM STDIN BINARY",
+            )
+            .await
+            .change_context(InsertionError)?;
+        let writer = BinaryCopyInWriter::new(
+            sink,
+            &[
+                Type::UUID,
+                Type::JSONB,
+                Type::BOOL,
+                Type::FLOAT8,
+                Type::JSONB,
+            ],
+        );
+        futures::pin_mut!(writer);
+        for (metadata, properties, _) in entities {
+            writer
+                .as_mut()
+                .write(&[
+                    &metadata.record_id.edition_id,
+                    properties,
+                    &metadata.archived,
+                    &metadata.confidence,
+                    &metadata.provenance.edition,
+                ])
+                .await
+                .change_context(InsertionError)
+                .attach_printable(metadata.record_id.entity_id.entity_uuid)?;
+        }
+        writer.finish().await.change_context(InsertionError)?;
+
+        let sink = self
+            .client
+            .copy_in(
+                "COPY entity_is_of_type (
+                    entity_edition_id,
+                    entity_type_ontology_id
+                ) FROM STDIN BINARY",
+            )
+            .await
+            .change_context(InsertionError)?;
+        let writer = BinaryCopyInWriter::new(sink, &[Type::UUID, Type::UUID]);
+        futures::pin_mut!(writer);
+        for (metadata, ..) in entities {
+            for entity_type_id in &metadata.entity_type_ids {
+                writer
+                    .as_mut()
+                    .write(&[
+                        &metadata.record_id.edition_id,
+                        &OntologyId::from(EntityTypeId::from_url(entity_type_id)),
+                    ])
+                    .await
+                    .change_context(InsertionError)
+                    .attach_printable(metadata.record_id.entity_id.entity_uuid)?;
+            }
+        }
+        writer.finish().await.change_context(InsertionError)?;
+
+        // The values of properties with a declared data type are converted on insertion, which
+        // cannot be expressed with `COPY`.
+        let mut property_rows = EntityPropertyRows::default();
+        for (metadata, properties, _) in entities {
+            property_rows.push(
+                metadata.record_id.edition_id,
+                properties,
+                &metadata.property_confidence,
+                &metadata.property_data_types,
+                &metadata.property_provenance,
+            );
+        }
+        self.insert_property_rows(&property_rows).await?;
+
+        // Only outgoing links are ordered, so the position is stored on the left entity edge.
+        for (left_right, position_column) in [("left", ", position"), ("right", "")] {
+            let sink = self
+                .client
+                .copy_in(&format!(
+                    "COPY entity_has_{left_right}_entity (
+                        web_id,
+                        entity_uuid,
+                        {left_right}_web_id,
+                        {left_right}_entity_uuid,
+                        confidence{position_column}
+                    ) FROM STDIN BINARY",
+                ))
+                .await
+                .change_context(InsertionError)?;
+            let mut types = vec![Type::UUID, Type::UUID, Type::UUID, Type::UUID, Type::FLOAT8];
+            if !position_column.is_empty() {
+                types.push(Type::TEXT);
+            }
+            let writer = BinaryCopyInWriter::new(sink, &types);
+            futures::pin_mut!(writer);
+            for (metadata, _, link_data) in entities {
+                let Some(link_data) = link_data else {
+                    continue;
+                };
+                let entity_id = metadata.record_id.entity_id;
+                let (linked_entity_id, confidence) = if left_right == "left" {
+                    (link_data.left_entity_id, link_data.left_entity_confidence)
+                } else {
+                    (link_data.right_entity_id, link_data.right_entity_confidence)
+                };
+                let mut row: Vec<&(dyn ToSql + Sync)> = vec![
+                    &entity_id.owned_by_id,
+                    &entity_id.entity_uuid,
+                    &linked_entity_id.owned_by_id,
+                    &linked_entity_id.entity_uuid,
+                    &confidence,
+                ];
+                if !position_column.is_empty() {
+                    row.push(&link_data.position);
+                }
+                writer
+                    .as_mut()
+                    .write(&row)
+                    .await
+                    .change_context(InsertionError)
+                    .attach_printable(entity_id.entity_uuid)?;
+            }
+            writer.finish().await.change_context(InsertionError)?;
+        }
+
+        let sink = self
+            .client
+            .copy_in(
+                "COPY entity_temporal_metadata (
+                    web_id,
+                    entity_uuid,
+                    draft_id,
+                    entity_edition_id,
+                    decision_time,
+                    transaction_time
+                ) FROM STDIN BINARY",
             )
             .await
             .change_context(InsertionError)?;
+        let writer = BinaryCopyInWriter::new(
+            sink,
+            &[
+                Type::UUID,
+                Type::UUID,
+                Type::UUID,
+                Type::UUID,
+                Type::TSTZ_RANGE,
+                Type::TSTZ_RANGE,
+            ],
+        );
+        futures::pin_mut!(writer);
+        for (metadata, ..) in entities {
+            let entity_id = metadata.record_id.entity_id;
+            writer
+                .as_mut()
+                .write(&[
+                    &entity_id.owned_by_id,
+                    &entity_id.entity_uuid,
+                    &entity_id.draft_id,
+                    &metadata.record_id.edition_id,
+                    &metadata.temporal_versioning.decision_time,
+                    &metadata.temporal_versioning.transaction_time,
+                ])
+                .await
+                .change_context(InsertionError)
+                .attach_printable(entity_id.entity_uuid)?;
+        }
+        writer.finish().await.change_context(InsertionError)?;
+
         Ok(())
     }
 
@@ -1850,6 +5215,180 @@ impl PostgresStore<tokio_postgres::Transaction<'_>> {
         })
     }
 
+    /// Merges the properties of the draft specified by `entity_id` into the live entity.
+    ///
+    /// If the live entity was updated since the draft was created, the changes of the draft are
+    /// applied on top of the current live edition. The confidence values, data types, and
+    /// provenance are merged along the same paths as the properties. Otherwise, the draft
+    /// properties are returned unchanged.
+    #[tracing::instrument(
+        level = "trace",
+        skip(
+            self,
+            properties,
+            property_confidence,
+            property_data_types,
+            property_provenance
+        )
+    )]
+    async fn merge_draft_properties(
+        &self,
+        entity_id: EntityId,
+        decision_time: Option<Timestamp<DecisionTime>>,
+        properties: PropertyObject,
+        property_confidence: PropertyConfidence<'static>,
+        property_data_types: PropertyDataTypes<'static>,
+        property_provenance: PropertyProvenances<'static>,
+    ) -> Result<
+        (
+            PropertyObject,
+            PropertyConfidence<'static>,
+            PropertyDataTypes<'static>,
+            PropertyProvenances<'static>,
+        ),
+        UpdateError,
+    > {
+        let unchanged = (
+            properties,
+            property_confidence,
+            property_data_types,
+            property_provenance,
+        );
+        let Some(draft_id) = entity_id.draft_id else {
+            return Ok(unchanged);
+        };
+
+        let base_edition_id: Option<EntityEditionId> = self
+            .as_client()
+            .query_one(
+                "
+                SELECT base_edition_id
+                FROM entity_drafts
+                WHERE web_id = $1
+                  AND entity_uuid = $2
+                  AND draft_id = $3;",
+                &[&entity_id.owned_by_id, &entity_id.entity_uuid, &draft_id],
+            )
+            .await
+            .change_context(UpdateError)?
+            .get(0);
+        let Some(base_edition_id) = base_edition_id else {
+            return Ok(unchanged);
+        };
+
+        let live_entity_id = EntityId {
+            draft_id: None,
+            ..entity_id
+        };
+        let Some(live_row) = self
+            .lock_entity_edition(live_entity_id, decision_time)
+            .await?
+        else {
+            return Ok(unchanged);
+        };
+        if live_row.entity_edition_id == base_edition_id {
+            return Ok(unchanged);
+        }
+
+        let base = self
+            .read_entity_edition(
+                live_entity_id,
+                EntityEditionSelector::EditionId(base_edition_id),
+            )
+            .await
+            .change_context(UpdateError)?;
+        let live = self
+            .read_entity_edition(
+                live_entity_id,
+                EntityEditionSelector::EditionId(live_row.entity_edition_id),
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        let (properties, property_confidence, property_data_types, property_provenance) = unchanged;
+        let merged_paths = properties.merged_paths(&base.properties, &live.properties);
+        let merged_properties = properties
+            .merge(&base.properties, &live.properties)
+            .map_err(|report| {
+                let status_code = if report.contains::<PropertyMergeConflict>() {
+                    StatusCode::Aborted
+                } else {
+                    StatusCode::InvalidArgument
+                };
+                report.attach(status_code).change_context(UpdateError)
+            })?;
+        let merged_property_confidence = property_confidence.merge(
+            &base.metadata.property_confidence,
+            &live.metadata.property_confidence,
+            &merged_paths,
+            &merged_properties,
+        );
+        let merged_property_data_types = property_data_types.merge(
+            &base.metadata.property_data_types,
+            &live.metadata.property_data_types,
+            &merged_paths,
+            &merged_properties,
+        );
+        let merged_property_provenance = property_provenance.merge(
+            &base.metadata.property_provenance,
+            &live.metadata.property_provenance,
+            &merged_paths,
+            &merged_properties,
+        );
+
+        Ok((
+            merged_properties,
+            merged_property_confidence,
+            merged_property_data_types,
+            merged_property_provenance,
+        ))
+    }
+
+    /// Inserts a temporal version of an entity edition which is valid within `decision_time` and
+    /// from now on in transaction time.
+    #[tracing::instrument(level = "trace", skip(self))]
+    async fn insert_temporal_slice(
+        &self,
+        entity_id: EntityId,
+        edition_id: EntityEditionId,
+        decision_time: LeftClosedTemporalInterval<DecisionTime>,
+    ) -> Result<EntityTemporalMetadata, InsertionError> {
+        let row = self
+            .as_client()
+            .query_one(
+                "
+                INSERT INTO entity_temporal_metadata (
+                    web_id,
+                    entity_uuid,
+                    draft_id,
+                    entity_edition_id,
+                    decision_time,
+                    transaction_time
+                ) VALUES (
+                    $1,
+                    $2,
+                    $3,
+                    $4,
+                    $5,
+                    tstzrange(now(), NULL, '[)')
+                ) RETURNING decision_time, transaction_time;",
+                &[
+                    &entity_id.owned_by_id,
+                    &entity_id.entity_uuid,
+                    &entity_id.draft_id,
+                    &edition_id,
+                    &decision_time,
+                ],
+            )
+            .await
+            .change_context(InsertionError)?;
+
+        Ok(EntityTemporalMetadata {
+            decision_time: row.get(0),
+            transaction_time: row.get(1),
+        })
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     async fn update_temporal_metadata(
         &self,
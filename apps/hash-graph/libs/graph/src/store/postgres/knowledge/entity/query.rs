@@ -7,7 +7,7 @@ use graph_types::{
             InferredEntityProvenanceMetadata,
         },
         link::LinkData,
-        Confidence, PropertyPath,
+        Confidence, PropertyPath, PropertyProvenance,
     },
     owned_by_id::OwnedById,
 };
@@ -192,8 +192,12 @@ pub struct EntityRecordRowIndices {
     pub entity_confidence: usize,
     pub left_entity_confidence: usize,
     pub right_entity_confidence: usize,
+    pub left_entity_position: usize,
     pub property_paths: usize,
     pub property_confidences: usize,
+    pub property_data_type_base_urls: usize,
+    pub property_data_type_versions: usize,
+    pub property_provenances: usize,
 
     pub archived: usize,
 }
@@ -266,6 +270,7 @@ impl QueryRecordDecode for Entity {
                     },
                     left_entity_confidence: row.get(indices.left_entity_confidence),
                     right_entity_confidence: row.get(indices.right_entity_confidence),
+                    position: row.get(indices.left_entity_position),
                 }),
                 (None, None, None, None) => None,
                 _ => unreachable!(
@@ -287,6 +292,39 @@ impl QueryRecordDecode for Entity {
 
         let inferred_provenance_data =
             row.get::<_, InferredEntityProvenanceMetadata>(indices.provenance);
+        let property_paths = row
+            .get::<_, Option<Vec<PropertyPath<'_>>>>(indices.property_paths)
+            .unwrap_or_default();
+        let property_data_types = property_paths
+            .iter()
+            .zip(
+                row.get::<_, Option<Vec<Option<BaseUrl>>>>(indices.property_data_type_base_urls)
+                    .unwrap_or_default(),
+            )
+            .zip(
+                row.get::<_, Option<Vec<Option<OntologyTypeVersion>>>>(
+                    indices.property_data_type_versions,
+                )
+                .unwrap_or_default(),
+            )
+            .filter_map(|((path, base_url), version)| {
+                Some((
+                    path.clone().into_owned(),
+                    VersionedUrl {
+                        base_url: base_url?,
+                        version: version?,
+                    },
+                ))
+            })
+            .collect();
+        let property_provenance = property_paths
+            .iter()
+            .zip(
+                row.get::<_, Option<Vec<Option<PropertyProvenance>>>>(indices.property_provenances)
+                    .unwrap_or_default(),
+            )
+            .filter_map(|(path, provenance)| Some((path.clone().into_owned(), provenance?)))
+            .collect();
         Self {
             properties: row.get(indices.properties),
             link_data,
@@ -317,9 +355,7 @@ impl QueryRecordDecode for Entity {
                     edition: row.get(indices.edition_provenance),
                 },
                 confidence: row.get(indices.entity_confidence),
-                property_confidence: row
-                    .get::<_, Option<Vec<PropertyPath<'_>>>>(indices.property_paths)
-                    .unwrap_or_default()
+                property_confidence: property_paths
                     .into_iter()
                     .zip(
                         row.get::<_, Option<Vec<Option<Confidence>>>>(indices.property_confidences)
@@ -329,6 +365,8 @@ impl QueryRecordDecode for Entity {
                         confidence.map(|confidence| (path.into_owned(), confidence))
                     })
                     .collect(),
+                property_data_types,
+                property_provenance,
                 archived: row.get(indices.archived),
             },
         }
@@ -397,9 +435,16 @@ impl PostgresRecord for Entity {
                 .add_selection_path(&EntityQueryPath::LeftEntityConfidence),
             right_entity_confidence: compiler
                 .add_selection_path(&EntityQueryPath::RightEntityConfidence),
+            left_entity_position: compiler.add_selection_path(&EntityQueryPath::LeftEntityPosition),
             property_paths: compiler.add_selection_path(&EntityQueryPath::PropertyPaths),
             property_confidences: compiler
                 .add_selection_path(&EntityQueryPath::PropertyConfidences),
+            property_data_type_base_urls: compiler
+                .add_selection_path(&EntityQueryPath::PropertyDataTypeBaseUrls),
+            property_data_type_versions: compiler
+                .add_selection_path(&EntityQueryPath::PropertyDataTypeVersions),
+            property_provenances: compiler
+                .add_selection_path(&EntityQueryPath::PropertyProvenances),
 
             archived: compiler.add_selection_path(&EntityQueryPath::Archived),
         }
@@ -33,7 +33,7 @@ use graph_types::{
     owned_by_id::OwnedById,
 };
 use postgres_types::Json;
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 use temporal_versioning::{DecisionTime, LeftClosedTemporalInterval, Timestamp, TransactionTime};
 use time::OffsetDateTime;
 use tokio_postgres::{
@@ -41,14 +41,14 @@ use tokio_postgres::{
 };
 use type_system::{
     url::{BaseUrl, OntologyTypeVersion, VersionedUrl},
-    ClosedEntityType, DataTypeReference, EntityType, EntityTypeReference, PropertyType,
+    ClosedEntityType, DataType, DataTypeReference, EntityType, EntityTypeReference, PropertyType,
     PropertyTypeReference,
 };
 
 pub use self::{
     pool::{AsClient, PostgresStorePool},
     query::CursorField,
-    traversal_context::TraversalContext,
+    traversal_context::{TraversalContext, TraversalQueue},
 };
 use crate::store::{
     account::{InsertAccountGroupIdParams, InsertAccountIdParams, InsertWebIdParams},
@@ -410,6 +410,36 @@ where
             .map(|row| row.get(0)))
     }
 
+    /// Reads the schema of the [`OntologyDatabaseType`] identified by [`OntologyId`].
+    ///
+    /// # Errors
+    ///
+    /// - if the ontology type does not exist
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn read_ontology_schema<T>(&self, ontology_id: OntologyId) -> Result<T, QueryError>
+    where
+        T: OntologyDatabaseType + DeserializeOwned,
+    {
+        // See `insert_with_id` for why the query is not prepared.
+        Ok(self
+            .as_client()
+            .query_one(
+                &format!(
+                    r#"
+                        SELECT schema
+                        FROM {}
+                        WHERE ontology_id = $1;
+                    "#,
+                    T::table()
+                ),
+                &[&ontology_id],
+            )
+            .await
+            .change_context(QueryError)?
+            .get::<_, Json<T>>(0)
+            .0)
+    }
+
     /// Inserts a [`EntityType`] identified by [`OntologyId`], and associated with an
     /// [`OwnedById`], [`EditionCreatedById`], and the optional label property, into the database.
     ///
@@ -452,6 +482,38 @@ where
             .map(|row| row.get(0)))
     }
 
+    #[tracing::instrument(level = "debug", skip(self, data_type))]
+    async fn insert_data_type_references(
+        &self,
+        data_type: &DataType,
+        ontology_id: OntologyId,
+    ) -> Result<(), InsertionError> {
+        for inherits_from in data_type.inherits_from().all_of() {
+            self.as_client()
+                .query_one(
+                    "
+                        INSERT INTO data_type_inherits_from (
+                            source_data_type_ontology_id,
+                            target_data_type_ontology_id
+                        ) VALUES (
+                            $1,
+                            (SELECT ontology_id FROM ontology_ids WHERE base_url = $2 AND version \
+                     = $3)
+                        ) RETURNING target_data_type_ontology_id;
+                    ",
+                    &[
+                        &ontology_id,
+                        &inherits_from.url().base_url,
+                        &inherits_from.url().version,
+                    ],
+                )
+                .await
+                .change_context(InsertionError)?;
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip(self, property_type))]
     async fn insert_property_type_references(
         &self,
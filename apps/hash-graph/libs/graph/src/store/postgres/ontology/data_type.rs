@@ -1,4 +1,8 @@
-use std::{collections::HashSet, iter::once};
+use std::{
+    collections::{HashMap, HashSet},
+    iter::once,
+    mem,
+};
 
 use authorization::{
     backend::ModifyRelationshipOperation,
@@ -9,45 +13,55 @@ use authorization::{
     zanzibar::{Consistency, Zookie},
     AuthorizationApi,
 };
-use error_stack::{Result, ResultExt};
+use error_stack::{bail, Report, Result, ResultExt};
 use graph_types::{
     account::{AccountId, EditionArchivedById, EditionCreatedById},
     ontology::{
-        DataTypeMetadata, DataTypeWithMetadata, OntologyEditionProvenanceMetadata,
-        OntologyProvenanceMetadata, OntologyTemporalMetadata, OntologyTypeClassificationMetadata,
-        OntologyTypeRecordId,
+        DataTypeConversion, DataTypeMetadata, DataTypeWithMetadata,
+        OntologyEditionProvenanceMetadata, OntologyProvenanceMetadata, OntologyTemporalMetadata,
+        OntologyTypeClassificationMetadata, OntologyTypeRecordId,
     },
     Embedding,
 };
 use postgres_types::{Json, ToSql};
 use temporal_client::TemporalClient;
-use temporal_versioning::{RightBoundedTemporalInterval, Timestamp, TransactionTime};
+use temporal_versioning::{Timestamp, TransactionTime};
 use tokio_postgres::{GenericClient, Row};
 use type_system::{
-    url::{OntologyTypeVersion, VersionedUrl},
-    DataType,
+    url::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+    DataType, OntologyTypeChange,
 };
 
 use crate::{
     ontology::DataTypeQueryPath,
     store::{
-        crud::{QueryResult, ReadPaginated, VertexIdSorting},
-        error::DeletionError,
+        crud::Read,
+        error::{BreakingOntologyTypeChanges, DeletionError},
         ontology::{
             ArchiveDataTypeParams, CreateDataTypeParams, GetDataTypesParams,
             UnarchiveDataTypeParams, UpdateDataTypeEmbeddingParams, UpdateDataTypesParams,
         },
         postgres::{
             crud::QueryRecordDecode,
-            ontology::{OntologyId, PostgresOntologyTypeClassificationMetadata},
-            query::{Distinctness, PostgresRecord, SelectCompiler, Table},
-            TraversalContext,
+            ontology::{
+                read::OntologyTypeTraversalData, OntologyId, PermittedOntologyTypes,
+                PostgresOntologyTypeClassificationMetadata,
+            },
+            query::{Distinctness, PostgresRecord, ReferenceTable, SelectCompiler, Table},
+            TraversalContext, TraversalQueue,
         },
-        AsClient, DataTypeStore, InsertionError, PostgresStore, QueryError, SubgraphRecord,
-        UpdateError,
+        query::{Filter, FilterExpression, ParameterList},
+        AsClient, DataTypeStore, InsertionError, PostgresStore, QueryError, UpdateError,
     },
     subgraph::{
-        edges::GraphResolveDepths, query::StructuralQuery, temporal_axes::VariableAxis, Subgraph,
+        edges::{EdgeDirection, OntologyEdgeKind},
+        identifier::{DataTypeVertexId, PropertyTypeVertexId},
+        query::StructuralQuery,
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
+            VariableTemporalAxisUnresolved,
+        },
+        Subgraph,
     },
 };
 
@@ -95,23 +109,243 @@ impl<C: AsClient> PostgresStore<C> {
     /// Internal method to read a [`DataTypeWithMetadata`] into a [`TraversalContext`].
     ///
     /// This is used to recursively resolve a type, so the result can be reused.
-    #[tracing::instrument(level = "info", skip(self))]
+    #[tracing::instrument(
+        level = "info",
+        skip(self, queue, traversal_context, subgraph, authorization_api, zookie)
+    )]
     pub(crate) async fn traverse_data_types<A: AuthorizationApi + Sync>(
         &self,
-        queue: Vec<(
-            OntologyId,
-            GraphResolveDepths,
-            RightBoundedTemporalInterval<VariableAxis>,
-        )>,
-        _: &mut TraversalContext,
+        queue: &mut TraversalQueue,
+        traversal_context: &mut TraversalContext,
         actor_id: AccountId,
-        _: &A,
-        _: &Zookie<'static>,
-        _: &mut Subgraph,
+        authorization_api: &A,
+        zookie: &Zookie<'static>,
+        subgraph: &mut Subgraph,
     ) -> Result<(), QueryError> {
-        // TODO: data types currently have no references to other types, so we don't need to do
-        //       anything here
-        //   see https://app.asana.com/0/1200211978612931/1202464168422955/f
+        // The only outgoing references of data types are the data types they inherit from.
+        let mut edges_to_traverse = Option::<OntologyTypeTraversalData>::None;
+        let mut inheritance_edges_to_traverse =
+            HashMap::<EdgeDirection, OntologyTypeTraversalData>::new();
+
+        for (data_type_ontology_id, graph_resolve_depths, traversal_interval) in
+            mem::take(&mut queue.data_types)
+        {
+            if let Some(new_graph_resolve_depths) = graph_resolve_depths.decrement_depth_for_edge(
+                OntologyEdgeKind::ConstrainsValuesOn,
+                EdgeDirection::Incoming,
+            ) {
+                edges_to_traverse.get_or_insert_with(Default::default).push(
+                    data_type_ontology_id,
+                    new_graph_resolve_depths,
+                    traversal_interval,
+                );
+            }
+
+            for edge_direction in [EdgeDirection::Outgoing, EdgeDirection::Incoming] {
+                if let Some(new_graph_resolve_depths) = graph_resolve_depths
+                    .decrement_depth_for_edge(OntologyEdgeKind::InheritsFrom, edge_direction)
+                {
+                    inheritance_edges_to_traverse
+                        .entry(edge_direction)
+                        .or_default()
+                        .push(
+                            data_type_ontology_id,
+                            new_graph_resolve_depths,
+                            traversal_interval,
+                        );
+                }
+            }
+        }
+
+        for (edge_direction, traversal_data) in inheritance_edges_to_traverse {
+            queue.data_types.extend(
+                Self::filter_data_types_by_permission(
+                    self.read_ontology_edges::<DataTypeVertexId, DataTypeVertexId>(
+                        &traversal_data,
+                        ReferenceTable::DataTypeInheritsFrom,
+                        edge_direction,
+                    )
+                    .await?,
+                    actor_id,
+                    authorization_api,
+                    zookie,
+                )
+                .await?
+                .flat_map(|edge| {
+                    subgraph.insert_edge(
+                        &edge.left_endpoint,
+                        OntologyEdgeKind::InheritsFrom,
+                        edge_direction,
+                        edge.right_endpoint.clone(),
+                    );
+
+                    traversal_context.add_data_type_id(
+                        edge.right_endpoint_ontology_id,
+                        edge.resolve_depths,
+                        edge.traversal_interval,
+                    )
+                }),
+            );
+        }
+
+        if let Some(traversal_data) = edges_to_traverse {
+            queue.property_types.extend(
+                Self::filter_property_types_by_permission(
+                    self.read_ontology_edges::<DataTypeVertexId, PropertyTypeVertexId>(
+                        &traversal_data,
+                        ReferenceTable::PropertyTypeConstrainsValuesOn,
+                        EdgeDirection::Incoming,
+                    )
+                    .await?,
+                    actor_id,
+                    authorization_api,
+                    zookie,
+                )
+                .await?
+                .flat_map(|edge| {
+                    subgraph.insert_edge(
+                        &edge.left_endpoint,
+                        OntologyEdgeKind::ConstrainsValuesOn,
+                        EdgeDirection::Incoming,
+                        edge.right_endpoint.clone(),
+                    );
+
+                    traversal_context.add_property_type_id(
+                        edge.right_endpoint_ontology_id,
+                        edge.resolve_depths,
+                        edge.traversal_interval,
+                    )
+                }),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the constraints of the data types are valid and only narrow the constraints of
+    /// the data types they inherit from.
+    ///
+    /// Parents are taken from `data_types` if they are created together with their children and
+    /// are read from the graph otherwise.
+    ///
+    /// # Errors
+    ///
+    /// - if the constraints of a data type are invalid
+    /// - if a parent data type does not exist
+    /// - if a data type widens a constraint of one of its parents
+    #[tracing::instrument(level = "debug", skip(self, data_types))]
+    async fn check_data_type_refinements<'d>(
+        &self,
+        data_types: impl IntoIterator<Item = &'d DataType> + Send,
+    ) -> Result<(), InsertionError> {
+        let data_types = data_types.into_iter().collect::<Vec<_>>();
+
+        for data_type in &data_types {
+            data_type
+                .validate_constraints()
+                .change_context(InsertionError)
+                .attach_printable_lazy(|| data_type.id().clone())?;
+        }
+
+        let mut parents = data_types
+            .iter()
+            .map(|data_type| (data_type.id().clone(), (*data_type).clone()))
+            .collect::<HashMap<_, _>>();
+
+        let missing_parent_ids = data_types
+            .iter()
+            .flat_map(|data_type| data_type.inherits_from().all_of())
+            .filter(|reference| !parents.contains_key(reference.url()))
+            .map(|reference| DataTypeId::from_url(reference.url()).into_uuid())
+            .collect::<Vec<_>>();
+
+        if !missing_parent_ids.is_empty() {
+            parents.extend(
+                Read::<DataTypeWithMetadata>::read_vec(
+                    self,
+                    &Filter::In(
+                        FilterExpression::Path(DataTypeQueryPath::OntologyId),
+                        ParameterList::Uuid(&missing_parent_ids),
+                    ),
+                    Some(
+                        &QueryTemporalAxesUnresolved::DecisionTime {
+                            pinned: PinnedTemporalAxisUnresolved::new(None),
+                            variable: VariableTemporalAxisUnresolved::new(None, None),
+                        }
+                        .resolve(),
+                    ),
+                    false,
+                )
+                .await
+                .change_context(InsertionError)?
+                .into_iter()
+                .map(|parent| (parent.schema.id().clone(), parent.schema)),
+            );
+        }
+
+        for data_type in data_types {
+            for reference in data_type.inherits_from().all_of() {
+                let parent = parents.get(reference.url()).ok_or_else(|| {
+                    Report::new(InsertionError).attach_printable(format!(
+                        "the parent data type `{}` of `{}` does not exist",
+                        reference.url(),
+                        data_type.id()
+                    ))
+                })?;
+                data_type
+                    .check_refinement(parent)
+                    .change_context(InsertionError)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Stores how values of the data type are converted into its canonical unit.
+    ///
+    /// # Errors
+    ///
+    /// - if the factor is zero or not finite
+    /// - if the data type would be converted into itself
+    /// - if the canonical unit does not exist
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn insert_data_type_conversion(
+        &self,
+        data_type_id: &VersionedUrl,
+        ontology_id: OntologyId,
+        conversion: &DataTypeConversion,
+    ) -> Result<(), InsertionError> {
+        if !conversion.factor.is_normal() {
+            bail!(Report::new(InsertionError).attach_printable(format!(
+                "the conversion factor of `{data_type_id}` has to be finite and non-zero but is {}",
+                conversion.factor
+            )));
+        }
+        if conversion.canonical_unit == data_type_id.base_url {
+            bail!(Report::new(InsertionError).attach_printable(format!(
+                "the data type `{data_type_id}` cannot be converted into itself"
+            )));
+        }
+
+        self.as_client()
+            .query(
+                "
+                    INSERT INTO data_type_conversions (
+                        source_data_type_ontology_id,
+                        target_data_type_base_url,
+                        factor
+                    ) VALUES ($1, $2, $3);
+                ",
+                &[&ontology_id, &conversion.canonical_unit, &conversion.factor],
+            )
+            .await
+            .change_context(InsertionError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "could not insert the conversion of `{data_type_id}` into `{}`",
+                    conversion.canonical_unit
+                )
+            })?;
 
         Ok(())
     }
@@ -125,6 +359,8 @@ impl<C: AsClient> PostgresStore<C> {
             .simple_query(
                 "
                     DELETE FROM data_type_embeddings;
+                    DELETE FROM data_type_inherits_from;
+                    DELETE FROM data_type_conversions;
                 ",
             )
             .await
@@ -168,8 +404,14 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
     {
         let transaction = self.transaction().await.change_context(InsertionError)?;
 
+        let params = params.into_iter().collect::<Vec<_>>();
+        transaction
+            .check_data_type_refinements(params.iter().map(|parameters| &parameters.schema))
+            .await?;
+
         let mut relationships = HashSet::new();
 
+        let mut inserted_ontology_ids = Vec::new();
         let mut inserted_data_type_metadata = Vec::new();
         let mut inserted_data_types = Vec::new();
 
@@ -232,17 +474,38 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
                     classification: parameters.classification,
                     temporal_versioning,
                     provenance,
+                    conversion: parameters.conversion,
                 };
-                if temporal_client.is_some() {
-                    inserted_data_types.push(DataTypeWithMetadata {
-                        schema: parameters.schema,
-                        metadata: metadata.clone(),
-                    });
-                }
+                inserted_ontology_ids.push(ontology_id);
+                inserted_data_types.push(DataTypeWithMetadata {
+                    schema: parameters.schema,
+                    metadata: metadata.clone(),
+                });
                 inserted_data_type_metadata.push(metadata);
             }
         }
 
+        for (ontology_id, data_type) in inserted_ontology_ids.into_iter().zip(&inserted_data_types)
+        {
+            transaction
+                .insert_data_type_references(&data_type.schema, ontology_id)
+                .await
+                .change_context(InsertionError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "could not insert references for data type: {}",
+                        data_type.schema.id()
+                    )
+                })
+                .attach_lazy(|| data_type.schema.clone())?;
+
+            if let Some(conversion) = &data_type.metadata.conversion {
+                transaction
+                    .insert_data_type_conversion(data_type.schema.id(), ontology_id, conversion)
+                    .await?;
+            }
+        }
+
         #[expect(clippy::needless_collect, reason = "Higher ranked lifetime error")]
         authorization_api
             .modify_data_type_relations(
@@ -298,7 +561,7 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
         actor_id: AccountId,
         authorization_api: &A,
         params: GetDataTypesParams<'_>,
-    ) -> Result<Subgraph, QueryError> {
+    ) -> Result<(Subgraph, Option<DataTypeVertexId>), QueryError> {
         let StructuralQuery {
             ref filter,
             graph_resolve_depths,
@@ -307,50 +570,30 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
         } = params.query;
 
         let temporal_axes = unresolved_temporal_axes.clone().resolve();
-        let time_axis = temporal_axes.variable_time_axis();
-
-        // TODO: Remove again when subgraph logic was revisited
-        //   see https://linear.app/hash/issue/H-297
-        let mut visited_ontology_ids = HashSet::new();
-
-        let (data, artifacts) = ReadPaginated::<DataTypeWithMetadata>::read_paginated_vec(
-            self,
-            filter,
-            Some(&temporal_axes),
-            &VertexIdSorting {
-                cursor: params.after,
-            },
-            params.limit,
-            include_drafts,
-        )
-        .await?;
-        let data_types = data
-            .into_iter()
-            .filter_map(|row| {
-                let data_type = row.decode_record(&artifacts);
-                let id = DataTypeId::from_url(data_type.schema.id());
-                let vertex_id = data_type.vertex_id(time_axis);
-                // The records are already sorted by time, so we can just take the first one
-                visited_ontology_ids
-                    .insert(id)
-                    .then_some((id, (vertex_id, data_type)))
-            })
-            .collect::<Vec<_>>();
-
-        let filtered_ids = data_types
-            .iter()
-            .map(|(data_type_id, _)| *data_type_id)
-            .collect::<Vec<_>>();
 
-        let (permissions, zookie) = authorization_api
-            .check_data_types_permission(
-                actor_id,
-                DataTypePermission::View,
-                filtered_ids,
-                Consistency::FullyConsistent,
+        let PermittedOntologyTypes {
+            ids: data_type_ids,
+            vertices: data_type_vertices,
+            zookie,
+            cursor,
+        } = self
+            .read_permitted_ontology_types(
+                filter,
+                &temporal_axes,
+                params.after,
+                params.limit,
+                include_drafts,
+                |data_type| DataTypeId::from_url(data_type.schema.id()),
+                |data_type_ids| {
+                    authorization_api.check_data_types_permission(
+                        actor_id,
+                        DataTypePermission::View,
+                        data_type_ids,
+                        Consistency::FullyConsistent,
+                    )
+                },
             )
-            .await
-            .change_context(QueryError)?;
+            .await?;
 
         let mut subgraph = Subgraph::new(
             graph_resolve_depths,
@@ -358,11 +601,6 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
             temporal_axes.clone(),
         );
 
-        let (data_type_ids, data_type_vertices): (Vec<_>, Vec<_>) = data_types
-            .into_iter()
-            .filter(|(id, _)| permissions.get(id).copied().unwrap_or(false))
-            .unzip();
-
         subgraph.roots.extend(
             data_type_vertices
                 .iter()
@@ -374,22 +612,26 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
 
         // TODO: We currently pass in the subgraph as mutable reference, thus we cannot borrow the
         //       vertices and have to `.collect()` the keys.
-        self.traverse_data_types(
-            data_type_ids
-                .into_iter()
-                .map(|id| {
-                    (
-                        OntologyId::from(id),
-                        subgraph.depths,
-                        subgraph.temporal_axes.resolved.variable_interval(),
-                    )
-                })
-                .collect(),
+        self.traverse(
+            TraversalQueue {
+                data_types: data_type_ids
+                    .into_iter()
+                    .map(|id| {
+                        (
+                            OntologyId::from(id),
+                            subgraph.depths,
+                            subgraph.temporal_axes.resolved.variable_interval(),
+                        )
+                    })
+                    .collect(),
+                ..TraversalQueue::default()
+            },
             &mut traversal_context,
             actor_id,
             authorization_api,
             &zookie,
             &mut subgraph,
+            include_drafts,
         )
         .await?;
 
@@ -397,7 +639,7 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
             .read_traversed_vertices(self, &mut subgraph, include_drafts)
             .await?;
 
-        Ok(subgraph)
+        Ok((subgraph, cursor))
     }
 
     #[tracing::instrument(level = "info", skip(self, authorization_api, params))]
@@ -440,6 +682,11 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
 
         let transaction = self.transaction().await.change_context(UpdateError)?;
 
+        transaction
+            .check_data_type_refinements([&params.schema])
+            .await
+            .change_context(UpdateError)?;
+
         let provenance = OntologyProvenanceMetadata {
             edition: OntologyEditionProvenanceMetadata {
                 created_by_id: EditionCreatedById::new(actor_id),
@@ -451,6 +698,39 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
         let (ontology_id, owned_by_id, temporal_versioning) = transaction
             .update::<DataType>(&params.schema, &provenance.edition)
             .await?;
+
+        if params.reject_breaking_changes {
+            let changes = transaction
+                .read_ontology_schema::<DataType>(OntologyId::from(old_ontology_id))
+                .await
+                .change_context(UpdateError)?
+                .changes_to(&params.schema)
+                .into_iter()
+                .filter(OntologyTypeChange::is_breaking)
+                .collect::<Vec<_>>();
+            if !changes.is_empty() {
+                return Err(Report::new(BreakingOntologyTypeChanges { changes })
+                    .change_context(UpdateError));
+            }
+        }
+
+        transaction
+            .insert_data_type_references(&params.schema, ontology_id)
+            .await
+            .change_context(UpdateError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "could not insert references for data type: {}",
+                    params.schema.id()
+                )
+            })
+            .attach_lazy(|| params.schema.clone())?;
+        if let Some(conversion) = &params.conversion {
+            transaction
+                .insert_data_type_conversion(params.schema.id(), ontology_id, conversion)
+                .await
+                .change_context(UpdateError)?;
+        }
         let data_type_id = DataTypeId::from(ontology_id);
 
         let relationships = params
@@ -499,6 +779,7 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
                 classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
                 temporal_versioning,
                 provenance,
+                conversion: params.conversion,
             };
 
             if let Some(temporal_client) = temporal_client {
@@ -627,6 +908,9 @@ pub struct DataTypeRowIndices {
 
     pub edition_provenance: usize,
     pub additional_metadata: usize,
+
+    pub canonical_unit: usize,
+    pub conversion_factor: usize,
 }
 
 impl QueryRecordDecode for DataTypeWithMetadata {
@@ -659,6 +943,12 @@ impl QueryRecordDecode for DataTypeWithMetadata {
                 provenance: OntologyProvenanceMetadata {
                     edition: row.get(indices.edition_provenance),
                 },
+                conversion: row.get::<_, Option<BaseUrl>>(indices.canonical_unit).map(
+                    |canonical_unit| DataTypeConversion {
+                        canonical_unit,
+                        factor: row.get(indices.conversion_factor),
+                    },
+                ),
             },
         }
     }
@@ -698,6 +988,8 @@ impl PostgresRecord for DataTypeWithMetadata {
                 .add_selection_path(&DataTypeQueryPath::EditionProvenance(None)),
             additional_metadata: compiler
                 .add_selection_path(&DataTypeQueryPath::AdditionalMetadata),
+            canonical_unit: compiler.add_selection_path(&DataTypeQueryPath::CanonicalUnit),
+            conversion_factor: compiler.add_selection_path(&DataTypeQueryPath::ConversionFactor),
         }
     }
 }
@@ -1,12 +1,13 @@
 use std::{
     collections::{HashMap, HashSet},
     iter::once,
+    mem,
 };
 
 use authorization::{
     backend::ModifyRelationshipOperation,
     schema::{
-        DataTypeId, EntityTypeId, EntityTypeOwnerSubject, EntityTypePermission,
+        DataTypeId, EntityPermission, EntityTypeId, EntityTypeOwnerSubject, EntityTypePermission,
         EntityTypeRelationAndSubject, WebPermission,
     },
     zanzibar::{Consistency, Zookie},
@@ -25,19 +26,18 @@ use graph_types::{
 };
 use postgres_types::{Json, ToSql};
 use temporal_client::TemporalClient;
-use temporal_versioning::{RightBoundedTemporalInterval, Timestamp, TransactionTime};
+use temporal_versioning::{Timestamp, TransactionTime};
 use tokio_postgres::{GenericClient, Row};
 use type_system::{
     url::{BaseUrl, OntologyTypeVersion, VersionedUrl},
-    ClosedEntityType, EntityType,
+    ClosedEntityType, EntityType, OntologyTypeChange,
 };
 use uuid::Uuid;
 
 use crate::{
     ontology::EntityTypeQueryPath,
     store::{
-        crud::{QueryResult, ReadPaginated, VertexIdSorting},
-        error::DeletionError,
+        error::{BreakingOntologyTypeChanges, DeletionError},
         ontology::{
             ArchiveEntityTypeParams, CreateEntityTypeParams, GetEntityTypesParams,
             UnarchiveEntityTypeParams, UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams,
@@ -45,22 +45,21 @@ use crate::{
         postgres::{
             crud::QueryRecordDecode,
             ontology::{
-                read::OntologyTypeTraversalData, OntologyId,
+                read::OntologyTypeTraversalData, OntologyId, PermittedOntologyTypes,
                 PostgresOntologyTypeClassificationMetadata,
             },
             query::{Distinctness, PostgresRecord, ReferenceTable, SelectCompiler, Table},
-            TraversalContext,
+            TraversalContext, TraversalQueue,
         },
         query::{Filter, FilterExpression, ParameterList},
-        AsClient, EntityTypeStore, InsertionError, PostgresStore, QueryError, SubgraphRecord,
-        UpdateError,
+        AsClient, EntityTypeStore, InsertionError, PostgresStore, QueryError, UpdateError,
     },
     subgraph::{
-        edges::{EdgeDirection, GraphResolveDepths, OntologyEdgeKind},
-        identifier::{EntityTypeVertexId, PropertyTypeVertexId},
+        edges::{EdgeDirection, OntologyEdgeKind, SharedEdgeKind},
+        identifier::{EntityIdWithInterval, EntityTypeVertexId, PropertyTypeVertexId},
         query::StructuralQuery,
         temporal_axes::{
-            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableAxis,
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
             VariableTemporalAxisUnresolved,
         },
         Subgraph,
@@ -113,26 +112,49 @@ impl<C: AsClient> PostgresStore<C> {
     /// This is used to recursively resolve a type, so the result can be reused.
     #[tracing::instrument(
         level = "info",
-        skip(self, traversal_context, subgraph, authorization_api, zookie)
+        skip(self, queue, traversal_context, subgraph, authorization_api, zookie)
+    )]
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "https://linear.app/hash/issue/H-1466/revisit-parameters-to-store-functions"
     )]
     pub(crate) async fn traverse_entity_types<A: AuthorizationApi + Sync>(
         &self,
-        mut entity_type_queue: Vec<(
-            OntologyId,
-            GraphResolveDepths,
-            RightBoundedTemporalInterval<VariableAxis>,
-        )>,
+        queue: &mut TraversalQueue,
         traversal_context: &mut TraversalContext,
         actor_id: AccountId,
         authorization_api: &A,
         zookie: &Zookie<'static>,
         subgraph: &mut Subgraph,
+        include_drafts: bool,
     ) -> Result<(), QueryError> {
-        let mut property_type_queue = Vec::new();
+        let mut entity_type_queue = mem::take(&mut queue.entity_types);
+
+        let entity_type_edges = [
+            (
+                OntologyEdgeKind::InheritsFrom,
+                ReferenceTable::EntityTypeInheritsFrom {
+                    inheritance_depth: None,
+                },
+            ),
+            (
+                OntologyEdgeKind::ConstrainsLinksOn,
+                ReferenceTable::EntityTypeConstrainsLinksOn {
+                    inheritance_depth: None,
+                },
+            ),
+            (
+                OntologyEdgeKind::ConstrainsLinkDestinationsOn,
+                ReferenceTable::EntityTypeConstrainsLinkDestinationsOn {
+                    inheritance_depth: None,
+                },
+            ),
+        ];
 
         while !entity_type_queue.is_empty() {
             let mut edges_to_traverse =
-                HashMap::<OntologyEdgeKind, OntologyTypeTraversalData>::new();
+                HashMap::<(OntologyEdgeKind, EdgeDirection), OntologyTypeTraversalData>::new();
+            let mut instances_to_traverse = Option::<OntologyTypeTraversalData>::None;
 
             #[expect(clippy::iter_with_drain, reason = "false positive, vector is reused")]
             for (entity_type_ontology_id, graph_resolve_depths, traversal_interval) in
@@ -144,30 +166,56 @@ impl<C: AsClient> PostgresStore<C> {
                     OntologyEdgeKind::ConstrainsLinksOn,
                     OntologyEdgeKind::ConstrainsLinkDestinationsOn,
                 ] {
-                    if let Some(new_graph_resolve_depths) = graph_resolve_depths
-                        .decrement_depth_for_edge(edge_kind, EdgeDirection::Outgoing)
-                    {
-                        edges_to_traverse.entry(edge_kind).or_default().push(
+                    for edge_direction in [EdgeDirection::Outgoing, EdgeDirection::Incoming] {
+                        if edge_kind == OntologyEdgeKind::ConstrainsPropertiesOn
+                            && edge_direction == EdgeDirection::Incoming
+                        {
+                            // Entity types cannot be referenced as property types
+                            continue;
+                        }
+
+                        if let Some(new_graph_resolve_depths) =
+                            graph_resolve_depths.decrement_depth_for_edge(edge_kind, edge_direction)
+                        {
+                            edges_to_traverse
+                                .entry((edge_kind, edge_direction))
+                                .or_default()
+                                .push(
+                                    entity_type_ontology_id,
+                                    new_graph_resolve_depths,
+                                    traversal_interval,
+                                );
+                        }
+                    }
+                }
+
+                if let Some(new_graph_resolve_depths) = graph_resolve_depths
+                    .decrement_depth_for_edge(SharedEdgeKind::IsOfType, EdgeDirection::Incoming)
+                {
+                    instances_to_traverse
+                        .get_or_insert_with(Default::default)
+                        .push(
                             entity_type_ontology_id,
                             new_graph_resolve_depths,
                             traversal_interval,
                         );
-                    }
                 }
             }
 
-            if let Some(traversal_data) =
-                edges_to_traverse.get(&OntologyEdgeKind::ConstrainsPropertiesOn)
-            {
+            if let Some(traversal_data) = edges_to_traverse.get(&(
+                OntologyEdgeKind::ConstrainsPropertiesOn,
+                EdgeDirection::Outgoing,
+            )) {
                 // TODO: Filter for entity types, which were not already added to the
                 //       subgraph to avoid unnecessary lookups.
-                property_type_queue.extend(
+                queue.property_types.extend(
                     Self::filter_property_types_by_permission(
                         self.read_ontology_edges::<EntityTypeVertexId, PropertyTypeVertexId>(
                             traversal_data,
                             ReferenceTable::EntityTypeConstrainsPropertiesOn {
                                 inheritance_depth: None,
                             },
+                            EdgeDirection::Outgoing,
                         )
                         .await?,
                         actor_id,
@@ -192,68 +240,105 @@ impl<C: AsClient> PostgresStore<C> {
                 );
             }
 
-            for (edge_kind, table) in [
-                (
-                    OntologyEdgeKind::InheritsFrom,
-                    ReferenceTable::EntityTypeInheritsFrom {
-                        inheritance_depth: None,
-                    },
-                ),
-                (
-                    OntologyEdgeKind::ConstrainsLinksOn,
-                    ReferenceTable::EntityTypeConstrainsLinksOn {
-                        inheritance_depth: None,
-                    },
-                ),
-                (
-                    OntologyEdgeKind::ConstrainsLinkDestinationsOn,
-                    ReferenceTable::EntityTypeConstrainsLinkDestinationsOn {
-                        inheritance_depth: None,
-                    },
-                ),
-            ] {
-                if let Some(traversal_data) = edges_to_traverse.get(&edge_kind) {
-                    entity_type_queue.extend(
-                        Self::filter_entity_types_by_permission(
-                            self.read_ontology_edges::<EntityTypeVertexId, EntityTypeVertexId>(
-                                traversal_data,
-                                table,
+            for (edge_kind, table) in entity_type_edges {
+                for edge_direction in [EdgeDirection::Outgoing, EdgeDirection::Incoming] {
+                    if let Some(traversal_data) =
+                        edges_to_traverse.get(&(edge_kind, edge_direction))
+                    {
+                        entity_type_queue.extend(
+                            Self::filter_entity_types_by_permission(
+                                self.read_ontology_edges::<EntityTypeVertexId, EntityTypeVertexId>(
+                                    traversal_data,
+                                    table,
+                                    edge_direction,
+                                )
+                                .await?,
+                                actor_id,
+                                authorization_api,
+                                zookie,
                             )
-                            .await?,
-                            actor_id,
-                            authorization_api,
-                            zookie,
-                        )
-                        .await?
+                            .await?
+                            .flat_map(|edge| {
+                                subgraph.insert_edge(
+                                    &edge.left_endpoint,
+                                    edge_kind,
+                                    edge_direction,
+                                    edge.right_endpoint.clone(),
+                                );
+
+                                traversal_context.add_entity_type_id(
+                                    edge.right_endpoint_ontology_id,
+                                    edge.resolve_depths,
+                                    edge.traversal_interval,
+                                )
+                            }),
+                        );
+                    }
+                }
+            }
+
+            if let Some(traversal_data) = instances_to_traverse {
+                let (entity_ids, instance_edges): (Vec<_>, Vec<_>) = self
+                    .read_entity_type_instances(
+                        &traversal_data,
+                        subgraph.temporal_axes.resolved.pinned_timestamp(),
+                        subgraph.temporal_axes.resolved.variable_time_axis(),
+                        include_drafts,
+                    )
+                    .await?
+                    .unzip();
+
+                if instance_edges.is_empty() {
+                    continue;
+                }
+
+                let permissions = authorization_api
+                    .check_entities_permission(
+                        actor_id,
+                        EntityPermission::View,
+                        entity_ids.iter().copied(),
+                        Consistency::AtExactSnapshot(zookie),
+                    )
+                    .await
+                    .change_context(QueryError)?
+                    .0;
+
+                queue.entities.extend(
+                    instance_edges
+                        .into_iter()
+                        .zip(entity_ids)
+                        .filter_map(|(edge, entity_id)| {
+                            permissions
+                                .get(&entity_id.entity_uuid)
+                                .copied()
+                                .unwrap_or(false)
+                                .then_some(edge)
+                        })
                         .flat_map(|edge| {
                             subgraph.insert_edge(
                                 &edge.left_endpoint,
-                                edge_kind,
-                                EdgeDirection::Outgoing,
-                                edge.right_endpoint.clone(),
+                                SharedEdgeKind::IsOfType,
+                                EdgeDirection::Incoming,
+                                EntityIdWithInterval {
+                                    entity_id: edge.right_endpoint.base_id,
+                                    interval: edge.edge_interval,
+                                },
                             );
 
-                            traversal_context.add_entity_type_id(
-                                edge.right_endpoint_ontology_id,
-                                edge.resolve_depths,
-                                edge.traversal_interval,
-                            )
+                            traversal_context
+                                .add_entity_id(
+                                    edge.right_endpoint_edition_id,
+                                    edge.resolve_depths,
+                                    edge.traversal_interval,
+                                )
+                                .map(move |(_, resolve_depths, interval)| {
+                                    (edge.right_endpoint, resolve_depths, interval)
+                                })
                         }),
-                    );
-                }
+                );
             }
         }
 
-        self.traverse_property_types(
-            property_type_queue,
-            traversal_context,
-            actor_id,
-            authorization_api,
-            zookie,
-            subgraph,
-        )
-        .await?;
-
         Ok(())
     }
 
@@ -615,7 +700,7 @@ impl<C: AsClient> EntityTypeStore for PostgresStore<C> {
         actor_id: AccountId,
         authorization_api: &A,
         params: GetEntityTypesParams<'_>,
-    ) -> Result<Subgraph, QueryError> {
+    ) -> Result<(Subgraph, Option<EntityTypeVertexId>), QueryError> {
         let StructuralQuery {
             ref filter,
             graph_resolve_depths,
@@ -624,50 +709,30 @@ impl<C: AsClient> EntityTypeStore for PostgresStore<C> {
         } = params.query;
 
         let temporal_axes = unresolved_temporal_axes.clone().resolve();
-        let time_axis = temporal_axes.variable_time_axis();
-
-        // TODO: Remove again when subgraph logic was revisited
-        //   see https://linear.app/hash/issue/H-297
-        let mut visited_ontology_ids = HashSet::new();
-
-        let (data, artifacts) = ReadPaginated::<EntityTypeWithMetadata>::read_paginated_vec(
-            self,
-            filter,
-            Some(&temporal_axes),
-            &VertexIdSorting {
-                cursor: params.after,
-            },
-            params.limit,
-            include_drafts,
-        )
-        .await?;
-        let entity_types = data
-            .into_iter()
-            .filter_map(|row| {
-                let entity_type = row.decode_record(&artifacts);
-                let id = EntityTypeId::from_url(entity_type.schema.id());
-                let vertex_id = entity_type.vertex_id(time_axis);
-                // The records are already sorted by time, so we can just take the first one
-                visited_ontology_ids
-                    .insert(id)
-                    .then_some((id, (vertex_id, entity_type)))
-            })
-            .collect::<Vec<_>>();
-
-        let filtered_ids = entity_types
-            .iter()
-            .map(|(entity_type_id, _)| *entity_type_id)
-            .collect::<Vec<_>>();
 
-        let (permissions, zookie) = authorization_api
-            .check_entity_types_permission(
-                actor_id,
-                EntityTypePermission::View,
-                filtered_ids,
-                Consistency::FullyConsistent,
+        let PermittedOntologyTypes {
+            ids: entity_type_ids,
+            vertices: entity_type_vertices,
+            zookie,
+            cursor,
+        } = self
+            .read_permitted_ontology_types(
+                filter,
+                &temporal_axes,
+                params.after,
+                params.limit,
+                include_drafts,
+                |entity_type| EntityTypeId::from_url(entity_type.schema.id()),
+                |entity_type_ids| {
+                    authorization_api.check_entity_types_permission(
+                        actor_id,
+                        EntityTypePermission::View,
+                        entity_type_ids,
+                        Consistency::FullyConsistent,
+                    )
+                },
             )
-            .await
-            .change_context(QueryError)?;
+            .await?;
 
         let mut subgraph = Subgraph::new(
             graph_resolve_depths,
@@ -675,11 +740,6 @@ impl<C: AsClient> EntityTypeStore for PostgresStore<C> {
             temporal_axes.clone(),
         );
 
-        let (entity_type_ids, entity_type_vertices): (Vec<_>, Vec<_>) = entity_types
-            .into_iter()
-            .filter(|(id, _)| permissions.get(id).copied().unwrap_or(false))
-            .unzip();
-
         subgraph.roots.extend(
             entity_type_vertices
                 .iter()
@@ -691,22 +751,26 @@ impl<C: AsClient> EntityTypeStore for PostgresStore<C> {
 
         // TODO: We currently pass in the subgraph as mutable reference, thus we cannot borrow the
         //       vertices and have to `.collect()` the keys.
-        self.traverse_entity_types(
-            entity_type_ids
-                .into_iter()
-                .map(|id| {
-                    (
-                        OntologyId::from(id),
-                        subgraph.depths,
-                        subgraph.temporal_axes.resolved.variable_interval(),
-                    )
-                })
-                .collect(),
+        self.traverse(
+            TraversalQueue {
+                entity_types: entity_type_ids
+                    .into_iter()
+                    .map(|id| {
+                        (
+                            OntologyId::from(id),
+                            subgraph.depths,
+                            subgraph.temporal_axes.resolved.variable_interval(),
+                        )
+                    })
+                    .collect(),
+                ..TraversalQueue::default()
+            },
             &mut traversal_context,
             actor_id,
             authorization_api,
             &zookie,
             &mut subgraph,
+            include_drafts,
         )
         .await?;
 
@@ -714,7 +778,7 @@ impl<C: AsClient> EntityTypeStore for PostgresStore<C> {
             .read_traversed_vertices(self, &mut subgraph, include_drafts)
             .await?;
 
-        Ok(subgraph)
+        Ok((subgraph, cursor))
     }
 
     #[tracing::instrument(level = "info", skip(self, authorization_api, params))]
@@ -772,6 +836,21 @@ impl<C: AsClient> EntityTypeStore for PostgresStore<C> {
             .update_owned_ontology_id(url, &provenance.edition)
             .await?;
 
+        if params.reject_breaking_changes {
+            let changes = transaction
+                .read_ontology_schema::<EntityType>(OntologyId::from(old_ontology_id))
+                .await
+                .change_context(UpdateError)?
+                .changes_to(&params.schema)
+                .into_iter()
+                .filter(OntologyTypeChange::is_breaking)
+                .collect::<Vec<_>>();
+            if !changes.is_empty() {
+                return Err(Report::new(BreakingOntologyTypeChanges { changes })
+                    .change_context(UpdateError));
+            }
+        }
+
         let mut insertions = transaction
             .resolve_entity_types([params.schema])
             .await
@@ -4,8 +4,15 @@ mod ontology_id;
 mod property_type;
 mod read;
 
-use std::{borrow::Cow, convert::identity};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    convert::identity,
+    future::Future,
+    hash::Hash,
+};
 
+use authorization::{backend::CheckError, zanzibar::Zookie};
 use error_stack::{Report, ResultExt};
 use graph_types::{
     ontology::{
@@ -23,14 +30,14 @@ pub use self::ontology_id::OntologyId;
 use crate::{
     ontology::{DataTypeQueryPath, EntityTypeQueryPath, PropertyTypeQueryPath},
     store::{
-        crud::{Sorting, VertexIdSorting},
+        crud::{QueryResult, ReadPaginated, Sorting, VertexIdSorting},
         error::DeletionError,
         postgres::{
             crud::QueryRecordDecode,
             query::{Distinctness, PostgresSorting, SelectCompiler},
         },
-        query::Parameter,
-        AsClient, Ordering, PostgresStore, SubgraphRecord,
+        query::{Filter, Parameter},
+        AsClient, Ordering, PostgresStore, QueryError, SubgraphRecord,
     },
     subgraph::temporal_axes::QueryTemporalAxes,
 };
@@ -131,6 +138,119 @@ impl PostgresStore<Transaction<'_>> {
     }
 }
 
+/// The ontology types returned by [`PostgresStore::read_permitted_ontology_types`].
+pub(crate) struct PermittedOntologyTypes<I, R: SubgraphRecord> {
+    pub ids: Vec<I>,
+    pub vertices: Vec<(R::VertexId, R)>,
+    pub zookie: Zookie<'static>,
+    /// The cursor to continue reading from, if more types may be available.
+    pub cursor: Option<R::VertexId>,
+}
+
+impl<C: AsClient> PostgresStore<C> {
+    /// Reads the ontology types matching `filter` which the actor is permitted to view.
+    ///
+    /// Types are read page by page until `limit` permitted types are found or no more types are
+    /// available. Only the first record of each type is returned. `ontology_id` returns the id used
+    /// to check the permissions of a record and `check_permissions` checks the view permission of
+    /// the ids of a page.
+    #[tracing::instrument(level = "trace", skip_all)]
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "https://linear.app/hash/issue/H-1466/revisit-parameters-to-store-functions"
+    )]
+    pub(crate) async fn read_permitted_ontology_types<R, I, F>(
+        &self,
+        filter: &Filter<'_, R>,
+        temporal_axes: &QueryTemporalAxes,
+        after: Option<R::VertexId>,
+        limit: Option<usize>,
+        include_drafts: bool,
+        ontology_id: impl Fn(&R) -> I + Send + Sync,
+        check_permissions: impl Fn(Vec<I>) -> F + Send + Sync,
+    ) -> Result<PermittedOntologyTypes<I, R>, Report<QueryError>>
+    where
+        Self: ReadPaginated<R>,
+        R: SubgraphRecord<VertexId: Clone>,
+        I: Copy + Eq + Hash,
+        F: Future<Output = Result<(HashMap<I, bool>, Zookie<'static>), Report<CheckError>>>,
+    {
+        let time_axis = temporal_axes.variable_time_axis();
+
+        // TODO: Remove again when subgraph logic was revisited
+        //   see https://linear.app/hash/issue/H-297
+        let mut visited_ontology_ids = HashSet::new();
+
+        let mut sorting = VertexIdSorting { cursor: after };
+        let mut records = Vec::new();
+
+        let (zookie, cursor) = loop {
+            let (rows, artifacts) = ReadPaginated::<R>::read_paginated_vec(
+                self,
+                filter,
+                Some(temporal_axes),
+                &sorting,
+                limit,
+                include_drafts,
+            )
+            .await?;
+            let num_returned_records = rows.len();
+            if let Some(cursor) = rows.last().map(|row| row.decode_cursor(&artifacts)) {
+                sorting.set_cursor(cursor);
+            }
+
+            let page = rows
+                .into_iter()
+                .filter_map(|row| {
+                    let record = row.decode_record(&artifacts);
+                    let id = ontology_id(&record);
+                    let vertex_id = record.vertex_id(time_axis);
+                    // The records are already sorted by time, so we can just take the first one
+                    visited_ontology_ids
+                        .insert(id)
+                        .then_some((id, (vertex_id, record)))
+                })
+                .collect::<Vec<_>>();
+
+            let (permissions, zookie) = check_permissions(page.iter().map(|(id, _)| *id).collect())
+                .await
+                .change_context(QueryError)?;
+
+            records.extend(
+                page.into_iter()
+                    .filter(|(id, _)| permissions.get(id).copied().unwrap_or(false))
+                    .take(limit.unwrap_or(usize::MAX) - records.len()),
+            );
+
+            if let Some(limit) = limit {
+                if num_returned_records < limit {
+                    // When fewer types than requested are returned, there are no more types to
+                    // read.
+                    break (zookie, None);
+                }
+                if records.len() == limit {
+                    // The requested limit is reached, so the last root is the next cursor.
+                    break (
+                        zookie,
+                        records.last().map(|(_, (vertex_id, _))| vertex_id.clone()),
+                    );
+                }
+            } else {
+                // Without a limit all types are returned.
+                break (zookie, None);
+            }
+        };
+
+        let (ids, vertices) = records.into_iter().unzip();
+        Ok(PermittedOntologyTypes {
+            ids,
+            vertices,
+            zookie,
+            cursor,
+        })
+    }
+}
+
 pub struct VersionedUrlCursorParameters<'p> {
     base_url: Parameter<'p>,
     version: Parameter<'p>,
@@ -1,6 +1,7 @@
 use std::{
     collections::{HashMap, HashSet},
     iter::once,
+    mem,
 };
 
 use authorization::{
@@ -12,7 +13,7 @@ use authorization::{
     zanzibar::{Consistency, Zookie},
     AuthorizationApi,
 };
-use error_stack::{Result, ResultExt};
+use error_stack::{Report, Result, ResultExt};
 use graph_types::{
     account::{AccountId, EditionArchivedById, EditionCreatedById},
     ontology::{
@@ -24,18 +25,17 @@ use graph_types::{
 };
 use postgres_types::{Json, ToSql};
 use temporal_client::TemporalClient;
-use temporal_versioning::{RightBoundedTemporalInterval, Timestamp, TransactionTime};
+use temporal_versioning::{Timestamp, TransactionTime};
 use tokio_postgres::{GenericClient, Row};
 use type_system::{
     url::{OntologyTypeVersion, VersionedUrl},
-    PropertyType,
+    OntologyTypeChange, PropertyType,
 };
 
 use crate::{
     ontology::PropertyTypeQueryPath,
     store::{
-        crud::{QueryResult, ReadPaginated, VertexIdSorting},
-        error::DeletionError,
+        error::{BreakingOntologyTypeChanges, DeletionError},
         ontology::{
             ArchivePropertyTypeParams, CreatePropertyTypeParams, GetPropertyTypesParams,
             UnarchivePropertyTypeParams, UpdatePropertyTypeEmbeddingParams,
@@ -44,20 +44,18 @@ use crate::{
         postgres::{
             crud::QueryRecordDecode,
             ontology::{
-                read::OntologyTypeTraversalData, OntologyId,
+                read::OntologyTypeTraversalData, OntologyId, PermittedOntologyTypes,
                 PostgresOntologyTypeClassificationMetadata,
             },
             query::{Distinctness, PostgresRecord, ReferenceTable, SelectCompiler, Table},
-            TraversalContext,
+            TraversalContext, TraversalQueue,
         },
-        AsClient, InsertionError, PostgresStore, PropertyTypeStore, QueryError, SubgraphRecord,
-        UpdateError,
+        AsClient, InsertionError, PostgresStore, PropertyTypeStore, QueryError, UpdateError,
     },
     subgraph::{
-        edges::{EdgeDirection, GraphResolveDepths, OntologyEdgeKind},
-        identifier::{DataTypeVertexId, PropertyTypeVertexId},
+        edges::{EdgeDirection, OntologyEdgeKind},
+        identifier::{DataTypeVertexId, EntityTypeVertexId, PropertyTypeVertexId},
         query::StructuralQuery,
-        temporal_axes::VariableAxis,
         Subgraph,
     },
 };
@@ -108,23 +106,20 @@ impl<C: AsClient> PostgresStore<C> {
     /// This is used to recursively resolve a type, so the result can be reused.
     #[tracing::instrument(
         level = "info",
-        skip(self, traversal_context, subgraph, authorization_api, zookie)
+        skip(self, queue, traversal_context, subgraph, authorization_api, zookie)
     )]
     pub(crate) async fn traverse_property_types<A: AuthorizationApi + Sync>(
         &self,
-        mut property_type_queue: Vec<(
-            OntologyId,
-            GraphResolveDepths,
-            RightBoundedTemporalInterval<VariableAxis>,
-        )>,
+        queue: &mut TraversalQueue,
         traversal_context: &mut TraversalContext,
         actor_id: AccountId,
         authorization_api: &A,
         zookie: &Zookie<'static>,
         subgraph: &mut Subgraph,
     ) -> Result<(), QueryError> {
-        let mut data_type_queue = Vec::new();
-        let mut edges_to_traverse = HashMap::<OntologyEdgeKind, OntologyTypeTraversalData>::new();
+        let mut property_type_queue = mem::take(&mut queue.property_types);
+        let mut edges_to_traverse =
+            HashMap::<(OntologyEdgeKind, EdgeDirection), OntologyTypeTraversalData>::new();
 
         while !property_type_queue.is_empty() {
             edges_to_traverse.clear();
@@ -133,30 +128,45 @@ impl<C: AsClient> PostgresStore<C> {
             for (property_type_ontology_id, graph_resolve_depths, traversal_interval) in
                 property_type_queue.drain(..)
             {
-                for edge_kind in [
-                    OntologyEdgeKind::ConstrainsValuesOn,
-                    OntologyEdgeKind::ConstrainsPropertiesOn,
+                for (edge_kind, edge_direction) in [
+                    (
+                        OntologyEdgeKind::ConstrainsValuesOn,
+                        EdgeDirection::Outgoing,
+                    ),
+                    (
+                        OntologyEdgeKind::ConstrainsPropertiesOn,
+                        EdgeDirection::Outgoing,
+                    ),
+                    (
+                        OntologyEdgeKind::ConstrainsPropertiesOn,
+                        EdgeDirection::Incoming,
+                    ),
                 ] {
-                    if let Some(new_graph_resolve_depths) = graph_resolve_depths
-                        .decrement_depth_for_edge(edge_kind, EdgeDirection::Outgoing)
+                    if let Some(new_graph_resolve_depths) =
+                        graph_resolve_depths.decrement_depth_for_edge(edge_kind, edge_direction)
                     {
-                        edges_to_traverse.entry(edge_kind).or_default().push(
-                            property_type_ontology_id,
-                            new_graph_resolve_depths,
-                            traversal_interval,
-                        );
+                        edges_to_traverse
+                            .entry((edge_kind, edge_direction))
+                            .or_default()
+                            .push(
+                                property_type_ontology_id,
+                                new_graph_resolve_depths,
+                                traversal_interval,
+                            );
                     }
                 }
             }
 
-            if let Some(traversal_data) =
-                edges_to_traverse.get(&OntologyEdgeKind::ConstrainsValuesOn)
-            {
-                data_type_queue.extend(
+            if let Some(traversal_data) = edges_to_traverse.get(&(
+                OntologyEdgeKind::ConstrainsValuesOn,
+                EdgeDirection::Outgoing,
+            )) {
+                queue.data_types.extend(
                     Self::filter_data_types_by_permission(
                         self.read_ontology_edges::<PropertyTypeVertexId, DataTypeVertexId>(
                             traversal_data,
                             ReferenceTable::PropertyTypeConstrainsValuesOn,
+                            EdgeDirection::Outgoing,
                         )
                         .await?,
                         actor_id,
@@ -181,14 +191,53 @@ impl<C: AsClient> PostgresStore<C> {
                 );
             }
 
-            if let Some(traversal_data) =
-                edges_to_traverse.get(&OntologyEdgeKind::ConstrainsPropertiesOn)
-            {
-                property_type_queue.extend(
-                    Self::filter_property_types_by_permission(
-                        self.read_ontology_edges::<PropertyTypeVertexId, PropertyTypeVertexId>(
+            for edge_direction in [EdgeDirection::Outgoing, EdgeDirection::Incoming] {
+                if let Some(traversal_data) = edges_to_traverse
+                    .get(&(OntologyEdgeKind::ConstrainsPropertiesOn, edge_direction))
+                {
+                    property_type_queue.extend(
+                        Self::filter_property_types_by_permission(
+                            self.read_ontology_edges::<PropertyTypeVertexId, PropertyTypeVertexId>(
+                                traversal_data,
+                                ReferenceTable::PropertyTypeConstrainsPropertiesOn,
+                                edge_direction,
+                            )
+                            .await?,
+                            actor_id,
+                            authorization_api,
+                            zookie,
+                        )
+                        .await?
+                        .flat_map(|edge| {
+                            subgraph.insert_edge(
+                                &edge.left_endpoint,
+                                OntologyEdgeKind::ConstrainsPropertiesOn,
+                                edge_direction,
+                                edge.right_endpoint.clone(),
+                            );
+
+                            traversal_context.add_property_type_id(
+                                edge.right_endpoint_ontology_id,
+                                edge.resolve_depths,
+                                edge.traversal_interval,
+                            )
+                        }),
+                    );
+                }
+            }
+
+            if let Some(traversal_data) = edges_to_traverse.get(&(
+                OntologyEdgeKind::ConstrainsPropertiesOn,
+                EdgeDirection::Incoming,
+            )) {
+                queue.entity_types.extend(
+                    Self::filter_entity_types_by_permission(
+                        self.read_ontology_edges::<PropertyTypeVertexId, EntityTypeVertexId>(
                             traversal_data,
-                            ReferenceTable::PropertyTypeConstrainsPropertiesOn,
+                            ReferenceTable::EntityTypeConstrainsPropertiesOn {
+                                inheritance_depth: None,
+                            },
+                            EdgeDirection::Incoming,
                         )
                         .await?,
                         actor_id,
@@ -200,30 +249,20 @@ impl<C: AsClient> PostgresStore<C> {
                         subgraph.insert_edge(
                             &edge.left_endpoint,
                             OntologyEdgeKind::ConstrainsPropertiesOn,
-                            EdgeDirection::Outgoing,
+                            EdgeDirection::Incoming,
                             edge.right_endpoint.clone(),
                         );
 
-                        traversal_context.add_property_type_id(
+                        traversal_context.add_entity_type_id(
                             edge.right_endpoint_ontology_id,
                             edge.resolve_depths,
                             edge.traversal_interval,
                         )
                     }),
                 );
-            };
+            }
         }
 
-        self.traverse_data_types(
-            data_type_queue,
-            traversal_context,
-            actor_id,
-            authorization_api,
-            zookie,
-            subgraph,
-        )
-        .await?;
-
         Ok(())
     }
 
@@ -428,7 +467,7 @@ impl<C: AsClient> PropertyTypeStore for PostgresStore<C> {
         actor_id: AccountId,
         authorization_api: &A,
         params: GetPropertyTypesParams<'_>,
-    ) -> Result<Subgraph, QueryError> {
+    ) -> Result<(Subgraph, Option<PropertyTypeVertexId>), QueryError> {
         let StructuralQuery {
             ref filter,
             graph_resolve_depths,
@@ -437,50 +476,30 @@ impl<C: AsClient> PropertyTypeStore for PostgresStore<C> {
         } = params.query;
 
         let temporal_axes = unresolved_temporal_axes.clone().resolve();
-        let time_axis = temporal_axes.variable_time_axis();
-
-        // TODO: Remove again when subgraph logic was revisited
-        //   see https://linear.app/hash/issue/H-297
-        let mut visited_ontology_ids = HashSet::new();
-
-        let (data, artifacts) = ReadPaginated::<PropertyTypeWithMetadata>::read_paginated_vec(
-            self,
-            filter,
-            Some(&temporal_axes),
-            &VertexIdSorting {
-                cursor: params.after,
-            },
-            params.limit,
-            include_drafts,
-        )
-        .await?;
-        let property_types = data
-            .into_iter()
-            .filter_map(|row| {
-                let property_type = row.decode_record(&artifacts);
-                let id = PropertyTypeId::from_url(property_type.schema.id());
-                let vertex_id = property_type.vertex_id(time_axis);
-                // The records are already sorted by time, so we can just take the first one
-                visited_ontology_ids
-                    .insert(id)
-                    .then_some((id, (vertex_id, property_type)))
-            })
-            .collect::<Vec<_>>();
 
-        let filtered_ids = property_types
-            .iter()
-            .map(|(property_type_id, _)| *property_type_id)
-            .collect::<Vec<_>>();
-
-        let (permissions, zookie) = authorization_api
-            .check_property_types_permission(
-                actor_id,
-                PropertyTypePermission::View,
-                filtered_ids,
-                Consistency::FullyConsistent,
+        let PermittedOntologyTypes {
+            ids: property_type_ids,
+            vertices: property_type_vertices,
+            zookie,
+            cursor,
+        } = self
+            .read_permitted_ontology_types(
+                filter,
+                &temporal_axes,
+                params.after,
+                params.limit,
+                include_drafts,
+                |property_type| PropertyTypeId::from_url(property_type.schema.id()),
+                |property_type_ids| {
+                    authorization_api.check_property_types_permission(
+                        actor_id,
+                        PropertyTypePermission::View,
+                        property_type_ids,
+                        Consistency::FullyConsistent,
+                    )
+                },
             )
-            .await
-            .change_context(QueryError)?;
+            .await?;
 
         let mut subgraph = Subgraph::new(
             graph_resolve_depths,
@@ -488,11 +507,6 @@ impl<C: AsClient> PropertyTypeStore for PostgresStore<C> {
             temporal_axes.clone(),
         );
 
-        let (property_type_ids, property_type_vertices): (Vec<_>, Vec<_>) = property_types
-            .into_iter()
-            .filter(|(id, _)| permissions.get(id).copied().unwrap_or(false))
-            .unzip();
-
         subgraph.roots.extend(
             property_type_vertices
                 .iter()
@@ -504,22 +518,26 @@ impl<C: AsClient> PropertyTypeStore for PostgresStore<C> {
 
         // TODO: We currently pass in the subgraph as mutable reference, thus we cannot borrow the
         //       vertices and have to `.collect()` the keys.
-        self.traverse_property_types(
-            property_type_ids
-                .into_iter()
-                .map(|id| {
-                    (
-                        OntologyId::from(id),
-                        subgraph.depths,
-                        subgraph.temporal_axes.resolved.variable_interval(),
-                    )
-                })
-                .collect(),
+        self.traverse(
+            TraversalQueue {
+                property_types: property_type_ids
+                    .into_iter()
+                    .map(|id| {
+                        (
+                            OntologyId::from(id),
+                            subgraph.depths,
+                            subgraph.temporal_axes.resolved.variable_interval(),
+                        )
+                    })
+                    .collect(),
+                ..TraversalQueue::default()
+            },
             &mut traversal_context,
             actor_id,
             authorization_api,
             &zookie,
             &mut subgraph,
+            include_drafts,
         )
         .await?;
 
@@ -527,7 +545,7 @@ impl<C: AsClient> PropertyTypeStore for PostgresStore<C> {
             .read_traversed_vertices(self, &mut subgraph, include_drafts)
             .await?;
 
-        Ok(subgraph)
+        Ok((subgraph, cursor))
     }
 
     #[tracing::instrument(level = "info", skip(self, authorization_api, params))]
@@ -583,6 +601,21 @@ impl<C: AsClient> PropertyTypeStore for PostgresStore<C> {
             .update::<PropertyType>(&params.schema, &provenance.edition)
             .await?;
 
+        if params.reject_breaking_changes {
+            let changes = transaction
+                .read_ontology_schema::<PropertyType>(OntologyId::from(old_ontology_id))
+                .await
+                .change_context(UpdateError)?
+                .changes_to(&params.schema)
+                .into_iter()
+                .filter(OntologyTypeChange::is_breaking)
+                .collect::<Vec<_>>();
+            if !changes.is_empty() {
+                return Err(Report::new(BreakingOntologyTypeChanges { changes })
+                    .change_context(UpdateError));
+            }
+        }
+
         transaction
             .insert_property_type_references(&params.schema, ontology_id)
             .await
@@ -1,11 +1,16 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, mem::swap};
 
 use authorization::schema::EntityTypeId;
 use error_stack::{Result, ResultExt};
 use futures::{Stream, StreamExt};
-use graph_types::ontology::EntityTypeWithMetadata;
+use graph_types::{
+    knowledge::entity::{EntityEditionId, EntityId},
+    ontology::EntityTypeWithMetadata,
+};
 use postgres_types::Json;
-use temporal_versioning::RightBoundedTemporalInterval;
+use temporal_versioning::{
+    LeftClosedTemporalInterval, RightBoundedTemporalInterval, TimeAxis, Timestamp,
+};
 use tokio_postgres::GenericClient;
 use type_system::{url::VersionedUrl, ClosedEntityType};
 
@@ -23,8 +28,9 @@ use crate::{
         AsClient, PostgresStore, QueryError,
     },
     subgraph::{
-        edges::GraphResolveDepths,
-        temporal_axes::{QueryTemporalAxes, VariableAxis},
+        edges::{EdgeDirection, GraphResolveDepths},
+        identifier::{EntityTypeVertexId, EntityVertexId},
+        temporal_axes::{PinnedAxis, QueryTemporalAxes, VariableAxis},
     },
 };
 
@@ -56,6 +62,16 @@ pub struct OntologyEdgeTraversal<L, R> {
     pub traversal_interval: RightBoundedTemporalInterval<VariableAxis>,
 }
 
+/// The result of an ontology-to-entity edge traversal.
+pub struct EntityTypeInstanceTraversal {
+    pub left_endpoint: EntityTypeVertexId,
+    pub right_endpoint: EntityVertexId,
+    pub right_endpoint_edition_id: EntityEditionId,
+    pub resolve_depths: GraphResolveDepths,
+    pub edge_interval: LeftClosedTemporalInterval<VariableAxis>,
+    pub traversal_interval: RightBoundedTemporalInterval<VariableAxis>,
+}
+
 impl<C: AsClient> PostgresStore<C> {
     #[tracing::instrument(level = "trace", skip(self, filter))]
     pub(crate) async fn read_closed_schemas<'f>(
@@ -94,25 +110,30 @@ impl<C: AsClient> PostgresStore<C> {
         &self,
         record_ids: &'r OntologyTypeTraversalData,
         reference_table: ReferenceTable,
+        edge_direction: EdgeDirection,
     ) -> Result<impl Iterator<Item = (OntologyId, OntologyEdgeTraversal<L, R>)> + 'r, QueryError>
     where
         L: From<VersionedUrl>,
         R: From<VersionedUrl>,
     {
         let table = Table::Reference(reference_table).transpile_to_string();
-        let source =
+        let mut source =
             if let ForeignKeyReference::Single { join, .. } = reference_table.source_relation() {
                 join.transpile_to_string()
             } else {
                 unreachable!("Ontology reference tables don't have multiple conditions")
             };
-        let target =
+        let mut target =
             if let ForeignKeyReference::Single { on, .. } = reference_table.target_relation() {
                 on.transpile_to_string()
             } else {
                 unreachable!("Ontology reference tables don't have multiple conditions")
             };
 
+        if edge_direction == EdgeDirection::Incoming {
+            swap(&mut source, &mut target);
+        }
+
         let depth = reference_table
             .inheritance_depth_column()
             .and_then(Column::inheritance_depth);
@@ -182,4 +203,102 @@ impl<C: AsClient> PostgresStore<C> {
                 )
             }))
     }
+
+    /// Reads the entities which are of one of the entity types in `record_ids`.
+    ///
+    /// This is the reversed direction of the `IS_OF_TYPE` edge. Only entities which are valid
+    /// at the pinned timestamp and overlap with the traversal interval are returned. Drafts are
+    /// only returned if `include_drafts` is set.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) async fn read_entity_type_instances<'r>(
+        &self,
+        record_ids: &'r OntologyTypeTraversalData,
+        pinned_timestamp: Timestamp<PinnedAxis>,
+        variable_axis: TimeAxis,
+        include_drafts: bool,
+    ) -> Result<impl Iterator<Item = (EntityId, EntityTypeInstanceTraversal)> + 'r, QueryError>
+    {
+        let (pinned_axis, variable_axis) = match variable_axis {
+            TimeAxis::DecisionTime => ("transaction_time", "decision_time"),
+            TimeAxis::TransactionTime => ("decision_time", "transaction_time"),
+        };
+        let draft_condition = if include_drafts {
+            ""
+        } else {
+            "AND target.draft_id IS NULL"
+        };
+
+        Ok(self
+            .client
+            .as_client()
+            .query(
+                &format!(
+                    r#"
+                        SELECT
+                             filter.idx,
+                             source.base_url,
+                             source.version,
+                             target.web_id,
+                             target.entity_uuid,
+                             lower(target.{variable_axis}),
+                             target.entity_edition_id,
+                             target.{variable_axis},
+                             target.{variable_axis} * filter.interval,
+                             target.draft_id
+                        FROM unnest($1::uuid[], $2::tstzrange[])
+                             WITH ORDINALITY
+                             AS filter(ontology_id, interval, idx)
+
+                        JOIN ontology_ids AS source
+                          ON source.ontology_id = filter.ontology_id
+
+                        JOIN entity_is_of_type
+                          ON entity_is_of_type.entity_type_ontology_id = filter.ontology_id
+
+                        JOIN entity_temporal_metadata AS target
+                          ON target.entity_edition_id = entity_is_of_type.entity_edition_id
+                         AND target.{pinned_axis} @> $3::timestamptz
+                         AND target.{variable_axis} && filter.interval
+                         {draft_condition};
+                    "#
+                ),
+                &[
+                    &record_ids.ontology_ids,
+                    &record_ids.traversal_intervals,
+                    &pinned_timestamp,
+                ],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| {
+                let index = usize::try_from(row.get::<_, i64>(0) - 1).unwrap_or_else(|error| {
+                    // The index is always a valid `usize` because it is the index of the
+                    // `record_ids` vectors that was just passed in.
+                    unreachable!("invalid index: {error}")
+                });
+                let right_endpoint_base_id = EntityId {
+                    owned_by_id: row.get(3),
+                    entity_uuid: row.get(4),
+                    draft_id: row.get(9),
+                };
+                (
+                    right_endpoint_base_id,
+                    EntityTypeInstanceTraversal {
+                        left_endpoint: EntityTypeVertexId::from(VersionedUrl {
+                            base_url: row.get(1),
+                            version: row.get(2),
+                        }),
+                        right_endpoint: EntityVertexId {
+                            base_id: right_endpoint_base_id,
+                            revision_id: row.get(5),
+                        },
+                        right_endpoint_edition_id: row.get(6),
+                        resolve_depths: record_ids.resolve_depths[index],
+                        edge_interval: row.get(7),
+                        traversal_interval: row.get(8),
+                    },
+                )
+            }))
+    }
 }
@@ -1,28 +1,38 @@
 use std::{
     collections::{HashMap, HashSet},
     iter::once,
+    mem,
 };
 
+use graph_types::knowledge::entity::Entity;
 use postgres_types::ToSql;
 use temporal_versioning::TimeAxis;
 
 use crate::{
+    knowledge::EntityQueryPath,
     store::{
         postgres::query::{
-            expression::GroupByExpression,
+            expression::{GroupByExpression, JoinOn, JoinType},
             table::{
-                DataTypeEmbeddings, EntityEmbeddings, EntityTemporalMetadata, EntityTypeEmbeddings,
-                OntologyIds, OntologyTemporalMetadata, PropertyTypeEmbeddings,
+                DataTypeEmbeddings, EntityEditions, EntityEmbeddings, EntityTemporalMetadata,
+                EntityTypeEmbeddings, LinkPath, OntologyIds, OntologyTemporalMetadata,
+                PropertyTypeEmbeddings,
             },
-            Alias, AliasedColumn, AliasedTable, Column, Condition, Distinctness, EqualityOperator,
-            Expression, Function, JoinExpression, OrderByExpression, PostgresQueryPath,
-            PostgresRecord, SelectExpression, SelectStatement, Table, Transpile, WhereExpression,
-            WindowStatement, WithExpression,
+            Alias, AliasedColumn, AliasedTable, Column, Condition, Constant, Distinctness,
+            EqualityOperator, Expression, Function, JoinExpression, OrderByExpression,
+            PostgresQueryPath, PostgresRecord, PostgresType, SelectExpression, SelectStatement,
+            Statement, Table, Transpile, WhereExpression, WindowStatement, WithExpression,
+        },
+        query::{
+            Filter, FilterExpression, LinkDirection, LinkPathFilter, LinkPathHop, Parameter,
+            ParameterList, ParameterType,
         },
-        query::{Filter, FilterExpression, Parameter, ParameterList, ParameterType},
         NullOrdering, Ordering, QueryRecord,
     },
-    subgraph::temporal_axes::QueryTemporalAxes,
+    subgraph::{
+        edges::{EdgeDirection, KnowledgeGraphEdgeKind},
+        temporal_axes::QueryTemporalAxes,
+    },
 };
 
 // # Lifetime guidance
@@ -334,6 +344,36 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
         }
     }
 
+    /// Adds a new path to the selection and groups the result by it.
+    pub fn add_grouped_selection_path(&mut self, path: &'p R::QueryPath<'q>) -> usize
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let index = self.add_selection_path(path);
+        if let Some(selection) = self.selections.get(path) {
+            self.statement
+                .group_by_expression
+                .columns
+                .push(selection.column);
+        }
+        index
+    }
+
+    /// Adds a new path to the selection, which aggregates all values of the path into an array.
+    ///
+    /// If no grouping is specified, all rows are aggregated into a single row.
+    pub fn add_aggregated_selection_path(&mut self, path: &'p R::QueryPath<'q>) -> usize
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let column = self.compile_path_column(path);
+        self.statement.selects.push(SelectExpression::new(
+            Expression::Function(Function::ArrayAgg(Box::new(Expression::Column(column)))),
+            None,
+        ));
+        self.statement.selects.len() - 1
+    }
+
     /// Adds a new path to the selection which can be used as cursor.
     pub fn add_cursor_selection(
         &mut self,
@@ -415,6 +455,35 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
                 rhs.as_ref()
                     .map(|expression| self.compile_filter_expression(expression).0),
             ),
+            Filter::Greater(lhs, rhs) => {
+                let (lhs, rhs) = self.compile_comparison_expressions(lhs, rhs);
+                Condition::Greater(lhs, rhs)
+            }
+            Filter::GreaterOrEqual(lhs, rhs) => {
+                let (lhs, rhs) = self.compile_comparison_expressions(lhs, rhs);
+                Condition::GreaterOrEqual(lhs, rhs)
+            }
+            Filter::Less(lhs, rhs) => {
+                let (lhs, rhs) = self.compile_comparison_expressions(lhs, rhs);
+                Condition::Less(lhs, rhs)
+            }
+            Filter::LessOrEqual(lhs, rhs) => {
+                let (lhs, rhs) = self.compile_comparison_expressions(lhs, rhs);
+                Condition::LessOrEqual(lhs, rhs)
+            }
+            Filter::Between(value, lower, upper) => {
+                let (value, value_type) = self.compile_filter_expression(value);
+                let (lower, lower_type) = self.compile_filter_expression(lower);
+                let (upper, upper_type) = self.compile_filter_expression(upper);
+
+                // The bounds are expected to be of the same type, so either one is sufficient to
+                // determine how the value has to be cast.
+                Condition::Between(
+                    Self::comparable_expression(value, &value_type, &lower_type),
+                    Self::comparable_expression(lower, &lower_type, &value_type),
+                    Self::comparable_expression(upper, &upper_type, &value_type),
+                )
+            }
             Filter::CosineDistance(lhs, rhs, max) => match (lhs, rhs) {
                 (FilterExpression::Path(path), FilterExpression::Parameter(parameter))
                 | (FilterExpression::Parameter(parameter), FilterExpression::Path(path)) => {
@@ -599,6 +668,373 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
 
                 Condition::ContainsSegment(left_filter, right_filter)
             }
+            Filter::Matches(lhs, rhs) => {
+                let (document, document_type) = self.compile_filter_expression(lhs);
+
+                // Searching a single property can't make use of the index on the properties of an
+                // entity, so we additionally search all properties to narrow down the candidates.
+                let properties_condition = match document {
+                    Expression::Column(AliasedColumn {
+                        column: Column::EntityEditions(EntityEditions::Properties(Some(_))),
+                        alias,
+                    }) => Some(Condition::Matches(
+                        Expression::Function(Function::JsonToTsVector(Box::new(
+                            Expression::Column(
+                                Column::EntityEditions(EntityEditions::Properties(None))
+                                    .aliased(alias),
+                            ),
+                        ))),
+                        self.compile_text_search_query(rhs),
+                    )),
+                    _ => None,
+                };
+
+                let condition = Condition::Matches(
+                    if document_type == ParameterType::Any {
+                        Expression::Function(Function::JsonToTsVector(Box::new(document)))
+                    } else {
+                        Expression::Function(Function::ToTsVector(Box::new(document)))
+                    },
+                    self.compile_text_search_query(rhs),
+                );
+
+                if let Some(properties_condition) = properties_condition {
+                    Condition::All(vec![properties_condition, condition])
+                } else {
+                    condition
+                }
+            }
+            Filter::LinkPath(link_path) => self.compile_link_path_filter(link_path),
+        }
+    }
+
+    /// Compiles a [`LinkPathFilter`] to a condition on the entity being filtered.
+    ///
+    /// Every hop is compiled into common table expressions: One selecting the links, which can be
+    /// followed in this hop, and one per depth following these links, starting at the entities
+    /// reached by the previous hop.
+    ///
+    /// The common table expressions are added to the sub-statement selecting the entities at the
+    /// start of the path. This way, the statement itself can still overwrite tables, e.g. for a
+    /// latest version filter.
+    ///
+    /// # Panics
+    ///
+    /// - if the statement is not querying entities
+    fn compile_link_path_filter(&mut self, link_path: &'p LinkPathFilter<'q>) -> Condition {
+        assert_eq!(
+            R::base_table(),
+            Table::EntityTemporalMetadata,
+            "Link path filters are only supported when querying entities"
+        );
+
+        let mut with = WithExpression::default();
+        let mut previous_path = None;
+        for hop in &link_path.hops {
+            previous_path = Some(self.compile_link_path_hop(&mut with, hop, previous_path));
+        }
+
+        let entity_table = self.statement.from;
+        if let Some(hook) = self.table_hooks.get(&entity_table.table) {
+            hook(self, entity_table.alias);
+        }
+        let entity_ids = vec![
+            Expression::Column(
+                Column::EntityTemporalMetadata(EntityTemporalMetadata::WebId)
+                    .aliased(entity_table.alias),
+            ),
+            Expression::Column(
+                Column::EntityTemporalMetadata(EntityTemporalMetadata::EntityUuid)
+                    .aliased(entity_table.alias),
+            ),
+        ];
+
+        match (previous_path, &link_path.filter) {
+            (Some(path), filter) => {
+                let mut where_expression = WhereExpression::default();
+                if let Some(filter) = filter {
+                    where_expression.add_condition(Condition::InSelect(
+                        vec![
+                            Expression::Column(link_path_column(path, LinkPath::TargetWebId)),
+                            Expression::Column(link_path_column(path, LinkPath::TargetEntityUuid)),
+                        ],
+                        Box::new(self.compile_entity_id_selection(filter)),
+                    ));
+                }
+                Condition::InSelect(
+                    entity_ids,
+                    Box::new(SelectStatement {
+                        with,
+                        distinct: Vec::new(),
+                        selects: vec![
+                            SelectExpression::from_column(
+                                link_path_column(path, LinkPath::WebId),
+                                None,
+                            ),
+                            SelectExpression::from_column(
+                                link_path_column(path, LinkPath::EntityUuid),
+                                None,
+                            ),
+                        ],
+                        from: link_path_table(path),
+                        joins: Vec::new(),
+                        where_expression,
+                        order_by_expression: OrderByExpression::default(),
+                        group_by_expression: GroupByExpression::default(),
+                        limit: None,
+                    }),
+                )
+            }
+            // Without any hop, the filter is applied to the entity itself
+            (None, Some(filter)) => Condition::InSelect(
+                entity_ids,
+                Box::new(self.compile_entity_id_selection(filter)),
+            ),
+            (None, None) => Condition::All(Vec::new()),
+        }
+    }
+
+    /// Adds the common table expressions for `hop` to `with` and returns the index of the
+    /// [`Table::LinkPath`] containing the entities reached after this hop.
+    ///
+    /// The path starts at the entities reached by `previous_path` or, for the first hop, at the
+    /// entities the links are followed from. The links are followed breadth-first, one level per
+    /// depth. Every level only continues from the entities which were not reached before, so
+    /// entities reached on multiple paths or through cycles are followed only once.
+    fn compile_link_path_hop(
+        &mut self,
+        with: &mut WithExpression,
+        hop: &'p LinkPathHop<'q>,
+        previous_path: Option<usize>,
+    ) -> usize {
+        let step = with.len();
+        let step_statement = self.compile_link_path_step(hop);
+        with.add_statement(Table::LinkPath(step), step_statement);
+
+        // The entities reached by following a single link
+        let origin = previous_path.unwrap_or(step);
+        let mut frontier = with.len();
+        with.add_statement(
+            Table::LinkPath(frontier),
+            link_path_level(
+                origin,
+                step,
+                previous_path
+                    .map(|previous_path| join_link_path_step(step, previous_path))
+                    .into_iter()
+                    .collect(),
+                WhereExpression::default(),
+            ),
+        );
+
+        let mut reached = frontier;
+        for _ in 1..hop.max_depth.get() {
+            let mut where_expression = WhereExpression::default();
+            where_expression.add_condition(Condition::Not(Box::new(Condition::InSelect(
+                link_path_columns(frontier, step)
+                    .into_iter()
+                    .map(Expression::Column)
+                    .collect(),
+                Box::new(link_path_rows(reached)),
+            ))));
+
+            let next_frontier = with.len();
+            with.add_statement(
+                Table::LinkPath(next_frontier),
+                link_path_level(
+                    frontier,
+                    step,
+                    vec![join_link_path_step(step, frontier)],
+                    where_expression,
+                ),
+            );
+            frontier = next_frontier;
+
+            let next_reached = with.len();
+            with.add_statement(
+                Table::LinkPath(next_reached),
+                Statement::Union(vec![link_path_rows(reached), link_path_rows(frontier)]),
+            );
+            reached = next_reached;
+        }
+
+        reached
+    }
+
+    /// Compiles a statement selecting the links, which can be followed in `hop`.
+    ///
+    /// The selected `web_id` and `entity_uuid` identify the entity the link is followed from, the
+    /// `target_web_id` and `target_entity_uuid` identify the entity the link points to.
+    fn compile_link_path_step(&mut self, hop: &'p LinkPathHop<'q>) -> SelectStatement {
+        let (source_edge, target_edge) = match hop.direction {
+            LinkDirection::Outgoing => (
+                KnowledgeGraphEdgeKind::HasLeftEntity,
+                KnowledgeGraphEdgeKind::HasRightEntity,
+            ),
+            LinkDirection::Incoming => (
+                KnowledgeGraphEdgeKind::HasRightEntity,
+                KnowledgeGraphEdgeKind::HasLeftEntity,
+            ),
+        };
+
+        self.compile_nested_entity_statement(|compiler| {
+            let mut target_ids = Vec::with_capacity(2);
+            for (edge_kind, web_id_alias, entity_uuid_alias, is_target) in [
+                (source_edge, "web_id", "entity_uuid", false),
+                (target_edge, "target_web_id", "target_entity_uuid", true),
+            ] {
+                for (path, column_alias) in [
+                    (EntityQueryPath::OwnedById, web_id_alias),
+                    (EntityQueryPath::Uuid, entity_uuid_alias),
+                ] {
+                    let path = EntityQueryPath::EntityEdge {
+                        edge_kind,
+                        path: Box::new(path),
+                        direction: EdgeDirection::Outgoing,
+                    };
+                    let column = path
+                        .terminating_column()
+                        .into_owned(compiler.artifacts.parameters.len() + 1)
+                        .0
+                        .aliased(compiler.add_join_statements(&path));
+                    compiler
+                        .statement
+                        .selects
+                        .push(SelectExpression::from_column(column, Some(column_alias)));
+                    // Only link entities have a left and a right entity
+                    compiler
+                        .statement
+                        .where_expression
+                        .add_condition(Condition::NotEqual(Some(Expression::Column(column)), None));
+                    if is_target {
+                        target_ids.push(Expression::Column(column));
+                    }
+                }
+            }
+
+            if let Some(filter) = &hop.link_filter {
+                compiler.add_filter(filter);
+            }
+            if let Some(filter) = &hop.entity_filter {
+                let condition = Condition::InSelect(
+                    target_ids,
+                    Box::new(compiler.compile_entity_id_selection(filter)),
+                );
+                compiler.statement.where_expression.add_condition(condition);
+            }
+        })
+    }
+
+    /// Compiles a statement selecting the `web_id` and `entity_uuid` of all entities matching
+    /// `filter`.
+    fn compile_entity_id_selection(&mut self, filter: &'p Filter<'q, Entity>) -> SelectStatement {
+        self.compile_nested_entity_statement(|compiler| {
+            let entity_table = compiler.statement.from;
+            if let Some(hook) = compiler.table_hooks.get(&entity_table.table) {
+                hook(compiler, entity_table.alias);
+            }
+            for column in [
+                EntityTemporalMetadata::WebId,
+                EntityTemporalMetadata::EntityUuid,
+            ] {
+                compiler
+                    .statement
+                    .selects
+                    .push(SelectExpression::from_column(
+                        Column::EntityTemporalMetadata(column).aliased(entity_table.alias),
+                        None,
+                    ));
+            }
+            compiler.add_filter(filter);
+        })
+    }
+
+    /// Compiles a sub-statement over entities using a separate compiler.
+    ///
+    /// The separate compiler uses the same temporal axes and shares the parameters with this
+    /// compiler, so the returned statement can be embedded into the statement of this compiler.
+    fn compile_nested_entity_statement(
+        &mut self,
+        compile: impl FnOnce(&mut SelectCompiler<'p, 'q, Entity>),
+    ) -> SelectStatement {
+        let mut compiler = SelectCompiler::<Entity>::new(self.temporal_axes, self.include_drafts);
+        compiler.artifacts.parameters = mem::take(&mut self.artifacts.parameters);
+        compiler.artifacts.table_info.pinned_timestamp_index =
+            self.artifacts.table_info.pinned_timestamp_index;
+        compiler.artifacts.table_info.variable_interval_index =
+            self.artifacts.table_info.variable_interval_index;
+
+        compile(&mut compiler);
+
+        self.artifacts.parameters = compiler.artifacts.parameters;
+        self.artifacts.table_info.pinned_timestamp_index =
+            compiler.artifacts.table_info.pinned_timestamp_index;
+        self.artifacts.table_info.variable_interval_index =
+            compiler.artifacts.table_info.variable_interval_index;
+        compiler.statement
+    }
+
+    fn compile_text_search_query(&mut self, expression: &'p FilterExpression<'q, R>) -> Expression
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let (query, query_type) = self.compile_filter_expression(expression);
+        let query = if query_type == ParameterType::Any {
+            Expression::Function(Function::JsonExtractText(Box::new(query)))
+        } else {
+            query
+        };
+        Expression::Function(Function::WebSearchToTsQuery(Box::new(query)))
+    }
+
+    /// Compiles both sides of an ordering comparison.
+    ///
+    /// See [`comparable_expression`] for how the expressions are cast.
+    ///
+    /// [`comparable_expression`]: Self::comparable_expression
+    fn compile_comparison_expressions(
+        &mut self,
+        lhs: &'p FilterExpression<'q, R>,
+        rhs: &'p FilterExpression<'q, R>,
+    ) -> (Expression, Expression)
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let (lhs, lhs_type) = self.compile_filter_expression(lhs);
+        let (rhs, rhs_type) = self.compile_filter_expression(rhs);
+        (
+            Self::comparable_expression(lhs, &lhs_type, &rhs_type),
+            Self::comparable_expression(rhs, &rhs_type, &lhs_type),
+        )
+    }
+
+    /// Casts `expression` so it can be ordered against an expression of type `other`.
+    ///
+    /// JSON values are compared as numbers, timestamps, or text depending on the other side of
+    /// the comparison. JSON values of a different type are not comparable and evaluate to `NULL`,
+    /// so they don't match the comparison. Time intervals are compared by their lower bound.
+    fn comparable_expression(
+        expression: Expression,
+        expression_type: &ParameterType,
+        other: &ParameterType,
+    ) -> Expression {
+        match (expression_type, other) {
+            (ParameterType::Any, ParameterType::I32 | ParameterType::F64) => {
+                Expression::JsonCast(Box::new(expression), PostgresType::Float8)
+            }
+            (ParameterType::Any, ParameterType::Timestamp) => {
+                Expression::JsonCast(Box::new(expression), PostgresType::TimestampTz)
+            }
+            (ParameterType::Any, ParameterType::Text) => {
+                Expression::Function(Function::JsonExtractText(Box::new(expression)))
+            }
+            (ParameterType::TimeInterval, ParameterType::Timestamp) => {
+                Expression::Function(Function::Lower(Box::new(expression)))
+            }
+            (ParameterType::Timestamp, ParameterType::Any | ParameterType::TimeInterval) => {
+                Expression::Cast(Box::new(expression), PostgresType::TimestampTz)
+            }
+            _ => expression,
         }
     }
 
@@ -915,3 +1351,91 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
         current_table.alias
     }
 }
+
+const LINK_PATH_ALIAS: Alias = Alias {
+    condition_index: 0,
+    chain_depth: 0,
+    number: 0,
+};
+
+const fn link_path_table(index: usize) -> AliasedTable {
+    Table::LinkPath(index).aliased(LINK_PATH_ALIAS)
+}
+
+const fn link_path_column(index: usize, column: LinkPath) -> AliasedColumn {
+    Column::LinkPath(column, index).aliased(LINK_PATH_ALIAS)
+}
+
+/// Returns the origins from the link path `origin` together with the targets of the links in
+/// `step`.
+const fn link_path_columns(origin: usize, step: usize) -> [AliasedColumn; 4] {
+    [
+        link_path_column(origin, LinkPath::WebId),
+        link_path_column(origin, LinkPath::EntityUuid),
+        link_path_column(step, LinkPath::TargetWebId),
+        link_path_column(step, LinkPath::TargetEntityUuid),
+    ]
+}
+
+/// Selects the distinct pairs of origins from the link path `origin` and targets of the links in
+/// `step`.
+fn link_path_level(
+    origin: usize,
+    step: usize,
+    joins: Vec<JoinExpression>,
+    where_expression: WhereExpression,
+) -> SelectStatement {
+    let columns = link_path_columns(origin, step);
+    SelectStatement {
+        with: WithExpression::default(),
+        distinct: columns.into(),
+        selects: columns
+            .into_iter()
+            .map(|column| SelectExpression::from_column(column, None))
+            .collect(),
+        from: link_path_table(origin),
+        joins,
+        where_expression,
+        order_by_expression: OrderByExpression::default(),
+        group_by_expression: GroupByExpression::default(),
+        limit: None,
+    }
+}
+
+/// Selects all rows of the link path `path`.
+fn link_path_rows(path: usize) -> SelectStatement {
+    SelectStatement {
+        with: WithExpression::default(),
+        distinct: Vec::new(),
+        selects: link_path_columns(path, path)
+            .into_iter()
+            .map(|column| SelectExpression::from_column(column, None))
+            .collect(),
+        from: link_path_table(path),
+        joins: Vec::new(),
+        where_expression: WhereExpression::default(),
+        order_by_expression: OrderByExpression::default(),
+        group_by_expression: GroupByExpression::default(),
+        limit: None,
+    }
+}
+
+/// Joins the links selected in `step` to the entities reached by the link path `path`.
+fn join_link_path_step(step: usize, path: usize) -> JoinExpression {
+    JoinExpression {
+        join: JoinType::Inner,
+        statement: None,
+        table: link_path_table(step),
+        on_alias: LINK_PATH_ALIAS,
+        on: vec![
+            JoinOn {
+                join: Column::LinkPath(LinkPath::WebId, step),
+                on: Column::LinkPath(LinkPath::TargetWebId, path),
+            },
+            JoinOn {
+                join: Column::LinkPath(LinkPath::EntityUuid, step),
+                on: Column::LinkPath(LinkPath::TargetEntityUuid, path),
+            },
+        ],
+    }
+}
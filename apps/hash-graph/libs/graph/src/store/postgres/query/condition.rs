@@ -1,6 +1,6 @@
 use std::{fmt, fmt::Write};
 
-use crate::store::postgres::query::{Expression, Transpile};
+use crate::store::postgres::query::{Expression, SelectStatement, Transpile};
 
 /// A [`Filter`], which can be transpiled.
 ///
@@ -16,12 +16,16 @@ pub enum Condition {
     LessOrEqual(Expression, Expression),
     Greater(Expression, Expression),
     GreaterOrEqual(Expression, Expression),
+    Between(Expression, Expression, Expression),
     In(Expression, Expression),
+    /// Checks if the row built from the expressions is returned by the statement.
+    InSelect(Vec<Expression>, Box<SelectStatement>),
     TimeIntervalContainsTimestamp(Expression, Expression),
     Overlap(Expression, Expression),
     StartsWith(Expression, Expression),
     EndsWith(Expression, Expression),
     ContainsSegment(Expression, Expression),
+    Matches(Expression, Expression),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -107,12 +111,31 @@ impl Transpile for Condition {
                 fmt.write_str(" >= ")?;
                 rhs.transpile(fmt)
             }
+            Self::Between(value, lower, upper) => {
+                value.transpile(fmt)?;
+                fmt.write_str(" BETWEEN ")?;
+                lower.transpile(fmt)?;
+                fmt.write_str(" AND ")?;
+                upper.transpile(fmt)
+            }
             Self::In(lhs, rhs) => {
                 lhs.transpile(fmt)?;
                 fmt.write_str(" = ANY(")?;
                 rhs.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::InSelect(expressions, statement) => {
+                fmt.write_char('(')?;
+                for (idx, expression) in expressions.iter().enumerate() {
+                    if idx > 0 {
+                        fmt.write_str(", ")?;
+                    }
+                    expression.transpile(fmt)?;
+                }
+                fmt.write_str(") IN (")?;
+                statement.transpile(fmt)?;
+                fmt.write_char(')')
+            }
             Self::TimeIntervalContainsTimestamp(lhs, rhs) => {
                 lhs.transpile(fmt)?;
                 fmt.write_str(" @> ")?;
@@ -141,6 +164,11 @@ impl Transpile for Condition {
                 rhs.transpile(fmt)?;
                 fmt.write_str(" || '%'")
             }
+            Self::Matches(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" @@ ")?;
+                rhs.transpile(fmt)
+            }
         }
     }
 }
@@ -315,6 +343,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn transpile_comparison_condition() {
+        test_condition(
+            &Filter::Greater(
+                FilterExpression::Path(DataTypeQueryPath::Version),
+                FilterExpression::Parameter(Parameter::I32(1)),
+            ),
+            r#""ontology_ids_0_1_0"."version" > $1"#,
+            &[&1],
+        );
+
+        test_condition(
+            &Filter::LessOrEqual(
+                FilterExpression::Path(DataTypeQueryPath::Version),
+                FilterExpression::Parameter(Parameter::I32(3)),
+            ),
+            r#""ontology_ids_0_1_0"."version" <= $1"#,
+            &[&3],
+        );
+
+        test_condition(
+            &Filter::Between(
+                FilterExpression::Path(DataTypeQueryPath::Version),
+                FilterExpression::Parameter(Parameter::I32(1)),
+                FilterExpression::Parameter(Parameter::I32(3)),
+            ),
+            r#""ontology_ids_0_1_0"."version" BETWEEN $1 AND $2"#,
+            &[&1, &3],
+        );
+    }
+
+    #[test]
+    fn transpile_matches_condition() {
+        test_condition(
+            &Filter::Matches(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("text"))),
+            ),
+            r#"to_tsvector('english', "data_types_0_1_0"."schema"->>'title') @@ websearch_to_tsquery('english', $1)"#,
+            &[&"text"],
+        );
+    }
+
     #[test]
     fn render_without_parameters() {
         test_condition(
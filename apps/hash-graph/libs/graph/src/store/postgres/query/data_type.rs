@@ -4,8 +4,9 @@ use crate::{
     ontology::DataTypeQueryPath,
     store::postgres::query::{
         table::{
-            Column, DataTypeEmbeddings, DataTypes, JsonField, OntologyAdditionalMetadata,
-            OntologyIds, OntologyOwnedMetadata, OntologyTemporalMetadata, ReferenceTable, Relation,
+            Column, DataTypeConversions, DataTypeEmbeddings, DataTypes, JsonField,
+            OntologyAdditionalMetadata, OntologyIds, OntologyOwnedMetadata,
+            OntologyTemporalMetadata, ReferenceTable, Relation,
         },
         PostgresQueryPath,
     },
@@ -25,6 +26,7 @@ impl PostgresQueryPath for DataTypeQueryPath<'_> {
             Self::OwnedById => vec![Relation::OntologyOwnedMetadata],
             Self::AdditionalMetadata => vec![Relation::OntologyAdditionalMetadata],
             Self::Embedding => vec![Relation::DataTypeEmbeddings],
+            Self::CanonicalUnit | Self::ConversionFactor => vec![Relation::DataTypeConversions],
             Self::TransactionTime | Self::EditionProvenance(_) => vec![],
             Self::PropertyTypeEdge {
                 edge_kind: OntologyEdgeKind::ConstrainsValuesOn,
@@ -49,6 +51,10 @@ impl PostgresQueryPath for DataTypeQueryPath<'_> {
             Self::OwnedById => Column::OntologyOwnedMetadata(OntologyOwnedMetadata::WebId),
             Self::OntologyId => Column::DataTypes(DataTypes::OntologyId),
             Self::Embedding => Column::DataTypeEmbeddings(DataTypeEmbeddings::Embedding),
+            Self::CanonicalUnit => {
+                Column::DataTypeConversions(DataTypeConversions::TargetDataTypeBaseUrl)
+            }
+            Self::ConversionFactor => Column::DataTypeConversions(DataTypeConversions::Factor),
             Self::Schema(path) => path
                 .as_ref()
                 .map_or(Column::DataTypes(DataTypes::Schema(None)), |path| {
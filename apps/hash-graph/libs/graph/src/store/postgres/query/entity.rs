@@ -6,7 +6,7 @@ use crate::{
         table::{
             Column, EntityEditions, EntityEmbeddings, EntityHasLeftEntity, EntityHasRightEntity,
             EntityIds, EntityIsOfTypeIds, EntityProperties, EntityTemporalMetadata, JsonField,
-            ReferenceTable, Relation,
+            PropertyPathParameter, ReferenceTable, Relation, TextSearchQuery,
         },
         PostgresQueryPath,
     },
@@ -26,10 +26,17 @@ impl PostgresQueryPath for EntityQueryPath<'_> {
                 vec![Relation::EntityIds]
             }
             Self::Embedding => vec![Relation::EntityEmbeddings],
-            Self::LeftEntityConfidence => vec![Relation::LeftEntity],
+            Self::LeftEntityConfidence | Self::LeftEntityPosition => vec![Relation::LeftEntity],
             Self::RightEntityConfidence => vec![Relation::RightEntity],
-            Self::PropertyPaths | Self::PropertyConfidences => vec![Relation::EntityProperties],
+            Self::PropertyPaths
+            | Self::PropertyConfidences
+            | Self::PropertyDataTypeBaseUrls
+            | Self::PropertyDataTypeVersions
+            | Self::PropertyProvenances => vec![Relation::EntityProperties],
             Self::Properties(_)
+            | Self::TextSearchRank(_)
+            | Self::PropertyConfidence(_)
+            | Self::NormalizedProperty(_)
             | Self::EditionProvenance(_)
             | Self::Archived
             | Self::EntityConfidence => {
@@ -152,15 +159,32 @@ impl PostgresQueryPath for EntityQueryPath<'_> {
                     ))))
                 },
             ),
+            Self::TextSearchRank(query) => Column::EntityEditions(
+                EntityEditions::PropertiesTextSearchRank(TextSearchQuery::Text(query)),
+            ),
             Self::EntityConfidence => Column::EntityEditions(EntityEditions::Confidence),
             Self::LeftEntityConfidence => {
                 Column::EntityHasLeftEntity(EntityHasLeftEntity::Confidence)
             }
+            Self::LeftEntityPosition => Column::EntityHasLeftEntity(EntityHasLeftEntity::Position),
             Self::RightEntityConfidence => {
                 Column::EntityHasRightEntity(EntityHasRightEntity::Confidence)
             }
             Self::PropertyPaths => Column::EntityProperties(EntityProperties::PropertyPaths),
             Self::PropertyConfidences => Column::EntityProperties(EntityProperties::Confidences),
+            Self::PropertyConfidence(path) => Column::EntityEditions(
+                EntityEditions::PropertyConfidence(PropertyPathParameter::Path(path)),
+            ),
+            Self::PropertyDataTypeBaseUrls => {
+                Column::EntityProperties(EntityProperties::DataTypeBaseUrls)
+            }
+            Self::PropertyDataTypeVersions => {
+                Column::EntityProperties(EntityProperties::DataTypeVersions)
+            }
+            Self::PropertyProvenances => Column::EntityProperties(EntityProperties::Provenances),
+            Self::NormalizedProperty(path) => Column::EntityEditions(
+                EntityEditions::NormalizedProperty(PropertyPathParameter::Path(path)),
+            ),
         }
     }
 }
@@ -6,6 +6,7 @@ use crate::store::postgres::query::{AliasedColumn, Transpile, WindowStatement};
 pub enum Function {
     Min(Box<Expression>),
     Max(Box<Expression>),
+    ArrayAgg(Box<Expression>),
     JsonExtractText(Box<Expression>),
     JsonExtractPath(Vec<Expression>),
     JsonContains(Box<Expression>, Box<Expression>),
@@ -13,6 +14,13 @@ pub enum Function {
     JsonBuildObject(Vec<(Expression, Expression)>),
     Lower(Box<Expression>),
     Upper(Box<Expression>),
+    /// Is backed by the search indexes on the title and description of the ontology types, which
+    /// have to be updated if the transpiled expression changes.
+    ToTsVector(Box<Expression>),
+    /// Is backed by the search index on the properties of entity editions, which has to be updated
+    /// if the transpiled expression changes.
+    JsonToTsVector(Box<Expression>),
+    WebSearchToTsQuery(Box<Expression>),
     Now,
 }
 
@@ -29,6 +37,11 @@ impl Transpile for Function {
                 expression.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::ArrayAgg(expression) => {
+                fmt.write_str("array_agg(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
             Self::JsonExtractPath(paths) => {
                 fmt.write_str("jsonb_extract_path(")?;
                 for (i, expression) in paths.iter().enumerate() {
@@ -84,6 +97,21 @@ impl Transpile for Function {
                 expression.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::ToTsVector(expression) => {
+                fmt.write_str("to_tsvector('english', ")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::JsonToTsVector(expression) => {
+                fmt.write_str("jsonb_to_tsvector('english', ")?;
+                expression.transpile(fmt)?;
+                fmt.write_str(r#", '["string"]')"#)
+            }
+            Self::WebSearchToTsQuery(expression) => {
+                fmt.write_str("websearch_to_tsquery('english', ")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
         }
     }
 }
@@ -105,6 +133,29 @@ impl Transpile for Constant {
     }
 }
 
+/// A Postgres type an [`Expression`] can be cast to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PostgresType {
+    Float8,
+    TimestampTz,
+}
+
+/// Matches an RFC 3339 timestamp, which can be cast to `timestamptz`.
+const TIMESTAMP_PATTERN: &str = concat!(
+    "^[0-9]{4}-(0[1-9]|1[0-2])-(0[1-9]|[12][0-9]|3[01])",
+    "[Tt ]([01][0-9]|2[0-3]):[0-5][0-9]:[0-5][0-9](\\.[0-9]+)?",
+    "([Zz]|[+-][0-9]{2}:[0-9]{2})$",
+);
+
+impl Transpile for PostgresType {
+    fn transpile(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Float8 => fmt.write_str("float8"),
+            Self::TimestampTz => fmt.write_str("timestamptz"),
+        }
+    }
+}
+
 /// A compiled expression in Postgres.
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub enum Expression {
@@ -118,6 +169,10 @@ pub enum Expression {
     Function(Function),
     CosineDistance(Box<Self>, Box<Self>),
     Window(Box<Self>, WindowStatement),
+    Cast(Box<Self>, PostgresType),
+    /// Casts a JSON value to the [`PostgresType`]. Values, which cannot be cast, evaluate to
+    /// `NULL` instead of failing the query.
+    JsonCast(Box<Self>, PostgresType),
 }
 
 impl Transpile for Expression {
@@ -139,6 +194,28 @@ impl Transpile for Expression {
                 window.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::Cast(expression, r#type) => {
+                fmt.write_char('(')?;
+                expression.transpile(fmt)?;
+                fmt.write_str(")::")?;
+                r#type.transpile(fmt)
+            }
+            Self::JsonCast(expression, PostgresType::Float8) => {
+                fmt.write_str("CASE WHEN jsonb_typeof(")?;
+                expression.transpile(fmt)?;
+                fmt.write_str(") = 'number' THEN (")?;
+                expression.transpile(fmt)?;
+                fmt.write_str(")::float8 END")
+            }
+            Self::JsonCast(expression, PostgresType::TimestampTz) => {
+                fmt.write_str("CASE WHEN jsonb_typeof(")?;
+                expression.transpile(fmt)?;
+                fmt.write_str(") = 'string' AND ((")?;
+                expression.transpile(fmt)?;
+                write!(fmt, ") #>> '{{}}'::text[]) ~ '{TIMESTAMP_PATTERN}' THEN ((")?;
+                expression.transpile(fmt)?;
+                fmt.write_str(") #>> '{}'::text[])::timestamptz END")
+            }
         }
     }
 }
@@ -194,4 +271,38 @@ mod tests {
             r#"MIN("ontology_ids_1_2_3"."version")"#
         );
     }
+
+    #[test]
+    fn transpile_cast_expression() {
+        assert_eq!(
+            Expression::Cast(
+                Box::new(Expression::Function(Function::JsonExtractText(Box::new(
+                    Expression::Parameter(1)
+                )))),
+                PostgresType::TimestampTz
+            )
+            .transpile_to_string(),
+            "((($1) #>> '{}'::text[]))::timestamptz"
+        );
+    }
+
+    #[test]
+    fn transpile_json_cast_expression() {
+        assert_eq!(
+            Expression::JsonCast(Box::new(Expression::Parameter(1)), PostgresType::Float8)
+                .transpile_to_string(),
+            "CASE WHEN jsonb_typeof($1) = 'number' THEN ($1)::float8 END"
+        );
+        assert_eq!(
+            Expression::JsonCast(
+                Box::new(Expression::Parameter(1)),
+                PostgresType::TimestampTz
+            )
+            .transpile_to_string(),
+            format!(
+                "CASE WHEN jsonb_typeof($1) = 'string' AND (($1) #>> '{{}}'::text[]) ~ \
+                 '{TIMESTAMP_PATTERN}' THEN (($1) #>> '{{}}'::text[])::timestamptz END"
+            )
+        );
+    }
 }
@@ -33,8 +33,8 @@ pub use self::{
     compile::SelectCompiler,
     condition::{Condition, EqualityOperator},
     expression::{
-        Constant, Expression, Function, JoinExpression, OrderByExpression, SelectExpression,
-        WhereExpression, WithExpression,
+        Constant, Expression, Function, JoinExpression, OrderByExpression, PostgresType,
+        SelectExpression, WhereExpression, WithExpression,
     },
     statement::{Distinctness, SelectStatement, Statement, WindowStatement},
     table::{
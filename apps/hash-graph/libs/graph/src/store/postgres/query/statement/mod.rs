@@ -12,12 +12,23 @@ use crate::store::postgres::query::Transpile;
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub enum Statement {
     Select(SelectStatement),
+    /// Combines the results of all statements, removing duplicate rows.
+    Union(Vec<SelectStatement>),
 }
 
 impl Transpile for Statement {
     fn transpile(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::Select(statement) => statement.transpile(fmt),
+            Self::Union(statements) => {
+                for (idx, statement) in statements.iter().enumerate() {
+                    if idx > 0 {
+                        fmt.write_str("\nUNION\n")?;
+                    }
+                    statement.transpile(fmt)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -88,14 +88,15 @@ impl Transpile for SelectStatement {
 
 #[cfg(test)]
 mod tests {
-    use std::borrow::Cow;
+    use std::{borrow::Cow, num::NonZeroU32};
 
     use graph_types::{
-        knowledge::entity::Entity,
+        knowledge::{entity::Entity, PropertyPath},
         ontology::{DataTypeWithMetadata, EntityTypeWithMetadata, PropertyTypeWithMetadata},
         Embedding,
     };
     use postgres_types::ToSql;
+    use type_system::url::BaseUrl;
     use uuid::Uuid;
 
     use crate::{
@@ -105,7 +106,10 @@ mod tests {
             postgres::query::{
                 test_helper::trim_whitespace, Distinctness, PostgresRecord, SelectCompiler,
             },
-            query::{Filter, FilterExpression, JsonPath, Parameter, PathToken},
+            query::{
+                Filter, FilterExpression, JsonPath, LinkDirection, LinkPathFilter, LinkPathHop,
+                Parameter, PathToken,
+            },
             NullOrdering, Ordering,
         },
         subgraph::{
@@ -747,6 +751,209 @@ mod tests {
         );
     }
 
+    #[test]
+    fn entity_property_range_query() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(Some(&temporal_axes), false);
+        let json_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            r#"$."https://blockprotocol.org/@alice/types/property-type/age/""#,
+        ))]);
+
+        let mut filter = Filter::Greater(
+            FilterExpression::Path(EntityQueryPath::Properties(Some(json_path.clone()))),
+            FilterExpression::Parameter(Parameter::I32(30)),
+        );
+        filter
+            .convert_parameters()
+            .expect("could not convert parameters");
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT *
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+              AND "entity_temporal_metadata_0_0_0"."transaction_time" @> $2::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $3
+              AND CASE WHEN jsonb_typeof(jsonb_path_query_first("entity_editions_0_1_0"."properties", $1::text::jsonpath)) = 'number'
+                THEN (jsonb_path_query_first("entity_editions_0_1_0"."properties", $1::text::jsonpath))::float8
+              END > $4
+            "#,
+            &[
+                &json_path,
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &30.0,
+            ],
+        );
+    }
+
+    #[test]
+    fn entity_property_full_text_search_query() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(Some(&temporal_axes), false);
+        let json_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            r#"$."https://blockprotocol.org/@alice/types/property-type/name/""#,
+        ))]);
+
+        let filter = Filter::Matches(
+            FilterExpression::Path(EntityQueryPath::Properties(Some(json_path.clone()))),
+            FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("alice"))),
+        );
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT *
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+              AND "entity_temporal_metadata_0_0_0"."transaction_time" @> $2::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $3
+              AND (jsonb_to_tsvector('english', "entity_editions_0_1_0"."properties", '["string"]') @@ websearch_to_tsquery('english', $4))
+              AND (jsonb_to_tsvector('english', jsonb_path_query_first("entity_editions_0_1_0"."properties", $1::text::jsonpath), '["string"]') @@ websearch_to_tsquery('english', $5))
+            "#,
+            &[
+                &json_path,
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &"alice",
+                &"alice",
+            ],
+        );
+    }
+
+    #[test]
+    fn entity_property_confidence() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(Some(&temporal_axes), false);
+
+        let mut property_path = PropertyPath::default();
+        property_path.push(
+            BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/name/".to_owned())
+                .expect("invalid base URL"),
+        );
+        let path = EntityQueryPath::PropertyConfidence(property_path.clone());
+
+        let filter = Filter::Less(
+            FilterExpression::Path(path.clone()),
+            FilterExpression::Parameter(Parameter::F64(0.5)),
+        );
+        compiler.add_filter(&filter);
+        compiler.add_distinct_selection_with_ordering(
+            &path,
+            Distinctness::Indistinct,
+            Some((Ordering::Ascending, None)),
+        );
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT *, (SELECT "entity_property"."confidence" FROM "entity_property" WHERE "entity_property"."entity_edition_id" = "entity_editions_1_1_0"."entity_edition_id" AND "entity_property"."property_path" = $5)
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            INNER JOIN "entity_editions" AS "entity_editions_1_1_0"
+              ON "entity_editions_1_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+              AND "entity_temporal_metadata_0_0_0"."transaction_time" @> $2::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $3
+              AND (SELECT "entity_property"."confidence" FROM "entity_property" WHERE "entity_property"."entity_edition_id" = "entity_editions_0_1_0"."entity_edition_id" AND "entity_property"."property_path" = $1) < $4
+            ORDER BY (SELECT "entity_property"."confidence" FROM "entity_property" WHERE "entity_property"."entity_edition_id" = "entity_editions_1_1_0"."entity_edition_id" AND "entity_property"."property_path" = $5) ASC
+            "#,
+            &[
+                &property_path,
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &0.5,
+                &property_path,
+            ],
+        );
+    }
+
+    #[test]
+    fn entity_normalized_property() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(Some(&temporal_axes), false);
+
+        let mut property_path = PropertyPath::default();
+        property_path.push(
+            BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/height/".to_owned())
+                .expect("invalid base URL"),
+        );
+
+        let filter = Filter::Greater(
+            FilterExpression::Path(EntityQueryPath::NormalizedProperty(property_path.clone())),
+            FilterExpression::Parameter(Parameter::F64(1.5)),
+        );
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT *
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+              AND "entity_temporal_metadata_0_0_0"."transaction_time" @> $2::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $3
+              AND (SELECT "entity_property"."normalized_value" FROM "entity_property" WHERE "entity_property"."entity_edition_id" = "entity_editions_0_1_0"."entity_edition_id" AND "entity_property"."property_path" = $1) > $4
+            "#,
+            &[
+                &property_path,
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &1.5,
+            ],
+        );
+    }
+
+    #[test]
+    fn entity_count_grouped_by_web() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::new(Some(&temporal_axes), false);
+
+        compiler.add_grouped_selection_path(&EntityQueryPath::OwnedById);
+        compiler.add_aggregated_selection_path(&EntityQueryPath::Uuid);
+
+        let filter = Filter::Equal(
+            Some(FilterExpression::Path(EntityQueryPath::Archived)),
+            Some(FilterExpression::Parameter(Parameter::Boolean(false))),
+        );
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT "entity_temporal_metadata_0_0_0"."web_id", array_agg("entity_temporal_metadata_0_0_0"."entity_uuid")
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+              AND "entity_temporal_metadata_0_0_0"."transaction_time" @> $1::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $2
+              AND "entity_editions_0_1_0"."archived" = $3
+            GROUP BY "entity_temporal_metadata_0_0_0"."web_id"
+            "#,
+            &[
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &false,
+            ],
+        );
+    }
+
     #[test]
     fn entity_outgoing_link_query() {
         let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
@@ -1049,6 +1256,142 @@ mod tests {
         );
     }
 
+    #[test]
+    #[expect(clippy::too_many_lines)]
+    fn filter_link_path() {
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(None, false);
+
+        let entity_uuid = Uuid::new_v4();
+        let web_id = Uuid::new_v4();
+        let filter = Filter::LinkPath(LinkPathFilter {
+            hops: vec![
+                LinkPathHop {
+                    direction: LinkDirection::Outgoing,
+                    link_filter: Some(Filter::Equal(
+                        Some(FilterExpression::Path(EntityQueryPath::Archived)),
+                        Some(FilterExpression::Parameter(Parameter::Boolean(false))),
+                    )),
+                    entity_filter: None,
+                    max_depth: NonZeroU32::MIN,
+                },
+                LinkPathHop {
+                    direction: LinkDirection::Incoming,
+                    link_filter: None,
+                    entity_filter: Some(Filter::Equal(
+                        Some(FilterExpression::Path(EntityQueryPath::Uuid)),
+                        Some(FilterExpression::Parameter(Parameter::Uuid(entity_uuid))),
+                    )),
+                    max_depth: NonZeroU32::new(3).expect("3 is not zero"),
+                },
+            ],
+            filter: Some(Box::new(Filter::Equal(
+                Some(FilterExpression::Path(EntityQueryPath::OwnedById)),
+                Some(FilterExpression::Parameter(Parameter::Uuid(web_id))),
+            ))),
+        });
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+              SELECT *
+              FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+              WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+                AND ("entity_temporal_metadata_0_0_0"."web_id", "entity_temporal_metadata_0_0_0"."entity_uuid") IN (WITH "link_path_0" AS (SELECT "entity_has_left_entity_0_1_0"."left_web_id" AS "web_id",
+                           "entity_has_left_entity_0_1_0"."left_entity_uuid" AS "entity_uuid",
+                           "entity_has_right_entity_0_1_0"."right_web_id" AS "target_web_id",
+                           "entity_has_right_entity_0_1_0"."right_entity_uuid" AS "target_entity_uuid"
+                    FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+                    LEFT OUTER JOIN "entity_has_left_entity" AS "entity_has_left_entity_0_1_0"
+                      ON "entity_has_left_entity_0_1_0"."web_id" = "entity_temporal_metadata_0_0_0"."web_id"
+                     AND "entity_has_left_entity_0_1_0"."entity_uuid" = "entity_temporal_metadata_0_0_0"."entity_uuid"
+                    LEFT OUTER JOIN "entity_has_right_entity" AS "entity_has_right_entity_0_1_0"
+                      ON "entity_has_right_entity_0_1_0"."web_id" = "entity_temporal_metadata_0_0_0"."web_id"
+                     AND "entity_has_right_entity_0_1_0"."entity_uuid" = "entity_temporal_metadata_0_0_0"."entity_uuid"
+                    INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+                      ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+                    WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+                      AND "entity_has_left_entity_0_1_0"."left_web_id" IS NOT NULL
+                      AND "entity_has_left_entity_0_1_0"."left_entity_uuid" IS NOT NULL
+                      AND "entity_has_right_entity_0_1_0"."right_web_id" IS NOT NULL
+                      AND "entity_has_right_entity_0_1_0"."right_entity_uuid" IS NOT NULL
+                      AND "entity_editions_0_1_0"."archived" = $1),
+                  "link_path_1" AS (SELECT DISTINCT ON("link_path_0_0_0_0"."web_id", "link_path_0_0_0_0"."entity_uuid", "link_path_0_0_0_0"."target_web_id", "link_path_0_0_0_0"."target_entity_uuid")
+                           "link_path_0_0_0_0"."web_id", "link_path_0_0_0_0"."entity_uuid",
+                           "link_path_0_0_0_0"."target_web_id", "link_path_0_0_0_0"."target_entity_uuid"
+                    FROM "link_path_0" AS "link_path_0_0_0_0"),
+                  "link_path_2" AS (SELECT "entity_has_right_entity_0_1_0"."right_web_id" AS "web_id",
+                           "entity_has_right_entity_0_1_0"."right_entity_uuid" AS "entity_uuid",
+                           "entity_has_left_entity_0_1_0"."left_web_id" AS "target_web_id",
+                           "entity_has_left_entity_0_1_0"."left_entity_uuid" AS "target_entity_uuid"
+                    FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+                    LEFT OUTER JOIN "entity_has_right_entity" AS "entity_has_right_entity_0_1_0"
+                      ON "entity_has_right_entity_0_1_0"."web_id" = "entity_temporal_metadata_0_0_0"."web_id"
+                     AND "entity_has_right_entity_0_1_0"."entity_uuid" = "entity_temporal_metadata_0_0_0"."entity_uuid"
+                    LEFT OUTER JOIN "entity_has_left_entity" AS "entity_has_left_entity_0_1_0"
+                      ON "entity_has_left_entity_0_1_0"."web_id" = "entity_temporal_metadata_0_0_0"."web_id"
+                     AND "entity_has_left_entity_0_1_0"."entity_uuid" = "entity_temporal_metadata_0_0_0"."entity_uuid"
+                    WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+                      AND "entity_has_right_entity_0_1_0"."right_web_id" IS NOT NULL
+                      AND "entity_has_right_entity_0_1_0"."right_entity_uuid" IS NOT NULL
+                      AND "entity_has_left_entity_0_1_0"."left_web_id" IS NOT NULL
+                      AND "entity_has_left_entity_0_1_0"."left_entity_uuid" IS NOT NULL
+                      AND ("entity_has_left_entity_0_1_0"."left_web_id", "entity_has_left_entity_0_1_0"."left_entity_uuid") IN (SELECT "entity_temporal_metadata_0_0_0"."web_id", "entity_temporal_metadata_0_0_0"."entity_uuid"
+                        FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+                        WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+                          AND "entity_temporal_metadata_0_0_0"."entity_uuid" = $2)),
+                  "link_path_3" AS (SELECT DISTINCT ON("link_path_1_0_0_0"."web_id", "link_path_1_0_0_0"."entity_uuid", "link_path_2_0_0_0"."target_web_id", "link_path_2_0_0_0"."target_entity_uuid")
+                           "link_path_1_0_0_0"."web_id", "link_path_1_0_0_0"."entity_uuid",
+                           "link_path_2_0_0_0"."target_web_id", "link_path_2_0_0_0"."target_entity_uuid"
+                    FROM "link_path_1" AS "link_path_1_0_0_0"
+                    INNER JOIN "link_path_2" AS "link_path_2_0_0_0"
+                      ON "link_path_2_0_0_0"."web_id" = "link_path_1_0_0_0"."target_web_id"
+                     AND "link_path_2_0_0_0"."entity_uuid" = "link_path_1_0_0_0"."target_entity_uuid"),
+                  "link_path_4" AS (SELECT DISTINCT ON("link_path_3_0_0_0"."web_id", "link_path_3_0_0_0"."entity_uuid", "link_path_2_0_0_0"."target_web_id", "link_path_2_0_0_0"."target_entity_uuid")
+                           "link_path_3_0_0_0"."web_id", "link_path_3_0_0_0"."entity_uuid",
+                           "link_path_2_0_0_0"."target_web_id", "link_path_2_0_0_0"."target_entity_uuid"
+                    FROM "link_path_3" AS "link_path_3_0_0_0"
+                    INNER JOIN "link_path_2" AS "link_path_2_0_0_0"
+                      ON "link_path_2_0_0_0"."web_id" = "link_path_3_0_0_0"."target_web_id"
+                     AND "link_path_2_0_0_0"."entity_uuid" = "link_path_3_0_0_0"."target_entity_uuid"
+                    WHERE NOT(("link_path_3_0_0_0"."web_id", "link_path_3_0_0_0"."entity_uuid", "link_path_2_0_0_0"."target_web_id", "link_path_2_0_0_0"."target_entity_uuid") IN (SELECT "link_path_3_0_0_0"."web_id", "link_path_3_0_0_0"."entity_uuid",
+                           "link_path_3_0_0_0"."target_web_id", "link_path_3_0_0_0"."target_entity_uuid"
+                    FROM "link_path_3" AS "link_path_3_0_0_0"))),
+                  "link_path_5" AS (SELECT "link_path_3_0_0_0"."web_id", "link_path_3_0_0_0"."entity_uuid",
+                           "link_path_3_0_0_0"."target_web_id", "link_path_3_0_0_0"."target_entity_uuid"
+                    FROM "link_path_3" AS "link_path_3_0_0_0"
+                    UNION
+                    SELECT "link_path_4_0_0_0"."web_id", "link_path_4_0_0_0"."entity_uuid",
+                           "link_path_4_0_0_0"."target_web_id", "link_path_4_0_0_0"."target_entity_uuid"
+                    FROM "link_path_4" AS "link_path_4_0_0_0"),
+                  "link_path_6" AS (SELECT DISTINCT ON("link_path_4_0_0_0"."web_id", "link_path_4_0_0_0"."entity_uuid", "link_path_2_0_0_0"."target_web_id", "link_path_2_0_0_0"."target_entity_uuid")
+                           "link_path_4_0_0_0"."web_id", "link_path_4_0_0_0"."entity_uuid",
+                           "link_path_2_0_0_0"."target_web_id", "link_path_2_0_0_0"."target_entity_uuid"
+                    FROM "link_path_4" AS "link_path_4_0_0_0"
+                    INNER JOIN "link_path_2" AS "link_path_2_0_0_0"
+                      ON "link_path_2_0_0_0"."web_id" = "link_path_4_0_0_0"."target_web_id"
+                     AND "link_path_2_0_0_0"."entity_uuid" = "link_path_4_0_0_0"."target_entity_uuid"
+                    WHERE NOT(("link_path_4_0_0_0"."web_id", "link_path_4_0_0_0"."entity_uuid", "link_path_2_0_0_0"."target_web_id", "link_path_2_0_0_0"."target_entity_uuid") IN (SELECT "link_path_5_0_0_0"."web_id", "link_path_5_0_0_0"."entity_uuid",
+                           "link_path_5_0_0_0"."target_web_id", "link_path_5_0_0_0"."target_entity_uuid"
+                    FROM "link_path_5" AS "link_path_5_0_0_0"))),
+                  "link_path_7" AS (SELECT "link_path_5_0_0_0"."web_id", "link_path_5_0_0_0"."entity_uuid",
+                           "link_path_5_0_0_0"."target_web_id", "link_path_5_0_0_0"."target_entity_uuid"
+                    FROM "link_path_5" AS "link_path_5_0_0_0"
+                    UNION
+                    SELECT "link_path_6_0_0_0"."web_id", "link_path_6_0_0_0"."entity_uuid",
+                           "link_path_6_0_0_0"."target_web_id", "link_path_6_0_0_0"."target_entity_uuid"
+                    FROM "link_path_6" AS "link_path_6_0_0_0")
+                  SELECT "link_path_7_0_0_0"."web_id", "link_path_7_0_0_0"."entity_uuid"
+                  FROM "link_path_7" AS "link_path_7_0_0_0"
+                  WHERE ("link_path_7_0_0_0"."target_web_id", "link_path_7_0_0_0"."target_entity_uuid") IN (SELECT "entity_temporal_metadata_0_0_0"."web_id", "entity_temporal_metadata_0_0_0"."entity_uuid"
+                    FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+                    WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+                      AND "entity_temporal_metadata_0_0_0"."web_id" = $3))
+            "#,
+            &[&false, &entity_uuid, &web_id],
+        );
+    }
+
     mod predefined {
         use graph_types::{
             knowledge::entity::{EntityId, EntityUuid},
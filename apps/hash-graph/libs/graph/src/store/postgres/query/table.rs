@@ -1,9 +1,11 @@
 use std::{
-    fmt::{self, Debug},
+    borrow::Cow,
+    fmt::{self, Debug, Write},
     hash::Hash,
     iter::{once, Chain, Once},
 };
 
+use graph_types::knowledge::PropertyPath;
 use postgres_types::ToSql;
 use temporal_versioning::TimeAxis;
 
@@ -25,6 +27,7 @@ pub enum Table {
     OntologyAdditionalMetadata,
     DataTypes,
     DataTypeEmbeddings,
+    DataTypeConversions,
     PropertyTypes,
     PropertyTypeEmbeddings,
     EntityTypes,
@@ -36,10 +39,15 @@ pub enum Table {
     EntityIsOfTypeIds,
     EntityProperties,
     Reference(ReferenceTable),
+    /// A common table expression used to resolve a link path.
+    ///
+    /// The index is used to distinguish multiple link paths within the same statement.
+    LinkPath(usize),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ReferenceTable {
+    DataTypeInheritsFrom,
     PropertyTypeConstrainsValuesOn,
     PropertyTypeConstrainsPropertiesOn,
     EntityTypeConstrainsPropertiesOn { inheritance_depth: Option<u32> },
@@ -93,6 +101,10 @@ impl ReferenceTable {
 
     pub const fn source_relation(self) -> ForeignKeyReference {
         match self {
+            Self::DataTypeInheritsFrom => ForeignKeyReference::Single {
+                on: Column::OntologyTemporalMetadata(OntologyTemporalMetadata::OntologyId),
+                join: Column::DataTypeInheritsFrom(DataTypeInheritsFrom::SourceDataTypeOntologyId),
+            },
             Self::PropertyTypeConstrainsValuesOn => ForeignKeyReference::Single {
                 on: Column::OntologyTemporalMetadata(OntologyTemporalMetadata::OntologyId),
                 join: Column::PropertyTypeConstrainsValuesOn(
@@ -168,6 +180,10 @@ impl ReferenceTable {
 
     pub const fn target_relation(self) -> ForeignKeyReference {
         match self {
+            Self::DataTypeInheritsFrom => ForeignKeyReference::Single {
+                on: Column::DataTypeInheritsFrom(DataTypeInheritsFrom::TargetDataTypeOntologyId),
+                join: Column::OntologyTemporalMetadata(OntologyTemporalMetadata::OntologyId),
+            },
             Self::PropertyTypeConstrainsValuesOn => ForeignKeyReference::Single {
                 on: Column::PropertyTypeConstrainsValuesOn(
                     PropertyTypeConstrainsValuesOn::TargetDataTypeOntologyId,
@@ -245,6 +261,7 @@ impl ReferenceTable {
 impl ReferenceTable {
     const fn as_str(self) -> &'static str {
         match self {
+            Self::DataTypeInheritsFrom => "data_type_inherits_from",
             Self::PropertyTypeConstrainsValuesOn => "property_type_constrains_values_on",
             Self::PropertyTypeConstrainsPropertiesOn => "property_type_constrains_properties_on",
             Self::EntityTypeConstrainsPropertiesOn {
@@ -291,6 +308,7 @@ impl Table {
             Self::OntologyAdditionalMetadata => "ontology_additional_metadata",
             Self::DataTypes => "data_types",
             Self::DataTypeEmbeddings => "data_type_embeddings",
+            Self::DataTypeConversions => "data_type_conversions",
             Self::PropertyTypes => "property_types",
             Self::PropertyTypeEmbeddings => "property_type_embeddings",
             Self::EntityTypes => "entity_types",
@@ -302,13 +320,17 @@ impl Table {
             Self::EntityIsOfTypeIds => "entity_is_of_type_ids",
             Self::EntityProperties => "entity_properties",
             Self::Reference(table) => table.as_str(),
+            Self::LinkPath(_) => "link_path",
         }
     }
 }
 
 impl Transpile for Table {
     fn transpile(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        write!(fmt, r#""{}""#, self.as_str())
+        match self {
+            Self::LinkPath(index) => write!(fmt, r#""{}_{index}""#, self.as_str()),
+            _ => write!(fmt, r#""{}""#, self.as_str()),
+        }
     }
 }
 
@@ -335,6 +357,53 @@ impl<'p> JsonField<'p> {
     }
 }
 
+/// A full-text search query, passed as parameter to the statement.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TextSearchQuery<'p> {
+    Text(&'p Cow<'p, str>),
+    TextParameter(usize),
+}
+
+impl<'p> TextSearchQuery<'p> {
+    pub const fn into_owned(
+        self,
+        current_parameter_index: usize,
+    ) -> (TextSearchQuery<'static>, Option<&'p (dyn ToSql + Sync)>) {
+        match self {
+            Self::Text(text) => (
+                TextSearchQuery::TextParameter(current_parameter_index),
+                Some(text),
+            ),
+            Self::TextParameter(index) => (TextSearchQuery::TextParameter(index), None),
+        }
+    }
+}
+
+/// The path to a property, passed as parameter to the statement.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PropertyPathParameter<'p> {
+    Path(&'p PropertyPath<'p>),
+    PathParameter(usize),
+}
+
+impl<'p> PropertyPathParameter<'p> {
+    pub const fn into_owned(
+        self,
+        current_parameter_index: usize,
+    ) -> (
+        PropertyPathParameter<'static>,
+        Option<&'p (dyn ToSql + Sync)>,
+    ) {
+        match self {
+            Self::Path(path) => (
+                PropertyPathParameter::PathParameter(current_parameter_index),
+                Some(path),
+            ),
+            Self::PathParameter(index) => (PropertyPathParameter::PathParameter(index), None),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum StaticJsonField {
     JsonPathParameter(usize),
@@ -797,6 +866,33 @@ impl DataTypeEmbeddings {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DataTypeConversions {
+    SourceDataTypeOntologyId,
+    TargetDataTypeBaseUrl,
+    Factor,
+}
+
+impl DataTypeConversions {
+    fn transpile_column(self, table: &impl Transpile, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let column = match self {
+            Self::SourceDataTypeOntologyId => "source_data_type_ontology_id",
+            Self::TargetDataTypeBaseUrl => "target_data_type_base_url",
+            Self::Factor => "factor",
+        };
+        table.transpile(fmt)?;
+        write!(fmt, r#"."{column}""#)
+    }
+
+    pub const fn parameter_type(self) -> ParameterType {
+        match self {
+            Self::SourceDataTypeOntologyId => ParameterType::Uuid,
+            Self::TargetDataTypeBaseUrl => ParameterType::BaseUrl,
+            Self::Factor => ParameterType::F64,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum PropertyTypeEmbeddings {
     OntologyId,
@@ -903,13 +999,25 @@ pub enum EntityEditions<'p> {
     Archived,
     Confidence,
     Provenance(Option<JsonField<'p>>),
+    PropertiesTextSearchRank(TextSearchQuery<'p>),
+    /// The confidence of a single property, read from `entity_property`.
+    PropertyConfidence(PropertyPathParameter<'p>),
+    /// The value of a single property in its canonical unit, read from `entity_property`.
+    NormalizedProperty(PropertyPathParameter<'p>),
 }
 
 impl<'p> EntityEditions<'p> {
     pub const fn nullable(self) -> bool {
         match self {
-            Self::EditionId | Self::Archived | Self::Provenance(None) => false,
-            Self::Properties(_) | Self::Confidence | Self::Provenance(Some(_)) => true,
+            Self::EditionId
+            | Self::Archived
+            | Self::Provenance(None)
+            | Self::PropertiesTextSearchRank(_) => false,
+            Self::Properties(_)
+            | Self::Confidence
+            | Self::Provenance(Some(_))
+            | Self::PropertyConfidence(_)
+            | Self::NormalizedProperty(_) => true,
         }
     }
 
@@ -918,7 +1026,10 @@ impl<'p> EntityEditions<'p> {
             Self::EditionId => ParameterType::Uuid,
             Self::Properties(_) | Self::Provenance(_) => ParameterType::Any,
             Self::Archived => ParameterType::Boolean,
-            Self::Confidence => ParameterType::F64,
+            Self::Confidence
+            | Self::PropertiesTextSearchRank(_)
+            | Self::PropertyConfidence(_)
+            | Self::NormalizedProperty(_) => ParameterType::F64,
         }
     }
 
@@ -940,8 +1051,43 @@ impl<'p> EntityEditions<'p> {
                 (EntityEditions::Provenance(Some(path)), parameter)
             }
             Self::Confidence => (EntityEditions::Confidence, None),
+            Self::PropertiesTextSearchRank(query) => {
+                let (query, parameter) = query.into_owned(current_parameter_index);
+                (EntityEditions::PropertiesTextSearchRank(query), parameter)
+            }
+            Self::PropertyConfidence(path) => {
+                let (path, parameter) = path.into_owned(current_parameter_index);
+                (EntityEditions::PropertyConfidence(path), parameter)
+            }
+            Self::NormalizedProperty(path) => {
+                let (path, parameter) = path.into_owned(current_parameter_index);
+                (EntityEditions::NormalizedProperty(path), parameter)
+            }
+        }
+    }
+}
+
+/// Transpiles a correlated subquery reading `column` of a single property from `entity_property`.
+fn transpile_entity_property_column(
+    column: &str,
+    path: PropertyPathParameter<'static>,
+    table: &impl Transpile,
+    fmt: &mut fmt::Formatter,
+) -> fmt::Result {
+    write!(
+        fmt,
+        r#"(SELECT "entity_property"."{column}" FROM "entity_property" "#
+    )?;
+    fmt.write_str(r#"WHERE "entity_property"."entity_edition_id" = "#)?;
+    table.transpile(fmt)?;
+    fmt.write_str(r#"."entity_edition_id" AND "entity_property"."property_path" = "#)?;
+    match path {
+        PropertyPathParameter::Path(path) => {
+            write!(fmt, "'{}'", path.to_json_pointer().replace('\'', "''"))?;
         }
+        PropertyPathParameter::PathParameter(index) => write!(fmt, "${index}")?,
     }
+    fmt.write_str(")")
 }
 
 impl EntityEditions<'static> {
@@ -958,6 +1104,24 @@ impl EntityEditions<'static> {
             }
             Self::Archived => "archived",
             Self::Confidence => "confidence",
+            Self::PropertiesTextSearchRank(query) => {
+                fmt.write_str("ts_rank(jsonb_to_tsvector('english', ")?;
+                table.transpile(fmt)?;
+                fmt.write_str(r#"."properties", '["string"]'), websearch_to_tsquery('english', "#)?;
+                match query {
+                    TextSearchQuery::Text(text) => {
+                        write!(fmt, "'{}'", text.replace('\'', "''"))?;
+                    }
+                    TextSearchQuery::TextParameter(index) => write!(fmt, "${index}")?,
+                }
+                return fmt.write_str("))::float8");
+            }
+            Self::PropertyConfidence(path) => {
+                return transpile_entity_property_column("confidence", path, table, fmt);
+            }
+            Self::NormalizedProperty(path) => {
+                return transpile_entity_property_column("normalized_value", path, table, fmt);
+            }
         };
         table.transpile(fmt)?;
         write!(fmt, r#"."{column}""#)
@@ -1022,6 +1186,9 @@ pub enum EntityProperties {
     EntityEditionId,
     PropertyPaths,
     Confidences,
+    DataTypeBaseUrls,
+    DataTypeVersions,
+    Provenances,
 }
 
 impl EntityProperties {
@@ -1030,6 +1197,9 @@ impl EntityProperties {
             Self::EntityEditionId => "entity_edition_id",
             Self::PropertyPaths => "property_paths",
             Self::Confidences => "confidences",
+            Self::DataTypeBaseUrls => "data_type_base_urls",
+            Self::DataTypeVersions => "data_type_versions",
+            Self::Provenances => "provenances",
         };
         table.transpile(fmt)?;
         write!(fmt, r#"."{column}""#)
@@ -1040,6 +1210,11 @@ impl EntityProperties {
             Self::EntityEditionId => ParameterType::Uuid,
             Self::PropertyPaths => ParameterType::Vector(Box::new(ParameterType::Text)),
             Self::Confidences => ParameterType::Vector(Box::new(ParameterType::F64)),
+            Self::DataTypeBaseUrls => ParameterType::Vector(Box::new(ParameterType::BaseUrl)),
+            Self::DataTypeVersions => {
+                ParameterType::Vector(Box::new(ParameterType::OntologyTypeVersion))
+            }
+            Self::Provenances => ParameterType::Vector(Box::new(ParameterType::Any)),
         }
     }
 }
@@ -1051,6 +1226,7 @@ pub enum EntityHasLeftEntity {
     LeftEntityWebId,
     LeftEntityUuid,
     Confidence,
+    Position,
 }
 
 impl EntityHasLeftEntity {
@@ -1061,6 +1237,7 @@ impl EntityHasLeftEntity {
             Self::LeftEntityWebId => "left_web_id",
             Self::LeftEntityUuid => "left_entity_uuid",
             Self::Confidence => "confidence",
+            Self::Position => "position",
         };
         table.transpile(fmt)?;
         write!(fmt, r#"."{column}""#)
@@ -1072,6 +1249,7 @@ impl EntityHasLeftEntity {
                 ParameterType::Uuid
             }
             Self::Confidence => ParameterType::F64,
+            Self::Position => ParameterType::Text,
         }
     }
 }
@@ -1108,6 +1286,68 @@ impl EntityHasRightEntity {
     }
 }
 
+/// The columns of a [`Table::LinkPath`].
+///
+/// Each row connects the entity the path starts at with an entity reachable by following the
+/// path.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LinkPath {
+    WebId,
+    EntityUuid,
+    TargetWebId,
+    TargetEntityUuid,
+}
+
+impl LinkPath {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::WebId => "web_id",
+            Self::EntityUuid => "entity_uuid",
+            Self::TargetWebId => "target_web_id",
+            Self::TargetEntityUuid => "target_entity_uuid",
+        }
+    }
+
+    fn transpile_column(self, table: &impl Transpile, fmt: &mut fmt::Formatter) -> fmt::Result {
+        table.transpile(fmt)?;
+        write!(fmt, r#"."{}""#, self.as_str())
+    }
+
+    pub const fn parameter_type(self) -> ParameterType {
+        match self {
+            Self::WebId | Self::EntityUuid | Self::TargetWebId | Self::TargetEntityUuid => {
+                ParameterType::Uuid
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DataTypeInheritsFrom {
+    SourceDataTypeOntologyId,
+    TargetDataTypeOntologyId,
+}
+
+impl DataTypeInheritsFrom {
+    fn transpile_column(self, table: &impl Transpile, fmt: &mut fmt::Formatter) -> fmt::Result {
+        table.transpile(fmt)?;
+        write!(
+            fmt,
+            r#"."{}""#,
+            match self {
+                Self::SourceDataTypeOntologyId => "source_data_type_ontology_id",
+                Self::TargetDataTypeOntologyId => "target_data_type_ontology_id",
+            }
+        )
+    }
+
+    pub const fn parameter_type(self) -> ParameterType {
+        match self {
+            Self::SourceDataTypeOntologyId | Self::TargetDataTypeOntologyId => ParameterType::Uuid,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum PropertyTypeConstrainsValuesOn {
     SourcePropertyTypeOntologyId,
@@ -1300,6 +1540,7 @@ pub enum Column<'p> {
     OntologyAdditionalMetadata(OntologyAdditionalMetadata),
     DataTypes(DataTypes<'p>),
     DataTypeEmbeddings(DataTypeEmbeddings),
+    DataTypeConversions(DataTypeConversions),
     PropertyTypes(PropertyTypes<'p>),
     PropertyTypeEmbeddings(PropertyTypeEmbeddings),
     EntityTypes(EntityTypes<'p>),
@@ -1308,6 +1549,7 @@ pub enum Column<'p> {
     EntityTemporalMetadata(EntityTemporalMetadata),
     EntityEditions(EntityEditions<'p>),
     EntityEmbeddings(EntityEmbeddings),
+    DataTypeInheritsFrom(DataTypeInheritsFrom),
     PropertyTypeConstrainsValuesOn(PropertyTypeConstrainsValuesOn),
     PropertyTypeConstrainsPropertiesOn(PropertyTypeConstrainsPropertiesOn),
     EntityTypeConstrainsPropertiesOn(EntityTypeConstrainsPropertiesOn, Option<u32>),
@@ -1319,6 +1561,7 @@ pub enum Column<'p> {
     EntityProperties(EntityProperties),
     EntityHasLeftEntity(EntityHasLeftEntity),
     EntityHasRightEntity(EntityHasRightEntity),
+    LinkPath(LinkPath, usize),
 }
 
 impl<'p> Column<'p> {
@@ -1331,6 +1574,7 @@ impl<'p> Column<'p> {
             Self::OntologyAdditionalMetadata(_) => Table::OntologyAdditionalMetadata,
             Self::DataTypes(_) => Table::DataTypes,
             Self::DataTypeEmbeddings(_) => Table::DataTypeEmbeddings,
+            Self::DataTypeConversions(_) => Table::DataTypeConversions,
             Self::PropertyTypes(_) => Table::PropertyTypes,
             Self::PropertyTypeEmbeddings(_) => Table::PropertyTypeEmbeddings,
             Self::EntityTypes(_) => Table::EntityTypes,
@@ -1339,6 +1583,7 @@ impl<'p> Column<'p> {
             Self::EntityTemporalMetadata(_) => Table::EntityTemporalMetadata,
             Self::EntityEditions(_) => Table::EntityEditions,
             Self::EntityEmbeddings(_) => Table::EntityEmbeddings,
+            Self::DataTypeInheritsFrom(_) => Table::Reference(ReferenceTable::DataTypeInheritsFrom),
             Self::PropertyTypeConstrainsValuesOn(_) => {
                 Table::Reference(ReferenceTable::PropertyTypeConstrainsValuesOn)
             }
@@ -1368,6 +1613,7 @@ impl<'p> Column<'p> {
             Self::EntityProperties(_) => Table::EntityProperties,
             Self::EntityHasLeftEntity(_) => Table::Reference(ReferenceTable::EntityHasLeftEntity),
             Self::EntityHasRightEntity(_) => Table::Reference(ReferenceTable::EntityHasRightEntity),
+            Self::LinkPath(_, index) => Table::LinkPath(index),
         }
     }
 
@@ -1391,6 +1637,7 @@ impl<'p> Column<'p> {
             Self::EntityEditions(column) => column.nullable(),
             Self::EntityIds(column) => column.nullable(),
             Self::EntityEmbeddings(_)
+            | Self::DataTypeConversions(_)
             | Self::EntityProperties(_)
             | Self::EntityHasLeftEntity(_)
             | Self::EntityHasRightEntity(_)
@@ -1422,6 +1669,7 @@ impl<'p> Column<'p> {
                 (Column::DataTypes(column), parameter)
             }
             Self::DataTypeEmbeddings(column) => (Column::DataTypeEmbeddings(column), None),
+            Self::DataTypeConversions(column) => (Column::DataTypeConversions(column), None),
             Self::PropertyTypes(column) => {
                 let (column, parameter) = column.into_owned(current_parameter_index);
                 (Column::PropertyTypes(column), parameter)
@@ -1442,6 +1690,7 @@ impl<'p> Column<'p> {
                 (Column::EntityEditions(column), parameter)
             }
             Self::EntityEmbeddings(column) => (Column::EntityEmbeddings(column), None),
+            Self::DataTypeInheritsFrom(column) => (Column::DataTypeInheritsFrom(column), None),
             Self::PropertyTypeConstrainsValuesOn(column) => {
                 (Column::PropertyTypeConstrainsValuesOn(column), None)
             }
@@ -1471,6 +1720,7 @@ impl<'p> Column<'p> {
             Self::EntityProperties(column) => (Column::EntityProperties(column), None),
             Self::EntityHasLeftEntity(column) => (Column::EntityHasLeftEntity(column), None),
             Self::EntityHasRightEntity(column) => (Column::EntityHasRightEntity(column), None),
+            Self::LinkPath(column, index) => (Column::LinkPath(column, index), None),
         }
     }
 }
@@ -1492,6 +1742,7 @@ impl Column<'static> {
             Self::OntologyAdditionalMetadata(column) => column.transpile_column(table, fmt),
             Self::DataTypes(column) => column.transpile_column(table, fmt),
             Self::DataTypeEmbeddings(column) => column.transpile_column(table, fmt),
+            Self::DataTypeConversions(column) => column.transpile_column(table, fmt),
             Self::PropertyTypes(column) => column.transpile_column(table, fmt),
             Self::PropertyTypeEmbeddings(column) => column.transpile_column(table, fmt),
             Self::EntityTypes(column) => column.transpile_column(table, fmt),
@@ -1500,6 +1751,7 @@ impl Column<'static> {
             Self::EntityTemporalMetadata(column) => column.transpile_column(table, fmt),
             Self::EntityEditions(column) => column.transpile_column(table, fmt),
             Self::EntityEmbeddings(column) => column.transpile_column(table, fmt),
+            Self::DataTypeInheritsFrom(column) => column.transpile_column(table, fmt),
             Self::PropertyTypeConstrainsValuesOn(column) => column.transpile_column(table, fmt),
             Self::PropertyTypeConstrainsPropertiesOn(column) => column.transpile_column(table, fmt),
             Self::EntityTypeConstrainsPropertiesOn(column, _) => {
@@ -1515,6 +1767,7 @@ impl Column<'static> {
             Self::EntityProperties(column) => column.transpile_column(table, fmt),
             Self::EntityHasLeftEntity(column) => column.transpile_column(table, fmt),
             Self::EntityHasRightEntity(column) => column.transpile_column(table, fmt),
+            Self::LinkPath(column, _) => column.transpile_column(table, fmt),
         }
     }
 
@@ -1527,6 +1780,7 @@ impl Column<'static> {
             Self::OntologyAdditionalMetadata(column) => column.parameter_type(),
             Self::DataTypes(column) => column.parameter_type(),
             Self::DataTypeEmbeddings(column) => column.parameter_type(),
+            Self::DataTypeConversions(column) => column.parameter_type(),
             Self::PropertyTypes(column) => column.parameter_type(),
             Self::PropertyTypeEmbeddings(column) => column.parameter_type(),
             Self::EntityTypes(column) => column.parameter_type(),
@@ -1535,6 +1789,7 @@ impl Column<'static> {
             Self::EntityTemporalMetadata(column) => column.parameter_type(),
             Self::EntityEditions(column) => column.parameter_type(),
             Self::EntityEmbeddings(column) => column.parameter_type(),
+            Self::DataTypeInheritsFrom(column) => column.parameter_type(),
             Self::PropertyTypeConstrainsValuesOn(column) => column.parameter_type(),
             Self::PropertyTypeConstrainsPropertiesOn(column) => column.parameter_type(),
             Self::EntityTypeConstrainsPropertiesOn(column, _) => column.parameter_type(),
@@ -1546,6 +1801,7 @@ impl Column<'static> {
             Self::EntityProperties(column) => column.parameter_type(),
             Self::EntityHasLeftEntity(column) => column.parameter_type(),
             Self::EntityHasRightEntity(column) => column.parameter_type(),
+            Self::LinkPath(column, _) => column.parameter_type(),
         }
     }
 }
@@ -1600,13 +1856,15 @@ pub struct AliasedTable {
 
 impl Transpile for AliasedTable {
     fn transpile(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_char('"')?;
+        fmt.write_str(self.table.as_str())?;
+        if let Table::LinkPath(index) = self.table {
+            write!(fmt, "_{index}")?;
+        }
         write!(
             fmt,
-            r#""{}_{}_{}_{}""#,
-            self.table.as_str(),
-            self.alias.condition_index,
-            self.alias.chain_depth,
-            self.alias.number
+            r#"_{}_{}_{}""#,
+            self.alias.condition_index, self.alias.chain_depth, self.alias.number
         )
     }
 }
@@ -1644,6 +1902,7 @@ pub enum Relation {
     EntityIds,
     EntityEditions,
     DataTypeEmbeddings,
+    DataTypeConversions,
     PropertyTypeEmbeddings,
     EntityTypeEmbeddings,
     EntityEmbeddings,
@@ -1746,6 +2005,14 @@ impl Relation {
                     join: Column::DataTypeEmbeddings(DataTypeEmbeddings::OntologyId),
                 })
             }
+            Self::DataTypeConversions => {
+                ForeignKeyJoin::from_reference(ForeignKeyReference::Single {
+                    on: Column::OntologyTemporalMetadata(OntologyTemporalMetadata::OntologyId),
+                    join: Column::DataTypeConversions(
+                        DataTypeConversions::SourceDataTypeOntologyId,
+                    ),
+                })
+            }
             Self::PropertyTypeIds => ForeignKeyJoin::from_reference(ForeignKeyReference::Single {
                 on: Column::OntologyTemporalMetadata(OntologyTemporalMetadata::OntologyId),
                 join: Column::PropertyTypes(PropertyTypes::OntologyId),
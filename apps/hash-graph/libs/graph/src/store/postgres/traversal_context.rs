@@ -1,7 +1,9 @@
 use std::{collections::HashMap, hash::Hash};
 
+use authorization::{zanzibar::Zookie, AuthorizationApi};
 use error_stack::Result;
 use graph_types::{
+    account::AccountId,
     knowledge::entity::{Entity, EntityEditionId},
     ontology::{DataTypeWithMetadata, EntityTypeWithMetadata, PropertyTypeWithMetadata},
 };
@@ -16,10 +18,114 @@ use crate::{
         query::{Filter, FilterExpression, ParameterList},
         AsClient, PostgresStore, QueryError, SubgraphRecord,
     },
-    subgraph::{edges::GraphResolveDepths, temporal_axes::VariableAxis, Subgraph},
+    subgraph::{
+        edges::GraphResolveDepths, identifier::EntityVertexId, temporal_axes::VariableAxis,
+        Subgraph,
+    },
 };
 
+/// The vertices which are yet to be traversed, grouped by their kind.
+#[derive(Debug, Default)]
+pub struct TraversalQueue {
+    pub data_types: Vec<(
+        OntologyId,
+        GraphResolveDepths,
+        RightBoundedTemporalInterval<VariableAxis>,
+    )>,
+    pub property_types: Vec<(
+        OntologyId,
+        GraphResolveDepths,
+        RightBoundedTemporalInterval<VariableAxis>,
+    )>,
+    pub entity_types: Vec<(
+        OntologyId,
+        GraphResolveDepths,
+        RightBoundedTemporalInterval<VariableAxis>,
+    )>,
+    pub entities: Vec<(
+        EntityVertexId,
+        GraphResolveDepths,
+        RightBoundedTemporalInterval<VariableAxis>,
+    )>,
+}
+
+impl TraversalQueue {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.data_types.is_empty()
+            && self.property_types.is_empty()
+            && self.entity_types.is_empty()
+            && self.entities.is_empty()
+    }
+}
+
 impl<C: AsClient> PostgresStore<C> {
+    /// Traverses the graph starting at the vertices in the `queue`.
+    ///
+    /// Edges can be followed in both directions, so traversing one kind of vertex may add
+    /// vertices of any other kind to the queue. The traversal ends when the queue is empty. Draft
+    /// instances of entity types are only added if `include_drafts` is set.
+    #[tracing::instrument(
+        level = "info",
+        skip(self, queue, traversal_context, subgraph, authorization_api, zookie)
+    )]
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "https://linear.app/hash/issue/H-1466/revisit-parameters-to-store-functions"
+    )]
+    pub(crate) async fn traverse<A: AuthorizationApi + Sync>(
+        &self,
+        mut queue: TraversalQueue,
+        traversal_context: &mut TraversalContext,
+        actor_id: AccountId,
+        authorization_api: &A,
+        zookie: &Zookie<'static>,
+        subgraph: &mut Subgraph,
+        include_drafts: bool,
+    ) -> Result<(), QueryError> {
+        while !queue.is_empty() {
+            self.traverse_entities(
+                &mut queue,
+                traversal_context,
+                actor_id,
+                authorization_api,
+                zookie,
+                subgraph,
+            )
+            .await?;
+            self.traverse_entity_types(
+                &mut queue,
+                traversal_context,
+                actor_id,
+                authorization_api,
+                zookie,
+                subgraph,
+                include_drafts,
+            )
+            .await?;
+            self.traverse_property_types(
+                &mut queue,
+                traversal_context,
+                actor_id,
+                authorization_api,
+                zookie,
+                subgraph,
+            )
+            .await?;
+            self.traverse_data_types(
+                &mut queue,
+                traversal_context,
+                actor_id,
+                authorization_api,
+                zookie,
+                subgraph,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
     async fn read_data_types_by_ids(
         &self,
         vertex_ids: impl IntoIterator<Item = OntologyId, IntoIter: Send> + Send,
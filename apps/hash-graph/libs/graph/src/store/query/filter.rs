@@ -1,4 +1,4 @@
-use std::{borrow::Cow, fmt, mem, str::FromStr};
+use std::{borrow::Cow, fmt, mem, num::NonZeroU32, str::FromStr};
 
 use derivative::Derivative;
 use error_stack::{bail, Context, Report, ResultExt};
@@ -6,7 +6,7 @@ use graph_types::{
     knowledge::entity::{Entity, EntityId},
     Embedding,
 };
-use serde::Deserialize;
+use serde::{de, Deserialize, Deserializer};
 use serde_json::{Number, Value};
 use temporal_versioning::Timestamp;
 use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
@@ -24,6 +24,7 @@ use crate::{
 /// A set of conditions used for queries.
 #[derive(Derivative, Deserialize)]
 #[derivative(
+    Clone(bound = "R::QueryPath<'p>: Clone"),
     Debug(bound = "R::QueryPath<'p>: fmt::Debug"),
     PartialEq(bound = "R::QueryPath<'p>: PartialEq")
 )]
@@ -43,6 +44,17 @@ pub enum Filter<'p, R: QueryRecord + ?Sized> {
         Option<FilterExpression<'p, R>>,
         Option<FilterExpression<'p, R>>,
     ),
+    Greater(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    GreaterOrEqual(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    Less(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    LessOrEqual(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Checks if the first expression lies within the range spanned by the second and the third
+    /// expression, both bounds inclusive.
+    Between(
+        FilterExpression<'p, R>,
+        FilterExpression<'p, R>,
+        FilterExpression<'p, R>,
+    ),
     CosineDistance(
         FilterExpression<'p, R>,
         FilterExpression<'p, R>,
@@ -53,6 +65,101 @@ pub enum Filter<'p, R: QueryRecord + ?Sized> {
     StartsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     EndsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     ContainsSegment(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Full-text search of the first expression using the search query in the second expression.
+    ///
+    /// The search query supports the web search syntax, e.g. quoted phrases, `or`, and `-` to
+    /// exclude words.
+    Matches(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches entities from which an entity can be reached by following a [`LinkPathFilter`].
+    ///
+    /// This filter is only supported when querying entities.
+    LinkPath(LinkPathFilter<'p>),
+}
+
+/// A declarative path through the knowledge graph, starting at the entity being filtered.
+///
+/// The path is made up of [`LinkPathHop`]s, each following links to the entity on the other end
+/// of the link. An entity matches the path if any entity reached after the last hop matches
+/// `filter`. If `filter` is not specified, it's sufficient that any entity is reached.
+///
+/// For example, the path `outgoingLinks[type=worksFor]->rightEntity[type=Company].name == 'HASH'`
+/// is represented by a single outgoing hop with a `linkFilter` on the link type, an
+/// `entityFilter` on the entity type, and a `filter` comparing the name property.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields, bound = "'de: 'p")]
+pub struct LinkPathFilter<'p> {
+    pub hops: Vec<LinkPathHop<'p>>,
+    #[serde(default)]
+    pub filter: Option<Box<Filter<'p, Entity>>>,
+}
+
+/// A single step in a [`LinkPathFilter`].
+///
+/// A hop follows between one and `maxDepth` links in the specified direction. Every link followed
+/// has to match `linkFilter` and every entity reached has to match `entityFilter`. `maxDepth` must
+/// not be greater than [`LinkPathHop::MAX_DEPTH`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields, bound = "'de: 'p")]
+pub struct LinkPathHop<'p> {
+    pub direction: LinkDirection,
+    #[serde(default)]
+    pub link_filter: Option<Filter<'p, Entity>>,
+    #[serde(default)]
+    pub entity_filter: Option<Filter<'p, Entity>>,
+    #[serde(
+        default = "LinkPathHop::default_max_depth",
+        deserialize_with = "LinkPathHop::deserialize_max_depth"
+    )]
+    pub max_depth: NonZeroU32,
+}
+
+impl LinkPathHop<'_> {
+    /// The maximum number of links, which can be followed in a single hop.
+    pub const MAX_DEPTH: u32 = 10;
+
+    const fn default_max_depth() -> NonZeroU32 {
+        NonZeroU32::MIN
+    }
+
+    fn deserialize_max_depth<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<NonZeroU32, D::Error> {
+        let max_depth = NonZeroU32::deserialize(deserializer)?;
+        if max_depth.get() > Self::MAX_DEPTH {
+            return Err(de::Error::custom(format!(
+                "`maxDepth` must not be greater than {}",
+                Self::MAX_DEPTH
+            )));
+        }
+        Ok(max_depth)
+    }
+}
+
+/// The direction in which a [`LinkPathHop`] follows links.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LinkDirection {
+    /// Follows the outgoing links of an entity to their right entity.
+    Outgoing,
+    /// Follows the incoming links of an entity to their left entity.
+    Incoming,
+}
+
+impl LinkPathFilter<'_> {
+    fn convert_parameters(&mut self) -> Result<(), Report<ParameterConversionError>> {
+        for hop in &mut self.hops {
+            if let Some(filter) = &mut hop.link_filter {
+                filter.convert_parameters()?;
+            }
+            if let Some(filter) = &mut hop.entity_filter {
+                filter.convert_parameters()?;
+            }
+        }
+        if let Some(filter) = &mut self.filter {
+            filter.convert_parameters()?;
+        }
+        Ok(())
+    }
 }
 
 impl<'p, R> Filter<'p, R>
@@ -141,6 +248,14 @@ where
                 ) => parameter.convert_to_parameter_type(path.expected_type())?,
                 (..) => {}
             },
+            Self::Greater(lhs, rhs)
+            | Self::GreaterOrEqual(lhs, rhs)
+            | Self::Less(lhs, rhs)
+            | Self::LessOrEqual(lhs, rhs) => Self::convert_comparison_parameters(lhs, rhs)?,
+            Self::Between(value, lower, upper) => {
+                Self::convert_comparison_parameters(value, lower)?;
+                Self::convert_comparison_parameters(value, upper)?;
+            }
             Self::CosineDistance(lhs, rhs, max) => {
                 if let FilterExpression::Parameter(parameter) = max {
                     parameter.convert_to_parameter_type(ParameterType::F64)?;
@@ -164,7 +279,8 @@ where
             }
             Self::StartsWith(lhs, rhs)
             | Self::EndsWith(lhs, rhs)
-            | Self::ContainsSegment(lhs, rhs) => {
+            | Self::ContainsSegment(lhs, rhs)
+            | Self::Matches(lhs, rhs) => {
                 // TODO: We need to find a way to support lists in addition to strings as well
                 if let FilterExpression::Parameter(parameter) = lhs {
                     parameter.convert_to_parameter_type(ParameterType::Text)?;
@@ -173,15 +289,30 @@ where
                     parameter.convert_to_parameter_type(ParameterType::Text)?;
                 }
             }
+            Self::LinkPath(link_path) => link_path.convert_parameters()?,
         }
 
         Ok(())
     }
+
+    fn convert_comparison_parameters(
+        lhs: &mut FilterExpression<'p, R>,
+        rhs: &mut FilterExpression<'p, R>,
+    ) -> Result<(), Report<ParameterConversionError>> {
+        match (lhs, rhs) {
+            (FilterExpression::Parameter(parameter), FilterExpression::Path(path))
+            | (FilterExpression::Path(path), FilterExpression::Parameter(parameter)) => {
+                parameter.convert_to_comparable_type(path.expected_type())
+            }
+            (..) => Ok(()),
+        }
+    }
 }
 
 /// A leaf value in a [`Filter`].
 #[derive(Derivative, Deserialize)]
 #[derivative(
+    Clone(bound = "R::QueryPath<'p>: Clone"),
     Debug(bound = "R::QueryPath<'p>: fmt::Debug"),
     PartialEq(bound = "R::QueryPath<'p>: PartialEq")
 )]
@@ -194,7 +325,7 @@ pub enum FilterExpression<'p, R: QueryRecord + ?Sized> {
     Parameter(Parameter<'p>),
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[serde(untagged)]
 pub enum Parameter<'p> {
     Boolean(bool),
@@ -295,6 +426,34 @@ impl fmt::Display for ParameterConversionError {
 impl Context for ParameterConversionError {}
 
 impl Parameter<'_> {
+    /// Converts the parameter to a type, which can be ordered against a value of type `expected`.
+    ///
+    /// Unlike [`convert_to_parameter_type`], this keeps the numeric or temporal nature of a
+    /// parameter when comparing it against a JSON value, so the JSON value can be cast
+    /// accordingly. Strings are always compared as text, even if they look like a timestamp. Time
+    /// intervals are compared by their lower bound, so the parameter is converted to a timestamp.
+    ///
+    /// [`convert_to_parameter_type`]: Self::convert_to_parameter_type
+    fn convert_to_comparable_type(
+        &mut self,
+        expected: ParameterType,
+    ) -> Result<(), Report<ParameterConversionError>> {
+        match (&*self, expected) {
+            (
+                Parameter::I32(_) | Parameter::F64(_) | Parameter::Any(Value::Number(_)),
+                ParameterType::Any,
+            ) => self.convert_to_parameter_type(ParameterType::F64),
+            (Parameter::Any(Value::String(_)), ParameterType::Any) => {
+                self.convert_to_parameter_type(ParameterType::Text)
+            }
+            (Parameter::Text(_), ParameterType::Any) => Ok(()),
+            (_, ParameterType::TimeInterval) => {
+                self.convert_to_parameter_type(ParameterType::Timestamp)
+            }
+            (_, expected) => self.convert_to_parameter_type(expected),
+        }
+    }
+
     #[expect(
         clippy::too_many_lines,
         reason = "This is one big match statement. Structural queries has to be changed in the \
@@ -310,6 +469,7 @@ impl Parameter<'_> {
             | (Parameter::I32(_), ParameterType::I32)
             | (Parameter::F64(_), ParameterType::F64)
             | (Parameter::Text(_), ParameterType::Text)
+            | (Parameter::Timestamp(_), ParameterType::Timestamp)
             | (Parameter::Any(_), ParameterType::Any) => {}
             (Parameter::Vector(_), ParameterType::Vector(rhs)) if **rhs == ParameterType::F64 => {}
 
@@ -339,6 +499,9 @@ impl Parameter<'_> {
                     }
                 })?);
             }
+            (Parameter::I32(number), ParameterType::F64) => {
+                *self = Parameter::F64(f64::from(*number));
+            }
             (Parameter::I32(number), ParameterType::OntologyTypeVersion) => {
                 *self = Parameter::OntologyTypeVersion(OntologyTypeVersion::new(
                     u32::try_from(*number).change_context_lazy(|| ParameterConversionError {
@@ -386,6 +549,22 @@ impl Parameter<'_> {
                 // TODO: validate versioned url
                 //   see https://app.asana.com/0/1202805690238892/1203225514907875/f
             }
+            (Parameter::Text(text), ParameterType::Timestamp) => {
+                *self = Parameter::Timestamp(Timestamp::from_str(&*text).change_context_lazy(
+                    || ParameterConversionError {
+                        actual: self.to_owned().into(),
+                        expected: ParameterType::Timestamp,
+                    },
+                )?);
+            }
+            (Parameter::Any(Value::String(string)), ParameterType::Timestamp) => {
+                *self = Parameter::Timestamp(Timestamp::from_str(string).change_context_lazy(
+                    || ParameterConversionError {
+                        actual: self.to_owned().into(),
+                        expected: ParameterType::Timestamp,
+                    },
+                )?);
+            }
             (Parameter::Text(text), ParameterType::Uuid) => {
                 *self = Parameter::Uuid(Uuid::from_str(&*text).change_context_lazy(|| {
                     ParameterConversionError {
@@ -573,4 +752,70 @@ mod tests {
             &expected,
         );
     }
+
+    #[test]
+    fn link_path() {
+        let expected = json!({
+          "linkPath": {
+            "hops": [
+              {
+                "direction": "outgoing",
+                "linkFilter": { "equal": [
+                  { "path": ["archived"] },
+                  { "parameter": false }
+                ]},
+                "maxDepth": 2
+              },
+              { "direction": "incoming" }
+            ],
+            "filter": { "equal": [
+              { "path": ["ownedById"] },
+              { "parameter": "00000000-0000-0000-0000-000000000000" }
+            ]}
+          }
+        });
+
+        test_filter_representation(
+            &Filter::<Entity>::LinkPath(LinkPathFilter {
+                hops: vec![
+                    LinkPathHop {
+                        direction: LinkDirection::Outgoing,
+                        link_filter: Some(Filter::Equal(
+                            Some(FilterExpression::Path(EntityQueryPath::Archived)),
+                            Some(FilterExpression::Parameter(Parameter::Boolean(false))),
+                        )),
+                        entity_filter: None,
+                        max_depth: NonZeroU32::new(2).expect("2 is not zero"),
+                    },
+                    LinkPathHop {
+                        direction: LinkDirection::Incoming,
+                        link_filter: None,
+                        entity_filter: None,
+                        max_depth: NonZeroU32::MIN,
+                    },
+                ],
+                filter: Some(Box::new(Filter::Equal(
+                    Some(FilterExpression::Path(EntityQueryPath::OwnedById)),
+                    Some(FilterExpression::Parameter(Parameter::Uuid(Uuid::nil()))),
+                ))),
+            }),
+            &expected,
+        );
+    }
+
+    #[test]
+    fn link_path_max_depth() {
+        let hop = |max_depth: u32| {
+            json!({
+              "linkPath": {
+                "hops": [{ "direction": "outgoing", "maxDepth": max_depth }]
+              }
+            })
+        };
+
+        Filter::<Entity>::deserialize(&hop(LinkPathHop::MAX_DEPTH))
+            .expect("could not deserialize filter");
+        Filter::<Entity>::deserialize(&hop(LinkPathHop::MAX_DEPTH + 1))
+            .expect_err("could deserialize filter exceeding the maximum depth");
+    }
 }
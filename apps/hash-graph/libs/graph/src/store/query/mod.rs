@@ -9,7 +9,10 @@ use serde::{
 };
 
 pub use self::{
-    filter::{Filter, FilterExpression, Parameter, ParameterConversionError, ParameterList},
+    filter::{
+        Filter, FilterExpression, LinkDirection, LinkPathFilter, LinkPathHop, Parameter,
+        ParameterConversionError, ParameterList,
+    },
     path::{JsonPath, PathToken},
 };
 
@@ -13,8 +13,11 @@ use error_stack::{ensure, Report, ResultExt};
 use futures::TryStreamExt;
 use graph_types::{
     account::AccountId,
-    knowledge::entity::{Entity, EntityId},
-    ontology::{DataTypeWithMetadata, EntityTypeWithMetadata, PropertyTypeWithMetadata},
+    knowledge::entity::{Entity, EntityId, EntityUuid},
+    ontology::{
+        DataTypeConversion, DataTypeWithMetadata, EntityTypeWithMetadata, PropertyTypeWithMetadata,
+    },
+    owned_by_id::OwnedById,
 };
 use tokio::sync::RwLock;
 use tokio_postgres::GenericClient;
@@ -22,7 +25,7 @@ use type_system::{
     url::{BaseUrl, VersionedUrl},
     ClosedEntityType, DataType, PropertyType,
 };
-use validation::{EntityProvider, EntityTypeProvider, OntologyTypeProvider};
+use validation::{DataTypeProvider, EntityProvider, EntityTypeProvider, OntologyTypeProvider};
 
 use crate::{
     store::{crud::Read, query::Filter, AsClient, PostgresStore, QueryError},
@@ -117,6 +120,7 @@ where
 #[derive(Debug, Default)]
 pub struct StoreCache {
     data_types: CacheHashMap<DataTypeId, DataType>,
+    data_type_conversions: CacheHashMap<DataTypeId, Option<DataTypeConversion>>,
     property_types: CacheHashMap<PropertyTypeId, PropertyType>,
     entity_types: CacheHashMap<EntityTypeId, ClosedEntityType>,
 }
@@ -126,6 +130,10 @@ pub struct StoreProvider<'a, S, A> {
     pub store: &'a S,
     pub cache: StoreCache,
     pub authorization: Option<(&'a A, AccountId, Consistency<'static>)>,
+    /// Entities which are about to be created but are not yet stored.
+    ///
+    /// These are provided before the store is queried, so links between them can be validated.
+    pub pending_entities: Option<&'a HashMap<(OwnedById, EntityUuid), Entity>>,
 }
 
 impl<S, A> StoreProvider<'_, S, A>
@@ -173,7 +181,7 @@ where
             return Err(error);
         }
 
-        let schema = self
+        let data_type: DataTypeWithMetadata = self
             .store
             .read_one(
                 &Filter::for_versioned_url(type_id),
@@ -186,15 +194,54 @@ where
                 ),
                 false,
             )
-            .await
-            .map(|data_type| data_type.schema)?;
+            .await?;
 
-        let schema = self.cache.data_types.grant(data_type_id, schema).await;
+        self.cache
+            .data_type_conversions
+            .grant(data_type_id, data_type.metadata.conversion)
+            .await;
+        let schema = self
+            .cache
+            .data_types
+            .grant(data_type_id, data_type.schema)
+            .await;
 
         Ok(schema)
     }
 }
 
+impl<S, A> DataTypeProvider for StoreProvider<'_, S, A>
+where
+    S: Read<DataTypeWithMetadata>,
+    A: AuthorizationApi + Sync,
+{
+    #[expect(refining_impl_trait)]
+    async fn provide_conversion(
+        &self,
+        data_type_id: &VersionedUrl,
+    ) -> Result<Option<DataTypeConversion>, Report<QueryError>> {
+        let id = DataTypeId::from_url(data_type_id);
+
+        if let Some(cached) = self.cache.data_type_conversions.get(&id).await {
+            return cached.map(|conversion| (*conversion).clone());
+        }
+
+        // Reading the data type populates the conversion cache as well
+        OntologyTypeProvider::<DataType>::provide_type(self, data_type_id).await?;
+
+        self.cache
+            .data_type_conversions
+            .get(&id)
+            .await
+            .unwrap_or_else(|| {
+                Err(Report::new(QueryError).attach_printable(format!(
+                    "The conversion of data type `{data_type_id}` was not cached"
+                )))
+            })
+            .map(|conversion| (*conversion).clone())
+    }
+}
+
 impl<S, A> StoreProvider<'_, S, A>
 where
     S: Read<PropertyTypeWithMetadata>,
@@ -411,6 +458,18 @@ where
         entity_id: EntityId,
         include_drafts: bool,
     ) -> Result<Entity, Report<QueryError>> {
+        if let Some(entity) = self
+            .pending_entities
+            .and_then(|entities| entities.get(&(entity_id.owned_by_id, entity_id.entity_uuid)))
+        {
+            let draft_id = entity.metadata.record_id.entity_id.draft_id;
+            if (include_drafts || draft_id.is_none())
+                && (entity_id.draft_id.is_none() || entity_id.draft_id == draft_id)
+            {
+                return Ok(entity.clone());
+            }
+        }
+
         if let Some((authorization_api, actor_id, consistency)) = self.authorization {
             authorization_api
                 .check_entity_permission(actor_id, EntityPermission::View, entity_id, consistency)
@@ -0,0 +1,352 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use graph_types::knowledge::entity::{Entity, EntityId};
+use serde::Serialize;
+use temporal_versioning::Timestamp;
+use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+#[cfg(feature = "utoipa")]
+use utoipa::{
+    openapi::{OneOfBuilder, Ref, RefOr, Schema},
+    ToSchema,
+};
+
+use crate::{
+    store::knowledge::DiffEntityResult,
+    subgraph::{
+        edges::{
+            AdjacencyList, EdgeDirection, EdgeEndpointSet, Edges, KnowledgeGraphEdgeKind,
+            OntologyEdgeKind, OutwardEdge, SharedEdgeKind,
+        },
+        identifier::{
+            DataTypeVertexId, EntityIdWithInterval, EntityTypeVertexId, EntityVertexId,
+            GraphElementVertexId, PropertyTypeVertexId, VertexId,
+        },
+        temporal_axes::VariableAxis,
+        vertices::Vertices,
+        Subgraph,
+    },
+};
+
+/// Identifies a vertex regardless of the revision of an entity.
+///
+/// Ontology types are identified by their versioned URL while entities are identified by their
+/// entity ID, so different editions of the same entity refer to the same element.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum GraphElementId {
+    Ontology(VersionedUrl),
+    KnowledgeGraph(EntityId),
+}
+
+// WARNING: This MUST be kept up to date with the enum variants.
+//   We have to do this because utoipa doesn't understand serde untagged:
+//   https://github.com/juhaku/utoipa/issues/320
+#[cfg(feature = "utoipa")]
+impl ToSchema<'_> for GraphElementId {
+    fn schema() -> (&'static str, RefOr<Schema>) {
+        (
+            "GraphElementId",
+            OneOfBuilder::new()
+                .item(Ref::from_schema_name("VersionedUrl"))
+                .item(Ref::from_schema_name("EntityId"))
+                .into(),
+        )
+    }
+}
+
+impl From<EntityIdWithInterval> for GraphElementId {
+    fn from(endpoint: EntityIdWithInterval) -> Self {
+        Self::KnowledgeGraph(endpoint.entity_id)
+    }
+}
+
+/// A vertex ID which can be used as the left endpoint of an edge.
+trait LeftEndpoint: VertexId {
+    fn into_graph_element_id(
+        base_id: Self::BaseId,
+        revision_id: Self::RevisionId,
+    ) -> GraphElementId;
+}
+
+macro_rules! impl_ontology_graph_element_id {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl From<$name> for GraphElementId {
+                fn from(vertex_id: $name) -> Self {
+                    Self::Ontology(VersionedUrl {
+                        base_url: vertex_id.base_id,
+                        version: vertex_id.revision_id,
+                    })
+                }
+            }
+
+            impl LeftEndpoint for $name {
+                fn into_graph_element_id(
+                    base_id: BaseUrl,
+                    revision_id: OntologyTypeVersion,
+                ) -> GraphElementId {
+                    GraphElementId::Ontology(VersionedUrl {
+                        base_url: base_id,
+                        version: revision_id,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_ontology_graph_element_id!(DataTypeVertexId, PropertyTypeVertexId, EntityTypeVertexId);
+
+impl LeftEndpoint for EntityVertexId {
+    fn into_graph_element_id(
+        base_id: EntityId,
+        _revision_id: Timestamp<VariableAxis>,
+    ) -> GraphElementId {
+        GraphElementId::KnowledgeGraph(base_id)
+    }
+}
+
+/// The kind of an edge in a [`GraphEdge`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum GraphEdgeKind {
+    Ontology(OntologyEdgeKind),
+    KnowledgeGraph(KnowledgeGraphEdgeKind),
+    Shared(SharedEdgeKind),
+}
+
+// WARNING: This MUST be kept up to date with the enum variants.
+//   We have to do this because utoipa doesn't understand serde untagged:
+//   https://github.com/juhaku/utoipa/issues/320
+#[cfg(feature = "utoipa")]
+impl ToSchema<'_> for GraphEdgeKind {
+    fn schema() -> (&'static str, RefOr<Schema>) {
+        (
+            "GraphEdgeKind",
+            OneOfBuilder::new()
+                .item(Ref::from_schema_name(OntologyEdgeKind::schema().0))
+                .item(Ref::from_schema_name(KnowledgeGraphEdgeKind::schema().0))
+                .item(Ref::from_schema_name(SharedEdgeKind::schema().0))
+                .into(),
+        )
+    }
+}
+
+impl From<OntologyEdgeKind> for GraphEdgeKind {
+    fn from(kind: OntologyEdgeKind) -> Self {
+        Self::Ontology(kind)
+    }
+}
+
+impl From<KnowledgeGraphEdgeKind> for GraphEdgeKind {
+    fn from(kind: KnowledgeGraphEdgeKind) -> Self {
+        Self::KnowledgeGraph(kind)
+    }
+}
+
+impl From<SharedEdgeKind> for GraphEdgeKind {
+    fn from(kind: SharedEdgeKind) -> Self {
+        Self::Shared(kind)
+    }
+}
+
+/// An edge between two graph elements, regardless of the revisions of the entities it connects.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GraphEdge {
+    pub left_endpoint: GraphElementId,
+    pub kind: GraphEdgeKind,
+    pub reversed: bool,
+    pub right_endpoint: GraphElementId,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase", tag = "op")]
+pub enum VertexDiff {
+    Added {
+        added: GraphElementVertexId,
+    },
+    Removed {
+        removed: GraphElementVertexId,
+    },
+    /// An entity which is part of both subgraphs but resolved to a different edition.
+    Changed {
+        old: EntityVertexId,
+        new: EntityVertexId,
+        diff: DiffEntityResult<'static>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase", tag = "op")]
+pub enum EdgeDiff {
+    Added { added: GraphEdge },
+    Removed { removed: GraphEdge },
+}
+
+/// The vertices and edges which differ between two subgraphs.
+#[derive(Debug, Default, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SubgraphDiff {
+    pub vertices: Vec<VertexDiff>,
+    pub edges: Vec<EdgeDiff>,
+}
+
+impl Subgraph {
+    /// Compares this subgraph with a `new` subgraph.
+    ///
+    /// Ontology vertices are compared by their versioned URL. Entities are compared by their
+    /// [`EntityId`], if a subgraph contains multiple revisions of an entity, only the latest
+    /// revision is taken into account. An entity is reported as changed if the latest revisions
+    /// in both subgraphs are different editions.
+    ///
+    /// Edges are compared regardless of the revisions of the entities they connect.
+    #[must_use]
+    pub fn diff(self, new: Self) -> SubgraphDiff {
+        SubgraphDiff {
+            vertices: self.vertices.diff(&new.vertices),
+            edges: self.edges.diff(new.edges),
+        }
+    }
+}
+
+impl Vertices {
+    fn diff(&self, new: &Self) -> Vec<VertexDiff> {
+        let mut diffs = Vec::new();
+        diff_vertex_ids(&self.data_types, &new.data_types, &mut diffs);
+        diff_vertex_ids(&self.property_types, &new.property_types, &mut diffs);
+        diff_vertex_ids(&self.entity_types, &new.entity_types, &mut diffs);
+
+        let old_entities = latest_entities(&self.entities);
+        let new_entities = latest_entities(&new.entities);
+        for (entity_id, (old_vertex_id, old_entity)) in &old_entities {
+            match new_entities.get(entity_id) {
+                None => diffs.push(VertexDiff::Removed {
+                    removed: (*old_vertex_id).into(),
+                }),
+                Some((new_vertex_id, new_entity))
+                    if old_entity.metadata.record_id.edition_id
+                        != new_entity.metadata.record_id.edition_id =>
+                {
+                    diffs.push(VertexDiff::Changed {
+                        old: *old_vertex_id,
+                        new: *new_vertex_id,
+                        diff: DiffEntityResult::from_entities(old_entity, new_entity),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        diffs.extend(
+            new_entities
+                .iter()
+                .filter(|(entity_id, _)| !old_entities.contains_key(entity_id))
+                .map(|(_, (new_vertex_id, _))| VertexDiff::Added {
+                    added: (*new_vertex_id).into(),
+                }),
+        );
+
+        diffs
+    }
+}
+
+fn diff_vertex_ids<V, R>(old: &HashMap<V, R>, new: &HashMap<V, R>, diffs: &mut Vec<VertexDiff>)
+where
+    V: Clone + Eq + Hash + Into<GraphElementVertexId>,
+{
+    diffs.extend(
+        old.keys()
+            .filter(|vertex_id| !new.contains_key(vertex_id))
+            .map(|vertex_id| VertexDiff::Removed {
+                removed: vertex_id.clone().into(),
+            }),
+    );
+    diffs.extend(
+        new.keys()
+            .filter(|vertex_id| !old.contains_key(vertex_id))
+            .map(|vertex_id| VertexDiff::Added {
+                added: vertex_id.clone().into(),
+            }),
+    );
+}
+
+/// Returns the latest revision of every entity in `entities`.
+fn latest_entities(
+    entities: &HashMap<EntityVertexId, Entity>,
+) -> HashMap<EntityId, (EntityVertexId, &Entity)> {
+    let mut latest = HashMap::<EntityId, (EntityVertexId, &Entity)>::new();
+    for (vertex_id, entity) in entities {
+        latest
+            .entry(vertex_id.base_id)
+            .and_modify(|(latest_vertex_id, latest_entity)| {
+                if vertex_id.revision_id > latest_vertex_id.revision_id {
+                    *latest_vertex_id = *vertex_id;
+                    *latest_entity = entity;
+                }
+            })
+            .or_insert((*vertex_id, entity));
+    }
+    latest
+}
+
+impl Edges {
+    fn diff(self, new: Self) -> Vec<EdgeDiff> {
+        let old_edges = self.into_graph_edges();
+        let new_edges = new.into_graph_edges();
+
+        old_edges
+            .difference(&new_edges)
+            .map(|removed| EdgeDiff::Removed {
+                removed: removed.clone(),
+            })
+            .chain(
+                new_edges
+                    .difference(&old_edges)
+                    .map(|added| EdgeDiff::Added {
+                        added: added.clone(),
+                    }),
+            )
+            .collect()
+    }
+
+    fn into_graph_edges(self) -> HashSet<GraphEdge> {
+        let mut edges = HashSet::new();
+        collect_graph_edges(self.entity_to_entity, &mut edges);
+        collect_graph_edges(self.entity_to_entity_type, &mut edges);
+        collect_graph_edges(self.entity_type_to_entity, &mut edges);
+        collect_graph_edges(self.entity_type_to_entity_type, &mut edges);
+        collect_graph_edges(self.entity_type_to_property_type, &mut edges);
+        collect_graph_edges(self.property_type_to_entity_type, &mut edges);
+        collect_graph_edges(self.property_type_to_property_type, &mut edges);
+        collect_graph_edges(self.property_type_to_data_type, &mut edges);
+        collect_graph_edges(self.data_type_to_property_type, &mut edges);
+        collect_graph_edges(self.data_type_to_data_type, &mut edges);
+        edges
+    }
+}
+
+fn collect_graph_edges<V, K, E>(edges: AdjacencyList<V, K, E>, graph_edges: &mut HashSet<GraphEdge>)
+where
+    V: LeftEndpoint<BaseId: Clone, RevisionId: Ord>,
+    K: Copy + Into<GraphEdgeKind>,
+    E: EdgeEndpointSet<EdgeEndpoint: Into<GraphElementId>>,
+{
+    for (base_id, revisions) in edges.into_flattened::<OutwardEdge<K, E::EdgeEndpoint>>() {
+        for (revision_id, outward_edges) in revisions {
+            let left_endpoint = V::into_graph_element_id(base_id.clone(), revision_id);
+            graph_edges.extend(outward_edges.into_iter().map(|edge| GraphEdge {
+                left_endpoint: left_endpoint.clone(),
+                kind: edge.kind.into(),
+                reversed: edge.direction == EdgeDirection::Incoming,
+                right_endpoint: edge.right_endpoint.into(),
+            }));
+        }
+    }
+}
@@ -1,10 +1,10 @@
 use std::{
-    collections::{BTreeSet, HashMap, HashSet},
+    collections::{HashMap, HashSet},
     hash::{BuildHasher, Hash},
 };
 
 use graph_types::knowledge::entity::EntityId;
-use temporal_versioning::LeftClosedTemporalInterval;
+use temporal_versioning::LeftClosedTemporalIntervalSet;
 
 use crate::subgraph::{
     identifier::{EdgeEndpoint, EntityIdWithInterval},
@@ -27,7 +27,7 @@ impl<S: BuildHasher, E: EdgeEndpoint + Eq + Hash> EdgeEndpointSet for HashSet<E,
 
 #[derive(Debug, Default)]
 pub struct EntityIdWithIntervalSet {
-    inner: HashMap<EntityId, BTreeSet<LeftClosedTemporalInterval<VariableAxis>>>,
+    inner: HashMap<EntityId, LeftClosedTemporalIntervalSet<VariableAxis>>,
 }
 
 impl IntoIterator for EntityIdWithIntervalSet {
@@ -37,7 +37,7 @@ impl IntoIterator for EntityIdWithIntervalSet {
 
     fn into_iter(self) -> Self::IntoIter {
         self.inner.into_iter().flat_map(|(entity_id, intervals)| {
-            // This merges overlapping intervals
+            // Overlapping and adjacent intervals are already merged by the interval set
             //  Examples   |       1       |       2       |       3
             //  ===========|===============|===============|===============
             //  Interval A | [--]          | (--]          | [--]
@@ -46,37 +46,7 @@ impl IntoIterator for EntityIdWithIntervalSet {
             //  Interval D |           [-] |           [-] |        [----]
             //  -----------|---------------|---------------|---------------
             //  Union      | [------]  [-] | (------)  [-] | [-----------]
-
-            // TODO: This is a very primitive implementation. Instead of iterating over all elements
-            //       and merging them, we should use a data structure that allows for efficient
-            //       merging of intervals. This is probably a tree structure that allows for
-            //       efficient merging of overlapping intervals.
-            //   See https://en.wikipedia.org/wiki/Interval_tree
-            //    or https://en.wikipedia.org/wiki/Segment_tree
-            //       For simplicity, we stick with this fairly robust implementation for now, but
-            //       as a short-term optimization, we could utilize `#![feature(generators)]` to
-            //       avoid the allocation of the intermediate vector.
             intervals
-                .into_iter()
-                .fold(
-                    Vec::<LeftClosedTemporalInterval<VariableAxis>>::new(),
-                    |mut acc, interval| {
-                        // The intervals are sorted, it's only necessary to check the union of this
-                        // with the last interval, if it overlaps two of the previous ones (which
-                        // would make it necessary to check the union with more than just the last)
-                        // then those would have been merged into one in the previous iteration
-                        // (again because they are sorted).
-                        if let Some(last) = acc.pop() {
-                            // `union` either returns one or two intervals, depending on whether
-                            // they overlap or not. If two intervals are returned, the ordering is
-                            // stable, so we can just push them in order.
-                            acc.extend(last.union(interval));
-                        } else {
-                            acc.push(interval);
-                        }
-                        acc
-                    },
-                )
                 .into_iter()
                 .map(move |interval| EntityIdWithInterval {
                     entity_id,
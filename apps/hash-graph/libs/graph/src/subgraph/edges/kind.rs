@@ -75,6 +75,17 @@ impl EdgeKind<EntityTypeVertexId, PropertyTypeVertexId> for OntologyEdgeKind {
     }
 }
 
+impl EdgeKind<PropertyTypeVertexId, EntityTypeVertexId> for OntologyEdgeKind {
+    type EdgeSet = HashSet<EntityTypeVertexId>;
+
+    fn subgraph_entry_mut<'a>(
+        &self,
+        edges: &'a mut Edges,
+    ) -> &'a mut AdjacencyList<PropertyTypeVertexId, Self, Self::EdgeSet> {
+        &mut edges.property_type_to_entity_type
+    }
+}
+
 impl EdgeKind<PropertyTypeVertexId, PropertyTypeVertexId> for OntologyEdgeKind {
     type EdgeSet = HashSet<PropertyTypeVertexId>;
 
@@ -97,6 +108,28 @@ impl EdgeKind<PropertyTypeVertexId, DataTypeVertexId> for OntologyEdgeKind {
     }
 }
 
+impl EdgeKind<DataTypeVertexId, DataTypeVertexId> for OntologyEdgeKind {
+    type EdgeSet = HashSet<DataTypeVertexId>;
+
+    fn subgraph_entry_mut<'a>(
+        &self,
+        edges: &'a mut Edges,
+    ) -> &'a mut AdjacencyList<DataTypeVertexId, Self, Self::EdgeSet> {
+        &mut edges.data_type_to_data_type
+    }
+}
+
+impl EdgeKind<DataTypeVertexId, PropertyTypeVertexId> for OntologyEdgeKind {
+    type EdgeSet = HashSet<PropertyTypeVertexId>;
+
+    fn subgraph_entry_mut<'a>(
+        &self,
+        edges: &'a mut Edges,
+    ) -> &'a mut AdjacencyList<DataTypeVertexId, Self, Self::EdgeSet> {
+        &mut edges.data_type_to_property_type
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 #[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -147,6 +180,17 @@ impl EdgeKind<EntityVertexId, EntityTypeVertexId> for SharedEdgeKind {
     }
 }
 
+impl EdgeKind<EntityTypeVertexId, EntityIdWithInterval> for SharedEdgeKind {
+    type EdgeSet = EntityIdWithIntervalSet;
+
+    fn subgraph_entry_mut<'a>(
+        &self,
+        edges: &'a mut Edges,
+    ) -> &'a mut AdjacencyList<EntityTypeVertexId, Self, Self::EdgeSet> {
+        &mut edges.entity_type_to_entity
+    }
+}
+
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[serde(deny_unknown_fields)]
@@ -169,9 +213,8 @@ impl EdgeResolveDepths {
 #[serde(deny_unknown_fields)]
 pub struct OutgoingEdgeResolveDepth {
     pub outgoing: u8,
-    #[serde(default, skip)]
-    #[doc(hidden)]
-    /// This is not used yet, but will be used in the future to support incoming edges.
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     pub incoming: u8,
 }
 
@@ -1,3 +1,4 @@
+pub mod diff;
 pub mod edges;
 pub mod identifier;
 pub mod query;
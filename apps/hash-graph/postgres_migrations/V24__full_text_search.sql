@@ -0,0 +1,39 @@
+-- Full text search is backed by expression indexes instead of generated `tsvector` columns:
+--
+-- - Search filters may target any JSON path of the properties or the schema, so the `tsvector` has
+--   to be computed by the query for these anyway. The indexes only cover the expressions emitted
+--   for the common cases, i.e. searching all string properties or the title and description.
+-- - Generated columns become part of the row types, which the snapshot restore uses to insert
+--   editions and types through `UNNEST` and to copy its temporary tables with `SELECT *`. Generated
+--   columns cannot be written to, so all of these statements would have to list their columns.
+--
+-- The expressions have to match the ones emitted by `Function::ToTsVector` and
+-- `Function::JsonToTsVector` exactly, including the text search configuration, as otherwise the
+-- planner does not use the indexes.
+CREATE INDEX "entity_editions_properties_search_idx"
+    ON "entity_editions"
+    USING gin (jsonb_to_tsvector('english', "properties", '["string"]'));
+
+CREATE INDEX "data_types_title_search_idx"
+    ON "data_types"
+    USING gin (to_tsvector('english', "schema"->>'title'));
+
+CREATE INDEX "data_types_description_search_idx"
+    ON "data_types"
+    USING gin (to_tsvector('english', "schema"->>'description'));
+
+CREATE INDEX "property_types_title_search_idx"
+    ON "property_types"
+    USING gin (to_tsvector('english', "schema"->>'title'));
+
+CREATE INDEX "property_types_description_search_idx"
+    ON "property_types"
+    USING gin (to_tsvector('english', "schema"->>'description'));
+
+CREATE INDEX "entity_types_title_search_idx"
+    ON "entity_types"
+    USING gin (to_tsvector('english', "schema"->>'title'));
+
+CREATE INDEX "entity_types_description_search_idx"
+    ON "entity_types"
+    USING gin (to_tsvector('english', "schema"->>'description'));
@@ -0,0 +1,29 @@
+CREATE TYPE entity_change_kind AS ENUM ('create', 'patch', 'archive', 'publish', 'delete');
+
+-- `entity_change_events` is an outbox of all changes made to entities. Rows are written in the same
+-- transaction as the change itself, so a change only becomes visible in the feed after it was
+-- committed.
+--
+-- Sequence ids are assigned when the row is inserted, not when the transaction commits, so they
+-- cannot be used as a cursor on their own. Instead, the feed only returns events of transactions
+-- older than the oldest running transaction and orders them by `(transaction_id, sequence_id)`.
+--
+-- Deleting an entity removes all of its records but keeps its `delete` event, so events do not
+-- reference the entity or its editions. `delete` events do not refer to an edition.
+CREATE TABLE entity_change_events (
+    sequence_id                 BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    transaction_id              BIGINT NOT NULL DEFAULT pg_current_xact_id()::TEXT::BIGINT,
+    web_id                      UUID NOT NULL,
+    entity_uuid                 UUID NOT NULL,
+    draft_id                    UUID,
+    entity_edition_id           UUID,
+    kind                        entity_change_kind NOT NULL,
+    transaction_time            TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
+
+CREATE INDEX entity_change_events_cursor_idx
+    ON entity_change_events (transaction_id, sequence_id);
+
+-- Used to prune events which are older than the configured retention.
+CREATE INDEX entity_change_events_transaction_time_idx
+    ON entity_change_events (transaction_time);
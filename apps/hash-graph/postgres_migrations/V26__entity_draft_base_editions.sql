@@ -0,0 +1,4 @@
+-- The live edition a draft was created from. When the draft is published after the live entity was
+-- updated in the meantime, this edition is used as the common ancestor to merge both changes.
+ALTER TABLE entity_drafts
+    ADD COLUMN base_edition_id UUID REFERENCES entity_editions ON DELETE SET NULL;
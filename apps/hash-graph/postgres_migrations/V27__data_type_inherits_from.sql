@@ -0,0 +1,5 @@
+CREATE TABLE "data_type_inherits_from" (
+    "source_data_type_ontology_id" UUID NOT NULL REFERENCES "data_types",
+    "target_data_type_ontology_id" UUID NOT NULL REFERENCES "data_types",
+    PRIMARY KEY ("source_data_type_ontology_id", "target_data_type_ontology_id")
+);
@@ -0,0 +1,21 @@
+CREATE TABLE "data_type_conversions" (
+    "source_data_type_ontology_id" UUID PRIMARY KEY REFERENCES "data_types",
+    "target_data_type_base_url" TEXT NOT NULL REFERENCES "base_urls",
+    "factor" DOUBLE PRECISION NOT NULL
+);
+
+ALTER TABLE entity_property
+ADD COLUMN data_type_ontology_id UUID REFERENCES data_types,
+ADD COLUMN normalized_value DOUBLE PRECISION;
+
+DROP VIEW entity_properties;
+CREATE VIEW entity_properties AS
+SELECT
+    entity_property.entity_edition_id,
+    array_agg(entity_property.property_path) AS property_paths,
+    array_agg(entity_property.confidence) AS confidences,
+    array_agg(ontology_ids.base_url) AS data_type_base_urls,
+    array_agg(ontology_ids.version) AS data_type_versions
+FROM entity_property
+LEFT OUTER JOIN ontology_ids ON ontology_ids.ontology_id = entity_property.data_type_ontology_id
+GROUP BY entity_property.entity_edition_id;
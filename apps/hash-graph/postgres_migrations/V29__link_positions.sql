@@ -0,0 +1,7 @@
+ALTER TABLE entity_has_left_entity
+ADD COLUMN position TEXT COLLATE "C";
+
+CREATE INDEX entity_has_left_entity_position_idx
+ON entity_has_left_entity (left_web_id, left_entity_uuid, position);
+
+ALTER TYPE entity_change_kind ADD VALUE 'move';
@@ -0,0 +1,15 @@
+ALTER TABLE entity_property
+ADD COLUMN provenance JSONB;
+
+DROP VIEW entity_properties;
+CREATE VIEW entity_properties AS
+SELECT
+    entity_property.entity_edition_id,
+    array_agg(entity_property.property_path) AS property_paths,
+    array_agg(entity_property.confidence) AS confidences,
+    array_agg(ontology_ids.base_url) AS data_type_base_urls,
+    array_agg(ontology_ids.version) AS data_type_versions,
+    array_agg(entity_property.provenance) AS provenances
+FROM entity_property
+LEFT OUTER JOIN ontology_ids ON ontology_ids.ontology_id = entity_property.data_type_ontology_id
+GROUP BY entity_property.entity_edition_id;
@@ -0,0 +1,286 @@
+use super::refinement::{allowed_values, as_f64, is_multiple_of};
+use crate::{
+    ontology::shared::compatibility::{metadata_changes, ReferenceChanges},
+    Compatibility, DataType, DataTypeReference, JsonSchemaValueType, OntologyTypeChange,
+    OntologyTypeChangeKind,
+};
+
+/// Reports how a constraint changed, `is_narrowed` returns `None` if the values cannot be ordered.
+fn constraint_change<T: PartialEq>(
+    constraint: &str,
+    previous: Option<T>,
+    updated: Option<T>,
+    is_narrowed: impl FnOnce(&T, &T) -> Option<bool>,
+) -> Option<OntologyTypeChange> {
+    let constraint = constraint.to_owned();
+    match (previous, updated) {
+        (None, None) => None,
+        (Some(previous), Some(updated)) if previous == updated => None,
+        (None, Some(_)) => Some(OntologyTypeChange::breaking(
+            OntologyTypeChangeKind::AddedConstraint { constraint },
+        )),
+        (Some(_), None) => Some(OntologyTypeChange::backwards_compatible(
+            OntologyTypeChangeKind::RemovedConstraint { constraint },
+        )),
+        (Some(previous), Some(updated)) => Some(match is_narrowed(&updated, &previous) {
+            Some(true) => {
+                OntologyTypeChange::breaking(OntologyTypeChangeKind::NarrowedConstraint {
+                    constraint,
+                })
+            }
+            Some(false) => OntologyTypeChange::backwards_compatible(
+                OntologyTypeChangeKind::WidenedConstraint { constraint },
+            ),
+            None => OntologyTypeChange::breaking(OntologyTypeChangeKind::ChangedConstraint {
+                constraint,
+            }),
+        }),
+    }
+}
+
+impl DataType {
+    /// Returns the changes from this data type to `updated`.
+    ///
+    /// A change is breaking if a value which is valid for this data type may be invalid for
+    /// `updated`. Inheriting from an additional data type is breaking as its constraints are
+    /// applied as well. Patterns cannot be compared, so any changed pattern is breaking.
+    #[must_use]
+    pub fn changes_to(&self, updated: &Self) -> Vec<OntologyTypeChange> {
+        let mut changes = Vec::new();
+
+        metadata_changes(
+            (self.title(), self.description()),
+            (updated.title(), updated.description()),
+            &mut changes,
+        );
+
+        let parents = ReferenceChanges::new(
+            self.inherits_from()
+                .all_of()
+                .iter()
+                .map(DataTypeReference::url),
+            updated
+                .inherits_from()
+                .all_of()
+                .iter()
+                .map(DataTypeReference::url),
+        );
+        changes.extend(parents.added.iter().map(|parent| {
+            OntologyTypeChange::breaking(OntologyTypeChangeKind::AddedParent {
+                parent: (*parent).clone(),
+            })
+        }));
+        changes.extend(parents.removed.iter().map(|parent| {
+            OntologyTypeChange::backwards_compatible(OntologyTypeChangeKind::RemovedParent {
+                parent: (*parent).clone(),
+            })
+        }));
+        parents.push_version_changes(&mut changes);
+
+        if self.json_type() != updated.json_type() {
+            changes.push(OntologyTypeChange::new(
+                OntologyTypeChangeKind::ChangedValueType {
+                    from: self.json_type(),
+                    to: updated.json_type(),
+                },
+                if self.json_type() == JsonSchemaValueType::Integer
+                    && updated.json_type() == JsonSchemaValueType::Number
+                {
+                    Compatibility::BackwardsCompatible
+                } else {
+                    Compatibility::Breaking
+                },
+            ));
+        }
+
+        let previous = self.constraints();
+        let constraints = updated.constraints();
+
+        let lower_bound = |updated: &f64, previous: &f64| Some(updated > previous);
+        let upper_bound = |updated: &f64, previous: &f64| Some(updated < previous);
+        let multiple_of = |updated: &f64, previous: &f64| {
+            if is_multiple_of(*updated, *previous) {
+                Some(true)
+            } else if is_multiple_of(*previous, *updated) {
+                Some(false)
+            } else {
+                None
+            }
+        };
+
+        changes.extend(
+            [
+                constraint_change(
+                    "minimum",
+                    as_f64(previous.minimum.as_ref()),
+                    as_f64(constraints.minimum.as_ref()),
+                    lower_bound,
+                ),
+                constraint_change(
+                    "exclusiveMinimum",
+                    as_f64(previous.exclusive_minimum.as_ref()),
+                    as_f64(constraints.exclusive_minimum.as_ref()),
+                    lower_bound,
+                ),
+                constraint_change(
+                    "maximum",
+                    as_f64(previous.maximum.as_ref()),
+                    as_f64(constraints.maximum.as_ref()),
+                    upper_bound,
+                ),
+                constraint_change(
+                    "exclusiveMaximum",
+                    as_f64(previous.exclusive_maximum.as_ref()),
+                    as_f64(constraints.exclusive_maximum.as_ref()),
+                    upper_bound,
+                ),
+                constraint_change(
+                    "multipleOf",
+                    as_f64(previous.multiple_of.as_ref()),
+                    as_f64(constraints.multiple_of.as_ref()),
+                    multiple_of,
+                ),
+                constraint_change(
+                    "minLength",
+                    previous.min_length,
+                    constraints.min_length,
+                    |updated, previous| Some(updated > previous),
+                ),
+                constraint_change(
+                    "maxLength",
+                    previous.max_length,
+                    constraints.max_length,
+                    |updated, previous| Some(updated < previous),
+                ),
+                constraint_change(
+                    "pattern",
+                    previous.pattern.as_ref(),
+                    constraints.pattern.as_ref(),
+                    |_, _| None,
+                ),
+                constraint_change("format", previous.format, constraints.format, |_, _| None),
+                constraint_change(
+                    if constraints.const_value.is_some()
+                        || (constraints.enum_values.is_none() && previous.const_value.is_some())
+                    {
+                        "const"
+                    } else {
+                        "enum"
+                    },
+                    allowed_values(previous),
+                    allowed_values(constraints),
+                    |updated, previous| {
+                        if previous.iter().all(|value| updated.contains(value)) {
+                            Some(false)
+                        } else if updated.iter().all(|value| previous.contains(value)) {
+                            Some(true)
+                        } else {
+                            None
+                        }
+                    },
+                ),
+            ]
+            .into_iter()
+            .flatten(),
+        );
+
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as JsonValue};
+
+    use super::*;
+
+    fn data_type(constraints: &JsonValue) -> DataType {
+        let mut data_type = json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "$id": "https://example.com/data-type/age/v/1",
+            "title": "Age",
+            "type": "integer",
+        });
+        data_type
+            .as_object_mut()
+            .expect("data type is an object")
+            .extend(
+                constraints
+                    .as_object()
+                    .expect("constraints are an object")
+                    .clone(),
+            );
+        serde_json::from_value(data_type).expect("invalid data type")
+    }
+
+    fn changes(previous: &JsonValue, updated: &JsonValue) -> Vec<OntologyTypeChange> {
+        data_type(previous).changes_to(&data_type(updated))
+    }
+
+    #[test]
+    fn unchanged() {
+        assert!(changes(&json!({ "minimum": 0 }), &json!({ "minimum": 0 })).is_empty());
+    }
+
+    #[test]
+    fn narrowed_constraint() {
+        assert_eq!(
+            changes(&json!({ "minimum": 0 }), &json!({ "minimum": 18 })),
+            [OntologyTypeChange::breaking(
+                OntologyTypeChangeKind::NarrowedConstraint {
+                    constraint: "minimum".to_owned()
+                }
+            )]
+        );
+        assert_eq!(
+            changes(&json!({}), &json!({ "maximum": 150 })),
+            [OntologyTypeChange::breaking(
+                OntologyTypeChangeKind::AddedConstraint {
+                    constraint: "maximum".to_owned()
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn widened_constraint() {
+        assert_eq!(
+            changes(&json!({ "enum": [1, 2] }), &json!({ "enum": [1, 2, 3] })),
+            [OntologyTypeChange::backwards_compatible(
+                OntologyTypeChangeKind::WidenedConstraint {
+                    constraint: "enum".to_owned()
+                }
+            )]
+        );
+        assert_eq!(
+            changes(&json!({ "multipleOf": 4 }), &json!({})),
+            [OntologyTypeChange::backwards_compatible(
+                OntologyTypeChangeKind::RemovedConstraint {
+                    constraint: "multipleOf".to_owned()
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn changed_value_type() {
+        let number: DataType = serde_json::from_value(json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "$id": "https://example.com/data-type/age/v/2",
+            "title": "Age",
+            "type": "number",
+        }))
+        .expect("invalid data type");
+
+        assert!(data_type(&json!({}))
+            .changes_to(&number)
+            .iter()
+            .all(|change| !change.is_breaking()));
+        assert!(number
+            .changes_to(&data_type(&json!({})))
+            .iter()
+            .any(OntologyTypeChange::is_breaking));
+    }
+}
@@ -0,0 +1,431 @@
+use core::fmt;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Number as JsonNumber, Value as JsonValue};
+
+use crate::{JsonSchemaValueType, ParseDataTypeConstraintError};
+
+/// A format a string value has to conform to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StringFormat {
+    Uri,
+    Uuid,
+    Regex,
+    Email,
+    Ipv4,
+    Ipv6,
+    Hostname,
+    DateTime,
+    Date,
+    Time,
+    Duration,
+}
+
+impl StringFormat {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Uri => "uri",
+            Self::Uuid => "uuid",
+            Self::Regex => "regex",
+            Self::Email => "email",
+            Self::Ipv4 => "ipv4",
+            Self::Ipv6 => "ipv6",
+            Self::Hostname => "hostname",
+            Self::DateTime => "date-time",
+            Self::Date => "date",
+            Self::Time => "time",
+            Self::Duration => "duration",
+        }
+    }
+}
+
+impl fmt::Display for StringFormat {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
+/// A regular expression a string value has to match.
+///
+/// The expression is compiled once when the data type is parsed. An expression which cannot be
+/// compiled is kept, so data types stored before patterns were checked can still be read, and is
+/// rejected by [`DataType::validate_constraints`].
+///
+/// [`DataType::validate_constraints`]: crate::DataType::validate_constraints
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    source: String,
+    regex: Result<Regex, String>,
+}
+
+impl Pattern {
+    /// Compiles the regular expression.
+    #[must_use]
+    pub fn new(pattern: String) -> Self {
+        let regex = Regex::new(&pattern).map_err(|error| error.to_string());
+        Self {
+            source: pattern,
+            regex,
+        }
+    }
+
+    /// Returns the compiled regular expression.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidPattern`] if the expression cannot be compiled
+    ///
+    /// [`InvalidPattern`]: ParseDataTypeConstraintError::InvalidPattern
+    pub fn regex(&self) -> Result<&Regex, ParseDataTypeConstraintError> {
+        self.regex
+            .as_ref()
+            .map_err(|reason| ParseDataTypeConstraintError::InvalidPattern {
+                pattern: self.source.clone(),
+                reason: reason.clone(),
+            })
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+}
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Pattern {}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
+/// The constraints a value of a [`DataType`] has to fulfill.
+///
+/// [`DataType`]: crate::DataType
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DataTypeConstraints {
+    pub const_value: Option<JsonValue>,
+    pub enum_values: Option<Vec<JsonValue>>,
+    pub minimum: Option<JsonNumber>,
+    pub maximum: Option<JsonNumber>,
+    pub exclusive_minimum: Option<JsonNumber>,
+    pub exclusive_maximum: Option<JsonNumber>,
+    pub multiple_of: Option<JsonNumber>,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub pattern: Option<Pattern>,
+    pub format: Option<StringFormat>,
+}
+
+impl DataTypeConstraints {
+    /// Checks that the constraints can be applied to values of `json_type` and are satisfiable.
+    ///
+    /// # Errors
+    ///
+    /// - [`UnsupportedConstraint`] if a constraint does not apply to values of `json_type`
+    /// - [`InvalidPattern`] if `pattern` cannot be compiled
+    /// - [`NonPositiveMultipleOf`] if `multipleOf` is not greater than zero
+    /// - [`EmptyRange`] if a lower bound and an upper bound do not allow any value
+    ///
+    /// [`UnsupportedConstraint`]: ParseDataTypeConstraintError::UnsupportedConstraint
+    /// [`InvalidPattern`]: ParseDataTypeConstraintError::InvalidPattern
+    /// [`NonPositiveMultipleOf`]: ParseDataTypeConstraintError::NonPositiveMultipleOf
+    /// [`EmptyRange`]: ParseDataTypeConstraintError::EmptyRange
+    pub(crate) fn check(
+        &self,
+        json_type: JsonSchemaValueType,
+    ) -> Result<(), ParseDataTypeConstraintError> {
+        let is_numeric = matches!(
+            json_type,
+            JsonSchemaValueType::Number | JsonSchemaValueType::Integer
+        );
+        let is_string = json_type == JsonSchemaValueType::String;
+
+        for (key, is_set, is_supported) in [
+            ("minimum", self.minimum.is_some(), is_numeric),
+            ("maximum", self.maximum.is_some(), is_numeric),
+            (
+                "exclusiveMinimum",
+                self.exclusive_minimum.is_some(),
+                is_numeric,
+            ),
+            (
+                "exclusiveMaximum",
+                self.exclusive_maximum.is_some(),
+                is_numeric,
+            ),
+            ("multipleOf", self.multiple_of.is_some(), is_numeric),
+            ("minLength", self.min_length.is_some(), is_string),
+            ("maxLength", self.max_length.is_some(), is_string),
+            ("pattern", self.pattern.is_some(), is_string),
+            ("format", self.format.is_some(), is_string),
+        ] {
+            if is_set && !is_supported {
+                return Err(ParseDataTypeConstraintError::UnsupportedConstraint {
+                    key: key.to_owned(),
+                    json_type,
+                });
+            }
+        }
+
+        if let Some(pattern) = &self.pattern {
+            pattern.regex()?;
+        }
+
+        if self
+            .multiple_of
+            .as_ref()
+            .and_then(JsonNumber::as_f64)
+            .is_some_and(|multiple_of| multiple_of <= 0.0)
+        {
+            return Err(ParseDataTypeConstraintError::NonPositiveMultipleOf);
+        }
+
+        let empty_range = |lower: &str, upper: &str| ParseDataTypeConstraintError::EmptyRange {
+            lower: lower.to_owned(),
+            upper: upper.to_owned(),
+        };
+
+        // An exclusive bound does not allow a value equal to the other bound.
+        for (lower_key, lower, upper_key, upper, is_exclusive) in [
+            ("minimum", &self.minimum, "maximum", &self.maximum, false),
+            (
+                "exclusiveMinimum",
+                &self.exclusive_minimum,
+                "maximum",
+                &self.maximum,
+                true,
+            ),
+            (
+                "minimum",
+                &self.minimum,
+                "exclusiveMaximum",
+                &self.exclusive_maximum,
+                true,
+            ),
+            (
+                "exclusiveMinimum",
+                &self.exclusive_minimum,
+                "exclusiveMaximum",
+                &self.exclusive_maximum,
+                true,
+            ),
+        ] {
+            if let Some((lower, upper)) = lower
+                .as_ref()
+                .and_then(JsonNumber::as_f64)
+                .zip(upper.as_ref().and_then(JsonNumber::as_f64))
+            {
+                if lower > upper || (is_exclusive && lower >= upper) {
+                    return Err(empty_range(lower_key, upper_key));
+                }
+            }
+        }
+
+        if let Some((min_length, max_length)) = self.min_length.zip(self.max_length) {
+            if min_length > max_length {
+                return Err(empty_range("minLength", "maxLength"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{raw, DataType, ParseDataTypeError};
+
+    fn parse(constraints: &JsonValue) -> Result<DataType, ParseDataTypeError> {
+        let mut data_type = json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "$id": "https://example.com/data-type/text/v/1",
+            "title": "Text",
+        });
+        data_type
+            .as_object_mut()
+            .expect("data type is an object")
+            .extend(
+                constraints
+                    .as_object()
+                    .expect("constraints are an object")
+                    .clone(),
+            );
+
+        let data_type: DataType = serde_json::from_value::<raw::DataType>(data_type)
+            .map_err(|error| ParseDataTypeError::InvalidJson(error.to_string()))?
+            .try_into()?;
+        data_type
+            .validate_constraints()
+            .map_err(ParseDataTypeError::InvalidConstraint)?;
+        Ok(data_type)
+    }
+
+    #[test]
+    fn typed_constraints() {
+        let data_type = parse(&json!({
+            "type": "string",
+            "minLength": 1,
+            "maxLength": 10,
+            "pattern": "^[a-z]+$",
+            "format": "email",
+        }))
+        .expect("failed to parse data type");
+
+        let constraints = data_type.constraints();
+        assert_eq!(constraints.min_length, Some(1));
+        assert_eq!(constraints.max_length, Some(10));
+        assert_eq!(
+            constraints.pattern.as_ref().map(Pattern::as_str),
+            Some("^[a-z]+$")
+        );
+        assert_eq!(constraints.format, Some(StringFormat::Email));
+        assert!(data_type.additional_properties().is_empty());
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        assert!(matches!(
+            parse(&json!({ "type": "string", "pattern": "[a-z" })),
+            Err(ParseDataTypeError::InvalidConstraint(
+                ParseDataTypeConstraintError::InvalidPattern { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn negative_max_length() {
+        parse(&json!({ "type": "string", "maxLength": -1 }))
+            .expect_err("parsed a negative `maxLength`");
+    }
+
+    #[test]
+    fn unknown_format() {
+        parse(&json!({ "type": "string", "format": "color" }))
+            .expect_err("parsed an unknown format");
+    }
+
+    #[test]
+    fn unsupported_constraint() {
+        assert_eq!(
+            parse(&json!({ "type": "string", "minimum": 0 })),
+            Err(ParseDataTypeError::InvalidConstraint(
+                ParseDataTypeConstraintError::UnsupportedConstraint {
+                    key: "minimum".to_owned(),
+                    json_type: JsonSchemaValueType::String,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn non_positive_multiple_of() {
+        assert_eq!(
+            parse(&json!({ "type": "number", "multipleOf": 0 })),
+            Err(ParseDataTypeError::InvalidConstraint(
+                ParseDataTypeConstraintError::NonPositiveMultipleOf
+            ))
+        );
+    }
+
+    #[test]
+    fn empty_range() {
+        assert_eq!(
+            parse(&json!({ "type": "number", "minimum": 10, "maximum": 1 })),
+            Err(ParseDataTypeError::InvalidConstraint(
+                ParseDataTypeConstraintError::EmptyRange {
+                    lower: "minimum".to_owned(),
+                    upper: "maximum".to_owned(),
+                }
+            ))
+        );
+        parse(&json!({ "type": "number", "minimum": 5, "maximum": 5 }))
+            .expect("an inclusive range may contain a single value");
+    }
+
+    #[test]
+    fn empty_exclusive_range() {
+        assert_eq!(
+            parse(&json!({ "type": "number", "exclusiveMinimum": 5, "maximum": 5 })),
+            Err(ParseDataTypeError::InvalidConstraint(
+                ParseDataTypeConstraintError::EmptyRange {
+                    lower: "exclusiveMinimum".to_owned(),
+                    upper: "maximum".to_owned(),
+                }
+            ))
+        );
+        assert_eq!(
+            parse(&json!({ "type": "number", "minimum": 5, "exclusiveMaximum": 5 })),
+            Err(ParseDataTypeError::InvalidConstraint(
+                ParseDataTypeConstraintError::EmptyRange {
+                    lower: "minimum".to_owned(),
+                    upper: "exclusiveMaximum".to_owned(),
+                }
+            ))
+        );
+        assert_eq!(
+            parse(&json!({ "type": "number", "exclusiveMinimum": 6, "exclusiveMaximum": 5 })),
+            Err(ParseDataTypeError::InvalidConstraint(
+                ParseDataTypeConstraintError::EmptyRange {
+                    lower: "exclusiveMinimum".to_owned(),
+                    upper: "exclusiveMaximum".to_owned(),
+                }
+            ))
+        );
+        parse(&json!({ "type": "number", "exclusiveMinimum": 5, "maximum": 6 }))
+            .expect("the range contains values greater than 5 up to 6");
+    }
+
+    #[test]
+    fn unchecked_deserialization() {
+        // Data types are read from the store without validating their constraints, so data types
+        // stored before a constraint was checked can still be read.
+        let data_type = serde_json::from_value::<DataType>(json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "$id": "https://example.com/data-type/text/v/1",
+            "title": "Text",
+            "type": "string",
+            "minLength": 10,
+            "maxLength": 1,
+            "pattern": "[a-z",
+        }))
+        .expect("failed to deserialize data type");
+
+        assert_eq!(
+            data_type
+                .constraints()
+                .pattern
+                .as_ref()
+                .map(Pattern::as_str),
+            Some("[a-z")
+        );
+        assert_eq!(
+            data_type.validate_constraints(),
+            Err(ParseDataTypeConstraintError::EmptyRange {
+                lower: "minLength".to_owned(),
+                upper: "maxLength".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn null_const() {
+        let data_type =
+            parse(&json!({ "type": "null", "const": null })).expect("failed to parse data type");
+        assert_eq!(data_type.constraints().const_value, Some(JsonValue::Null));
+    }
+}
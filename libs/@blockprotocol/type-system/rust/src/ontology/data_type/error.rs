@@ -3,7 +3,10 @@ use thiserror::Error;
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
-use crate::url::ParseVersionedUrlError;
+use crate::{
+    url::{ParseVersionedUrlError, VersionedUrl},
+    JsonSchemaValueType, ParseAllOfError,
+};
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
@@ -11,8 +14,54 @@ use crate::url::ParseVersionedUrlError;
 pub enum ParseDataTypeError {
     #[error("invalid `$schema` property, expected `\"https://blockprotocol.org/types/modules/graph/0.3/schema/data-type\"` but received: `{0}`")]
     InvalidMetaSchema(String),
+    #[error("invalid all of field: `{0}`")]
+    InvalidAllOf(ParseAllOfError),
+    #[error("invalid constraint: `{0}`")]
+    InvalidConstraint(ParseDataTypeConstraintError),
     #[error("invalid versioned URL: `{0}`")]
     InvalidVersionedUrl(ParseVersionedUrlError),
     #[error("error in JSON: `{0}`")]
     InvalidJson(String),
 }
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum ParseDataTypeConstraintError {
+    #[error("the `{key}` constraint cannot be applied to values of type `{json_type}`")]
+    UnsupportedConstraint {
+        key: String,
+        json_type: JsonSchemaValueType,
+    },
+    #[error("invalid pattern `{pattern}`: {reason}")]
+    InvalidPattern { pattern: String, reason: String },
+    #[error("the `multipleOf` constraint has to be greater than zero")]
+    NonPositiveMultipleOf,
+    #[error("the `{lower}` and `{upper}` constraints do not allow any value")]
+    EmptyRange { lower: String, upper: String },
+}
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum DataTypeRefinementError {
+    #[error("`{parent}` is not contained in the `allOf` property of `{child}`")]
+    DoesNotInheritFrom {
+        child: VersionedUrl,
+        parent: VersionedUrl,
+    },
+    #[error(
+        "the type `{child_type}` of `{child}` does not refine the type `{parent_type}` of \
+         `{parent}`"
+    )]
+    IncompatibleType {
+        child: VersionedUrl,
+        child_type: JsonSchemaValueType,
+        parent: VersionedUrl,
+        parent_type: JsonSchemaValueType,
+    },
+    #[error("the `{key}` constraint of `{child}` is wider than the one of `{parent}`")]
+    WidenedConstraint {
+        child: VersionedUrl,
+        parent: VersionedUrl,
+        key: String,
+    },
+}
@@ -1,21 +1,26 @@
 use core::fmt;
 use std::{collections::HashMap, ptr};
 
-pub use error::ParseDataTypeError;
+pub use constraint::{DataTypeConstraints, Pattern, StringFormat};
+pub use error::{DataTypeRefinementError, ParseDataTypeConstraintError, ParseDataTypeError};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
 use crate::{
     url::{BaseUrl, VersionedUrl},
-    ValidateUrl, ValidationError,
+    AllOf, ValidateUrl, ValidationError,
 };
 
+mod compatibility;
+mod constraint;
 mod error;
 pub(in crate::ontology) mod raw;
+mod refinement;
 #[cfg(target_arch = "wasm32")]
 mod wasm;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum JsonSchemaValueType {
     Null,
@@ -60,11 +65,14 @@ pub struct DataType {
     id: VersionedUrl,
     title: String,
     description: Option<String>,
+    inherits_from: AllOf<DataTypeReference>,
     json_type: JsonSchemaValueType,
+    constraints: DataTypeConstraints,
     /// Properties which are not currently strongly typed.
     ///
     /// The data type meta-schema currently allows arbitrary, untyped properties. This is a
-    /// catch-all field to store all non-typed data.
+    /// catch-all field to store all non-typed data which does not constrain values, e.g. the
+    /// `label` of a data type.
     additional_properties: HashMap<String, JsonValue>,
 }
 
@@ -74,14 +82,18 @@ impl DataType {
         id: VersionedUrl,
         title: String,
         description: Option<String>,
+        inherits_from: AllOf<DataTypeReference>,
         json_type: JsonSchemaValueType,
+        constraints: DataTypeConstraints,
         additional_properties: HashMap<String, JsonValue>,
     ) -> Self {
         Self {
             id,
             title,
             description,
+            inherits_from,
             json_type,
+            constraints,
             additional_properties,
         }
     }
@@ -101,11 +113,47 @@ impl DataType {
         self.description.as_deref()
     }
 
+    /// Returns the data types this data type inherits the constraints from.
+    #[must_use]
+    pub const fn inherits_from(&self) -> &AllOf<DataTypeReference> {
+        &self.inherits_from
+    }
+
     #[must_use]
     pub const fn json_type(&self) -> JsonSchemaValueType {
         self.json_type
     }
 
+    /// Returns the constraints a value of this data type has to fulfill.
+    ///
+    /// Constraints of the data types this data type inherits from are not included.
+    #[must_use]
+    pub const fn constraints(&self) -> &DataTypeConstraints {
+        &self.constraints
+    }
+
+    /// Checks that the constraints can be applied to values of this data type and are
+    /// satisfiable.
+    ///
+    /// Constraints are not checked when a data type is deserialized, so data types which were
+    /// stored before a constraint was checked can still be read. This has to be called before a
+    /// data type is created or updated.
+    ///
+    /// # Errors
+    ///
+    /// - [`UnsupportedConstraint`] if a constraint does not apply to values of this data type
+    /// - [`InvalidPattern`] if `pattern` cannot be compiled
+    /// - [`NonPositiveMultipleOf`] if `multipleOf` is not greater than zero
+    /// - [`EmptyRange`] if a lower bound and an upper bound do not allow any value
+    ///
+    /// [`UnsupportedConstraint`]: ParseDataTypeConstraintError::UnsupportedConstraint
+    /// [`InvalidPattern`]: ParseDataTypeConstraintError::InvalidPattern
+    /// [`NonPositiveMultipleOf`]: ParseDataTypeConstraintError::NonPositiveMultipleOf
+    /// [`EmptyRange`]: ParseDataTypeConstraintError::EmptyRange
+    pub fn validate_constraints(&self) -> Result<(), ParseDataTypeConstraintError> {
+        self.constraints.check(self.json_type)
+    }
+
     #[must_use]
     pub const fn additional_properties(&self) -> &HashMap<String, JsonValue> {
         &self.additional_properties
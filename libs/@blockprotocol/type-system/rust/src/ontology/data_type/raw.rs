@@ -1,11 +1,16 @@
 use std::{collections::HashMap, str::FromStr};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Number as JsonNumber, Value as JsonValue};
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
 use crate::{
-    ontology::data_type::JsonSchemaValueType,
+    ontology::data_type::{
+        constraint::{Pattern, StringFormat},
+        JsonSchemaValueType,
+    },
+    raw,
     url::{ParseVersionedUrlError, VersionedUrl},
     ParseDataTypeError,
 };
@@ -38,8 +43,12 @@ pub struct DataType {
     #[cfg_attr(target_arch = "wasm32", tsify(optional))]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     description: Option<String>,
+    #[serde(flatten)]
+    all_of: raw::AllOf<DataTypeReference>,
     #[serde(rename = "type")]
     json_type: JsonSchemaValueType,
+    #[serde(flatten)]
+    constraints: DataTypeConstraints,
     /// Properties which are not currently strongly typed.
     ///
     /// The data type meta-schema currently allows arbitrary, untyped properties. This is a
@@ -60,11 +69,18 @@ impl TryFrom<DataType> for super::DataType {
             return Err(ParseDataTypeError::InvalidMetaSchema(data_type_repr.schema));
         }
 
+        let inherits_from = data_type_repr
+            .all_of
+            .try_into()
+            .map_err(ParseDataTypeError::InvalidAllOf)?;
+
         Ok(Self::new(
             id,
             data_type_repr.title,
             data_type_repr.description,
+            inherits_from,
             data_type_repr.json_type,
+            data_type_repr.constraints.into(),
             data_type_repr.additional_properties,
         ))
     }
@@ -78,12 +94,112 @@ impl From<super::DataType> for DataType {
             id: data_type.id.to_string(),
             title: data_type.title,
             description: data_type.description,
+            all_of: data_type.inherits_from.into(),
             json_type: data_type.json_type,
+            constraints: data_type.constraints.into(),
             additional_properties: data_type.additional_properties,
         }
     }
 }
 
+/// Deserializes a present value as `Some`, even if it is `null`.
+fn deserialize_some<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataTypeConstraints {
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "any"))]
+    #[serde(
+        rename = "const",
+        default,
+        deserialize_with = "deserialize_some",
+        skip_serializing_if = "Option::is_none"
+    )]
+    const_value: Option<JsonValue>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "any[]"))]
+    #[serde(rename = "enum", default, skip_serializing_if = "Option::is_none")]
+    enum_values: Option<Vec<JsonValue>>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "number"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    minimum: Option<JsonNumber>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "number"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    maximum: Option<JsonNumber>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "number"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    exclusive_minimum: Option<JsonNumber>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "number"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    exclusive_maximum: Option<JsonNumber>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "number"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    multiple_of: Option<JsonNumber>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_length: Option<usize>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_length: Option<usize>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pattern: Option<String>,
+    #[cfg_attr(
+        target_arch = "wasm32",
+        tsify(
+            optional,
+            type = "'uri' | 'uuid' | 'regex' | 'email' | 'ipv4' | 'ipv6' | 'hostname' | \
+                    'date-time' | 'date' | 'time' | 'duration'"
+        )
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    format: Option<StringFormat>,
+}
+
+impl From<DataTypeConstraints> for super::DataTypeConstraints {
+    fn from(constraints_repr: DataTypeConstraints) -> Self {
+        Self {
+            const_value: constraints_repr.const_value,
+            enum_values: constraints_repr.enum_values,
+            minimum: constraints_repr.minimum,
+            maximum: constraints_repr.maximum,
+            exclusive_minimum: constraints_repr.exclusive_minimum,
+            exclusive_maximum: constraints_repr.exclusive_maximum,
+            multiple_of: constraints_repr.multiple_of,
+            min_length: constraints_repr.min_length,
+            max_length: constraints_repr.max_length,
+            pattern: constraints_repr.pattern.map(Pattern::new),
+            format: constraints_repr.format,
+        }
+    }
+}
+
+impl From<super::DataTypeConstraints> for DataTypeConstraints {
+    fn from(constraints: super::DataTypeConstraints) -> Self {
+        Self {
+            const_value: constraints.const_value,
+            enum_values: constraints.enum_values,
+            minimum: constraints.minimum,
+            maximum: constraints.maximum,
+            exclusive_minimum: constraints.exclusive_minimum,
+            exclusive_maximum: constraints.exclusive_maximum,
+            multiple_of: constraints.multiple_of,
+            min_length: constraints.min_length,
+            max_length: constraints.max_length,
+            pattern: constraints
+                .pattern
+                .map(|pattern| pattern.as_str().to_owned()),
+            format: constraints.format,
+        }
+    }
+}
+
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -0,0 +1,279 @@
+use serde_json::{Number as JsonNumber, Value as JsonValue};
+
+use crate::{DataType, DataTypeConstraints, DataTypeRefinementError, JsonSchemaValueType};
+
+/// Returns the values allowed by the `const` or `enum` constraint.
+pub(super) fn allowed_values(constraints: &DataTypeConstraints) -> Option<Vec<&JsonValue>> {
+    constraints.const_value.as_ref().map_or_else(
+        || {
+            constraints
+                .enum_values
+                .as_ref()
+                .map(|values| values.iter().collect())
+        },
+        |value| Some(vec![value]),
+    )
+}
+
+/// Returns if `value` does not allow more than `parent_value` or if any of both is not set.
+fn narrows<T>(
+    value: Option<T>,
+    parent_value: Option<T>,
+    is_narrowed: impl FnOnce(T, T) -> bool,
+) -> bool {
+    value
+        .zip(parent_value)
+        .map_or(true, |(value, parent_value)| {
+            is_narrowed(value, parent_value)
+        })
+}
+
+/// Returns if `value` is an integer multiple of `parent_value`.
+///
+/// Decimal fractions like `0.1` cannot be represented exactly, so the quotient is only required to
+/// be an integer up to the rounding error of the division.
+#[expect(clippy::float_arithmetic)]
+pub(super) fn is_multiple_of(value: f64, parent_value: f64) -> bool {
+    let quotient = value / parent_value;
+    (quotient - quotient.round()).abs() <= quotient.abs().max(1.0) * f64::EPSILON * 4.0
+}
+
+pub(super) fn as_f64(number: Option<&JsonNumber>) -> Option<f64> {
+    number.and_then(JsonNumber::as_f64)
+}
+
+impl DataType {
+    /// Checks that this data type only narrows the constraints of `parent`.
+    ///
+    /// Constraints are inherited, so a constraint of the parent which is not specified on this
+    /// data type is still applied. Only constraints specified on both data types are compared.
+    /// Patterns cannot be compared and are always accepted as a value has to match both.
+    ///
+    /// # Errors
+    ///
+    /// - [`DoesNotInheritFrom`] if `parent` is not contained in the `allOf` of this data type
+    /// - [`IncompatibleType`] if the type of this data type is not the type of `parent`
+    /// - [`WidenedConstraint`] if a constraint allows values which are not allowed by `parent`
+    ///
+    /// [`DoesNotInheritFrom`]: DataTypeRefinementError::DoesNotInheritFrom
+    /// [`IncompatibleType`]: DataTypeRefinementError::IncompatibleType
+    /// [`WidenedConstraint`]: DataTypeRefinementError::WidenedConstraint
+    pub fn check_refinement(&self, parent: &Self) -> Result<(), DataTypeRefinementError> {
+        if !self
+            .inherits_from()
+            .all_of()
+            .iter()
+            .any(|reference| reference.url() == parent.id())
+        {
+            return Err(DataTypeRefinementError::DoesNotInheritFrom {
+                child: self.id().clone(),
+                parent: parent.id().clone(),
+            });
+        }
+
+        if self.json_type() != parent.json_type()
+            && !(self.json_type() == JsonSchemaValueType::Integer
+                && parent.json_type() == JsonSchemaValueType::Number)
+        {
+            return Err(DataTypeRefinementError::IncompatibleType {
+                child: self.id().clone(),
+                child_type: self.json_type(),
+                parent: parent.id().clone(),
+                parent_type: parent.json_type(),
+            });
+        }
+
+        let widened = |key: &str| DataTypeRefinementError::WidenedConstraint {
+            child: self.id().clone(),
+            parent: parent.id().clone(),
+            key: key.to_owned(),
+        };
+
+        let constraints = self.constraints();
+        let parent_constraints = parent.constraints();
+
+        for (key, narrowed) in [
+            (
+                "minimum",
+                narrows(
+                    as_f64(constraints.minimum.as_ref()),
+                    as_f64(parent_constraints.minimum.as_ref()),
+                    |value, parent_value| value >= parent_value,
+                ),
+            ),
+            (
+                "exclusiveMinimum",
+                narrows(
+                    as_f64(constraints.exclusive_minimum.as_ref()),
+                    as_f64(parent_constraints.exclusive_minimum.as_ref()),
+                    |value, parent_value| value >= parent_value,
+                ),
+            ),
+            (
+                "maximum",
+                narrows(
+                    as_f64(constraints.maximum.as_ref()),
+                    as_f64(parent_constraints.maximum.as_ref()),
+                    |value, parent_value| value <= parent_value,
+                ),
+            ),
+            (
+                "exclusiveMaximum",
+                narrows(
+                    as_f64(constraints.exclusive_maximum.as_ref()),
+                    as_f64(parent_constraints.exclusive_maximum.as_ref()),
+                    |value, parent_value| value <= parent_value,
+                ),
+            ),
+            (
+                "multipleOf",
+                narrows(
+                    as_f64(constraints.multiple_of.as_ref()),
+                    as_f64(parent_constraints.multiple_of.as_ref()),
+                    is_multiple_of,
+                ),
+            ),
+            (
+                "minLength",
+                narrows(
+                    constraints.min_length,
+                    parent_constraints.min_length,
+                    |value, parent_value| value >= parent_value,
+                ),
+            ),
+            (
+                "maxLength",
+                narrows(
+                    constraints.max_length,
+                    parent_constraints.max_length,
+                    |value, parent_value| value <= parent_value,
+                ),
+            ),
+            (
+                "format",
+                narrows(
+                    constraints.format,
+                    parent_constraints.format,
+                    |value, parent_value| value == parent_value,
+                ),
+            ),
+        ] {
+            if !narrowed {
+                return Err(widened(key));
+            }
+        }
+
+        if let Some((values, parent_values)) =
+            allowed_values(constraints).zip(allowed_values(parent_constraints))
+        {
+            if !values.iter().all(|value| parent_values.contains(value)) {
+                return Err(widened(if constraints.const_value.is_some() {
+                    "const"
+                } else {
+                    "enum"
+                }));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn data_type(value: JsonValue) -> DataType {
+        serde_json::from_value(value).expect("invalid data type")
+    }
+
+    fn integer() -> DataType {
+        data_type(json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "$id": "https://example.com/data-type/integer/v/1",
+            "title": "Integer",
+            "type": "integer",
+            "minimum": 0,
+            "maximum": 100,
+        }))
+    }
+
+    fn positive_integer(constraints: &JsonValue) -> DataType {
+        let mut data_type = json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "$id": "https://example.com/data-type/positive-integer/v/1",
+            "title": "Positive Integer",
+            "allOf": [{ "$ref": "https://example.com/data-type/integer/v/1" }],
+            "type": "integer",
+        });
+        data_type
+            .as_object_mut()
+            .expect("data type is an object")
+            .extend(
+                constraints
+                    .as_object()
+                    .expect("constraints are an object")
+                    .clone(),
+            );
+        self::data_type(data_type)
+    }
+
+    #[test]
+    fn narrowed_constraints() {
+        positive_integer(&json!({ "minimum": 1 }))
+            .check_refinement(&integer())
+            .expect("minimum is narrowed");
+        positive_integer(&json!({}))
+            .check_refinement(&integer())
+            .expect("constraints are inherited");
+    }
+
+    #[test]
+    fn widened_constraints() {
+        assert_eq!(
+            positive_integer(&json!({ "maximum": 1000 })).check_refinement(&integer()),
+            Err(DataTypeRefinementError::WidenedConstraint {
+                child: positive_integer(&json!({})).id().clone(),
+                parent: integer().id().clone(),
+                key: "maximum".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn narrowed_multiple_of() {
+        let parent = data_type(json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "$id": "https://example.com/data-type/integer/v/1",
+            "title": "Integer",
+            "type": "integer",
+            "multipleOf": 0.1,
+        }));
+        positive_integer(&json!({ "multipleOf": 0.3 }))
+            .check_refinement(&parent)
+            .expect("0.3 is a multiple of 0.1");
+        positive_integer(&json!({ "multipleOf": 0.25 }))
+            .check_refinement(&parent)
+            .expect_err("0.25 is not a multiple of 0.1");
+    }
+
+    #[test]
+    fn incompatible_type() {
+        let text = data_type(json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "$id": "https://example.com/data-type/integer/v/1",
+            "title": "Text",
+            "type": "string",
+        }));
+
+        positive_integer(&json!({}))
+            .check_refinement(&text)
+            .expect_err("an integer cannot refine a string");
+    }
+}
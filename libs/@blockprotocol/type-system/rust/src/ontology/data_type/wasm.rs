@@ -8,7 +8,11 @@ fn convert_data_type(data_type_obj: &JsValue) -> std::result::Result<DataType, P
         .into_serde::<raw::DataType>()
         .map_err(|err| ParseDataTypeError::InvalidJson(err.to_string()))?;
 
-    DataType::try_from(data_type_repr)
+    let data_type = DataType::try_from(data_type_repr)?;
+    data_type
+        .validate_constraints()
+        .map_err(ParseDataTypeError::InvalidConstraint)?;
+    Ok(data_type)
 }
 
 #[wasm_bindgen(typescript_custom_section)]
@@ -0,0 +1,263 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    ontology::shared::compatibility::{
+        array_bounds_compatibility, metadata_changes, property_changes, ReferenceChanges,
+    },
+    url::VersionedUrl,
+    EntityType, EntityTypeReference, MaybeOrderedArray, OneOf, OntologyTypeChange,
+    OntologyTypeChangeKind,
+};
+
+type LinkDestinations = MaybeOrderedArray<Option<OneOf<EntityTypeReference>>>;
+
+fn link_changes(
+    link: &VersionedUrl,
+    previous: &LinkDestinations,
+    updated: &LinkDestinations,
+    changes: &mut Vec<OntologyTypeChange>,
+) {
+    if let Some(compatibility) = array_bounds_compatibility(previous.array(), updated.array()) {
+        changes.push(OntologyTypeChange::new(
+            OntologyTypeChangeKind::ChangedLinkArrayBounds { link: link.clone() },
+            compatibility,
+        ));
+    }
+
+    if previous.ordered() != updated.ordered() {
+        changes.push(OntologyTypeChange::backwards_compatible(
+            OntologyTypeChangeKind::ChangedLinkOrdering { link: link.clone() },
+        ));
+    }
+
+    match (previous.array().items(), updated.array().items()) {
+        (None, None) => {}
+        (None, Some(_)) => changes.push(OntologyTypeChange::breaking(
+            OntologyTypeChangeKind::RestrictedLinkDestinations { link: link.clone() },
+        )),
+        (Some(_), None) => changes.push(OntologyTypeChange::backwards_compatible(
+            OntologyTypeChangeKind::UnrestrictedLinkDestinations { link: link.clone() },
+        )),
+        (Some(previous), Some(updated)) => {
+            let destinations = ReferenceChanges::new(
+                previous.one_of().iter().map(EntityTypeReference::url),
+                updated.one_of().iter().map(EntityTypeReference::url),
+            );
+            changes.extend(destinations.added.iter().map(|destination| {
+                OntologyTypeChange::backwards_compatible(
+                    OntologyTypeChangeKind::AddedLinkDestination {
+                        link: link.clone(),
+                        destination: (*destination).clone(),
+                    },
+                )
+            }));
+            changes.extend(destinations.removed.iter().map(|destination| {
+                OntologyTypeChange::breaking(OntologyTypeChangeKind::RemovedLinkDestination {
+                    link: link.clone(),
+                    destination: (*destination).clone(),
+                })
+            }));
+            destinations.push_version_changes(changes);
+        }
+    }
+}
+
+impl EntityType {
+    /// Returns the changes from this entity type to `updated`.
+    ///
+    /// A change is breaking if an entity which is valid for this entity type may be invalid for
+    /// `updated`. Properties are closed, so removing a property is breaking even if it was
+    /// optional. Adding or removing a parent is breaking as it changes the properties and links of
+    /// the entity type. Properties, links, and link destinations are matched by their base URL.
+    #[must_use]
+    pub fn changes_to(&self, updated: &Self) -> Vec<OntologyTypeChange> {
+        let mut changes = Vec::new();
+
+        metadata_changes(
+            (self.title(), self.description()),
+            (updated.title(), updated.description()),
+            &mut changes,
+        );
+
+        let parents = ReferenceChanges::new(
+            self.inherits_from()
+                .all_of()
+                .iter()
+                .map(EntityTypeReference::url),
+            updated
+                .inherits_from()
+                .all_of()
+                .iter()
+                .map(EntityTypeReference::url),
+        );
+        changes.extend(parents.added.iter().map(|parent| {
+            OntologyTypeChange::breaking(OntologyTypeChangeKind::AddedParent {
+                parent: (*parent).clone(),
+            })
+        }));
+        changes.extend(parents.removed.iter().map(|parent| {
+            OntologyTypeChange::breaking(OntologyTypeChangeKind::RemovedParent {
+                parent: (*parent).clone(),
+            })
+        }));
+        parents.push_version_changes(&mut changes);
+
+        property_changes(
+            (self.properties(), self.required()),
+            (updated.properties(), updated.required()),
+            &mut changes,
+        );
+
+        let links = ReferenceChanges::new(self.links().keys(), updated.links().keys());
+        for link in &links.added {
+            let is_required = updated.links()[*link].array().min_items().unwrap_or(0) > 0;
+            changes.push(if is_required {
+                OntologyTypeChange::breaking(OntologyTypeChangeKind::AddedLink {
+                    link: (*link).clone(),
+                })
+            } else {
+                OntologyTypeChange::backwards_compatible(OntologyTypeChangeKind::AddedLink {
+                    link: (*link).clone(),
+                })
+            });
+        }
+        changes.extend(links.removed.iter().map(|link| {
+            OntologyTypeChange::breaking(OntologyTypeChangeKind::RemovedLink {
+                link: (*link).clone(),
+            })
+        }));
+        links.push_version_changes(&mut changes);
+
+        let updated_links = updated
+            .links()
+            .iter()
+            .map(|(link, destinations)| (&link.base_url, (link, destinations)))
+            .collect::<BTreeMap<_, _>>();
+        for (link, previous) in self.links().iter().collect::<BTreeMap<_, _>>() {
+            if let Some((link, updated)) = updated_links.get(&link.base_url) {
+                link_changes(link, previous, updated, &mut changes);
+            }
+        }
+
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as JsonValue};
+
+    use super::*;
+    use crate::url::BaseUrl;
+
+    fn entity_type(properties: &JsonValue, required: &JsonValue, links: &JsonValue) -> EntityType {
+        serde_json::from_value(json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type",
+            "kind": "entityType",
+            "$id": "https://example.com/entity-type/person/v/1",
+            "type": "object",
+            "title": "Person",
+            "properties": properties,
+            "required": required,
+            "links": links,
+        }))
+        .expect("invalid entity type")
+    }
+
+    fn name() -> BaseUrl {
+        BaseUrl::new("https://example.com/property-type/name/".to_owned())
+            .expect("invalid base URL")
+    }
+
+    fn age() -> BaseUrl {
+        BaseUrl::new("https://example.com/property-type/age/".to_owned()).expect("invalid base URL")
+    }
+
+    fn properties(properties: &[BaseUrl]) -> JsonValue {
+        properties
+            .iter()
+            .map(|property| {
+                (
+                    property.to_string(),
+                    json!({ "$ref": format!("{property}v/1") }),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>()
+            .into()
+    }
+
+    #[test]
+    fn added_optional_property() {
+        let previous = entity_type(&properties(&[name()]), &json!([]), &json!({}));
+        let updated = entity_type(&properties(&[name(), age()]), &json!([]), &json!({}));
+
+        assert_eq!(
+            previous.changes_to(&updated),
+            [OntologyTypeChange::backwards_compatible(
+                OntologyTypeChangeKind::AddedProperty {
+                    property: age(),
+                    required: false,
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn removed_required_property() {
+        let previous = entity_type(&properties(&[name(), age()]), &json!([age()]), &json!({}));
+        let updated = entity_type(&properties(&[name()]), &json!([]), &json!({}));
+
+        assert_eq!(
+            previous.changes_to(&updated),
+            [OntologyTypeChange::breaking(
+                OntologyTypeChangeKind::RemovedProperty {
+                    property: age(),
+                    required: true,
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn changed_link_destination() {
+        let link = "https://example.com/entity-type/friend-of/v/1";
+        let person = "https://example.com/entity-type/person/v/1";
+        let company = "https://example.com/entity-type/company/v/1";
+        let links = |destinations: &[&str]| {
+            json!({
+                link: {
+                    "type": "array",
+                    "items": {
+                        "oneOf": destinations
+                            .iter()
+                            .map(|destination| json!({ "$ref": destination }))
+                            .collect::<Vec<_>>()
+                    },
+                }
+            })
+        };
+        let url = |url: &str| serde_json::from_value(json!(url)).expect("invalid URL");
+
+        let previous = entity_type(&json!({}), &json!([]), &links(&[person]));
+        let updated = entity_type(&json!({}), &json!([]), &links(&[person, company]));
+
+        assert_eq!(
+            previous.changes_to(&updated),
+            [OntologyTypeChange::backwards_compatible(
+                OntologyTypeChangeKind::AddedLinkDestination {
+                    link: url(link),
+                    destination: url(company),
+                }
+            )]
+        );
+        assert_eq!(
+            updated.changes_to(&previous),
+            [OntologyTypeChange::breaking(
+                OntologyTypeChangeKind::RemovedLinkDestination {
+                    link: url(link),
+                    destination: url(company),
+                }
+            )]
+        );
+    }
+}
@@ -1,4 +1,5 @@
 mod closed;
+mod compatibility;
 mod error;
 pub(in crate::ontology) mod links;
 pub(in crate::ontology) mod raw;
@@ -12,7 +12,10 @@ pub mod url;
 
 mod shared;
 
-pub use data_type::{DataType, DataTypeReference, JsonSchemaValueType, ParseDataTypeError};
+pub use data_type::{
+    DataType, DataTypeConstraints, DataTypeReference, DataTypeRefinementError, JsonSchemaValueType,
+    ParseDataTypeConstraintError, ParseDataTypeError, Pattern, StringFormat,
+};
 pub use entity_type::{
     links::{Links, MaybeOrderedArray, ParseLinksError},
     ClosedEntityType, ClosedEntityTypeSchemaData, EntityType, EntityTypeReference,
@@ -30,6 +33,7 @@ pub use shared::{
         },
         Array, ValueOrArray,
     },
+    compatibility::{Compatibility, OntologyTypeChange, OntologyTypeChangeKind},
     object::{error::ParsePropertyTypeObjectError, Object},
     one_of::{error::ParseOneOfError, OneOf},
     validate::{ValidateUrl, ValidationError},
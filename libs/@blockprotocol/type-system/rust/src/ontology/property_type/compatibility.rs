@@ -0,0 +1,192 @@
+use crate::{
+    ontology::shared::compatibility::{metadata_changes, property_changes, ReferenceChanges},
+    OntologyTypeChange, OntologyTypeChangeKind, PropertyType, PropertyValues,
+};
+
+fn values_matching(
+    property_type: &PropertyType,
+    predicate: impl Fn(&PropertyValues) -> bool,
+) -> Vec<&PropertyValues> {
+    property_type
+        .one_of()
+        .iter()
+        .filter(|values| predicate(*values))
+        .collect()
+}
+
+impl PropertyType {
+    /// Returns the changes from this property type to `updated`.
+    ///
+    /// A change is breaking if a value which is valid for this property type may be invalid for
+    /// `updated`. Data types are matched by their base URL. Property objects are compared
+    /// property by property if both versions allow exactly one object, otherwise objects and
+    /// arrays are only reported as added or removed.
+    #[must_use]
+    pub fn changes_to(&self, updated: &Self) -> Vec<OntologyTypeChange> {
+        let mut changes = Vec::new();
+
+        metadata_changes(
+            (self.title(), self.description()),
+            (updated.title(), updated.description()),
+            &mut changes,
+        );
+
+        let data_types = ReferenceChanges::new(
+            self.one_of().iter().filter_map(|values| match values {
+                PropertyValues::DataTypeReference(reference) => Some(reference.url()),
+                _ => None,
+            }),
+            updated.one_of().iter().filter_map(|values| match values {
+                PropertyValues::DataTypeReference(reference) => Some(reference.url()),
+                _ => None,
+            }),
+        );
+        changes.extend(data_types.added.iter().map(|data_type| {
+            OntologyTypeChange::backwards_compatible(OntologyTypeChangeKind::AddedDataType {
+                data_type: (*data_type).clone(),
+            })
+        }));
+        changes.extend(data_types.removed.iter().map(|data_type| {
+            OntologyTypeChange::breaking(OntologyTypeChangeKind::RemovedDataType {
+                data_type: (*data_type).clone(),
+            })
+        }));
+        data_types.push_version_changes(&mut changes);
+
+        let is_object =
+            |values: &PropertyValues| matches!(values, PropertyValues::PropertyTypeObject(_));
+        match (
+            values_matching(self, is_object).as_slice(),
+            values_matching(updated, is_object).as_slice(),
+        ) {
+            (
+                [PropertyValues::PropertyTypeObject(previous)],
+                [PropertyValues::PropertyTypeObject(object)],
+            ) => property_changes(
+                (previous.properties(), previous.required()),
+                (object.properties(), object.required()),
+                &mut changes,
+            ),
+            (previous, objects) => {
+                changes.extend(
+                    previous
+                        .iter()
+                        .filter(|values| !objects.contains(*values))
+                        .map(|_| {
+                            OntologyTypeChange::breaking(
+                                OntologyTypeChangeKind::RemovedPropertyObject,
+                            )
+                        }),
+                );
+                changes.extend(
+                    objects
+                        .iter()
+                        .filter(|values| !previous.contains(*values))
+                        .map(|_| {
+                            OntologyTypeChange::backwards_compatible(
+                                OntologyTypeChangeKind::AddedPropertyObject,
+                            )
+                        }),
+                );
+            }
+        }
+
+        let is_array =
+            |values: &PropertyValues| matches!(values, PropertyValues::ArrayOfPropertyValues(_));
+        let previous = values_matching(self, is_array);
+        let arrays = values_matching(updated, is_array);
+        changes.extend(
+            previous
+                .iter()
+                .filter(|values| !arrays.contains(*values))
+                .map(|_| {
+                    OntologyTypeChange::breaking(OntologyTypeChangeKind::RemovedPropertyArray)
+                }),
+        );
+        changes.extend(
+            arrays
+                .iter()
+                .filter(|values| !previous.contains(*values))
+                .map(|_| {
+                    OntologyTypeChange::backwards_compatible(
+                        OntologyTypeChangeKind::AddedPropertyArray,
+                    )
+                }),
+        );
+
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value as JsonValue};
+
+    use super::*;
+    use crate::url::BaseUrl;
+
+    fn property_type(one_of: &JsonValue) -> PropertyType {
+        serde_json::from_value(json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+            "kind": "propertyType",
+            "$id": "https://example.com/property-type/address/v/1",
+            "title": "Address",
+            "oneOf": one_of,
+        }))
+        .expect("invalid property type")
+    }
+
+    fn street() -> BaseUrl {
+        BaseUrl::new("https://example.com/property-type/street/".to_owned())
+            .expect("invalid base URL")
+    }
+
+    #[test]
+    fn changed_data_types() {
+        let text =
+            json!({ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" });
+        let number = json!({ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1" });
+
+        let changes =
+            property_type(&json!([text])).changes_to(&property_type(&json!([text, number])));
+        assert_eq!(
+            changes,
+            [OntologyTypeChange::backwards_compatible(
+                OntologyTypeChangeKind::AddedDataType {
+                    data_type: serde_json::from_value(number["$ref"].clone()).expect("invalid URL"),
+                }
+            )]
+        );
+
+        let changes =
+            property_type(&json!([text, number])).changes_to(&property_type(&json!([number])));
+        assert_eq!(
+            changes,
+            [OntologyTypeChange::breaking(
+                OntologyTypeChangeKind::RemovedDataType {
+                    data_type: serde_json::from_value(text["$ref"].clone()).expect("invalid URL"),
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn changed_object() {
+        let object = |required: &JsonValue| {
+            property_type(&json!([{
+                "type": "object",
+                "properties": {
+                    street().to_string(): { "$ref": "https://example.com/property-type/street/v/1" }
+                },
+                "required": required,
+            }]))
+        };
+
+        assert_eq!(
+            object(&json!([])).changes_to(&object(&json!([street()]))),
+            [OntologyTypeChange::breaking(
+                OntologyTypeChangeKind::MadePropertyRequired { property: street() }
+            )]
+        );
+    }
+}
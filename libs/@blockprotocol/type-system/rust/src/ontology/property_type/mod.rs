@@ -8,6 +8,7 @@ use crate::{
     Array, DataTypeReference, Object, OneOf, ValidateUrl, ValidationError, ValueOrArray,
 };
 
+mod compatibility;
 mod error;
 pub(in crate::ontology) mod raw;
 #[cfg(target_arch = "wasm32")]
@@ -11,4 +11,6 @@ use crate::url::ParseVersionedUrlError;
 pub enum ParseAllOfError {
     #[error("invalid entity type reference: `{0}`")]
     EntityTypeReferenceError(ParseVersionedUrlError),
+    #[error("invalid data type reference: `{0}`")]
+    DataTypeReferenceError(ParseVersionedUrlError),
 }
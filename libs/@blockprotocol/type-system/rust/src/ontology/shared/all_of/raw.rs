@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
-use crate::{raw, EntityTypeReference, ParseAllOfError};
+use crate::{raw, DataTypeReference, EntityTypeReference, ParseAllOfError};
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -34,6 +34,23 @@ impl TryFrom<AllOf<raw::EntityTypeReference>> for super::AllOf<EntityTypeReferen
     }
 }
 
+impl TryFrom<AllOf<raw::DataTypeReference>> for super::AllOf<DataTypeReference> {
+    type Error = ParseAllOfError;
+
+    fn try_from(all_of_repr: AllOf<raw::DataTypeReference>) -> Result<Self, Self::Error> {
+        let inner = all_of_repr
+            .elements
+            .into_iter()
+            .map(|ele| {
+                ele.try_into()
+                    .map_err(ParseAllOfError::DataTypeReferenceError)
+            })
+            .collect::<Result<Vec<_>, Self::Error>>()?;
+
+        Ok(Self::new(inner))
+    }
+}
+
 impl<T, R> From<super::AllOf<T>> for AllOf<R>
 where
     R: From<T>,
@@ -0,0 +1,354 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    num::NonZero,
+};
+
+use serde::Serialize;
+
+use crate::{
+    url::{BaseUrl, VersionedUrl},
+    Array, JsonSchemaValueType, PropertyTypeReference, ValueOrArray,
+};
+
+/// Whether values which are valid for the previous version of a type remain valid for the updated
+/// version.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum Compatibility {
+    BackwardsCompatible,
+    Breaking,
+}
+
+/// A difference between two versions of an ontology type.
+///
+/// Changes of a referenced type are reported when the referenced type itself is updated, so
+/// referring to a newer version of the same type is a backwards compatible
+/// `changedReferenceVersion`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum OntologyTypeChangeKind {
+    ChangedTitle,
+    ChangedDescription,
+    AddedParent {
+        parent: VersionedUrl,
+    },
+    RemovedParent {
+        parent: VersionedUrl,
+    },
+    ChangedReferenceVersion {
+        from: VersionedUrl,
+        to: VersionedUrl,
+    },
+    ChangedValueType {
+        from: JsonSchemaValueType,
+        to: JsonSchemaValueType,
+    },
+    AddedConstraint {
+        constraint: String,
+    },
+    RemovedConstraint {
+        constraint: String,
+    },
+    NarrowedConstraint {
+        constraint: String,
+    },
+    WidenedConstraint {
+        constraint: String,
+    },
+    ChangedConstraint {
+        constraint: String,
+    },
+    AddedDataType {
+        #[serde(rename = "dataType")]
+        data_type: VersionedUrl,
+    },
+    RemovedDataType {
+        #[serde(rename = "dataType")]
+        data_type: VersionedUrl,
+    },
+    AddedPropertyObject,
+    RemovedPropertyObject,
+    AddedPropertyArray,
+    RemovedPropertyArray,
+    AddedProperty {
+        property: BaseUrl,
+        required: bool,
+    },
+    RemovedProperty {
+        property: BaseUrl,
+        required: bool,
+    },
+    MadePropertyRequired {
+        property: BaseUrl,
+    },
+    MadePropertyOptional {
+        property: BaseUrl,
+    },
+    ChangedPropertyCardinality {
+        property: BaseUrl,
+    },
+    ChangedPropertyArrayBounds {
+        property: BaseUrl,
+    },
+    AddedLink {
+        link: VersionedUrl,
+    },
+    RemovedLink {
+        link: VersionedUrl,
+    },
+    ChangedLinkArrayBounds {
+        link: VersionedUrl,
+    },
+    ChangedLinkOrdering {
+        link: VersionedUrl,
+    },
+    RestrictedLinkDestinations {
+        link: VersionedUrl,
+    },
+    UnrestrictedLinkDestinations {
+        link: VersionedUrl,
+    },
+    AddedLinkDestination {
+        link: VersionedUrl,
+        destination: VersionedUrl,
+    },
+    RemovedLinkDestination {
+        link: VersionedUrl,
+        destination: VersionedUrl,
+    },
+}
+
+/// A difference between two versions of an ontology type together with its [`Compatibility`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct OntologyTypeChange {
+    pub kind: OntologyTypeChangeKind,
+    pub compatibility: Compatibility,
+}
+
+impl OntologyTypeChange {
+    #[must_use]
+    pub const fn new(kind: OntologyTypeChangeKind, compatibility: Compatibility) -> Self {
+        Self {
+            kind,
+            compatibility,
+        }
+    }
+
+    #[must_use]
+    pub const fn backwards_compatible(kind: OntologyTypeChangeKind) -> Self {
+        Self::new(kind, Compatibility::BackwardsCompatible)
+    }
+
+    #[must_use]
+    pub const fn breaking(kind: OntologyTypeChangeKind) -> Self {
+        Self::new(kind, Compatibility::Breaking)
+    }
+
+    #[must_use]
+    pub fn is_breaking(&self) -> bool {
+        self.compatibility == Compatibility::Breaking
+    }
+}
+
+/// Changes of the title and the description, which never affect values.
+pub(in crate::ontology) fn metadata_changes(
+    previous: (&str, Option<&str>),
+    updated: (&str, Option<&str>),
+    changes: &mut Vec<OntologyTypeChange>,
+) {
+    if previous.0 != updated.0 {
+        changes.push(OntologyTypeChange::backwards_compatible(
+            OntologyTypeChangeKind::ChangedTitle,
+        ));
+    }
+    if previous.1 != updated.1 {
+        changes.push(OntologyTypeChange::backwards_compatible(
+            OntologyTypeChangeKind::ChangedDescription,
+        ));
+    }
+}
+
+/// References which differ between two sets of references, matched by their base URL.
+#[derive(Debug, Default)]
+pub(in crate::ontology) struct ReferenceChanges<'u> {
+    pub(in crate::ontology) added: Vec<&'u VersionedUrl>,
+    pub(in crate::ontology) removed: Vec<&'u VersionedUrl>,
+    pub(in crate::ontology) changed_version: Vec<(&'u VersionedUrl, &'u VersionedUrl)>,
+}
+
+impl<'u> ReferenceChanges<'u> {
+    pub(in crate::ontology) fn new(
+        previous: impl IntoIterator<Item = &'u VersionedUrl>,
+        updated: impl IntoIterator<Item = &'u VersionedUrl>,
+    ) -> Self {
+        let previous = previous
+            .into_iter()
+            .map(|url| (&url.base_url, url))
+            .collect::<BTreeMap<_, _>>();
+        let updated = updated
+            .into_iter()
+            .map(|url| (&url.base_url, url))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut changes = Self::default();
+        for base_url in previous
+            .keys()
+            .chain(updated.keys())
+            .collect::<BTreeSet<_>>()
+        {
+            match (previous.get(base_url), updated.get(base_url)) {
+                (Some(&from), Some(&to)) if from != to => changes.changed_version.push((from, to)),
+                (Some(_), Some(_)) | (None, None) => {}
+                (Some(&from), None) => changes.removed.push(from),
+                (None, Some(&to)) => changes.added.push(to),
+            }
+        }
+        changes
+    }
+
+    /// Reports version changes, leaving added and removed references to the caller.
+    pub(in crate::ontology) fn push_version_changes(&self, changes: &mut Vec<OntologyTypeChange>) {
+        changes.extend(self.changed_version.iter().map(|(from, to)| {
+            OntologyTypeChange::backwards_compatible(
+                OntologyTypeChangeKind::ChangedReferenceVersion {
+                    from: (*from).clone(),
+                    to: (*to).clone(),
+                },
+            )
+        }));
+    }
+}
+
+/// Returns the compatibility of changed `minItems` and `maxItems`, or `None` if they are equal.
+pub(in crate::ontology) fn array_bounds_compatibility<T, U>(
+    previous: &Array<T>,
+    updated: &Array<U>,
+) -> Option<Compatibility> {
+    let previous_min = previous.min_items().unwrap_or(0);
+    let updated_min = updated.min_items().unwrap_or(0);
+    let previous_max = previous.max_items().map(NonZero::get);
+    let updated_max = updated.max_items().map(NonZero::get);
+
+    let narrowed = updated_min > previous_min
+        || match (previous_max, updated_max) {
+            (_, None) => false,
+            (None, Some(_)) => true,
+            (Some(previous_max), Some(updated_max)) => updated_max < previous_max,
+        };
+
+    if narrowed {
+        Some(Compatibility::Breaking)
+    } else if previous_min != updated_min || previous_max != updated_max {
+        Some(Compatibility::BackwardsCompatible)
+    } else {
+        None
+    }
+}
+
+/// Changes of the properties of an object, which are closed, so any removed property is breaking.
+pub(in crate::ontology) fn property_changes(
+    previous: (
+        &HashMap<BaseUrl, ValueOrArray<PropertyTypeReference>>,
+        &HashSet<BaseUrl>,
+    ),
+    updated: (
+        &HashMap<BaseUrl, ValueOrArray<PropertyTypeReference>>,
+        &HashSet<BaseUrl>,
+    ),
+    changes: &mut Vec<OntologyTypeChange>,
+) {
+    let (previous_properties, previous_required) = previous;
+    let (updated_properties, updated_required) = updated;
+
+    for property in previous_properties
+        .keys()
+        .chain(updated_properties.keys())
+        .collect::<BTreeSet<_>>()
+    {
+        let was_required = previous_required.contains(property);
+        let is_required = updated_required.contains(property);
+
+        let (previous, updated) = match (
+            previous_properties.get(property),
+            updated_properties.get(property),
+        ) {
+            (Some(previous), Some(updated)) => (previous, updated),
+            (None, Some(_)) => {
+                changes.push(OntologyTypeChange::new(
+                    OntologyTypeChangeKind::AddedProperty {
+                        property: property.clone(),
+                        required: is_required,
+                    },
+                    if is_required {
+                        Compatibility::Breaking
+                    } else {
+                        Compatibility::BackwardsCompatible
+                    },
+                ));
+                continue;
+            }
+            (Some(_), None) => {
+                changes.push(OntologyTypeChange::breaking(
+                    OntologyTypeChangeKind::RemovedProperty {
+                        property: property.clone(),
+                        required: was_required,
+                    },
+                ));
+                continue;
+            }
+            (None, None) => continue,
+        };
+
+        if !was_required && is_required {
+            changes.push(OntologyTypeChange::breaking(
+                OntologyTypeChangeKind::MadePropertyRequired {
+                    property: property.clone(),
+                },
+            ));
+        } else if was_required && !is_required {
+            changes.push(OntologyTypeChange::backwards_compatible(
+                OntologyTypeChangeKind::MadePropertyOptional {
+                    property: property.clone(),
+                },
+            ));
+        }
+
+        let (previous_reference, updated_reference) = match (previous, updated) {
+            (ValueOrArray::Value(previous), ValueOrArray::Value(updated)) => (previous, updated),
+            (ValueOrArray::Array(previous), ValueOrArray::Array(updated)) => {
+                if let Some(compatibility) = array_bounds_compatibility(previous, updated) {
+                    changes.push(OntologyTypeChange::new(
+                        OntologyTypeChangeKind::ChangedPropertyArrayBounds {
+                            property: property.clone(),
+                        },
+                        compatibility,
+                    ));
+                }
+                (previous.items(), updated.items())
+            }
+            (ValueOrArray::Value(previous), ValueOrArray::Array(updated)) => {
+                changes.push(OntologyTypeChange::breaking(
+                    OntologyTypeChangeKind::ChangedPropertyCardinality {
+                        property: property.clone(),
+                    },
+                ));
+                (previous, updated.items())
+            }
+            (ValueOrArray::Array(previous), ValueOrArray::Value(updated)) => {
+                changes.push(OntologyTypeChange::breaking(
+                    OntologyTypeChangeKind::ChangedPropertyCardinality {
+                        property: property.clone(),
+                    },
+                ));
+                (previous.items(), updated)
+            }
+        };
+
+        ReferenceChanges::new([previous_reference.url()], [updated_reference.url()])
+            .push_version_changes(changes);
+    }
+}
@@ -1,5 +1,6 @@
 pub(crate) mod all_of;
 pub(crate) mod array;
+pub(crate) mod compatibility;
 pub(crate) mod object;
 pub(crate) mod one_of;
 pub(crate) mod validate;
@@ -0,0 +1,517 @@
+use std::collections::HashMap;
+
+use error_stack::Report;
+use serde::{Deserialize, Serialize};
+use temporal_versioning::ClosedTemporalBound;
+use type_system::url::VersionedUrl;
+
+use crate::knowledge::{
+    entity::Entity,
+    property::{PatchError, PropertyConfidence, PropertyDataTypes, PropertyProvenances},
+    Confidence, Property, PropertyObject, PropertyPatchOperation, PropertyPath, PropertyProvenance,
+};
+
+/// Decides which value is kept if two merged entities have different values for a property.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(tag = "strategy", rename_all = "camelCase")]
+pub enum PropertyMergeStrategy {
+    /// Keeps the value of the entity which was updated most recently.
+    #[default]
+    PreferNewest,
+    /// Keeps the value with the highest confidence. Values without a confidence are less
+    /// confident than any value with a confidence.
+    PreferHighestConfidence,
+    /// Replaces the value with the provided one.
+    Explicit { value: Property },
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum MergeSide {
+    Survivor,
+    Duplicate,
+}
+
+struct PropertyMerge<'a> {
+    survivor: &'a Entity,
+    duplicate: &'a Entity,
+    default_strategy: &'a PropertyMergeStrategy,
+    strategies: &'a HashMap<PropertyPath<'static>, PropertyMergeStrategy>,
+    newest: MergeSide,
+    confidence: HashMap<PropertyPath<'static>, Confidence>,
+    data_types: HashMap<PropertyPath<'static>, VersionedUrl>,
+    provenance: HashMap<PropertyPath<'static>, PropertyProvenance>,
+}
+
+impl<'a> PropertyMerge<'a> {
+    const fn survivor_or_duplicate(&self, side: MergeSide) -> &'a Entity {
+        match side {
+            MergeSide::Survivor => self.survivor,
+            MergeSide::Duplicate => self.duplicate,
+        }
+    }
+
+    /// Takes over the confidence values, data types, and provenance of the property at `path` from
+    /// the given side.
+    fn take_metadata(&mut self, side: MergeSide, path: &PropertyPath<'static>) {
+        let metadata = &self.survivor_or_duplicate(side).metadata;
+        self.confidence.extend(
+            metadata
+                .property_confidence
+                .iter()
+                .filter(|(key, _)| key.starts_with(path))
+                .map(|(key, confidence)| (key.clone(), *confidence)),
+        );
+        self.data_types.extend(
+            metadata
+                .property_data_types
+                .iter()
+                .filter(|(key, _)| key.starts_with(path))
+                .map(|(key, data_type_id)| (key.clone(), data_type_id.clone())),
+        );
+        self.provenance.extend(
+            metadata
+                .property_provenance
+                .iter()
+                .filter(|(key, _)| key.starts_with(path))
+                .map(|(key, provenance)| (key.clone(), provenance.clone())),
+        );
+    }
+
+    fn resolve_conflict(
+        &mut self,
+        path: &PropertyPath<'static>,
+        survivor: &Property,
+        duplicate: &Property,
+    ) -> Property {
+        let side = match self.strategies.get(path).unwrap_or(self.default_strategy) {
+            PropertyMergeStrategy::PreferNewest => self.newest,
+            PropertyMergeStrategy::PreferHighestConfidence => {
+                let survivor_confidence = self.survivor.metadata.property_confidence.get(path);
+                let duplicate_confidence = self.duplicate.metadata.property_confidence.get(path);
+                if duplicate_confidence > survivor_confidence {
+                    MergeSide::Duplicate
+                } else {
+                    MergeSide::Survivor
+                }
+            }
+            PropertyMergeStrategy::Explicit { value } => return value.clone(),
+        };
+
+        self.take_metadata(side, path);
+        match side {
+            MergeSide::Survivor => survivor.clone(),
+            MergeSide::Duplicate => duplicate.clone(),
+        }
+    }
+
+    fn merge_property(
+        &mut self,
+        path: &mut PropertyPath<'static>,
+        survivor: Option<&Property>,
+        duplicate: Option<&Property>,
+    ) -> Option<Property> {
+        match (survivor, duplicate) {
+            (None, None) => None,
+            (Some(property), None) => {
+                self.take_metadata(MergeSide::Survivor, path);
+                Some(property.clone())
+            }
+            (None, Some(property)) => {
+                self.take_metadata(MergeSide::Duplicate, path);
+                Some(property.clone())
+            }
+            (Some(survivor), Some(duplicate)) if survivor == duplicate => {
+                self.take_metadata(MergeSide::Survivor, path);
+                Some(survivor.clone())
+            }
+            (Some(Property::Object(survivor)), Some(Property::Object(duplicate)))
+                if !self.strategies.contains_key(path) =>
+            {
+                if let Some(confidence) = self
+                    .survivor
+                    .metadata
+                    .property_confidence
+                    .get(path)
+                    .or_else(|| self.duplicate.metadata.property_confidence.get(path))
+                {
+                    self.confidence.insert(path.clone(), confidence);
+                }
+                if let Some(provenance) = self
+                    .survivor
+                    .metadata
+                    .property_provenance
+                    .get(path)
+                    .or_else(|| self.duplicate.metadata.property_provenance.get(path))
+                {
+                    self.provenance.insert(path.clone(), provenance.clone());
+                }
+                Some(Property::Object(
+                    self.merge_object(path, survivor, duplicate),
+                ))
+            }
+            (Some(survivor), Some(duplicate)) => {
+                Some(self.resolve_conflict(path, survivor, duplicate))
+            }
+        }
+    }
+
+    fn merge_object(
+        &mut self,
+        path: &mut PropertyPath<'static>,
+        survivor: &PropertyObject,
+        duplicate: &PropertyObject,
+    ) -> PropertyObject {
+        let keys = survivor
+            .properties()
+            .keys()
+            .chain(
+                duplicate
+                    .properties()
+                    .keys()
+                    .filter(|key| !survivor.properties().contains_key(*key)),
+            )
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut merged = HashMap::with_capacity(keys.len());
+        for key in keys {
+            path.push(key.clone());
+            if let Some(property) = self.merge_property(
+                path,
+                survivor.properties().get(&key),
+                duplicate.properties().get(&key),
+            ) {
+                merged.insert(key, property);
+            }
+            path.pop();
+        }
+        PropertyObject::new(merged)
+    }
+}
+
+impl Entity {
+    /// Merges the properties of `duplicate` into the properties of this entity.
+    ///
+    /// Properties which are only set on one of the entities are kept and nested objects are
+    /// merged property by property. If both entities have different values for a property, the
+    /// strategy registered for its path in `strategies` decides which value is kept, falling back
+    /// to `default_strategy`. A strategy registered for an object applies to the object as a
+    /// whole. Explicit strategies are always applied, even if both entities agree on the value.
+    ///
+    /// The confidence values, data types, and provenance of a property are taken from the entity
+    /// its value was taken from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an explicit value could not be set because its parent does not exist
+    pub fn merge_properties(
+        &self,
+        duplicate: &Self,
+        default_strategy: &PropertyMergeStrategy,
+        strategies: &HashMap<PropertyPath<'static>, PropertyMergeStrategy>,
+    ) -> Result<
+        (
+            PropertyObject,
+            PropertyConfidence<'static>,
+            PropertyDataTypes<'static>,
+            PropertyProvenances<'static>,
+        ),
+        Report<PatchError>,
+    > {
+        let last_updated_at = |entity: &Self| {
+            let ClosedTemporalBound::Inclusive(decision_time) =
+                *entity.metadata.temporal_versioning.decision_time.start();
+            let ClosedTemporalBound::Inclusive(transaction_time) =
+                *entity.metadata.temporal_versioning.transaction_time.start();
+            (decision_time, transaction_time)
+        };
+
+        let mut merge = PropertyMerge {
+            survivor: self,
+            duplicate,
+            default_strategy,
+            strategies,
+            newest: if last_updated_at(duplicate) > last_updated_at(self) {
+                MergeSide::Duplicate
+            } else {
+                MergeSide::Survivor
+            },
+            confidence: HashMap::new(),
+            data_types: HashMap::new(),
+            provenance: HashMap::new(),
+        };
+        let mut properties = merge.merge_object(
+            &mut PropertyPath::default(),
+            &self.properties,
+            &duplicate.properties,
+        );
+        let mut property_confidence = PropertyConfidence::new(merge.confidence);
+        let mut property_data_types = PropertyDataTypes::new(merge.data_types);
+        let mut property_provenance = PropertyProvenances::new(merge.provenance);
+
+        // Shorter paths are set first, so explicit values of nested properties are not
+        // overwritten by explicit values of their parents.
+        let mut explicit_values = strategies
+            .iter()
+            .filter_map(|(path, strategy)| match strategy {
+                PropertyMergeStrategy::Explicit { value } => Some((path, value)),
+                PropertyMergeStrategy::PreferNewest
+                | PropertyMergeStrategy::PreferHighestConfidence => None,
+            })
+            .collect::<Vec<_>>();
+        explicit_values.sort_by_key(|(path, _)| path.len());
+        let operations = explicit_values
+            .into_iter()
+            .map(|(path, value)| PropertyPatchOperation::Add {
+                path: path.clone(),
+                value: value.clone(),
+                confidence: None,
+                data_type_id: None,
+                provenance: None,
+            })
+            .collect::<Vec<_>>();
+        properties.patch(&operations)?;
+        property_confidence.patch(&operations);
+        property_data_types.patch(&operations);
+        property_provenance.patch(&operations);
+
+        Ok((
+            properties,
+            property_confidence,
+            property_data_types,
+            property_provenance,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use type_system::url::BaseUrl;
+
+    use super::*;
+    use crate::knowledge::entity::{EntityMetadata, SourceProvenance};
+
+    const NAME: &str = "https://example.com/property-type/name/";
+    const EMAIL: &str = "https://example.com/property-type/email/";
+    const ADDRESS: &str = "https://example.com/property-type/address/";
+    const CITY: &str = "https://example.com/property-type/city/";
+
+    fn path(elements: &[&str]) -> PropertyPath<'static> {
+        elements
+            .iter()
+            .map(|element| {
+                BaseUrl::new((*element).to_owned())
+                    .expect("invalid base URL")
+                    .into()
+            })
+            .collect()
+    }
+
+    fn confidence(value: f64) -> Confidence {
+        serde_json::from_value(json!(value)).expect("invalid confidence")
+    }
+
+    fn entity(
+        updated_at: &str,
+        properties: serde_json::Value,
+        property_confidence: PropertyConfidence<'static>,
+    ) -> Entity {
+        let mut metadata: EntityMetadata = serde_json::from_value(json!({
+            "recordId": {
+                "entityId": "00000000-0000-0000-0000-000000000000~00000000-0000-0000-0000-000000000001",
+                "editionId": "00000000-0000-0000-0000-000000000002",
+            },
+            "temporalVersioning": {
+                "decisionTime": {
+                    "start": { "kind": "inclusive", "limit": updated_at },
+                    "end": { "kind": "unbounded" },
+                },
+                "transactionTime": {
+                    "start": { "kind": "inclusive", "limit": updated_at },
+                    "end": { "kind": "unbounded" },
+                },
+            },
+            "entityTypeIds": ["https://example.com/entity-type/person/v/1"],
+            "provenance": {
+                "createdById": "00000000-0000-0000-0000-000000000003",
+                "createdAtTransactionTime": updated_at,
+                "createdAtDecisionTime": updated_at,
+                "edition": {
+                    "createdById": "00000000-0000-0000-0000-000000000003",
+                },
+            },
+            "archived": false,
+        }))
+        .expect("invalid entity metadata");
+        metadata.property_confidence = property_confidence;
+
+        Entity {
+            properties: serde_json::from_value(properties).expect("invalid property object"),
+            link_data: None,
+            metadata,
+        }
+    }
+
+    fn survivor() -> Entity {
+        entity(
+            "2024-01-01T00:00:00Z",
+            json!({
+                NAME: "Alice",
+                ADDRESS: { CITY: "London" },
+            }),
+            PropertyConfidence::new(HashMap::from([(path(&[NAME]), confidence(0.5))])),
+        )
+    }
+
+    fn duplicate() -> Entity {
+        entity(
+            "2024-02-01T00:00:00Z",
+            json!({
+                NAME: "Alice Smith",
+                EMAIL: "alice@example.com",
+                ADDRESS: { CITY: "Paris" },
+            }),
+            PropertyConfidence::new(HashMap::from([
+                (path(&[NAME]), confidence(0.25)),
+                (path(&[ADDRESS, CITY]), confidence(0.75)),
+            ])),
+        )
+    }
+
+    fn properties(value: serde_json::Value) -> PropertyObject {
+        serde_json::from_value(value).expect("invalid property object")
+    }
+
+    #[test]
+    fn prefer_newest() {
+        let (properties, confidence, ..) = survivor()
+            .merge_properties(
+                &duplicate(),
+                &PropertyMergeStrategy::PreferNewest,
+                &HashMap::new(),
+            )
+            .expect("could not merge properties");
+
+        assert_eq!(
+            properties,
+            self::properties(json!({
+                NAME: "Alice Smith",
+                EMAIL: "alice@example.com",
+                ADDRESS: { CITY: "Paris" },
+            }))
+        );
+        assert_eq!(confidence, duplicate().metadata.property_confidence);
+    }
+
+    #[test]
+    fn prefer_highest_confidence() {
+        let (properties, confidence, ..) = survivor()
+            .merge_properties(
+                &duplicate(),
+                &PropertyMergeStrategy::PreferHighestConfidence,
+                &HashMap::new(),
+            )
+            .expect("could not merge properties");
+
+        assert_eq!(
+            properties,
+            self::properties(json!({
+                NAME: "Alice",
+                EMAIL: "alice@example.com",
+                ADDRESS: { CITY: "Paris" },
+            }))
+        );
+        assert_eq!(
+            confidence.get(&path(&[NAME])),
+            survivor().metadata.property_confidence.get(&path(&[NAME]))
+        );
+        assert_eq!(
+            confidence.get(&path(&[ADDRESS, CITY])),
+            duplicate()
+                .metadata
+                .property_confidence
+                .get(&path(&[ADDRESS, CITY]))
+        );
+    }
+
+    #[test]
+    fn provenance_follows_value() {
+        let provenance = |url: &str| PropertyProvenance {
+            sources: vec![SourceProvenance {
+                url: url.parse().expect("invalid URL"),
+            }],
+        };
+        let mut survivor = survivor();
+        survivor.metadata.property_provenance = PropertyProvenances::new(HashMap::from([(
+            path(&[NAME]),
+            provenance("https://example.com/survivor"),
+        )]));
+        let mut duplicate = duplicate();
+        duplicate.metadata.property_provenance = PropertyProvenances::new(HashMap::from([(
+            path(&[EMAIL]),
+            provenance("https://example.com/duplicate"),
+        )]));
+
+        let (.., newest) = survivor
+            .merge_properties(
+                &duplicate,
+                &PropertyMergeStrategy::PreferNewest,
+                &HashMap::new(),
+            )
+            .expect("could not merge properties");
+        assert_eq!(newest, duplicate.metadata.property_provenance);
+
+        let (.., most_confident) = survivor
+            .merge_properties(
+                &duplicate,
+                &PropertyMergeStrategy::PreferHighestConfidence,
+                &HashMap::new(),
+            )
+            .expect("could not merge properties");
+        assert_eq!(
+            most_confident.get(&path(&[NAME])),
+            survivor.metadata.property_provenance.get(&path(&[NAME]))
+        );
+        assert_eq!(
+            most_confident.get(&path(&[EMAIL])),
+            duplicate.metadata.property_provenance.get(&path(&[EMAIL]))
+        );
+    }
+
+    #[test]
+    fn per_path_strategies() {
+        let strategies = HashMap::from([
+            (
+                path(&[NAME]),
+                PropertyMergeStrategy::Explicit {
+                    value: Property::Value(json!("Alice J. Smith")),
+                },
+            ),
+            (
+                path(&[ADDRESS]),
+                PropertyMergeStrategy::PreferHighestConfidence,
+            ),
+        ]);
+        let (properties, confidence, ..) = survivor()
+            .merge_properties(
+                &duplicate(),
+                &PropertyMergeStrategy::PreferNewest,
+                &strategies,
+            )
+            .expect("could not merge properties");
+
+        // The address object itself has no confidence on either side, so the survivor's value is
+        // kept as a whole.
+        assert_eq!(
+            properties,
+            self::properties(json!({
+                NAME: "Alice J. Smith",
+                EMAIL: "alice@example.com",
+                ADDRESS: { CITY: "London" },
+            }))
+        );
+        assert!(confidence.is_empty());
+    }
+}
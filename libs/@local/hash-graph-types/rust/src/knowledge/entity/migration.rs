@@ -0,0 +1,216 @@
+use error_stack::{Report, ResultExt};
+use serde::{Deserialize, Serialize};
+
+use crate::knowledge::{
+    entity::Entity, property::PatchError, Property, PropertyPatchOperation, PropertyPath,
+};
+
+/// Changes the properties of an entity when it is migrated to another entity type.
+///
+/// Migrations whose source property does not exist are skipped.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(tag = "op", rename_all = "camelCase", deny_unknown_fields)]
+pub enum PropertyMigration {
+    /// Moves the property at `from` to `to`.
+    Rename {
+        from: PropertyPath<'static>,
+        to: PropertyPath<'static>,
+    },
+    /// Removes the property at `path`.
+    Drop { path: PropertyPath<'static> },
+    /// Sets the property at `path` to `value` if it does not exist.
+    Default {
+        path: PropertyPath<'static>,
+        value: Property,
+    },
+    /// Sets the property at `path` to the value found at the JSON pointer `pointer` in the
+    /// properties of the entity before the migration.
+    ///
+    /// Unlike a property path, the pointer may point into a property value, e.g. into a value of
+    /// an object data type.
+    Transform {
+        pointer: String,
+        path: PropertyPath<'static>,
+    },
+}
+
+impl Entity {
+    /// Applies the property migrations in order to the entity.
+    ///
+    /// The confidence and data types of a renamed property are kept. Confidence values and data
+    /// types of dropped, defaulted, or transformed properties are removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a migration could not be applied, e.g. because the parent of the target
+    /// property does not exist
+    pub fn migrate(&mut self, migrations: &[PropertyMigration]) -> Result<(), Report<PatchError>> {
+        let original = serde_json::to_value(&self.properties).change_context(PatchError)?;
+
+        for migration in migrations {
+            let operation = match migration {
+                PropertyMigration::Rename { from, to } => {
+                    if !self.properties.path_exists(from) {
+                        continue;
+                    }
+                    PropertyPatchOperation::Move {
+                        from: from.clone(),
+                        path: to.clone(),
+                        confidence: self.metadata.property_confidence.get(from),
+                    }
+                }
+                PropertyMigration::Drop { path } => {
+                    if !self.properties.path_exists(path) {
+                        continue;
+                    }
+                    PropertyPatchOperation::Remove { path: path.clone() }
+                }
+                PropertyMigration::Default { path, value } => {
+                    if self.properties.path_exists(path) {
+                        continue;
+                    }
+                    PropertyPatchOperation::Add {
+                        path: path.clone(),
+                        value: value.clone(),
+                        confidence: None,
+                        data_type_id: None,
+                        provenance: None,
+                    }
+                }
+                PropertyMigration::Transform { pointer, path } => {
+                    let Some(value) = original.pointer(pointer) else {
+                        continue;
+                    };
+                    PropertyPatchOperation::Add {
+                        path: path.clone(),
+                        value: Property::deserialize(value).change_context(PatchError)?,
+                        confidence: None,
+                        data_type_id: None,
+                        provenance: None,
+                    }
+                }
+            };
+            self.patch(&[operation])?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::knowledge::{entity::EntityMetadata, PropertyObject};
+
+    const NAME: &str = "https://example.com/property-type/name/";
+    const FULL_NAME: &str = "https://example.com/property-type/full-name/";
+    const AGE: &str = "https://example.com/property-type/age/";
+    const ADDRESS: &str = "https://example.com/property-type/address/";
+    const CITY: &str = "https://example.com/property-type/city/";
+
+    fn migration(value: serde_json::Value) -> PropertyMigration {
+        serde_json::from_value(value).expect("invalid migration")
+    }
+
+    fn entity(properties: serde_json::Value) -> Entity {
+        let metadata: EntityMetadata = serde_json::from_value(json!({
+            "recordId": {
+                "entityId": "00000000-0000-0000-0000-000000000000~00000000-0000-0000-0000-000000000001",
+                "editionId": "00000000-0000-0000-0000-000000000002",
+            },
+            "temporalVersioning": {
+                "decisionTime": {
+                    "start": { "kind": "inclusive", "limit": "2024-01-01T00:00:00Z" },
+                    "end": { "kind": "unbounded" },
+                },
+                "transactionTime": {
+                    "start": { "kind": "inclusive", "limit": "2024-01-01T00:00:00Z" },
+                    "end": { "kind": "unbounded" },
+                },
+            },
+            "entityTypeIds": ["https://example.com/entity-type/person/v/1"],
+            "provenance": {
+                "createdById": "00000000-0000-0000-0000-000000000003",
+                "createdAtTransactionTime": "2024-01-01T00:00:00Z",
+                "createdAtDecisionTime": "2024-01-01T00:00:00Z",
+                "edition": {
+                    "createdById": "00000000-0000-0000-0000-000000000003",
+                },
+            },
+            "archived": false,
+        }))
+        .expect("invalid entity metadata");
+
+        Entity {
+            properties: serde_json::from_value(properties).expect("invalid property object"),
+            link_data: None,
+            metadata,
+        }
+    }
+
+    fn pointer(url: &str) -> String {
+        format!("/{}", url.replace('~', "~0").replace('/', "~1"))
+    }
+
+    #[test]
+    fn migrate_properties() {
+        let mut entity = entity(json!({
+            NAME: "Alice",
+            AGE: 42,
+            ADDRESS: { CITY: "London" },
+        }));
+
+        entity
+            .migrate(&[
+                migration(
+                    json!({ "op": "rename", "from": pointer(NAME), "to": pointer(FULL_NAME) }),
+                ),
+                migration(json!({ "op": "drop", "path": pointer(AGE) })),
+                migration(json!({
+                    "op": "transform",
+                    "pointer": format!("{}{}", pointer(ADDRESS), pointer(CITY)),
+                    "path": pointer(CITY),
+                })),
+                migration(json!({ "op": "drop", "path": pointer(ADDRESS) })),
+            ])
+            .expect("could not migrate entity");
+
+        assert_eq!(
+            entity.properties,
+            serde_json::from_value::<PropertyObject>(json!({
+                FULL_NAME: "Alice",
+                CITY: "London",
+            }))
+            .expect("invalid property object")
+        );
+    }
+
+    #[test]
+    fn skip_missing_properties() {
+        let mut entity = entity(json!({ NAME: "Alice" }));
+
+        entity
+            .migrate(&[
+                migration(json!({ "op": "rename", "from": pointer(AGE), "to": pointer(NAME) })),
+                migration(json!({ "op": "drop", "path": pointer(ADDRESS) })),
+                migration(json!({ "op": "default", "path": pointer(NAME), "value": "Bob" })),
+                migration(json!({ "op": "default", "path": pointer(AGE), "value": 0 })),
+                migration(
+                    json!({ "op": "transform", "pointer": pointer(CITY), "path": pointer(CITY) }),
+                ),
+            ])
+            .expect("could not migrate entity");
+
+        assert_eq!(
+            entity.properties,
+            serde_json::from_value::<PropertyObject>(json!({
+                NAME: "Alice",
+                AGE: 0,
+            }))
+            .expect("invalid property object")
+        );
+    }
+}
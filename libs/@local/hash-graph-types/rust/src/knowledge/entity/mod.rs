@@ -1,3 +1,5 @@
+mod merge;
+mod migration;
 mod provenance;
 
 use std::{fmt, str::FromStr};
@@ -12,14 +14,19 @@ use type_system::url::{BaseUrl, VersionedUrl};
 use utoipa::{openapi, ToSchema};
 use uuid::Uuid;
 
-pub use self::provenance::{
-    EntityEditionProvenanceMetadata, EntityProvenanceMetadata, InferredEntityProvenanceMetadata,
-    ProvidedEntityEditionProvenanceMetadata,
+pub use self::{
+    merge::PropertyMergeStrategy,
+    migration::PropertyMigration,
+    provenance::{
+        ActorType, EntityEditionProvenanceMetadata, EntityProvenanceMetadata,
+        InferredEntityProvenanceMetadata, OriginProvenance,
+        ProvidedEntityEditionProvenanceMetadata, SourceProvenance,
+    },
 };
 use crate::{
     knowledge::{
         link::LinkData,
-        property::{PatchError, PropertyConfidence},
+        property::{PatchError, PropertyConfidence, PropertyDataTypes, PropertyProvenances},
         Confidence, PropertyObject, PropertyPatchOperation,
     },
     owned_by_id::OwnedById,
@@ -100,6 +107,12 @@ pub struct EntityMetadata {
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     #[serde(default, skip_serializing_if = "PropertyConfidence::is_empty")]
     pub property_confidence: PropertyConfidence<'static>,
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default, skip_serializing_if = "PropertyDataTypes::is_empty")]
+    pub property_data_types: PropertyDataTypes<'static>,
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default, skip_serializing_if = "PropertyProvenances::is_empty")]
+    pub property_provenance: PropertyProvenances<'static>,
 }
 
 /// A record of an [`Entity`] that has been persisted in the datastore, with its associated
@@ -116,7 +129,7 @@ pub struct Entity {
 }
 
 impl Entity {
-    /// Modify the properties and confidence values of the entity.
+    /// Modify the properties, confidence values, data types, and provenance of the entity.
     ///
     /// # Errors
     ///
@@ -127,6 +140,8 @@ impl Entity {
     ) -> Result<(), Report<PatchError>> {
         self.properties.patch(operations)?;
         self.metadata.property_confidence.patch(operations);
+        self.metadata.property_data_types.patch(operations);
+        self.metadata.property_provenance.patch(operations);
 
         Ok(())
     }
@@ -323,6 +338,33 @@ mod tests {
         test_entity(graph_test_data::entity::PAGE_V1);
     }
 
+    #[test]
+    fn edition_provenance() {
+        let json_value = serde_json::json!({
+            "createdById": "00000000-0000-0000-0000-000000000000",
+            "actorType": "ai",
+            "origin": {
+                "workflowId": "research-entities",
+            },
+            "sources": [
+                { "url": "https://example.com/people/alice" },
+            ],
+            "note": "Extracted from a web page",
+        });
+
+        let provenance: EntityEditionProvenanceMetadata =
+            serde_json::from_value(json_value.clone()).expect("invalid provenance");
+        assert_eq!(provenance.user_defined.actor_type, Some(ActorType::Ai));
+        assert_eq!(provenance.user_defined.sources.len(), 1);
+        assert!(!provenance.user_defined.is_empty());
+
+        assert_eq!(
+            serde_json::to_value(&provenance).expect("could not serialize"),
+            json_value,
+            "{provenance:#?}"
+        );
+    }
+
     mod diff {
         use std::{borrow::Cow, iter::once};
 
@@ -375,8 +417,8 @@ mod tests {
                 &new,
                 [PropertyDiff::Changed {
                     path: PropertyPath::default(),
-                    old: &old,
-                    new: &new,
+                    old: Cow::Borrowed(&old),
+                    new: Cow::Borrowed(&new),
                 }],
             );
         }
@@ -396,13 +438,13 @@ mod tests {
                 [
                     PropertyDiff::Changed {
                         path: once(PropertyPathElement::Index(1)).collect(),
-                        old: &property!("bar"),
-                        new: &property!("baz"),
+                        old: Cow::Borrowed(&property!("bar")),
+                        new: Cow::Borrowed(&property!("baz")),
                     },
                     PropertyDiff::Changed {
                         path: PropertyPath::default(),
-                        old: &old,
-                        new: &new,
+                        old: Cow::Borrowed(&old),
+                        new: Cow::Borrowed(&new),
                     },
                 ],
             );
@@ -418,12 +460,12 @@ mod tests {
                 [
                     PropertyDiff::Added {
                         path: once(PropertyPathElement::Index(1)).collect(),
-                        added: &property!("bar"),
+                        added: Cow::Borrowed(&property!("bar")),
                     },
                     PropertyDiff::Changed {
                         path: PropertyPath::default(),
-                        old: &old,
-                        new: &new,
+                        old: Cow::Borrowed(&old),
+                        new: Cow::Borrowed(&new),
                     },
                 ],
             );
@@ -439,12 +481,12 @@ mod tests {
                 [
                     PropertyDiff::Removed {
                         path: once(PropertyPathElement::Index(1)).collect(),
-                        removed: &property!("bar"),
+                        removed: Cow::Borrowed(&property!("bar")),
                     },
                     PropertyDiff::Changed {
                         path: PropertyPath::default(),
-                        old: &old,
-                        new: &new,
+                        old: Cow::Borrowed(&old),
+                        new: Cow::Borrowed(&new),
                     },
                 ],
             );
@@ -460,17 +502,17 @@ mod tests {
                 [
                     PropertyDiff::Changed {
                         path: once(PropertyPathElement::Index(1)).collect(),
-                        old: &property!("bar"),
-                        new: &property!("baz"),
+                        old: Cow::Borrowed(&property!("bar")),
+                        new: Cow::Borrowed(&property!("baz")),
                     },
                     PropertyDiff::Added {
                         path: once(PropertyPathElement::Index(2)).collect(),
-                        added: &property!("bar"),
+                        added: Cow::Borrowed(&property!("bar")),
                     },
                     PropertyDiff::Changed {
                         path: PropertyPath::default(),
-                        old: &old,
-                        new: &new,
+                        old: Cow::Borrowed(&old),
+                        new: Cow::Borrowed(&new),
                     },
                 ],
             );
@@ -486,17 +528,17 @@ mod tests {
                 [
                     PropertyDiff::Changed {
                         path: once(PropertyPathElement::Index(1)).collect(),
-                        old: &property!("bar"),
-                        new: &property!("baz"),
+                        old: Cow::Borrowed(&property!("bar")),
+                        new: Cow::Borrowed(&property!("baz")),
                     },
                     PropertyDiff::Removed {
                         path: once(PropertyPathElement::Index(2)).collect(),
-                        removed: &property!("baz"),
+                        removed: Cow::Borrowed(&property!("baz")),
                     },
                     PropertyDiff::Changed {
                         path: PropertyPath::default(),
-                        old: &old,
-                        new: &new,
+                        old: Cow::Borrowed(&old),
+                        new: Cow::Borrowed(&new),
                     },
                 ],
             );
@@ -517,18 +559,18 @@ mod tests {
                         ]
                         .into_iter()
                         .collect(),
-                        old: &property!("bar"),
-                        new: &property!("baz"),
+                        old: Cow::Borrowed(&property!("bar")),
+                        new: Cow::Borrowed(&property!("baz")),
                     },
                     PropertyDiff::Changed {
                         path: once(PropertyPathElement::Index(0)).collect(),
-                        old: &property!({create_base_url(0): "bar"}),
-                        new: &property!({create_base_url(0): "baz"}),
+                        old: Cow::Borrowed(&property!({create_base_url(0): "bar"})),
+                        new: Cow::Borrowed(&property!({create_base_url(0): "baz"})),
                     },
                     PropertyDiff::Changed {
                         path: PropertyPath::default(),
-                        old: &old,
-                        new: &new,
+                        old: Cow::Borrowed(&old),
+                        new: Cow::Borrowed(&new),
                     },
                 ],
             );
@@ -549,7 +591,7 @@ mod tests {
                         ]
                         .into_iter()
                         .collect(),
-                        removed: &property!("bar"),
+                        removed: Cow::Borrowed(&property!("bar")),
                     },
                     PropertyDiff::Added {
                         path: [
@@ -558,17 +600,17 @@ mod tests {
                         ]
                         .into_iter()
                         .collect(),
-                        added: &property!("baz"),
+                        added: Cow::Borrowed(&property!("baz")),
                     },
                     PropertyDiff::Changed {
                         path: once(PropertyPathElement::Index(0)).collect(),
-                        old: &property!({ create_base_url(0): "bar" }),
-                        new: &property!({ create_base_url(1): "baz" }),
+                        old: Cow::Borrowed(&property!({ create_base_url(0): "bar" })),
+                        new: Cow::Borrowed(&property!({ create_base_url(1): "baz" })),
                     },
                     PropertyDiff::Changed {
                         path: PropertyPath::default(),
-                        old: &old,
-                        new: &new,
+                        old: Cow::Borrowed(&old),
+                        new: Cow::Borrowed(&new),
                     },
                 ],
             );
@@ -596,12 +638,12 @@ mod tests {
                             &create_base_url(1),
                         )))
                         .collect(),
-                        added: &property!("foo"),
+                        added: Cow::Borrowed(&property!("foo")),
                     },
                     PropertyDiff::Changed {
                         path: PropertyPath::default(),
-                        old: &old,
-                        new: &new,
+                        old: Cow::Borrowed(&old),
+                        new: Cow::Borrowed(&new),
                     },
                 ],
             );
@@ -622,21 +664,21 @@ mod tests {
                         ]
                         .into_iter()
                         .collect(),
-                        old: &property!("foo"),
-                        new: &property!("bar"),
+                        old: Cow::Borrowed(&property!("foo")),
+                        new: Cow::Borrowed(&property!("bar")),
                     },
                     PropertyDiff::Changed {
                         path: once(PropertyPathElement::Property(Cow::Borrowed(
                             &create_base_url(1),
                         )))
                         .collect(),
-                        old: &property!({ create_base_url(2): "foo" }),
-                        new: &property!({ create_base_url(2): "bar" }),
+                        old: Cow::Borrowed(&property!({ create_base_url(2): "foo" })),
+                        new: Cow::Borrowed(&property!({ create_base_url(2): "bar" })),
                     },
                     PropertyDiff::Changed {
                         path: PropertyPath::default(),
-                        old: &old,
-                        new: &new,
+                        old: Cow::Borrowed(&old),
+                        new: Cow::Borrowed(&new),
                     },
                 ],
             );
@@ -655,19 +697,19 @@ mod tests {
                             &create_base_url(1),
                         )))
                         .collect(),
-                        removed: &property!({ create_base_url(3): "foo" }),
+                        removed: Cow::Borrowed(&property!({ create_base_url(3): "foo" })),
                     },
                     PropertyDiff::Added {
                         path: once(PropertyPathElement::Property(Cow::Borrowed(
                             &create_base_url(2),
                         )))
                         .collect(),
-                        added: &property!({ create_base_url(3): "foo" }),
+                        added: Cow::Borrowed(&property!({ create_base_url(3): "foo" })),
                     },
                     PropertyDiff::Changed {
                         path: PropertyPath::default(),
-                        old: &old,
-                        new: &new,
+                        old: Cow::Borrowed(&old),
+                        new: Cow::Borrowed(&new),
                     },
                 ],
             );
@@ -688,7 +730,7 @@ mod tests {
                         ]
                         .into_iter()
                         .collect(),
-                        added: &property!("foo"),
+                        added: Cow::Borrowed(&property!("foo")),
                     },
                     PropertyDiff::Removed {
                         path: [
@@ -697,28 +739,28 @@ mod tests {
                         ]
                         .into_iter()
                         .collect(),
-                        removed: &property!("foo"),
+                        removed: Cow::Borrowed(&property!("foo")),
                     },
                     PropertyDiff::Changed {
                         path: once(PropertyPathElement::Property(Cow::Borrowed(
                             &create_base_url(1),
                         )))
                         .collect(),
-                        old: &property!({ create_base_url(3): "foo" }),
-                        new: &property!({}),
+                        old: Cow::Borrowed(&property!({ create_base_url(3): "foo" })),
+                        new: Cow::Borrowed(&property!({})),
                     },
                     PropertyDiff::Changed {
                         path: once(PropertyPathElement::Property(Cow::Borrowed(
                             &create_base_url(2),
                         )))
                         .collect(),
-                        old: &property!({}),
-                        new: &property!({ create_base_url(3): "foo" }),
+                        old: Cow::Borrowed(&property!({})),
+                        new: Cow::Borrowed(&property!({ create_base_url(3): "foo" })),
                     },
                     PropertyDiff::Changed {
                         path: PropertyPath::default(),
-                        old: &old,
-                        new: &new,
+                        old: Cow::Borrowed(&old),
+                        new: Cow::Borrowed(&new),
                     },
                 ],
             );
@@ -737,13 +779,13 @@ mod tests {
                             &create_base_url(1),
                         )))
                         .collect(),
-                        old: &property!("foo"),
-                        new: &property!("bar"),
+                        old: Cow::Borrowed(&property!("foo")),
+                        new: Cow::Borrowed(&property!("bar")),
                     },
                     PropertyDiff::Changed {
                         path: PropertyPath::default(),
-                        old: &old,
-                        new: &new,
+                        old: Cow::Borrowed(&old),
+                        new: Cow::Borrowed(&new),
                     },
                 ],
             );
@@ -762,12 +804,12 @@ mod tests {
                             &create_base_url(1),
                         )))
                         .collect(),
-                        removed: &property!("foo"),
+                        removed: Cow::Borrowed(&property!("foo")),
                     },
                     PropertyDiff::Changed {
                         path: PropertyPath::default(),
-                        old: &old,
-                        new: &new,
+                        old: Cow::Borrowed(&old),
+                        new: Cow::Borrowed(&new),
                     },
                 ],
             );
@@ -7,8 +7,12 @@ use bytes::BytesMut;
 use postgres_types::{FromSql, IsNull, Json, ToSql, Type};
 use serde::{Deserialize, Serialize};
 use temporal_versioning::{DecisionTime, Timestamp, TransactionTime};
+use url::Url;
 
-use crate::account::{CreatedById, EditionArchivedById, EditionCreatedById};
+use crate::{
+    account::{CreatedById, EditionArchivedById, EditionCreatedById},
+    knowledge::entity::EntityId,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
@@ -18,6 +22,10 @@ pub struct EntityEditionProvenanceMetadata {
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub archived_by_id: Option<EditionArchivedById>,
+    /// The entity this entity was merged into. Only set on the edition archiving the entity.
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merged_into: Option<EntityId>,
     #[serde(flatten)]
     pub user_defined: ProvidedEntityEditionProvenanceMetadata,
 }
@@ -49,20 +57,67 @@ impl ToSql for EntityEditionProvenanceMetadata {
     }
 }
 
+/// The kind of actor which provided the data of an entity edition.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ActorType {
+    Human,
+    Machine,
+    Ai,
+}
+
+/// The workflow in which the data of an entity edition was produced.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct OriginProvenance {
+    pub workflow_id: String,
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow_run_id: Option<String>,
+}
+
+/// A source the data of an entity edition was taken from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct SourceProvenance {
+    #[cfg_attr(feature = "utoipa", schema(value_type = String, format = "uri"))]
+    pub url: Url,
+}
+
+/// Provenance of an entity edition which is provided by the user.
+///
+/// The provenance is stored alongside the edition, so it describes where the data of this
+/// edition came from. Sources of individual properties are stored with the properties instead,
+/// see [`PropertyProvenances`].
+///
+/// [`PropertyProvenances`]: crate::knowledge::PropertyProvenances
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct ProvidedEntityEditionProvenanceMetadata {
-    /// This field is only used to generate a TS type.
-    #[serde(default, rename = "__placeholder")]
-    __placeholder: (),
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_type: Option<ActorType>,
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<OriginProvenance>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<SourceProvenance>,
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
 }
 
 impl ProvidedEntityEditionProvenanceMetadata {
     #[must_use]
-    #[expect(clippy::unused_self, clippy::missing_const_for_fn)]
     pub fn is_empty(&self) -> bool {
-        true
+        self.actor_type.is_none()
+            && self.origin.is_none()
+            && self.sources.is_empty()
+            && self.note.is_none()
     }
 }
 
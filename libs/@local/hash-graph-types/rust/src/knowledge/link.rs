@@ -1,9 +1,16 @@
+use core::fmt;
+
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(feature = "utoipa")]
+use utoipa::{openapi, ToSchema};
 
 use crate::knowledge::{entity::EntityId, Confidence};
 
 /// The associated information for 'Link' entities
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct LinkData {
@@ -15,4 +22,233 @@ pub struct LinkData {
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub right_entity_confidence: Option<Confidence>,
+    /// The position of the link among the outgoing links of the left entity.
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<LinkPosition>,
+}
+
+const DIGITS: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+fn digit(byte: u8) -> Option<usize> {
+    DIGITS.iter().position(|digit| *digit == byte)
+}
+
+/// Returns the key between `lower` and `upper`, an empty `lower` is the start of the key space and
+/// a missing `upper` is its end.
+///
+/// `lower` has to be smaller than `upper` and neither of them may end with a `0`.
+fn midpoint(lower: &[u8], upper: Option<&[u8]>) -> Vec<u8> {
+    if let Some(upper) = upper {
+        let common = upper
+            .iter()
+            .enumerate()
+            .take_while(|(index, digit)| lower.get(*index).copied().unwrap_or(b'0') == **digit)
+            .count();
+        if common > 0 {
+            let mut key = upper[..common].to_vec();
+            key.extend(midpoint(
+                lower.get(common..).unwrap_or_default(),
+                Some(&upper[common..]),
+            ));
+            return key;
+        }
+    }
+
+    let lower_digit = lower.first().and_then(|byte| digit(*byte)).unwrap_or(0);
+    let upper_digit = upper
+        .and_then(|upper| upper.first())
+        .and_then(|byte| digit(*byte))
+        .unwrap_or(DIGITS.len());
+
+    if upper_digit - lower_digit > 1 {
+        vec![DIGITS[(lower_digit + upper_digit) / 2]]
+    } else if let Some(upper) = upper.filter(|upper| upper.len() > 1) {
+        vec![upper[0]]
+    } else {
+        let mut key = vec![DIGITS[lower_digit]];
+        key.extend(midpoint(lower.get(1..).unwrap_or_default(), None));
+        key
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LinkPositionError {
+    #[error("`{0}` is not a valid link position")]
+    Invalid(String),
+    #[error("`{lower}` is not lower than `{upper}`")]
+    InvalidRange {
+        lower: LinkPosition,
+        upper: LinkPosition,
+    },
+}
+
+/// The position of a link among its siblings as a fractional index.
+///
+/// Positions are compared lexicographically and there is always a position between two different
+/// positions, so a link can be moved between two siblings without changing the position of any
+/// other link. A position consists of the digits `0-9`, `A-Z`, and `a-z` and does not end with `0`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+#[repr(transparent)]
+pub struct LinkPosition(String);
+
+impl LinkPosition {
+    /// Creates a link position from its string representation.
+    ///
+    /// # Errors
+    ///
+    /// - [`Invalid`] if `position` is empty, contains other characters than digits and ASCII
+    ///   letters, or ends with `0`
+    ///
+    /// [`Invalid`]: LinkPositionError::Invalid
+    pub fn new(position: String) -> Result<Self, LinkPositionError> {
+        if position.is_empty()
+            || position.ends_with('0')
+            || !position.bytes().all(|byte| byte.is_ascii_alphanumeric())
+        {
+            Err(LinkPositionError::Invalid(position))
+        } else {
+            Ok(Self(position))
+        }
+    }
+
+    /// Returns a position between `lower` and `upper`.
+    ///
+    /// If `lower` is `None` the position is before `upper`, if `upper` is `None` the position is
+    /// after `lower`. If both are `None` a position for the first link is returned.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidRange`] if `lower` is not lower than `upper`
+    ///
+    /// [`InvalidRange`]: LinkPositionError::InvalidRange
+    pub fn between(lower: Option<&Self>, upper: Option<&Self>) -> Result<Self, LinkPositionError> {
+        if let (Some(lower), Some(upper)) = (lower, upper) {
+            if lower >= upper {
+                return Err(LinkPositionError::InvalidRange {
+                    lower: lower.clone(),
+                    upper: upper.clone(),
+                });
+            }
+        }
+
+        let key = midpoint(
+            lower.map_or(b"".as_slice(), |lower| lower.0.as_bytes()),
+            upper.map(|upper| upper.0.as_bytes()),
+        );
+        Ok(Self(
+            String::from_utf8(key).expect("positions only consist of ASCII digits"),
+        ))
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for LinkPosition {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for LinkPosition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Self::new(String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl ToSchema<'_> for LinkPosition {
+    fn schema() -> (&'static str, openapi::RefOr<openapi::Schema>) {
+        (
+            "LinkPosition",
+            openapi::Schema::Object(
+                openapi::schema::ObjectBuilder::new()
+                    .schema_type(openapi::SchemaType::String)
+                    .pattern(Some("^[0-9A-Za-z]*[1-9A-Za-z]$"))
+                    .build(),
+            )
+            .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(position: &str) -> LinkPosition {
+        LinkPosition::new(position.to_owned()).expect("invalid link position")
+    }
+
+    fn between(lower: Option<&str>, upper: Option<&str>) -> String {
+        let lower = lower.map(position);
+        let upper = upper.map(position);
+        let key = LinkPosition::between(lower.as_ref(), upper.as_ref())
+            .expect("could not generate position");
+        if let Some(lower) = &lower {
+            assert!(*lower < key, "{key} is not after {lower}");
+        }
+        if let Some(upper) = &upper {
+            assert!(key < *upper, "{key} is not before {upper}");
+        }
+        key.0
+    }
+
+    #[test]
+    fn invalid_position() {
+        for invalid in ["", "a0", "a-b", "ä"] {
+            assert!(LinkPosition::new(invalid.to_owned()).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn between_positions() {
+        assert_eq!(between(None, None), "V");
+        assert_eq!(between(Some("V"), None), "k");
+        assert_eq!(between(None, Some("V")), "F");
+        assert_eq!(between(Some("a"), Some("c")), "b");
+        assert_eq!(between(Some("a"), Some("b")), "aV");
+        assert_eq!(between(Some("a"), Some("b1")), "b");
+        assert_eq!(between(Some("z"), None), "zV");
+        assert_eq!(between(None, Some("1")), "0V");
+        assert_eq!(between(Some("a1"), Some("a2")), "a1V");
+    }
+
+    #[test]
+    fn invalid_range() {
+        assert!(LinkPosition::between(Some(&position("b")), Some(&position("a"))).is_err());
+        assert!(LinkPosition::between(Some(&position("a")), Some(&position("a"))).is_err());
+    }
+
+    #[test]
+    fn repeated_insertion() {
+        let mut lower = None;
+        let upper = position("1");
+        for _ in 0..100 {
+            let key = LinkPosition::between(lower.as_ref(), Some(&upper))
+                .expect("could not generate position");
+            assert!(key < upper);
+            if let Some(lower) = &lower {
+                assert!(*lower < key);
+            }
+            lower = Some(key);
+        }
+
+        let mut upper = None;
+        for _ in 0..100 {
+            let key =
+                LinkPosition::between(None, upper.as_ref()).expect("could not generate position");
+            if let Some(upper) = &upper {
+                assert!(key < *upper);
+            }
+            upper = Some(key);
+        }
+    }
 }
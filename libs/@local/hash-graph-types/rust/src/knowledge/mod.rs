@@ -4,8 +4,9 @@ pub mod link;
 pub use self::{
     confidence::Confidence,
     property::{
-        Property, PropertyConfidence, PropertyDiff, PropertyObject, PropertyPatchOperation,
-        PropertyPath, PropertyPathElement,
+        Property, PropertyConfidence, PropertyDataTypes, PropertyDiff, PropertyMergeConflict,
+        PropertyObject, PropertyPatchOperation, PropertyPath, PropertyPathElement,
+        PropertyProvenance, PropertyProvenances,
     },
 };
 
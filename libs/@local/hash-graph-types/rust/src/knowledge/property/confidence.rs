@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::knowledge::{Confidence, PropertyPatchOperation, PropertyPath};
+use crate::knowledge::{Confidence, PropertyObject, PropertyPatchOperation, PropertyPath};
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
@@ -31,6 +31,48 @@ impl<'a> PropertyConfidence<'a> {
         self.map.iter()
     }
 
+    /// Returns the confidence of the property at the given path.
+    #[must_use]
+    pub fn get(&self, path: &PropertyPath<'a>) -> Option<Confidence> {
+        self.map.get(path).copied()
+    }
+
+    /// Applies the changes made to `self` since `base` to `other`.
+    ///
+    /// This is the counterpart of [`PropertyObject::merge`] for the confidence values of the
+    /// properties, where `merged_paths` are the paths taken from `self` and `properties` are the
+    /// merged properties. Within `merged_paths`, the confidence values of `self` are used. Outside
+    /// of them, confidence values changed in `self` since `base` are kept unless `other` changed
+    /// them as well. Confidence values of properties which don't exist in `properties` are
+    /// dropped.
+    #[must_use]
+    pub fn merge(
+        &self,
+        base: &Self,
+        other: &Self,
+        merged_paths: &[PropertyPath<'a>],
+        properties: &PropertyObject,
+    ) -> Self {
+        self.map
+            .keys()
+            .chain(base.map.keys())
+            .chain(other.map.keys())
+            .filter(|path| properties.path_exists(path))
+            .filter_map(|path| {
+                let take_ours = merged_paths
+                    .iter()
+                    .any(|merged_path| path.starts_with(merged_path))
+                    || (self.get(path) != base.get(path) && other.get(path) == base.get(path));
+                let confidence = if take_ours {
+                    self.get(path)
+                } else {
+                    other.get(path)
+                };
+                Some((path.clone(), confidence?))
+            })
+            .collect()
+    }
+
     pub fn patch(&mut self, operations: &[PropertyPatchOperation]) {
         for operation in operations {
             match operation {
@@ -41,6 +83,8 @@ impl<'a> PropertyConfidence<'a> {
                     path,
                     value: _,
                     confidence,
+                    data_type_id: _,
+                    provenance: _,
                 }
                 | PropertyPatchOperation::Copy {
                     from: _,
@@ -51,6 +95,8 @@ impl<'a> PropertyConfidence<'a> {
                     path,
                     value: _,
                     confidence,
+                    data_type_id: _,
+                    provenance: _,
                 } => {
                     self.map.retain(|key, _| !key.starts_with(path));
                     if let Some(confidence) = confidence {
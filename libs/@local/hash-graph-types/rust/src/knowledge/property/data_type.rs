@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use type_system::url::VersionedUrl;
+
+use crate::knowledge::{PropertyObject, PropertyPatchOperation, PropertyPath};
+
+/// The data types the values of properties are expressed in.
+///
+/// Declaring the data type of a value is required for values which are given in a unit other
+/// than the one the property type expects, e.g. a length in centimeters for a property which
+/// expects meters.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct PropertyDataTypes<'a> {
+    #[serde(flatten)]
+    map: HashMap<PropertyPath<'a>, VersionedUrl>,
+}
+
+impl<'a> PropertyDataTypes<'a> {
+    #[must_use]
+    pub const fn new(data_types: HashMap<PropertyPath<'a>, VersionedUrl>) -> Self {
+        Self { map: data_types }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&PropertyPath<'a>, &VersionedUrl)> {
+        self.map.iter()
+    }
+
+    /// Returns the data type the value of the property at the given path is expressed in.
+    #[must_use]
+    pub fn get(&self, path: &PropertyPath<'a>) -> Option<&VersionedUrl> {
+        self.map.get(path)
+    }
+
+    /// Applies the changes made to `self` since `base` to `other`.
+    ///
+    /// This is the counterpart of [`PropertyObject::merge`] for the data types of the properties
+    /// and follows the same rules as [`PropertyConfidence::merge`].
+    ///
+    /// [`PropertyConfidence::merge`]: crate::knowledge::PropertyConfidence::merge
+    #[must_use]
+    pub fn merge(
+        &self,
+        base: &Self,
+        other: &Self,
+        merged_paths: &[PropertyPath<'a>],
+        properties: &PropertyObject,
+    ) -> Self {
+        self.map
+            .keys()
+            .chain(base.map.keys())
+            .chain(other.map.keys())
+            .filter(|path| properties.path_exists(path))
+            .filter_map(|path| {
+                let take_ours = merged_paths
+                    .iter()
+                    .any(|merged_path| path.starts_with(merged_path))
+                    || (self.get(path) != base.get(path) && other.get(path) == base.get(path));
+                let data_type_id = if take_ours {
+                    self.get(path)
+                } else {
+                    other.get(path)
+                };
+                Some((path.clone(), data_type_id?.clone()))
+            })
+            .collect()
+    }
+
+    /// Applies the patch operations to the data types.
+    ///
+    /// Moved and copied values keep their data types, while values which are added or replaced
+    /// are only given the data type specified in the operation.
+    pub fn patch(&mut self, operations: &[PropertyPatchOperation]) {
+        for operation in operations {
+            match operation {
+                PropertyPatchOperation::Remove { path } => {
+                    self.map.retain(|key, _| !key.starts_with(path));
+                }
+                PropertyPatchOperation::Add {
+                    path,
+                    value: _,
+                    confidence: _,
+                    data_type_id,
+                    provenance: _,
+                }
+                | PropertyPatchOperation::Replace {
+                    path,
+                    value: _,
+                    confidence: _,
+                    data_type_id,
+                    provenance: _,
+                } => {
+                    self.map.retain(|key, _| !key.starts_with(path));
+                    if let Some(data_type_id) = data_type_id {
+                        self.map.insert(path.clone(), data_type_id.clone());
+                    }
+                }
+                PropertyPatchOperation::Copy {
+                    from,
+                    path,
+                    confidence: _,
+                } => {
+                    let copied = self.rebase(from, path);
+                    self.map.retain(|key, _| !key.starts_with(path));
+                    self.map.extend(copied);
+                }
+                PropertyPatchOperation::Move {
+                    from,
+                    path,
+                    confidence: _,
+                } => {
+                    let moved = self.rebase(from, path);
+                    self.map
+                        .retain(|key, _| !key.starts_with(from) && !key.starts_with(path));
+                    self.map.extend(moved);
+                }
+                PropertyPatchOperation::Test { path: _, value: _ } => {}
+            }
+        }
+    }
+
+    /// Returns the data types of the values at or below `from` as if they were located at `to`.
+    fn rebase(
+        &self,
+        from: &PropertyPath<'a>,
+        to: &PropertyPath<'a>,
+    ) -> Vec<(PropertyPath<'a>, VersionedUrl)> {
+        self.map
+            .iter()
+            .filter(|(key, _)| key.starts_with(from))
+            .map(|(key, data_type_id)| {
+                (
+                    to.clone()
+                        .into_iter()
+                        .chain(key.clone().into_iter().skip(from.len()))
+                        .collect(),
+                    data_type_id.clone(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<'a> IntoIterator for PropertyDataTypes<'a> {
+    type IntoIter = std::collections::hash_map::IntoIter<PropertyPath<'a>, VersionedUrl>;
+    type Item = (PropertyPath<'a>, VersionedUrl);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter()
+    }
+}
+
+impl<'a, 'p> IntoIterator for &'p PropertyDataTypes<'a> {
+    type IntoIter = std::collections::hash_map::Iter<'p, PropertyPath<'a>, VersionedUrl>;
+    type Item = (&'p PropertyPath<'a>, &'p VersionedUrl);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.iter()
+    }
+}
+
+impl<'a> FromIterator<(PropertyPath<'a>, VersionedUrl)> for PropertyDataTypes<'a> {
+    fn from_iter<T: IntoIterator<Item = (PropertyPath<'a>, VersionedUrl)>>(iter: T) -> Self {
+        Self {
+            map: iter.into_iter().collect(),
+        }
+    }
+}
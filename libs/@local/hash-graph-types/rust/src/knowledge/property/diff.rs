@@ -1,18 +1,58 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+
 use crate::knowledge::{Property, PropertyPath};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", tag = "op")]
 pub enum PropertyDiff<'e> {
     Added {
         path: PropertyPath<'e>,
-        added: &'e Property,
+        #[cfg_attr(feature = "utoipa", schema(value_type = Property))]
+        added: Cow<'e, Property>,
     },
     Removed {
         path: PropertyPath<'e>,
-        removed: &'e Property,
+        #[cfg_attr(feature = "utoipa", schema(value_type = Property))]
+        removed: Cow<'e, Property>,
     },
     Changed {
         path: PropertyPath<'e>,
-        old: &'e Property,
-        new: &'e Property,
+        #[cfg_attr(feature = "utoipa", schema(value_type = Property))]
+        old: Cow<'e, Property>,
+        #[cfg_attr(feature = "utoipa", schema(value_type = Property))]
+        new: Cow<'e, Property>,
     },
 }
+
+impl<'e> PropertyDiff<'e> {
+    #[must_use]
+    pub const fn path(&self) -> &PropertyPath<'e> {
+        match self {
+            Self::Added { path, .. } | Self::Removed { path, .. } | Self::Changed { path, .. } => {
+                path
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn into_owned(self) -> PropertyDiff<'static> {
+        match self {
+            Self::Added { path, added } => PropertyDiff::Added {
+                path: path.into_owned(),
+                added: Cow::Owned(added.into_owned()),
+            },
+            Self::Removed { path, removed } => PropertyDiff::Removed {
+                path: path.into_owned(),
+                removed: Cow::Owned(removed.into_owned()),
+            },
+            Self::Changed { path, old, new } => PropertyDiff::Changed {
+                path: path.into_owned(),
+                old: Cow::Owned(old.into_owned()),
+                new: Cow::Owned(new.into_owned()),
+            },
+        }
+    }
+}
@@ -1,10 +1,12 @@
 mod confidence;
+mod data_type;
 mod diff;
 mod object;
 mod patch;
 mod path;
+mod provenance;
 
-use std::{cmp::Ordering, collections::HashMap, fmt, io};
+use std::{borrow::Cow, cmp::Ordering, collections::HashMap, fmt, io};
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
@@ -13,10 +15,12 @@ use type_system::{url::BaseUrl, JsonSchemaValueType};
 
 pub use self::{
     confidence::PropertyConfidence,
+    data_type::PropertyDataTypes,
     diff::PropertyDiff,
     object::PropertyObject,
     patch::PropertyPatchOperation,
     path::{PropertyPath, PropertyPathElement},
+    provenance::{PropertyProvenance, PropertyProvenances},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -32,6 +36,15 @@ pub enum Property {
 #[error("Failed to apply patch")]
 pub struct PatchError;
 
+/// Both sides of a merge changed the properties at the given paths to different values.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Error)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+#[error("Conflicting changes to {} properties", .paths.len())]
+pub struct PropertyMergeConflict {
+    pub paths: Vec<PropertyPath<'static>>,
+}
+
 impl Property {
     #[must_use]
     pub fn json_type(&self) -> JsonSchemaValueType {
@@ -111,7 +124,7 @@ impl Property {
                     path.push(index);
                     yield PropertyDiff::Added {
                         path: path.clone(),
-                        added: property,
+                        added: Cow::Borrowed(property),
                     };
                     path.pop();
                 }
@@ -122,7 +135,7 @@ impl Property {
                     path.push(index);
                     yield PropertyDiff::Removed {
                         path: path.clone(),
-                        removed: property,
+                        removed: Cow::Borrowed(property),
                     };
                     path.pop();
                 }
@@ -145,7 +158,7 @@ impl Property {
             } else {
                 yield PropertyDiff::Removed {
                     path: path.clone(),
-                    removed: property,
+                    removed: Cow::Borrowed(property),
                 };
             }
             path.pop();
@@ -155,7 +168,7 @@ impl Property {
                 path.push(key);
                 yield PropertyDiff::Added {
                     path: path.clone(),
-                    added: property,
+                    added: Cow::Borrowed(property),
                 };
                 path.pop();
             }
@@ -189,8 +202,8 @@ impl Property {
         if changed {
             yield PropertyDiff::Changed {
                 path: path.clone(),
-                old: self,
-                new: other,
+                old: Cow::Borrowed(self),
+                new: Cow::Borrowed(other),
             };
         }
     }
@@ -18,7 +18,7 @@ use type_system::url::BaseUrl;
 use utoipa::ToSchema;
 
 use crate::knowledge::{
-    property::{PatchError, Property},
+    property::{PatchError, Property, PropertyMergeConflict},
     PropertyDiff, PropertyPatchOperation, PropertyPath, PropertyPathElement,
 };
 
@@ -64,6 +64,128 @@ impl PropertyObject {
         Property::diff_object(self.properties(), other.properties(), path)
     }
 
+    /// Returns the property at the given path.
+    #[must_use]
+    pub fn get<'a>(&self, path: &'a PropertyPath<'a>) -> Option<&Property> {
+        let mut path_iter = path.iter();
+        let PropertyPathElement::Property(first_key) = path_iter.next()? else {
+            return None;
+        };
+        self.0.get(first_key)?.get(path_iter)
+    }
+
+    /// Returns the innermost paths which were changed between `self` and `other`.
+    ///
+    /// [`Self::diff`] also reports a change for every object or array containing a changed
+    /// property, these changes are omitted. Arrays are compared as a whole: a change inside of an
+    /// array is reported at the path of the array, as elements cannot be matched by their index
+    /// once elements were added or removed.
+    fn changed_paths(&self, other: &Self) -> Vec<PropertyPath<'static>> {
+        let mut paths = self
+            .diff(other, &mut PropertyPath::default())
+            .map(|change| {
+                change
+                    .path()
+                    .clone()
+                    .into_owned()
+                    .into_iter()
+                    .take_while(|element| matches!(element, PropertyPathElement::Property(_)))
+                    .collect::<PropertyPath<'static>>()
+            })
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths.dedup();
+        paths
+            .iter()
+            .filter(|path| {
+                !paths
+                    .iter()
+                    .any(|other_path| other_path.len() > path.len() && other_path.starts_with(path))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the paths which were changed differently in `self` and `other` since `base`.
+    ///
+    /// Two changes conflict if one of the changed paths contains the other one and the resulting
+    /// values at the outer path differ. Arrays are compared as a whole, so any two different
+    /// changes to the same array conflict.
+    #[must_use]
+    pub fn merge_conflicts(&self, base: &Self, other: &Self) -> Vec<PropertyPath<'static>> {
+        let their_paths = base.changed_paths(other);
+        base.changed_paths(self)
+            .into_iter()
+            .filter(|our_path| {
+                their_paths.iter().any(|their_path| {
+                    let (outer_path, inner_path) = if our_path.len() <= their_path.len() {
+                        (our_path, their_path)
+                    } else {
+                        (their_path, our_path)
+                    };
+                    inner_path.starts_with(outer_path)
+                        && self.get(outer_path) != other.get(outer_path)
+                })
+            })
+            .collect()
+    }
+
+    /// Applies the changes made to `self` since `base` to `other`.
+    ///
+    /// This is a three-way merge where `self` and `other` were both derived from `base`. Changes
+    /// are merged at the level of the innermost changed properties, so both sides may change
+    /// different properties of the same nested object. Arrays are merged as whole values.
+    ///
+    /// # Errors
+    ///
+    /// - [`PropertyMergeConflict`] if both sides changed the same properties to different values
+    /// - [`PatchError`] if the changes could not be applied to `other`
+    pub fn merge(&self, base: &Self, other: &Self) -> Result<Self, Report<PatchError>> {
+        let conflicts = self.merge_conflicts(base, other);
+        if !conflicts.is_empty() {
+            return Err(
+                Report::new(PropertyMergeConflict { paths: conflicts }).change_context(PatchError)
+            );
+        }
+
+        let mut operations = Vec::new();
+        for path in self.merged_paths(base, other) {
+            match (self.get(&path), other.get(&path)) {
+                (Some(value), Some(_)) => operations.push(PropertyPatchOperation::Replace {
+                    path,
+                    value: value.clone(),
+                    confidence: None,
+                    data_type_id: None,
+                    provenance: None,
+                }),
+                (Some(value), None) => operations.push(PropertyPatchOperation::Add {
+                    path,
+                    value: value.clone(),
+                    confidence: None,
+                    data_type_id: None,
+                    provenance: None,
+                }),
+                (None, _) => operations.push(PropertyPatchOperation::Remove { path }),
+            }
+        }
+
+        let mut merged = other.clone();
+        merged.patch(&operations)?;
+        Ok(merged)
+    }
+
+    /// Returns the paths of the properties which [`Self::merge`] takes from `self`.
+    ///
+    /// These are the properties changed in `self` since `base`, unless `other` contains the same
+    /// change already.
+    #[must_use]
+    pub fn merged_paths(&self, base: &Self, other: &Self) -> Vec<PropertyPath<'static>> {
+        base.changed_paths(self)
+            .into_iter()
+            .filter(|path| self.get(path) != other.get(path))
+            .collect()
+    }
+
     #[must_use]
     pub fn path_exists(&self, path: &PropertyPath<'_>) -> bool {
         let mut path_iter = path.iter();
@@ -97,6 +219,8 @@ impl PropertyObject {
                         path,
                         value,
                         confidence: _,
+                        data_type_id: _,
+                        provenance: _,
                     } => PatchOperation::Add(AddOperation {
                         path: path.to_json_pointer(),
                         value: serde_json::to_value(value).change_context(PatchError)?,
@@ -110,6 +234,8 @@ impl PropertyObject {
                         path,
                         value,
                         confidence: _,
+                        data_type_id: _,
+                        provenance: _,
                     } => PatchOperation::Replace(ReplaceOperation {
                         path: path.to_json_pointer(),
                         value: serde_json::to_value(value).change_context(PatchError)?,
@@ -196,3 +322,147 @@ impl<'a> FromSql<'a> for PropertyObject {
         <postgres_types::Json<Self> as ToSql>::accepts(ty)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const NAME: &str = "https://example.com/property-type/name/";
+    const ADDRESS: &str = "https://example.com/property-type/address/";
+    const STREET: &str = "https://example.com/property-type/street/";
+    const CITY: &str = "https://example.com/property-type/city/";
+    const TAGS: &str = "https://example.com/property-type/tags/";
+
+    fn object(value: JsonValue) -> PropertyObject {
+        serde_json::from_value(value).expect("invalid property object")
+    }
+
+    fn base() -> PropertyObject {
+        object(json!({
+            NAME: "Alice",
+            ADDRESS: {
+                STREET: "Main Street",
+                CITY: "London",
+            },
+        }))
+    }
+
+    #[test]
+    fn merge_independent_changes() {
+        let ours = object(json!({
+            NAME: "Bob",
+            ADDRESS: {
+                STREET: "Main Street",
+                CITY: "London",
+            },
+        }));
+        let theirs = object(json!({
+            NAME: "Alice",
+            ADDRESS: {
+                STREET: "Main Street",
+                CITY: "Paris",
+            },
+        }));
+
+        assert_eq!(
+            ours.merge(&base(), &theirs).expect("could not merge"),
+            object(json!({
+                NAME: "Bob",
+                ADDRESS: {
+                    STREET: "Main Street",
+                    CITY: "Paris",
+                },
+            }))
+        );
+    }
+
+    #[test]
+    fn merge_identical_changes() {
+        let changed = object(json!({
+            NAME: "Alice",
+            ADDRESS: {
+                STREET: "High Street",
+                CITY: "London",
+            },
+        }));
+
+        assert!(changed.merge_conflicts(&base(), &changed).is_empty());
+        assert_eq!(
+            changed.merge(&base(), &changed).expect("could not merge"),
+            changed
+        );
+    }
+
+    #[test]
+    fn merge_conflicting_changes() {
+        let ours = object(json!({
+            NAME: "Alice",
+            ADDRESS: {
+                STREET: "High Street",
+                CITY: "London",
+            },
+        }));
+        let theirs = object(json!({
+            NAME: "Alice",
+        }));
+
+        let mut street_path = PropertyPath::default();
+        street_path.push(BaseUrl::new(ADDRESS.to_owned()).expect("invalid base URL"));
+        street_path.push(BaseUrl::new(STREET.to_owned()).expect("invalid base URL"));
+        assert_eq!(ours.merge_conflicts(&base(), &theirs), [street_path]);
+
+        let report = ours
+            .merge(&base(), &theirs)
+            .expect_err("conflicting changes were merged");
+        assert!(report.contains::<PropertyMergeConflict>());
+    }
+
+    #[test]
+    fn merge_one_sided_array_change() {
+        let base = object(json!({
+            NAME: "Alice",
+            TAGS: ["a", "b", "c"],
+        }));
+        let ours = object(json!({
+            NAME: "Alice",
+            TAGS: ["a", "c"],
+        }));
+        let theirs = object(json!({
+            NAME: "Bob",
+            TAGS: ["a", "b", "c"],
+        }));
+
+        assert!(ours.merge_conflicts(&base, &theirs).is_empty());
+        assert_eq!(
+            ours.merge(&base, &theirs).expect("could not merge"),
+            object(json!({
+                NAME: "Bob",
+                TAGS: ["a", "c"],
+            }))
+        );
+    }
+
+    #[test]
+    fn merge_divergent_array_changes() {
+        let base = object(json!({
+            TAGS: ["a", "b", "c"],
+        }));
+        let ours = object(json!({
+            TAGS: ["a", "c"],
+        }));
+        let theirs = object(json!({
+            TAGS: ["a", "b", "d"],
+        }));
+
+        let mut tags_path = PropertyPath::default();
+        tags_path.push(BaseUrl::new(TAGS.to_owned()).expect("invalid base URL"));
+        assert_eq!(ours.merge_conflicts(&base, &theirs), [tags_path]);
+
+        let report = ours
+            .merge(&base, &theirs)
+            .expect_err("divergent array changes were merged");
+        assert!(report.contains::<PropertyMergeConflict>());
+    }
+}
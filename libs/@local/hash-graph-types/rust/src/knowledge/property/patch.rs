@@ -1,6 +1,7 @@
 use serde::Deserialize;
+use type_system::url::VersionedUrl;
 
-use crate::knowledge::{Confidence, Property, PropertyPath};
+use crate::knowledge::{Confidence, Property, PropertyPath, PropertyProvenance};
 
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
@@ -12,6 +13,16 @@ pub enum PropertyPatchOperation {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         #[cfg_attr(feature = "utoipa", schema(nullable = false))]
         confidence: Option<Confidence>,
+        #[serde(
+            rename = "dataTypeId",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+        data_type_id: Option<VersionedUrl>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+        provenance: Option<PropertyProvenance>,
     },
     Remove {
         path: PropertyPath<'static>,
@@ -22,6 +33,16 @@ pub enum PropertyPatchOperation {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         #[cfg_attr(feature = "utoipa", schema(nullable = false))]
         confidence: Option<Confidence>,
+        #[serde(
+            rename = "dataTypeId",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+        data_type_id: Option<VersionedUrl>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+        provenance: Option<PropertyProvenance>,
     },
     Move {
         from: PropertyPath<'static>,
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+#[cfg(feature = "postgres")]
+use std::error::Error;
+
+#[cfg(feature = "postgres")]
+use bytes::BytesMut;
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, IsNull, Json, ToSql, Type};
+use serde::{Deserialize, Serialize};
+
+use crate::knowledge::{
+    entity::SourceProvenance, PropertyObject, PropertyPatchOperation, PropertyPath,
+};
+
+/// Provenance of a single property of an entity.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct PropertyProvenance {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<SourceProvenance>,
+}
+
+impl PropertyProvenance {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<'a> FromSql<'a> for PropertyProvenance {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(Json::from_sql(ty, raw)?.0)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <Json<Self> as FromSql>::accepts(ty)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl ToSql for PropertyProvenance {
+    postgres_types::to_sql_checked!();
+
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+    where
+        Self: Sized,
+    {
+        Json(self).to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <Json<Self> as ToSql>::accepts(ty)
+    }
+}
+
+/// The provenance of the properties of an entity.
+///
+/// The provenance is attached to the value of a property rather than to the edition which set it,
+/// so the sources of a property are kept when other properties of the entity are changed.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct PropertyProvenances<'a> {
+    #[serde(flatten)]
+    map: HashMap<PropertyPath<'a>, PropertyProvenance>,
+}
+
+impl<'a> PropertyProvenances<'a> {
+    #[must_use]
+    pub const fn new(provenances: HashMap<PropertyPath<'a>, PropertyProvenance>) -> Self {
+        Self { map: provenances }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&PropertyPath<'a>, &PropertyProvenance)> {
+        self.map.iter()
+    }
+
+    /// Returns the provenance of the property at the given path.
+    #[must_use]
+    pub fn get(&self, path: &PropertyPath<'a>) -> Option<&PropertyProvenance> {
+        self.map.get(path)
+    }
+
+    /// Applies the changes made to `self` since `base` to `other`.
+    ///
+    /// This is the counterpart of [`PropertyObject::merge`] for the provenance of the properties
+    /// and follows the same rules as [`PropertyConfidence::merge`].
+    ///
+    /// [`PropertyConfidence::merge`]: crate::knowledge::PropertyConfidence::merge
+    #[must_use]
+    pub fn merge(
+        &self,
+        base: &Self,
+        other: &Self,
+        merged_paths: &[PropertyPath<'a>],
+        properties: &PropertyObject,
+    ) -> Self {
+        self.map
+            .keys()
+            .chain(base.map.keys())
+            .chain(other.map.keys())
+            .filter(|path| properties.path_exists(path))
+            .filter_map(|path| {
+                let take_ours = merged_paths
+                    .iter()
+                    .any(|merged_path| path.starts_with(merged_path))
+                    || (self.get(path) != base.get(path) && other.get(path) == base.get(path));
+                let provenance = if take_ours {
+                    self.get(path)
+                } else {
+                    other.get(path)
+                };
+                Some((path.clone(), provenance?.clone()))
+            })
+            .collect()
+    }
+
+    /// Applies the patch operations to the provenance.
+    ///
+    /// Moved and copied values keep their provenance, while values which are added or replaced
+    /// are only given the provenance specified in the operation.
+    pub fn patch(&mut self, operations: &[PropertyPatchOperation]) {
+        for operation in operations {
+            match operation {
+                PropertyPatchOperation::Remove { path } => {
+                    self.map.retain(|key, _| !key.starts_with(path));
+                }
+                PropertyPatchOperation::Add {
+                    path,
+                    value: _,
+                    confidence: _,
+                    data_type_id: _,
+                    provenance,
+                }
+                | PropertyPatchOperation::Replace {
+                    path,
+                    value: _,
+                    confidence: _,
+                    data_type_id: _,
+                    provenance,
+                } => {
+                    self.map.retain(|key, _| !key.starts_with(path));
+                    if let Some(provenance) = provenance {
+                        self.map.insert(path.clone(), provenance.clone());
+                    }
+                }
+                PropertyPatchOperation::Copy {
+                    from,
+                    path,
+                    confidence: _,
+                } => {
+                    let copied = self.rebase(from, path);
+                    self.map.retain(|key, _| !key.starts_with(path));
+                    self.map.extend(copied);
+                }
+                PropertyPatchOperation::Move {
+                    from,
+                    path,
+                    confidence: _,
+                } => {
+                    let moved = self.rebase(from, path);
+                    self.map
+                        .retain(|key, _| !key.starts_with(from) && !key.starts_with(path));
+                    self.map.extend(moved);
+                }
+                PropertyPatchOperation::Test { path: _, value: _ } => {}
+            }
+        }
+    }
+
+    /// Returns the provenance of the values at or below `from` as if they were located at `to`.
+    fn rebase(
+        &self,
+        from: &PropertyPath<'a>,
+        to: &PropertyPath<'a>,
+    ) -> Vec<(PropertyPath<'a>, PropertyProvenance)> {
+        self.map
+            .iter()
+            .filter(|(key, _)| key.starts_with(from))
+            .map(|(key, provenance)| {
+                (
+                    to.clone()
+                        .into_iter()
+                        .chain(key.clone().into_iter().skip(from.len()))
+                        .collect(),
+                    provenance.clone(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<'a> IntoIterator for PropertyProvenances<'a> {
+    type IntoIter = std::collections::hash_map::IntoIter<PropertyPath<'a>, PropertyProvenance>;
+    type Item = (PropertyPath<'a>, PropertyProvenance);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter()
+    }
+}
+
+impl<'a, 'p> IntoIterator for &'p PropertyProvenances<'a> {
+    type IntoIter = std::collections::hash_map::Iter<'p, PropertyPath<'a>, PropertyProvenance>;
+    type Item = (&'p PropertyPath<'a>, &'p PropertyProvenance);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.iter()
+    }
+}
+
+impl<'a> FromIterator<(PropertyPath<'a>, PropertyProvenance)> for PropertyProvenances<'a> {
+    fn from_iter<T: IntoIterator<Item = (PropertyPath<'a>, PropertyProvenance)>>(iter: T) -> Self {
+        Self {
+            map: iter.into_iter().collect(),
+        }
+    }
+}
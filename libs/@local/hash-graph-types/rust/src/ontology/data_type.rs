@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
-use type_system::{url::VersionedUrl, DataType};
+use type_system::{
+    url::{BaseUrl, VersionedUrl},
+    DataType,
+};
 #[cfg(feature = "utoipa")]
 use utoipa::{
     openapi::{schema, Ref, RefOr, Schema},
@@ -19,7 +22,35 @@ pub struct PartialDataTypeMetadata {
     pub classification: OntologyTypeClassificationMetadata,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// Converts values of a data type into values of the data type representing the canonical unit.
+///
+/// A data type with a conversion represents a unit, e.g. a "Centimeter" data type converts into
+/// the canonical "Meter" data type with a factor of `0.01`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DataTypeConversion {
+    /// The base URL of the data type representing the canonical unit.
+    pub canonical_unit: BaseUrl,
+    /// The factor a value is multiplied with to express it in the canonical unit.
+    pub factor: f64,
+}
+
+impl DataTypeConversion {
+    #[must_use]
+    #[expect(clippy::float_arithmetic)]
+    pub fn to_canonical(&self, value: f64) -> f64 {
+        value * self.factor
+    }
+
+    #[must_use]
+    #[expect(clippy::float_arithmetic)]
+    pub fn from_canonical(&self, value: f64) -> f64 {
+        value / self.factor
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataTypeMetadata {
     pub record_id: OntologyTypeRecordId,
@@ -27,6 +58,8 @@ pub struct DataTypeMetadata {
     pub classification: OntologyTypeClassificationMetadata,
     pub temporal_versioning: OntologyTemporalMetadata,
     pub provenance: OntologyProvenanceMetadata,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conversion: Option<DataTypeConversion>,
 }
 
 #[cfg(feature = "utoipa")]
@@ -53,6 +86,10 @@ impl ToSchema<'static> for DataTypeMetadata {
                                 Ref::from_schema_name("OntologyProvenanceMetadata"),
                             )
                             .required("provenance")
+                            .property(
+                                "conversion",
+                                Ref::from_schema_name(DataTypeConversion::schema().0),
+                            )
                             .build(),
                     )
                     .item(
@@ -72,6 +109,10 @@ impl ToSchema<'static> for DataTypeMetadata {
                                 Ref::from_schema_name("OntologyProvenanceMetadata"),
                             )
                             .required("provenance")
+                            .property(
+                                "conversion",
+                                Ref::from_schema_name(DataTypeConversion::schema().0),
+                            )
                             .build(),
                     )
                     .build(),
@@ -14,7 +14,9 @@ use type_system::{
 };
 
 pub use self::{
-    data_type::{DataTypeMetadata, DataTypeWithMetadata, PartialDataTypeMetadata},
+    data_type::{
+        DataTypeConversion, DataTypeMetadata, DataTypeWithMetadata, PartialDataTypeMetadata,
+    },
     entity_type::{
         EntityTypeEmbedding, EntityTypeMetadata, EntityTypeWithMetadata, PartialEntityTypeMetadata,
     },
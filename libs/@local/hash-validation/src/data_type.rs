@@ -1,5 +1,6 @@
 use core::borrow::Borrow;
 use std::{
+    collections::HashSet,
     net::{Ipv4Addr, Ipv6Addr},
     str::FromStr,
     sync::OnceLock,
@@ -10,9 +11,12 @@ use error_stack::{bail, ensure, Report, ResultExt};
 use graph_types::knowledge::Property;
 use iso8601_duration::Duration;
 use regex::Regex;
-use serde_json::Value as JsonValue;
+use serde_json::{Number as JsonNumber, Value as JsonValue};
 use thiserror::Error;
-use type_system::{url::VersionedUrl, DataType, DataTypeReference, JsonSchemaValueType};
+use type_system::{
+    url::VersionedUrl,
+    DataType, DataTypeConstraints, DataTypeReference, JsonSchemaValueType, StringFormat,
+};
 use url::Url;
 use uuid::Uuid;
 
@@ -83,8 +87,6 @@ pub enum DataTypeConstraint {
          of at most length `{expected}`"
     )]
     MaxLength { actual: String, expected: usize },
-    #[error("the provided pattern could not be compiled, got `{pattern}`")]
-    InvalidPattern { pattern: String },
     #[error("the provided value does not match the expected pattern `{pattern}`, got `{actual}`")]
     Pattern { actual: String, pattern: Regex },
     #[error("the provided value `{actual}` does not match the expected format `{format}`")]
@@ -94,8 +96,6 @@ pub enum DataTypeConstraint {
     },
     #[error("unknown constraint: `{key}`")]
     UnknownConstraint { key: String },
-    #[error("unknown format: `{key}`")]
-    UnknownFormat { key: String },
 }
 
 #[derive(Debug, Error)]
@@ -115,104 +115,82 @@ pub enum DataValidationError {
     UnknownType { schema: String },
 }
 
-fn as_usize(number: &JsonValue) -> Result<usize, Report<DataValidationError>> {
-    usize::try_from(number.as_u64().ok_or_else(|| {
-        Report::new(DataValidationError::InvalidType {
-            actual: JsonSchemaValueType::from(number),
-            expected: JsonSchemaValueType::Integer,
-        })
-    })?)
-    .change_context(DataValidationError::ConstraintUnfulfilled)
-}
-
-fn check_numeric_additional_property<'a, T>(
+fn check_numeric_constraints<T>(
     value: &JsonValue,
-    additional_properties: impl IntoIterator<Item = (impl AsRef<str>, &'a JsonValue)>,
+    constraints: &DataTypeConstraints,
     expected_type: JsonSchemaValueType,
-    from_json_value: impl Fn(&JsonValue) -> Option<T>,
+    from_json_number: impl Fn(&JsonNumber) -> Option<T>,
     multiple_of: impl Fn(&T, &T) -> bool,
 ) -> Result<(), Report<DataValidationError>>
 where
     T: PartialOrd,
 {
-    let number = from_json_value(value).ok_or_else(|| {
+    let invalid_type = || {
         Report::new(DataValidationError::InvalidType {
             actual: JsonSchemaValueType::from(value),
             expected: expected_type,
         })
-    })?;
-    for (additional_key, additional_property) in additional_properties {
-        match (
-            additional_key.as_ref(),
-            from_json_value(additional_property),
-        ) {
-            ("minimum", Some(minimum)) => {
-                ensure!(
-                    number >= minimum,
-                    Report::new(DataTypeConstraint::Minimum {
-                        actual: Property::Value(value.clone()),
-                        expected: additional_property.clone(),
-                    })
-                    .change_context(DataValidationError::ConstraintUnfulfilled)
-                );
-            }
-            ("maximum", Some(maximum)) => {
-                ensure!(
-                    number <= maximum,
-                    Report::new(DataTypeConstraint::Maximum {
-                        actual: Property::Value(value.clone()),
-                        expected: additional_property.clone(),
-                    })
-                    .change_context(DataValidationError::ConstraintUnfulfilled)
-                );
-            }
-            ("exclusiveMinimum", Some(minimum)) => {
-                ensure!(
-                    number > minimum,
-                    Report::new(DataTypeConstraint::ExclusiveMinimum {
-                        actual: Property::Value(value.clone()),
-                        expected: additional_property.clone(),
-                    })
-                    .change_context(DataValidationError::ConstraintUnfulfilled)
-                );
-            }
-            ("exclusiveMaximum", Some(maximum)) => {
-                ensure!(
-                    number < maximum,
-                    Report::new(DataTypeConstraint::ExclusiveMaximum {
-                        actual: Property::Value(value.clone()),
-                        expected: additional_property.clone(),
-                    })
-                    .change_context(DataValidationError::ConstraintUnfulfilled)
-                );
-            }
-            ("multipleOf", Some(multiple)) => {
-                ensure!(
-                    multiple_of(&number, &multiple),
-                    Report::new(DataTypeConstraint::MultipleOf {
-                        actual: Property::Value(value.clone()),
-                        expected: additional_property.clone(),
-                    })
-                    .change_context(DataValidationError::ConstraintUnfulfilled)
-                );
-            }
-            (
-                "minimum" | "maximum" | "exclusiveMinimum" | "exclusiveMaximum" | "multipleOf",
-                None,
-            ) => {
-                bail!(Report::new(DataValidationError::InvalidType {
-                    actual: JsonSchemaValueType::from(value),
-                    expected: expected_type,
-                }));
-            }
-            _ => {}
-        }
+    };
+
+    let number = value
+        .as_number()
+        .and_then(&from_json_number)
+        .ok_or_else(invalid_type)?;
+    let bound = |constraint: &JsonNumber| from_json_number(constraint).ok_or_else(invalid_type);
+    let unfulfilled = |constraint: DataTypeConstraint| {
+        Report::new(constraint).change_context(DataValidationError::ConstraintUnfulfilled)
+    };
+
+    if let Some(minimum) = &constraints.minimum {
+        ensure!(
+            number >= bound(minimum)?,
+            unfulfilled(DataTypeConstraint::Minimum {
+                actual: Property::Value(value.clone()),
+                expected: JsonValue::Number(minimum.clone()),
+            })
+        );
+    }
+    if let Some(maximum) = &constraints.maximum {
+        ensure!(
+            number <= bound(maximum)?,
+            unfulfilled(DataTypeConstraint::Maximum {
+                actual: Property::Value(value.clone()),
+                expected: JsonValue::Number(maximum.clone()),
+            })
+        );
+    }
+    if let Some(minimum) = &constraints.exclusive_minimum {
+        ensure!(
+            number > bound(minimum)?,
+            unfulfilled(DataTypeConstraint::ExclusiveMinimum {
+                actual: Property::Value(value.clone()),
+                expected: JsonValue::Number(minimum.clone()),
+            })
+        );
+    }
+    if let Some(maximum) = &constraints.exclusive_maximum {
+        ensure!(
+            number < bound(maximum)?,
+            unfulfilled(DataTypeConstraint::ExclusiveMaximum {
+                actual: Property::Value(value.clone()),
+                expected: JsonValue::Number(maximum.clone()),
+            })
+        );
+    }
+    if let Some(multiple) = &constraints.multiple_of {
+        ensure!(
+            multiple_of(&number, &bound(multiple)?),
+            unfulfilled(DataTypeConstraint::MultipleOf {
+                actual: Property::Value(value.clone()),
+                expected: JsonValue::Number(multiple.clone()),
+            })
+        );
     }
     Ok(())
 }
 
 #[expect(clippy::too_many_lines)]
-fn check_format(value: &str, format: &str) -> Result<(), Report<DataValidationError>> {
+fn check_format(value: &str, format: StringFormat) -> Result<(), Report<DataValidationError>> {
     // Only the simplest date format are supported in all three, RFC-3339, ISO-8601 and HTML
     const DATE_REGEX_STRING: &str = r"(?P<Y>\d{4})-(?P<M>\d{2})-(?P<D>\d{2})";
     static DATE_REGEX: OnceLock<Regex> = OnceLock::new();
@@ -225,65 +203,65 @@ fn check_format(value: &str, format: &str) -> Result<(), Report<DataValidationEr
     static DATE_TIME_REGEX: OnceLock<Regex> = OnceLock::new();
 
     match format {
-        "uri" => {
+        StringFormat::Uri => {
             Url::parse(value)
                 .change_context_lazy(|| DataTypeConstraint::Format {
                     actual: value.to_owned(),
-                    format: "uri",
+                    format: format.as_str(),
                 })
                 .change_context(DataValidationError::ConstraintUnfulfilled)?;
         }
-        "uuid" => {
+        StringFormat::Uuid => {
             Uuid::parse_str(value)
                 .change_context_lazy(|| DataTypeConstraint::Format {
                     actual: value.to_owned(),
-                    format: "uuid",
+                    format: format.as_str(),
                 })
                 .change_context(DataValidationError::ConstraintUnfulfilled)?;
         }
-        "regex" => {
+        StringFormat::Regex => {
             Regex::new(value)
                 .change_context_lazy(|| DataTypeConstraint::Format {
                     actual: value.to_owned(),
-                    format: "regex",
+                    format: format.as_str(),
                 })
                 .change_context(DataValidationError::ConstraintUnfulfilled)?;
         }
-        "email" => {
+        StringFormat::Email => {
             EmailAddress::from_str(value)
                 .change_context_lazy(|| DataTypeConstraint::Format {
                     actual: value.to_owned(),
-                    format: "email",
+                    format: format.as_str(),
                 })
                 .change_context(DataValidationError::ConstraintUnfulfilled)?;
         }
-        "ipv4" => {
+        StringFormat::Ipv4 => {
             value
                 .parse::<Ipv4Addr>()
                 .change_context_lazy(|| DataTypeConstraint::Format {
                     actual: value.to_owned(),
-                    format: "ipv4",
+                    format: format.as_str(),
                 })
                 .change_context(DataValidationError::ConstraintUnfulfilled)?;
         }
-        "ipv6" => {
+        StringFormat::Ipv6 => {
             value
                 .parse::<Ipv6Addr>()
                 .change_context_lazy(|| DataTypeConstraint::Format {
                     actual: value.to_owned(),
-                    format: "ipv6",
+                    format: format.as_str(),
                 })
                 .change_context(DataValidationError::ConstraintUnfulfilled)?;
         }
-        "hostname" => {
+        StringFormat::Hostname => {
             url::Host::parse(value)
                 .change_context_lazy(|| DataTypeConstraint::Format {
                     actual: value.to_owned(),
-                    format: "hostname",
+                    format: format.as_str(),
                 })
                 .change_context(DataValidationError::ConstraintUnfulfilled)?;
         }
-        "date-time" => {
+        StringFormat::DateTime => {
             DATE_TIME_REGEX
                 .get_or_init(|| {
                     Regex::new(&format!("^{DATE_REGEX_STRING}T{TIME_REGEX_STRING}$"))
@@ -294,12 +272,12 @@ fn check_format(value: &str, format: &str) -> Result<(), Report<DataValidationEr
                 .ok_or_else(|| {
                     Report::new(DataTypeConstraint::Format {
                         actual: value.to_owned(),
-                        format: "date-time",
+                        format: format.as_str(),
                     })
                     .change_context(DataValidationError::ConstraintUnfulfilled)
                 })?;
         }
-        "date" => {
+        StringFormat::Date => {
             DATE_REGEX
                 .get_or_init(|| {
                     Regex::new(&format!("^{DATE_REGEX_STRING}$"))
@@ -310,12 +288,12 @@ fn check_format(value: &str, format: &str) -> Result<(), Report<DataValidationEr
                 .ok_or_else(|| {
                     Report::new(DataTypeConstraint::Format {
                         actual: value.to_owned(),
-                        format: "date",
+                        format: format.as_str(),
                     })
                     .change_context(DataValidationError::ConstraintUnfulfilled)
                 })?;
         }
-        "time" => {
+        StringFormat::Time => {
             TIME_REGEX
                 .get_or_init(|| {
                     Regex::new(&format!("^{TIME_REGEX_STRING}$"))
@@ -326,95 +304,74 @@ fn check_format(value: &str, format: &str) -> Result<(), Report<DataValidationEr
                 .ok_or_else(|| {
                     Report::new(DataTypeConstraint::Format {
                         actual: value.to_owned(),
-                        format: "time",
+                        format: format.as_str(),
                     })
                     .change_context(DataValidationError::ConstraintUnfulfilled)
                 })?;
         }
-        "duration" => {
+        StringFormat::Duration => {
             value
                 .parse::<Duration>()
                 .map_err(|error| {
                     Report::new(DataTypeConstraint::Format {
                         actual: value.to_owned(),
-                        format: "duration",
+                        format: format.as_str(),
                     })
                     .attach_printable(format!("{error:?}"))
                 })
                 .change_context(DataValidationError::ConstraintUnfulfilled)?;
         }
-        _ => bail!(
-            Report::new(DataTypeConstraint::UnknownFormat {
-                key: format.to_owned(),
-            })
-            .change_context(DataValidationError::ConstraintUnfulfilled)
-        ),
     }
     Ok(())
 }
 
-fn check_string_additional_property<'a>(
+fn check_string_constraints(
     value: &JsonValue,
-    additional_properties: impl IntoIterator<Item = (impl AsRef<str>, &'a JsonValue)>,
-    expected_type: JsonSchemaValueType,
-    from_json_value: impl Fn(&JsonValue) -> Option<&str>,
+    constraints: &DataTypeConstraints,
 ) -> Result<(), Report<DataValidationError>> {
-    let string = from_json_value(value).ok_or_else(|| {
+    let string = value.as_str().ok_or_else(|| {
         Report::new(DataValidationError::InvalidType {
             actual: JsonSchemaValueType::from(value),
-            expected: expected_type,
+            expected: JsonSchemaValueType::String,
         })
     })?;
-    for (additional_key, additional_property) in additional_properties {
-        match (additional_key.as_ref(), additional_property) {
-            ("format", JsonValue::String(additional_property)) => {
-                check_format(string, additional_property)?;
-            }
-            ("minLength", minimum) => {
-                let minimum = as_usize(minimum)?;
-                ensure!(
-                    string.len() >= minimum,
-                    Report::new(DataTypeConstraint::MinLength {
-                        actual: string.to_owned(),
-                        expected: minimum,
-                    })
-                    .change_context(DataValidationError::ConstraintUnfulfilled)
-                );
-            }
-            ("maxLength", maximum) => {
-                let maximum = as_usize(maximum)?;
-                ensure!(
-                    string.len() <= maximum,
-                    Report::new(DataTypeConstraint::MaxLength {
-                        actual: string.to_owned(),
-                        expected: maximum,
-                    })
-                    .change_context(DataValidationError::ConstraintUnfulfilled)
-                );
-            }
-            ("pattern", JsonValue::String(pattern)) => {
-                let regex = Regex::new(pattern)
-                    .change_context_lazy(|| DataTypeConstraint::InvalidPattern {
-                        pattern: pattern.clone(),
-                    })
-                    .change_context(DataValidationError::ConstraintUnfulfilled)?;
-                ensure!(
-                    regex.is_match(string),
-                    Report::new(DataTypeConstraint::Pattern {
-                        actual: string.to_owned(),
-                        pattern: regex,
-                    })
-                    .change_context(DataValidationError::ConstraintUnfulfilled)
-                );
-            }
-            ("format" | "pattern", _) => {
-                bail!(Report::new(DataValidationError::InvalidType {
-                    actual: JsonSchemaValueType::from(value),
-                    expected: JsonSchemaValueType::String,
-                }));
-            }
-            _ => {}
-        }
+
+    if let Some(format) = constraints.format {
+        check_format(string, format)?;
+    }
+    if let Some(minimum) = constraints.min_length {
+        ensure!(
+            string.len() >= minimum,
+            Report::new(DataTypeConstraint::MinLength {
+                actual: string.to_owned(),
+                expected: minimum,
+            })
+            .change_context(DataValidationError::ConstraintUnfulfilled)
+        );
+    }
+    if let Some(maximum) = constraints.max_length {
+        ensure!(
+            string.len() <= maximum,
+            Report::new(DataTypeConstraint::MaxLength {
+                actual: string.to_owned(),
+                expected: maximum,
+            })
+            .change_context(DataValidationError::ConstraintUnfulfilled)
+        );
+    }
+    if let Some(pattern) = &constraints.pattern {
+        let regex = pattern
+            .regex()
+            .map_err(Report::new)
+            .change_context(DataValidationError::ConstraintUnfulfilled)?;
+        ensure!(
+            regex.is_match(string),
+            Report::new(DataTypeConstraint::Pattern {
+                actual: string.to_owned(),
+                pattern: regex.clone(),
+            })
+            .change_context(DataValidationError::ConstraintUnfulfilled)
+        );
     }
     Ok(())
 }
@@ -428,34 +385,31 @@ impl<P: Sync> Schema<Property, P> for DataType {
         _: ValidateEntityComponents,
         _: &'a P,
     ) -> Result<(), Report<DataValidationError>> {
+        let constraints = self.constraints();
+
         match (self.json_type(), property) {
             (JsonSchemaValueType::Number, Property::Value(value)) => {
                 #[expect(clippy::float_arithmetic)]
-                check_numeric_additional_property(
+                check_numeric_constraints(
                     value,
-                    self.additional_properties(),
+                    constraints,
                     JsonSchemaValueType::Number,
-                    JsonValue::as_f64,
+                    JsonNumber::as_f64,
                     |number, multiple| number % multiple < f64::EPSILON,
                 )?;
             }
             (JsonSchemaValueType::Integer, Property::Value(value)) => {
-                check_numeric_additional_property(
+                check_numeric_constraints(
                     value,
-                    self.additional_properties(),
+                    constraints,
                     JsonSchemaValueType::Integer,
-                    JsonValue::as_i64,
+                    JsonNumber::as_i64,
                     #[expect(clippy::integer_division_remainder_used)]
                     |number, multiple| number % multiple == 0,
                 )?;
             }
             (JsonSchemaValueType::String, Property::Value(value)) => {
-                check_string_additional_property(
-                    value,
-                    self.additional_properties(),
-                    JsonSchemaValueType::String,
-                    JsonValue::as_str,
-                )?;
+                check_string_constraints(value, constraints)?;
             }
             (expected, _) => ensure!(
                 property.json_type() == expected,
@@ -466,33 +420,31 @@ impl<P: Sync> Schema<Property, P> for DataType {
             ),
         }
 
-        for (additional_key, additional_property) in self.additional_properties() {
+        if let Some(expected) = &constraints.const_value {
+            ensure!(
+                property == expected,
+                Report::new(DataTypeConstraint::Const {
+                    actual: property.clone(),
+                    expected: expected.clone(),
+                })
+                .change_context(DataValidationError::ConstraintUnfulfilled)
+            );
+        }
+        if let Some(expected) = &constraints.enum_values {
+            ensure!(
+                expected.iter().any(|expected| property == expected),
+                Report::new(DataTypeConstraint::Enum {
+                    actual: property.clone(),
+                    expected: JsonValue::Array(expected.clone()),
+                })
+                .change_context(DataValidationError::ConstraintUnfulfilled)
+            );
+        }
+
+        // Constraints are typed when the data type is parsed, so the remaining properties must not
+        // constrain the value.
+        for additional_key in self.additional_properties().keys() {
             match additional_key.as_str() {
-                "const" => ensure!(
-                    property == additional_property,
-                    Report::new(DataTypeConstraint::Const {
-                        actual: property.clone(),
-                        expected: additional_property.clone(),
-                    })
-                    .change_context(DataValidationError::ConstraintUnfulfilled)
-                ),
-                "enum" => {
-                    ensure!(
-                        additional_property
-                            .as_array()
-                            .is_some_and(|array| array.iter().any(|expected| property == expected)),
-                        Report::new(DataTypeConstraint::Enum {
-                            actual: property.clone(),
-                            expected: additional_property.clone(),
-                        })
-                        .change_context(DataValidationError::ConstraintUnfulfilled)
-                    );
-                }
-                "minimum" | "maximum" | "exclusiveMinimum" | "exclusiveMaximum" | "multipleOf"
-                    if self.json_type() == JsonSchemaValueType::Integer
-                        || self.json_type() == JsonSchemaValueType::Number => {}
-                "format" | "minLength" | "maxLength" | "pattern"
-                    if self.json_type() == JsonSchemaValueType::String => {}
                 "label" => {
                     // Label does not have to be validated
                 }
@@ -545,7 +497,46 @@ where
             .validate_value(value, components, provider)
             .await
             .attach_lazy(|| Expected::DataType(data_type.borrow().clone()))
-            .attach_lazy(|| Actual::Property(value.clone()))
+            .attach_lazy(|| Actual::Property(value.clone()))?;
+
+        // The constraints of all data types the data type inherits from have to be fulfilled as
+        // well.
+        let mut visited = HashSet::from([self.url().clone()]);
+        let mut parents = data_type
+            .borrow()
+            .inherits_from()
+            .all_of()
+            .iter()
+            .map(|reference| reference.url().clone())
+            .collect::<Vec<_>>();
+        while let Some(parent_id) = parents.pop() {
+            if !visited.insert(parent_id.clone()) {
+                continue;
+            }
+
+            let parent = provider
+                .provide_type(&parent_id)
+                .await
+                .change_context_lazy(|| DataValidationError::DataTypeRetrieval {
+                    id: parent_id.clone(),
+                })?;
+            parent
+                .borrow()
+                .validate_value(value, components, provider)
+                .await
+                .attach_lazy(|| Expected::DataType(parent.borrow().clone()))
+                .attach_lazy(|| Actual::Property(value.clone()))?;
+            parents.extend(
+                parent
+                    .borrow()
+                    .inherits_from()
+                    .all_of()
+                    .iter()
+                    .map(|reference| reference.url().clone()),
+            );
+        }
+
+        Ok(())
     }
 }
 
@@ -570,7 +561,10 @@ mod tests {
     use serde_json::json;
     use uuid::Uuid;
 
-    use crate::{tests::validate_data, ValidateEntityComponents};
+    use crate::{
+        tests::{validate_data, validate_data_reference},
+        ValidateEntityComponents,
+    };
 
     #[tokio::test]
     async fn null() {
@@ -1948,4 +1942,65 @@ mod tests {
             "passed invalid formats: {passed_formats:#?}"
         );
     }
+
+    #[tokio::test]
+    async fn inherited_constraints() {
+        let percentage_type = serde_json::to_string(&json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "$id": "https://localhost:4000/@alice/types/data-type/percentage/v/1",
+            "title": "Percentage",
+            "type": "number",
+            "minimum": 0,
+            "maximum": 100,
+        }))
+        .expect("failed to serialize percentage type");
+
+        let integer_percentage_type = serde_json::to_string(&json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "$id": "https://localhost:4000/@alice/types/data-type/integer-percentage/v/1",
+            "title": "Integer Percentage",
+            "allOf": [{ "$ref": "https://localhost:4000/@alice/types/data-type/percentage/v/1" }],
+            "type": "integer",
+            "minimum": 1,
+        }))
+        .expect("failed to serialize integer percentage type");
+
+        validate_data_reference(
+            json!(50),
+            &integer_percentage_type,
+            [percentage_type.as_str()],
+            ValidateEntityComponents::full(),
+        )
+        .await
+        .expect("validation failed");
+
+        _ = validate_data_reference(
+            json!(0),
+            &integer_percentage_type,
+            [percentage_type.as_str()],
+            ValidateEntityComponents::full(),
+        )
+        .await
+        .expect_err("validation succeeded");
+
+        _ = validate_data_reference(
+            json!(101),
+            &integer_percentage_type,
+            [percentage_type.as_str()],
+            ValidateEntityComponents::full(),
+        )
+        .await
+        .expect_err("validation succeeded");
+
+        _ = validate_data_reference(
+            json!(50),
+            &integer_percentage_type,
+            [],
+            ValidateEntityComponents::full(),
+        )
+        .await
+        .expect_err("validation succeeded without the parent data type");
+    }
 }
@@ -15,8 +15,8 @@ use type_system::{
 
 use crate::{
     error::{Actual, Expected},
-    EntityProvider, EntityTypeProvider, OntologyTypeProvider, Schema, Validate,
-    ValidateEntityComponents,
+    normalize_properties, DataTypeProvider, EntityProvider, EntityTypeProvider,
+    OntologyTypeProvider, Schema, Validate, ValidateEntityComponents,
 };
 
 macro_rules! extend_report {
@@ -43,12 +43,24 @@ pub enum EntityValidationError {
     EntityTypeRetrieval { ids: Vec<VersionedUrl> },
     #[error("the validator was unable to read the entity `{id}`")]
     EntityRetrieval { id: EntityId },
+    #[error("The link endpoint `{id}` only exists as a draft")]
+    UnpublishedLinkEndpoint { id: EntityId },
     #[error("The link type `{link_types:?}` is not allowed")]
     InvalidLinkTypeId { link_types: Vec<VersionedUrl> },
     #[error("The link target `{target_types:?}` is not allowed")]
     InvalidLinkTargetId { target_types: Vec<VersionedUrl> },
+    #[error("The link type `{link_types:?}` is not ordered but the link has a position")]
+    UnorderedLinkPosition { link_types: Vec<VersionedUrl> },
     #[error("The property path is invalid: `{path:?}`")]
     InvalidPropertyPath { path: PropertyPath<'static> },
+    #[error(
+        "The value at `{path:?}` is not a valid `{data_type_id}` or cannot be converted into a \
+         data type expected by the property type"
+    )]
+    InvalidPropertyDataType {
+        path: PropertyPath<'static>,
+        data_type_id: VersionedUrl,
+    },
 }
 
 impl<P> Schema<PropertyObject, P> for ClosedEntityType
@@ -150,7 +162,7 @@ where
     P: EntityProvider
         + EntityTypeProvider
         + OntologyTypeProvider<PropertyType>
-        + OntologyTypeProvider<DataType>
+        + DataTypeProvider
         + Sync,
 {
     type Error = EntityValidationError;
@@ -166,8 +178,21 @@ where
         if self.metadata.entity_type_ids.is_empty() {
             extend_report!(status, EntityValidationError::EmptyEntityTypes);
         }
-        if let Err(error) = self.properties.validate(schema, components, provider).await {
-            extend_report!(status, error);
+        match normalize_properties(
+            &self.properties,
+            &self.metadata.property_data_types,
+            schema,
+            components,
+            provider,
+        )
+        .await
+        {
+            Ok(properties) => {
+                if let Err(error) = properties.validate(schema, components, provider).await {
+                    extend_report!(status, error);
+                }
+            }
+            Err(error) => extend_report!(status, error),
         }
         if let Err(error) = self
             .link_data
@@ -185,11 +210,39 @@ where
         {
             extend_report!(status, error);
         }
+        if let Err(error) = self
+            .metadata
+            .property_provenance
+            .validate(&self.properties, components, provider)
+            .await
+        {
+            extend_report!(status, error);
+        }
 
         status
     }
 }
 
+/// Returns the error to report for a link endpoint which could not be read.
+///
+/// If only published endpoints are allowed but the entity exists as a draft, the endpoint is
+/// reported as unpublished instead of missing.
+async fn link_endpoint_error<P>(
+    provider: &P,
+    entity_id: EntityId,
+    components: ValidateEntityComponents,
+) -> EntityValidationError
+where
+    P: EntityProvider + Sync,
+{
+    if components.published_link_endpoints && provider.provide_entity(entity_id, true).await.is_ok()
+    {
+        EntityValidationError::UnpublishedLinkEndpoint { id: entity_id }
+    } else {
+        EntityValidationError::EntityRetrieval { id: entity_id }
+    }
+}
+
 impl<P> Schema<LinkData, P> for ClosedEntityType
 where
     P: EntityProvider + EntityTypeProvider + Sync,
@@ -198,20 +251,46 @@ where
 
     // TODO: validate link data
     //   see https://linear.app/hash/issue/H-972
+    #[expect(clippy::too_many_lines)]
     async fn validate_value<'a>(
         &'a self,
         link_data: &'a LinkData,
-        _: ValidateEntityComponents,
+        components: ValidateEntityComponents,
         provider: &'a P,
     ) -> Result<(), Report<EntityValidationError>> {
         let mut status: Result<(), Report<EntityValidationError>> = Ok(());
 
+        let include_drafts = !components.published_link_endpoints;
         let left_entity = provider
-            .provide_entity(link_data.left_entity_id, true)
-            .await
-            .change_context_lazy(|| EntityValidationError::EntityRetrieval {
-                id: link_data.left_entity_id,
-            })?;
+            .provide_entity(link_data.left_entity_id, include_drafts)
+            .await;
+        let right_entity = provider
+            .provide_entity(link_data.right_entity_id, include_drafts)
+            .await;
+
+        // Both endpoints are reported, so all entities blocking the link are known at once.
+        let (left_entity, right_entity) = match (left_entity, right_entity) {
+            (Ok(left_entity), Ok(right_entity)) => (left_entity, right_entity),
+            (Err(left_error), Ok(_)) => {
+                return Err(left_error.change_context(
+                    link_endpoint_error(provider, link_data.left_entity_id, components).await,
+                ));
+            }
+            (Ok(_), Err(right_error)) => {
+                return Err(right_error.change_context(
+                    link_endpoint_error(provider, link_data.right_entity_id, components).await,
+                ));
+            }
+            (Err(left_error), Err(right_error)) => {
+                let mut report = left_error.change_context(
+                    link_endpoint_error(provider, link_data.left_entity_id, components).await,
+                );
+                report.extend_one(right_error.change_context(
+                    link_endpoint_error(provider, link_data.right_entity_id, components).await,
+                ));
+                return Err(report);
+            }
+        };
 
         let left_entity_type = stream::iter(&left_entity.borrow().metadata.entity_type_ids)
             .then(|entity_type| async {
@@ -229,13 +308,6 @@ where
             .try_collect::<Self>()
             .await?;
 
-        let right_entity = provider
-            .provide_entity(link_data.right_entity_id, true)
-            .await
-            .change_context_lazy(|| EntityValidationError::EntityRetrieval {
-                id: link_data.right_entity_id,
-            })?;
-
         let right_entity_type = stream::iter(&right_entity.borrow().metadata.entity_type_ids)
             .then(|entity_type| async {
                 Ok::<_, Report<EntityValidationError>>(
@@ -255,6 +327,7 @@ where
         // We track that at least one link type was found to avoid reporting an error if no
         // link type was found.
         let mut found_link_target = false;
+        let mut found_ordered_link = false;
         for link_type_id in self.schemas.keys() {
             let Some(maybe_allowed_targets) = left_entity_type.links.links().get(link_type_id)
             else {
@@ -263,6 +336,7 @@ where
 
             // At least one link type was found
             found_link_target = true;
+            found_ordered_link |= maybe_allowed_targets.ordered();
 
             let Some(allowed_targets) = maybe_allowed_targets.array().items() else {
                 continue;
@@ -298,6 +372,13 @@ where
                     link_types: self.schemas.keys().cloned().collect(),
                 }
             );
+        } else if link_data.position.is_some() && !found_ordered_link {
+            extend_report!(
+                status,
+                EntityValidationError::UnorderedLinkPosition {
+                    link_types: self.schemas.keys().cloned().collect(),
+                }
+            );
         }
 
         status
@@ -10,6 +10,7 @@ pub mod error;
 pub use self::{
     data_type::{DataTypeConstraint, DataValidationError},
     entity_type::EntityValidationError,
+    property::normalize_properties,
     property_type::PropertyValidationError,
 };
 
@@ -21,11 +22,14 @@ mod property_type;
 use std::borrow::Borrow;
 
 use error_stack::{Context, Report};
-use graph_types::knowledge::entity::{Entity, EntityId};
+use graph_types::{
+    knowledge::entity::{Entity, EntityId},
+    ontology::DataTypeConversion,
+};
 use serde::Deserialize;
 use type_system::{
     url::{BaseUrl, VersionedUrl},
-    ClosedEntityType,
+    ClosedEntityType, DataType,
 };
 
 trait Schema<V: ?Sized, P: Sync> {
@@ -73,6 +77,10 @@ const fn default_true() -> bool {
 #[derive(Debug, Copy, Clone, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "Each component can be toggled independently"
+)]
 pub struct ValidateEntityComponents {
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     #[serde(default = "default_true")]
@@ -83,6 +91,10 @@ pub struct ValidateEntityComponents {
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     #[serde(default = "default_true")]
     pub num_items: bool,
+    /// Requires the endpoints of a link to be published, i.e. to not only exist as a draft.
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default = "default_true")]
+    pub published_link_endpoints: bool,
 }
 
 impl ValidateEntityComponents {
@@ -92,6 +104,7 @@ impl ValidateEntityComponents {
             link_data: true,
             required_properties: true,
             num_items: true,
+            published_link_endpoints: true,
         }
     }
 
@@ -100,6 +113,7 @@ impl ValidateEntityComponents {
         Self {
             num_items: false,
             required_properties: false,
+            published_link_endpoints: false,
             ..Self::full()
         }
     }
@@ -149,6 +163,14 @@ pub trait EntityTypeProvider: OntologyTypeProvider<ClosedEntityType> {
     ) -> impl Future<Output = Result<bool, Report<impl Context>>> + Send;
 }
 
+pub trait DataTypeProvider: OntologyTypeProvider<DataType> {
+    /// Returns the conversion of values of the data type into its canonical unit, if any.
+    fn provide_conversion(
+        &self,
+        data_type_id: &VersionedUrl,
+    ) -> impl Future<Output = Result<Option<DataTypeConversion>, Report<impl Context>>> + Send;
+}
+
 pub trait EntityProvider {
     fn provide_entity(
         &self,
@@ -161,10 +183,10 @@ pub trait EntityProvider {
 mod tests {
     use std::collections::HashMap;
 
-    use graph_types::knowledge::{Property, PropertyObject};
+    use graph_types::knowledge::{Property, PropertyDataTypes, PropertyObject};
     use serde_json::Value as JsonValue;
     use thiserror::Error;
-    use type_system::{DataType, EntityType, PropertyType};
+    use type_system::{DataType, DataTypeReference, EntityType, PropertyType};
 
     use super::*;
     use crate::error::install_error_stack_hooks;
@@ -174,6 +196,7 @@ mod tests {
         entity_types: HashMap<VersionedUrl, ClosedEntityType>,
         property_types: HashMap<VersionedUrl, PropertyType>,
         data_types: HashMap<VersionedUrl, DataType>,
+        conversions: HashMap<VersionedUrl, DataTypeConversion>,
     }
     impl Provider {
         fn new(
@@ -196,6 +219,7 @@ mod tests {
                     .into_iter()
                     .map(|schema| (schema.id().clone(), schema))
                     .collect(),
+                conversions: HashMap::new(),
             }
         }
     }
@@ -295,6 +319,17 @@ mod tests {
         }
     }
 
+    impl DataTypeProvider for Provider {
+        #[expect(refining_impl_trait)]
+        async fn provide_conversion(
+            &self,
+            data_type_id: &VersionedUrl,
+        ) -> Result<Option<DataTypeConversion>, Report<InvalidDataType>> {
+            OntologyTypeProvider::<DataType>::provide_type(self, data_type_id).await?;
+            Ok(self.conversions.get(data_type_id).cloned())
+        }
+    }
+
     pub(crate) async fn validate_entity(
         entity: &'static str,
         entity_type: &'static str,
@@ -331,6 +366,57 @@ mod tests {
             .await
     }
 
+    pub(crate) async fn validate_entity_with_data_types(
+        properties: JsonValue,
+        property_data_types: PropertyDataTypes<'static>,
+        entity_type: JsonValue,
+        property_types: impl IntoIterator<Item = JsonValue> + Send,
+        data_types: impl IntoIterator<Item = (JsonValue, Option<DataTypeConversion>)> + Send,
+    ) -> Result<(), Report<EntityValidationError>> {
+        install_error_stack_hooks();
+
+        let data_types = data_types
+            .into_iter()
+            .map(|(data_type, conversion)| {
+                (
+                    serde_json::from_value::<DataType>(data_type)
+                        .expect("failed to parse data type"),
+                    conversion,
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut provider = Provider::new(
+            [],
+            [],
+            property_types.into_iter().map(|property_type| {
+                serde_json::from_value(property_type).expect("failed to parse property type")
+            }),
+            data_types.iter().map(|(data_type, _)| data_type.clone()),
+        );
+        provider.conversions.extend(
+            data_types
+                .into_iter()
+                .filter_map(|(data_type, conversion)| Some((data_type.id().clone(), conversion?))),
+        );
+
+        let entity_type = ClosedEntityType::from(
+            serde_json::from_value::<EntityType>(entity_type).expect("failed to parse entity type"),
+        );
+        let properties = serde_json::from_value::<PropertyObject>(properties)
+            .expect("failed to read properties");
+
+        normalize_properties(
+            &properties,
+            &property_data_types,
+            &entity_type,
+            ValidateEntityComponents::full(),
+            &provider,
+        )
+        .await?
+        .validate(&entity_type, ValidateEntityComponents::full(), &provider)
+        .await
+    }
+
     pub(crate) async fn validate_property(
         property: JsonValue,
         property_type: &'static str,
@@ -374,4 +460,35 @@ mod tests {
 
         property.validate(&data_type, components, &()).await
     }
+
+    pub(crate) async fn validate_data_reference(
+        data: JsonValue,
+        data_type: &str,
+        data_types: impl IntoIterator<Item = &str> + Send,
+        components: ValidateEntityComponents,
+    ) -> Result<(), Report<DataValidationError>> {
+        install_error_stack_hooks();
+
+        let property: Property =
+            serde_json::from_value(data).expect("failed to deserialize data into property");
+        let data_type: DataType =
+            serde_json::from_str(data_type).expect("failed to parse data type");
+        let data_type_reference = DataTypeReference::new(data_type.id().clone());
+
+        let provider = Provider::new(
+            [],
+            [],
+            [],
+            data_types
+                .into_iter()
+                .map(|data_type| {
+                    serde_json::from_str(data_type).expect("failed to parse data type")
+                })
+                .chain([data_type]),
+        );
+
+        property
+            .validate(&data_type_reference, components, &provider)
+            .await
+    }
 }
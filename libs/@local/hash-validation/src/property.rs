@@ -1,7 +1,24 @@
-use error_stack::Report;
-use graph_types::knowledge::{PropertyConfidence, PropertyObject};
+use core::borrow::Borrow;
+use std::borrow::Cow;
 
-use crate::{EntityValidationError, Validate, ValidateEntityComponents};
+use error_stack::{Report, ResultExt};
+use graph_types::{
+    knowledge::{
+        Property, PropertyConfidence, PropertyDataTypes, PropertyObject, PropertyPatchOperation,
+        PropertyPath, PropertyPathElement, PropertyProvenances,
+    },
+    ontology::DataTypeConversion,
+};
+use serde_json::{Number as JsonNumber, Value as JsonValue};
+use type_system::{
+    url::VersionedUrl, ClosedEntityType, DataTypeReference, PropertyType, PropertyTypeReference,
+    PropertyValues, ValueOrArray,
+};
+
+use crate::{
+    DataTypeProvider, EntityValidationError, OntologyTypeProvider, Schema, Validate,
+    ValidateEntityComponents,
+};
 
 macro_rules! extend_report {
     ($status:ident, $error:expr $(,)?) => {
@@ -41,3 +58,369 @@ where
         status
     }
 }
+
+impl<P> Validate<PropertyObject, P> for PropertyProvenances<'_>
+where
+    P: Sync,
+{
+    type Error = EntityValidationError;
+
+    async fn validate(
+        &self,
+        object: &PropertyObject,
+        _components: ValidateEntityComponents,
+        _provider: &P,
+    ) -> Result<(), Report<Self::Error>> {
+        let mut status: Result<(), Report<EntityValidationError>> = Ok(());
+
+        for (path, _provenance) in self {
+            if !object.path_exists(path) {
+                extend_report!(
+                    status,
+                    EntityValidationError::InvalidPropertyPath {
+                        path: path.clone().into_owned()
+                    }
+                );
+            }
+        }
+
+        status
+    }
+}
+
+/// Returns the data types the entity type allows for the value at `path`.
+async fn expected_data_types<P>(
+    schema: &ClosedEntityType,
+    path: &PropertyPath<'_>,
+    provider: &P,
+) -> Result<Vec<VersionedUrl>, Report<EntityValidationError>>
+where
+    P: OntologyTypeProvider<PropertyType> + Sync,
+{
+    let elements = path.iter().collect::<Vec<_>>();
+    let invalid_path = || EntityValidationError::InvalidPropertyPath {
+        path: path.clone().into_owned(),
+    };
+
+    let mut properties = Vec::<(ValueOrArray<PropertyTypeReference>, usize)>::new();
+    if let Some(PropertyPathElement::Property(key)) = elements.first() {
+        if let Some(property) = schema.properties.get(key.as_ref()) {
+            properties.push((property.clone(), 1));
+        }
+    }
+
+    let mut data_types = Vec::new();
+    while let Some((property, position)) = properties.pop() {
+        let (reference, position) = match (&property, elements.get(position)) {
+            (ValueOrArray::Value(reference), _) => (reference, position),
+            (ValueOrArray::Array(array), Some(PropertyPathElement::Index(_))) => {
+                (array.items(), position + 1)
+            }
+            (ValueOrArray::Array(_), _) => continue,
+        };
+        let property_type = provider
+            .provide_type(reference.url())
+            .await
+            .change_context_lazy(invalid_path)?;
+
+        let mut values = property_type
+            .borrow()
+            .one_of()
+            .iter()
+            .map(|values| (values, position))
+            .collect::<Vec<_>>();
+        while let Some((values, position)) = values.pop() {
+            match (values, elements.get(position)) {
+                (PropertyValues::DataTypeReference(reference), None) => {
+                    data_types.push(reference.url().clone());
+                }
+                (
+                    PropertyValues::PropertyTypeObject(object),
+                    Some(PropertyPathElement::Property(key)),
+                ) => {
+                    if let Some(property) = object.properties().get(key.as_ref()) {
+                        properties.push((property.clone(), position + 1));
+                    }
+                }
+                (
+                    PropertyValues::ArrayOfPropertyValues(array),
+                    Some(PropertyPathElement::Index(_)),
+                ) => {
+                    values.extend(
+                        array
+                            .items()
+                            .one_of()
+                            .iter()
+                            .map(|values| (values, position + 1)),
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(data_types)
+}
+
+/// Converts a value given in the unit of `source` into the unit of `target`.
+///
+/// Returns `None` if the units do not share a canonical unit. A data type without a conversion is
+/// its own canonical unit.
+fn convert_value(
+    value: f64,
+    (source_id, source): (&VersionedUrl, Option<&DataTypeConversion>),
+    (target_id, target): (&VersionedUrl, Option<&DataTypeConversion>),
+) -> Option<f64> {
+    let source_unit = source.map_or(&source_id.base_url, |source| &source.canonical_unit);
+    let target_unit = target.map_or(&target_id.base_url, |target| &target.canonical_unit);
+    if source_unit != target_unit {
+        return None;
+    }
+
+    let canonical = source.map_or(value, |source| source.to_canonical(value));
+    Some(target.map_or(canonical, |target| target.from_canonical(canonical)))
+}
+
+/// Converts a value given in the unit of `source_id` into the first of the `expected` data types
+/// which shares a canonical unit with it.
+async fn convert_into_expected<P>(
+    value: f64,
+    source_id: &VersionedUrl,
+    expected: &[VersionedUrl],
+    provider: &P,
+) -> Result<Option<f64>, Report<EntityValidationError>>
+where
+    P: DataTypeProvider + Sync,
+{
+    let source = provider
+        .provide_conversion(source_id)
+        .await
+        .change_context(EntityValidationError::InvalidProperties)?;
+    for target_id in expected {
+        let target = provider
+            .provide_conversion(target_id)
+            .await
+            .change_context(EntityValidationError::InvalidProperties)?;
+        if let Some(converted) = convert_value(
+            value,
+            (source_id, source.as_ref()),
+            (target_id, target.as_ref()),
+        ) {
+            return Ok(Some(converted));
+        }
+    }
+    Ok(None)
+}
+
+/// Converts the properties with a declared data type into the units expected by the entity type.
+///
+/// The value of each property with a declared data type has to be valid for the declared data
+/// type. If the entity type does not expect the declared data type at the path, the value is
+/// converted into the first expected data type which shares a canonical unit with it. The returned
+/// properties can then be validated against the entity type.
+pub async fn normalize_properties<'a, P>(
+    properties: &'a PropertyObject,
+    data_types: &PropertyDataTypes<'_>,
+    schema: &ClosedEntityType,
+    components: ValidateEntityComponents,
+    provider: &P,
+) -> Result<Cow<'a, PropertyObject>, Report<EntityValidationError>>
+where
+    P: OntologyTypeProvider<PropertyType> + DataTypeProvider + Sync,
+{
+    if data_types.is_empty() {
+        return Ok(Cow::Borrowed(properties));
+    }
+
+    let mut status: Result<(), Report<EntityValidationError>> = Ok(());
+    let mut operations = Vec::new();
+
+    for (path, data_type_id) in data_types {
+        let invalid_data_type = || EntityValidationError::InvalidPropertyDataType {
+            path: path.clone().into_owned(),
+            data_type_id: data_type_id.clone(),
+        };
+
+        let Some(value) = properties.get(path) else {
+            extend_report!(
+                status,
+                EntityValidationError::InvalidPropertyPath {
+                    path: path.clone().into_owned()
+                }
+            );
+            continue;
+        };
+
+        if let Err(error) = <&DataTypeReference>::from(data_type_id)
+            .validate_value(value, components, provider)
+            .await
+        {
+            extend_report!(status, error.change_context(invalid_data_type()));
+            continue;
+        }
+
+        let expected = match expected_data_types(schema, path, provider).await {
+            Ok(expected) => expected,
+            Err(error) => {
+                extend_report!(status, error);
+                continue;
+            }
+        };
+        if expected.contains(data_type_id) {
+            continue;
+        }
+
+        let Some(number) = (match value {
+            Property::Value(value) => value.as_f64(),
+            Property::Array(_) | Property::Object(_) => None,
+        }) else {
+            extend_report!(status, invalid_data_type());
+            continue;
+        };
+        let converted = match convert_into_expected(number, data_type_id, &expected, provider).await
+        {
+            Ok(converted) => converted,
+            Err(error) => {
+                extend_report!(status, error.change_context(invalid_data_type()));
+                continue;
+            }
+        };
+
+        match converted.and_then(JsonNumber::from_f64) {
+            Some(converted) => operations.push(PropertyPatchOperation::Replace {
+                path: path.clone().into_owned(),
+                value: Property::Value(JsonValue::Number(converted)),
+                confidence: None,
+                data_type_id: None,
+                provenance: None,
+            }),
+            None => extend_report!(status, invalid_data_type()),
+        }
+    }
+
+    status?;
+
+    let mut normalized = properties.clone();
+    normalized
+        .patch(&operations)
+        .change_context(EntityValidationError::InvalidProperties)?;
+    Ok(Cow::Owned(normalized))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use graph_types::{
+        knowledge::{PropertyDataTypes, PropertyPath},
+        ontology::DataTypeConversion,
+    };
+    use serde_json::{json, Value as JsonValue};
+    use type_system::url::{BaseUrl, VersionedUrl};
+
+    use crate::{tests::validate_entity_with_data_types, EntityValidationError};
+
+    const METER: &str = "https://example.com/data-type/meter/";
+    const CENTIMETER: &str = "https://example.com/data-type/centimeter/";
+    const SECOND: &str = "https://example.com/data-type/second/";
+    const HEIGHT: &str = "https://example.com/property-type/height/";
+
+    fn data_type(base_url: &str, title: &str, maximum: u32) -> JsonValue {
+        json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "$id": format!("{base_url}v/1"),
+            "title": title,
+            "type": "number",
+            "minimum": 0,
+            "maximum": maximum,
+        })
+    }
+
+    fn data_type_id(base_url: &str) -> VersionedUrl {
+        format!("{base_url}v/1")
+            .parse()
+            .expect("invalid data type id")
+    }
+
+    async fn validate_height(
+        height: JsonValue,
+        unit: Option<&str>,
+    ) -> Result<(), error_stack::Report<EntityValidationError>> {
+        let path = PropertyPath::from_json_pointer(&format!("/{}", HEIGHT.replace('/', "~1")))
+            .expect("invalid property path");
+
+        validate_entity_with_data_types(
+            json!({ HEIGHT: height }),
+            PropertyDataTypes::new(
+                unit.map(|unit| (path, data_type_id(unit)))
+                    .into_iter()
+                    .collect::<HashMap<_, _>>(),
+            ),
+            json!({
+                "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type",
+                "kind": "entityType",
+                "$id": "https://example.com/entity-type/person/v/1",
+                "type": "object",
+                "title": "Person",
+                "properties": { HEIGHT: { "$ref": format!("{HEIGHT}v/1") } },
+            }),
+            [json!({
+                "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+                "kind": "propertyType",
+                "$id": format!("{HEIGHT}v/1"),
+                "title": "Height",
+                "oneOf": [{ "$ref": format!("{METER}v/1") }],
+            })],
+            [
+                (data_type(METER, "Meter", 10), None),
+                (
+                    data_type(CENTIMETER, "Centimeter", 10_000),
+                    Some(DataTypeConversion {
+                        canonical_unit: BaseUrl::new(METER.to_owned()).expect("invalid base URL"),
+                        factor: 0.01,
+                    }),
+                ),
+                (data_type(SECOND, "Second", 10), None),
+            ],
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn expected_unit() {
+        validate_height(json!(1.5), None)
+            .await
+            .expect("validation failed");
+        validate_height(json!(1.5), Some(METER))
+            .await
+            .expect("validation failed");
+        _ = validate_height(json!(150), None)
+            .await
+            .expect_err("validation succeeded for a value out of range");
+    }
+
+    #[tokio::test]
+    async fn convertible_unit() {
+        validate_height(json!(150), Some(CENTIMETER))
+            .await
+            .expect("validation failed");
+        _ = validate_height(json!(1_500), Some(CENTIMETER))
+            .await
+            .expect_err("validation succeeded for a converted value out of range");
+    }
+
+    #[tokio::test]
+    async fn unrelated_unit() {
+        let report = validate_height(json!(1.5), Some(SECOND))
+            .await
+            .expect_err("validation succeeded for an unrelated unit");
+        assert!(
+            matches!(
+                report.current_context(),
+                EntityValidationError::InvalidPropertyDataType { .. }
+            ),
+            "wrong error, got {report:?}"
+        );
+    }
+}
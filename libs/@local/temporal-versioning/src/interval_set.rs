@@ -0,0 +1,495 @@
+use core::{fmt, ops::Bound};
+
+use serde::{Deserialize, Deserializer, Serialize};
+#[cfg(feature = "utoipa")]
+use utoipa::{openapi, ToSchema};
+
+use crate::{
+    bounds::{compare_bounds, BoundType, IntervalBound, IntervalBoundHelper},
+    Interval,
+};
+
+/// A normalised set of disjoint intervals.
+///
+/// The intervals in the set are sorted by their start bound and neither overlap nor are adjacent
+/// to each other. Inserting an interval which overlaps or is adjacent to intervals already in the
+/// set merges them into a single interval, so two sets covering the same points always compare
+/// equal.
+#[derive(Serialize)]
+#[serde(transparent, bound(serialize = "S: Serialize, E: Serialize"))]
+pub struct IntervalSet<T, S, E> {
+    intervals: Vec<Interval<T, S, E>>,
+}
+
+impl<T, S, E> IntervalSet<T, S, E> {
+    /// Creates an empty set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the set does not contain any points.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns the number of disjoint intervals in this set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Returns an iterator over the disjoint intervals in ascending order.
+    pub fn iter(&self) -> core::slice::Iter<'_, Interval<T, S, E>> {
+        self.intervals.iter()
+    }
+
+    /// Returns the disjoint intervals in ascending order.
+    #[must_use]
+    pub fn as_slice(&self) -> &[Interval<T, S, E>] {
+        &self.intervals
+    }
+}
+
+impl<T, S, E> IntervalSet<T, S, E>
+where
+    T: Ord,
+    S: IntervalBound<T>,
+    E: IntervalBound<T>,
+{
+    fn connects(lhs: &Interval<T, S, E>, rhs: &Interval<T, S, E>) -> bool {
+        lhs.overlaps(rhs) || lhs.is_adjacent_to(rhs)
+    }
+
+    /// Adds an interval to the set.
+    ///
+    /// All intervals in the set which overlap or are adjacent to `interval` are merged with it.
+    pub fn insert(&mut self, interval: Interval<T, S, E>) {
+        // The intervals are sorted and disjoint, so the intervals connecting to the new interval
+        // form a consecutive run.
+        let start = self.intervals.partition_point(|existing| {
+            *existing < interval && !Self::connects(existing, &interval)
+        });
+        let end = start
+            + self.intervals[start..]
+                .iter()
+                .take_while(|existing| Self::connects(existing, &interval))
+                .count();
+
+        let merged = self
+            .intervals
+            .drain(start..end)
+            .fold(interval, Interval::merge);
+        self.intervals.insert(start, merged);
+    }
+
+    /// Returns `true` if any interval in this set contains `point`.
+    #[must_use]
+    pub fn contains_point(&self, point: &T) -> bool {
+        let index = self.intervals.partition_point(|interval| {
+            compare_bounds(
+                interval.end().as_bound(),
+                Bound::Included(point),
+                BoundType::End,
+                BoundType::End,
+                Ord::cmp,
+            )
+            .is_lt()
+        });
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.contains_point(point))
+    }
+
+    /// Returns `true` if a single interval in this set completely contains `interval`.
+    ///
+    /// As the intervals in the set are neither overlapping nor adjacent, this is equivalent to
+    /// checking that every point of `interval` is contained in the set.
+    #[must_use]
+    pub fn contains_interval(
+        &self,
+        interval: &Interval<T, impl IntervalBound<T>, impl IntervalBound<T>>,
+    ) -> bool {
+        self.intervals
+            .iter()
+            .any(|existing| existing.contains_interval(interval))
+    }
+
+    /// Returns a set containing all points which are in either set.
+    #[must_use]
+    pub fn union(mut self, other: Self) -> Self {
+        if self.intervals.len() < other.intervals.len() {
+            return other.union(self);
+        }
+        for interval in other.intervals {
+            self.insert(interval);
+        }
+        self
+    }
+
+    /// Returns a set containing all points which are in both sets.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self
+    where
+        S: Clone,
+        E: Clone,
+    {
+        let mut intervals = Vec::new();
+        let (mut lhs, mut rhs) = (0, 0);
+        while let (Some(left), Some(right)) = (self.intervals.get(lhs), other.intervals.get(rhs)) {
+            if let Some(intersection) = clone_interval(left).intersect(clone_interval(right)) {
+                intervals.push(intersection);
+            }
+            // Advance the interval which ends first, it cannot intersect with any later interval
+            // of the other set.
+            if left.cmp_end_to_end(right).is_le() {
+                lhs += 1;
+            } else {
+                rhs += 1;
+            }
+        }
+        // Intersections of disjoint, sorted intervals are disjoint and sorted as well, but two of
+        // them may still be adjacent, so they are normalised again.
+        intervals.into_iter().collect()
+    }
+
+    /// Returns a set containing all points which are in this set but not in `other`.
+    #[must_use]
+    pub fn difference(self, other: &Self) -> Self
+    where
+        S: Clone,
+        E: Clone,
+    {
+        self.intervals
+            .into_iter()
+            .flat_map(|interval| {
+                other
+                    .intervals
+                    .iter()
+                    .fold(vec![interval], |remaining, subtrahend| {
+                        remaining
+                            .into_iter()
+                            .flat_map(|part| {
+                                if part.overlaps(subtrahend) {
+                                    part.difference(clone_interval(subtrahend)).collect()
+                                } else {
+                                    vec![part]
+                                }
+                            })
+                            .collect()
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns a set containing all points which are not in this set.
+    ///
+    /// Like [`Interval::complement`], this requires the bound types to be able to represent the
+    /// flipped bounds of the intervals in this set.
+    #[must_use]
+    pub fn complement(self) -> Self {
+        let mut intervals = Vec::with_capacity(self.intervals.len() + 1);
+        let mut start = Some(S::from_bound(Bound::Unbounded));
+        for interval in self.intervals {
+            let (interval_start, interval_end) = interval.into_bounds();
+            if let Some(start) = start.take() {
+                if !matches!(
+                    (start.as_bound(), interval_start.as_bound()),
+                    (Bound::Unbounded, Bound::Unbounded)
+                ) {
+                    intervals.push(Interval::new_unchecked(start, interval_start.flip()));
+                }
+            }
+            if !matches!(interval_end.as_bound(), Bound::Unbounded) {
+                start = Some(interval_end.flip());
+            }
+        }
+        if let Some(start) = start {
+            intervals.push(Interval::new_unchecked(
+                start,
+                E::from_bound(Bound::Unbounded),
+            ));
+        }
+        Self { intervals }
+    }
+}
+
+fn clone_interval<T, S: Clone, E: Clone>(interval: &Interval<T, S, E>) -> Interval<T, S, E> {
+    Interval::new_unchecked(interval.start().clone(), interval.end().clone())
+}
+
+impl<T, S, E> Default for IntervalSet<T, S, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, S: Clone, E: Clone> Clone for IntervalSet<T, S, E> {
+    fn clone(&self) -> Self {
+        Self {
+            intervals: self.intervals.iter().map(clone_interval).collect(),
+        }
+    }
+}
+
+impl<T, S, E> PartialEq for IntervalSet<T, S, E>
+where
+    T: PartialEq,
+    S: IntervalBound<T>,
+    E: IntervalBound<T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.intervals == other.intervals
+    }
+}
+
+impl<T, S, E> Eq for IntervalSet<T, S, E>
+where
+    T: Eq,
+    S: IntervalBound<T>,
+    E: IntervalBound<T>,
+{
+}
+
+impl<T, S, E> fmt::Debug for IntervalSet<T, S, E>
+where
+    T: fmt::Debug,
+    S: IntervalBound<T>,
+    E: IntervalBound<T>,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_set().entries(&self.intervals).finish()
+    }
+}
+
+impl<T, S, E> FromIterator<Interval<T, S, E>> for IntervalSet<T, S, E>
+where
+    T: Ord,
+    S: IntervalBound<T>,
+    E: IntervalBound<T>,
+{
+    fn from_iter<I: IntoIterator<Item = Interval<T, S, E>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T, S, E> Extend<Interval<T, S, E>> for IntervalSet<T, S, E>
+where
+    T: Ord,
+    S: IntervalBound<T>,
+    E: IntervalBound<T>,
+{
+    fn extend<I: IntoIterator<Item = Interval<T, S, E>>>(&mut self, iter: I) {
+        let mut intervals = core::mem::take(&mut self.intervals);
+        intervals.extend(iter);
+        intervals.sort_unstable();
+
+        // The intervals are sorted, so it's only necessary to check the union with the last
+        // interval. If an interval connected two of the previous ones, those would have been
+        // merged in the previous iteration already.
+        self.intervals = intervals.into_iter().fold(Vec::new(), |mut acc, interval| {
+            match acc.pop() {
+                Some(last) if Self::connects(&last, &interval) => {
+                    acc.push(last.merge(interval));
+                }
+                Some(last) => {
+                    acc.push(last);
+                    acc.push(interval);
+                }
+                None => acc.push(interval),
+            }
+            acc
+        });
+    }
+}
+
+impl<T, S, E> IntoIterator for IntervalSet<T, S, E> {
+    type IntoIter = std::vec::IntoIter<Interval<T, S, E>>;
+    type Item = Interval<T, S, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.into_iter()
+    }
+}
+
+impl<'a, T, S, E> IntoIterator for &'a IntervalSet<T, S, E> {
+    type IntoIter = core::slice::Iter<'a, Interval<T, S, E>>;
+    type Item = &'a Interval<T, S, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'de, T, S, E> Deserialize<'de> for IntervalSet<T, S, E>
+where
+    T: Ord,
+    S: IntervalBound<T> + Deserialize<'de>,
+    E: IntervalBound<T> + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // The serialized intervals are not trusted to be normalised, so they are merged again.
+        Vec::<Interval<T, S, E>>::deserialize(deserializer).map(Self::from_iter)
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl<'s, T, S, E> ToSchema<'s> for IntervalSet<T, S, E>
+where
+    S: ToSchema<'s>,
+    E: ToSchema<'s>,
+{
+    fn schema() -> (&'static str, openapi::RefOr<openapi::Schema>) {
+        (
+            "IntervalSet",
+            openapi::ArrayBuilder::new()
+                .items(Interval::<T, S, E>::schema().1)
+                .build()
+                .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Bound;
+
+    use super::*;
+
+    type TestInterval = Interval<u32, Bound<u32>, Bound<u32>>;
+    type TestIntervalSet = IntervalSet<u32, Bound<u32>, Bound<u32>>;
+
+    const fn closed(start: u32, end: u32) -> TestInterval {
+        Interval::new_unchecked(Bound::Included(start), Bound::Included(end))
+    }
+
+    const fn right_open(start: u32, end: u32) -> TestInterval {
+        Interval::new_unchecked(Bound::Included(start), Bound::Excluded(end))
+    }
+
+    fn set(intervals: impl IntoIterator<Item = TestInterval>) -> TestIntervalSet {
+        intervals.into_iter().collect()
+    }
+
+    #[test]
+    fn normalises_on_insert() {
+        let mut intervals = TestIntervalSet::new();
+        intervals.insert(closed(10, 12));
+        intervals.insert(closed(0, 2));
+        intervals.insert(right_open(5, 7));
+        assert_eq!(
+            intervals.as_slice(),
+            [closed(0, 2), right_open(5, 7), closed(10, 12)]
+        );
+
+        // Adjacent to `[5, 7)` and overlapping `[10, 12]`
+        intervals.insert(closed(7, 11));
+        assert_eq!(intervals.as_slice(), [closed(0, 2), closed(5, 12)]);
+
+        // Covers everything
+        intervals.insert(closed(0, 20));
+        assert_eq!(intervals.as_slice(), [closed(0, 20)]);
+    }
+
+    #[test]
+    fn collect_matches_insert() {
+        let intervals = [
+            closed(10, 12),
+            right_open(5, 7),
+            closed(0, 2),
+            closed(7, 11),
+            closed(1, 3),
+        ];
+        let mut inserted = TestIntervalSet::new();
+        for interval in intervals {
+            inserted.insert(interval);
+        }
+        assert_eq!(inserted, set(intervals));
+        assert_eq!(inserted.as_slice(), [closed(0, 3), closed(5, 12)]);
+    }
+
+    #[test]
+    fn union() {
+        let lhs = set([closed(0, 2), closed(8, 10)]);
+        let rhs = set([closed(2, 4), closed(12, 14)]);
+        assert_eq!(
+            lhs.union(rhs).as_slice(),
+            [closed(0, 4), closed(8, 10), closed(12, 14)]
+        );
+    }
+
+    #[test]
+    fn intersection() {
+        let lhs = set([closed(0, 5), closed(8, 12)]);
+        let rhs = set([closed(3, 9), closed(11, 20)]);
+        assert_eq!(
+            lhs.intersection(&rhs).as_slice(),
+            [closed(3, 5), closed(8, 9), closed(11, 12)]
+        );
+        assert!(lhs.intersection(&TestIntervalSet::new()).is_empty());
+    }
+
+    #[test]
+    fn difference() {
+        let lhs = set([right_open(0, 10), right_open(20, 30)]);
+        let rhs = set([right_open(2, 4), right_open(6, 22), right_open(28, 40)]);
+        assert_eq!(
+            lhs.difference(&rhs).as_slice(),
+            [right_open(0, 2), right_open(4, 6), right_open(22, 28)]
+        );
+    }
+
+    #[test]
+    fn complement() {
+        let intervals = set([right_open(0, 10), right_open(20, 30)]);
+        assert_eq!(
+            intervals.clone().complement().as_slice(),
+            [
+                Interval::new_unchecked(Bound::Unbounded, Bound::Excluded(0)),
+                right_open(10, 20),
+                Interval::new_unchecked(Bound::Included(30), Bound::Unbounded),
+            ]
+        );
+        assert_eq!(intervals.clone().complement().complement(), intervals);
+
+        assert_eq!(
+            TestIntervalSet::new().complement().as_slice(),
+            [Interval::new_unchecked(Bound::Unbounded, Bound::Unbounded)]
+        );
+        assert!(
+            set([Interval::new_unchecked(Bound::Unbounded, Bound::Unbounded)])
+                .complement()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let intervals = set([right_open(0, 10), closed(20, 30)]);
+        assert!(intervals.contains_point(&0));
+        assert!(!intervals.contains_point(&10));
+        assert!(intervals.contains_point(&30));
+        assert!(!intervals.contains_point(&31));
+        assert!(intervals.contains_interval(&closed(22, 25)));
+        assert!(!intervals.contains_interval(&closed(5, 25)));
+    }
+
+    #[test]
+    fn deserialize_normalises() {
+        let intervals: TestIntervalSet = serde_json::from_value(serde_json::json!([
+            { "start": { "Included": 5 }, "end": { "Included": 10 } },
+            { "start": { "Included": 0 }, "end": { "Excluded": 5 } },
+        ]))
+        .expect("should be able to deserialize interval set");
+        assert_eq!(intervals.as_slice(), [closed(0, 10)]);
+        assert_eq!(
+            serde_json::to_value(&intervals).expect("should be able to serialize interval set"),
+            serde_json::json!([{ "start": { "Included": 0 }, "end": { "Included": 10 } }])
+        );
+    }
+}
@@ -3,6 +3,7 @@
 mod axis;
 mod bounds;
 mod interval;
+mod interval_set;
 mod temporal_bound;
 mod timestamp;
 
@@ -10,6 +11,7 @@ pub use self::{
     axis::{DecisionTime, TemporalTagged, TimeAxis, TransactionTime},
     bounds::IntervalBound,
     interval::Interval,
+    interval_set::IntervalSet,
     temporal_bound::{ClosedTemporalBound, LimitedTemporalBound, OpenTemporalBound, TemporalBound},
     timestamp::Timestamp,
 };
@@ -26,3 +28,8 @@ pub type RightBoundedTemporalInterval<A> =
 /// or unbounded.
 pub type LeftClosedTemporalInterval<A> =
     Interval<Timestamp<A>, ClosedTemporalBound<A>, OpenTemporalBound<A>>;
+
+/// A set of disjoint temporal intervals, where the lower bound of each interval is inclusive and
+/// the upper bound is either exclusive or unbounded.
+pub type LeftClosedTemporalIntervalSet<A> =
+    IntervalSet<Timestamp<A>, ClosedTemporalBound<A>, OpenTemporalBound<A>>;
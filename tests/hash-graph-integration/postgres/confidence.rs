@@ -1,6 +1,13 @@
 use std::{borrow::Cow, collections::HashMap, iter::once, str::FromStr};
 
-use graph::store::knowledge::PatchEntityParams;
+use graph::{
+    knowledge::EntityQueryPath,
+    store::{
+        knowledge::PatchEntityParams,
+        query::{Filter, FilterExpression, Parameter},
+        EntityQuerySorting, EntityQuerySortingRecord, NullOrdering, Ordering,
+    },
+};
 use graph_test_data::{data_type, entity, entity_type, property_type};
 use graph_types::knowledge::{
     entity::ProvidedEntityEditionProvenanceMetadata, Confidence, Property, PropertyConfidence,
@@ -101,6 +108,7 @@ async fn initial_confidence() {
     let updated_entity = api
         .patch_entity(PatchEntityParams {
             entity_id: entity.record_id.entity_id,
+            expected_edition_id: None,
             properties: Vec::new(),
             entity_type_ids: vec![],
             archived: None,
@@ -117,6 +125,7 @@ async fn initial_confidence() {
     let updated_entity = api
         .patch_entity(PatchEntityParams {
             entity_id: entity.record_id.entity_id,
+            expected_edition_id: None,
             properties: Vec::new(),
             entity_type_ids: vec![],
             archived: None,
@@ -158,6 +167,7 @@ async fn no_initial_draft() {
     let updated_entity = api
         .patch_entity(PatchEntityParams {
             entity_id: entity.record_id.entity_id,
+            expected_edition_id: None,
             properties: Vec::new(),
             entity_type_ids: vec![],
             archived: None,
@@ -174,6 +184,7 @@ async fn no_initial_draft() {
     let updated_entity = api
         .patch_entity(PatchEntityParams {
             entity_id: entity.record_id.entity_id,
+            expected_edition_id: None,
             properties: Vec::new(),
             entity_type_ids: vec![],
             archived: None,
@@ -193,10 +204,13 @@ async fn no_initial_draft() {
     let updated_entity = api
         .patch_entity(PatchEntityParams {
             entity_id: entity.record_id.entity_id,
+            expected_edition_id: None,
             properties: vec![PropertyPatchOperation::Replace {
                 path: once(PropertyPathElement::from(name_property_type_id())).collect(),
                 value: Property::Value(json!("Alice")),
                 confidence: Some(confidence(0.5)),
+                data_type_id: None,
+                provenance: None,
             }],
             entity_type_ids: vec![],
             archived: None,
@@ -217,6 +231,7 @@ async fn no_initial_draft() {
     let updated_entity = api
         .patch_entity(PatchEntityParams {
             entity_id: entity.record_id.entity_id,
+            expected_edition_id: None,
             properties: Vec::new(),
             entity_type_ids: vec![],
             archived: None,
@@ -257,12 +272,15 @@ async fn properties_add() {
     let updated_entity = api
         .patch_entity(PatchEntityParams {
             entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: vec![],
             properties: vec![PropertyPatchOperation::Add {
                 path: path.clone(),
                 value: Property::Value(json!(30)),
                 confidence: Some(confidence(0.5)),
+                data_type_id: None,
+                provenance: None,
             }],
             draft: None,
             archived: None,
@@ -308,6 +326,7 @@ async fn properties_remove() {
     let updated_entity = api
         .patch_entity(PatchEntityParams {
             entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: vec![],
             properties: vec![
@@ -315,11 +334,15 @@ async fn properties_remove() {
                     path: once(PropertyPathElement::from(interests_property_type_id())).collect(),
                     value: Property::Value(json!({})),
                     confidence: Some(confidence(0.5)),
+                    data_type_id: None,
+                    provenance: None,
                 },
                 PropertyPatchOperation::Add {
                     path: film_path.clone(),
                     value: Property::Value(json!("Fight Club")),
                     confidence: Some(confidence(0.5)),
+                    data_type_id: None,
+                    provenance: None,
                 },
             ],
             draft: None,
@@ -343,6 +366,7 @@ async fn properties_remove() {
     let updated_entity = api
         .patch_entity(PatchEntityParams {
             entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: vec![],
             properties: vec![PropertyPatchOperation::Remove {
@@ -358,3 +382,87 @@ async fn properties_remove() {
 
     assert!(updated_entity.property_confidence.is_empty());
 }
+
+#[tokio::test]
+async fn filter_and_sort_by_property_confidence() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let name_path: PropertyPath<'static> =
+        once(PropertyPathElement::from(name_property_type_id())).collect();
+
+    let mut entity_ids = Vec::new();
+    for (person, name_confidence) in [
+        (entity::PERSON_ALICE_V1, 0.9),
+        (entity::PERSON_BOB_V1, 0.3),
+        (entity::PERSON_CHARLES_V1, 0.1),
+    ] {
+        let entity = api
+            .create_entity(
+                serde_json::from_str(person).expect("could not parse entity"),
+                vec![person_entity_type_id()],
+                None,
+                false,
+                None,
+                PropertyConfidence::new(HashMap::from([(
+                    name_path.clone(),
+                    confidence(name_confidence),
+                )])),
+            )
+            .await
+            .expect("could not create entity");
+        entity_ids.push(entity.record_id.entity_id);
+    }
+    let [alice_id, bob_id, charles_id] = entity_ids[..] else {
+        panic!("expected three entities");
+    };
+
+    let mut low_confidence_entities = api
+        .get_entities_by_filter(Filter::Less(
+            FilterExpression::Path(EntityQueryPath::PropertyConfidence(name_path.clone())),
+            FilterExpression::Parameter(Parameter::F64(0.5)),
+        ))
+        .await
+        .expect("could not query entities")
+        .into_iter()
+        .map(|entity| entity.metadata.record_id.entity_id)
+        .collect::<Vec<_>>();
+    low_confidence_entities.sort_by_key(|entity_id| entity_id.entity_uuid.into_uuid());
+    let mut expected = vec![bob_id, charles_id];
+    expected.sort_by_key(|entity_id| entity_id.entity_uuid.into_uuid());
+    assert_eq!(low_confidence_entities, expected);
+
+    let sorting_paths = vec![EntityQuerySortingRecord {
+        path: EntityQueryPath::PropertyConfidence(name_path),
+        ordering: Ordering::Ascending,
+        nulls: Some(NullOrdering::Last),
+    }];
+    let (first_page, cursor) = api
+        .get_all_entities(
+            2,
+            EntityQuerySorting {
+                paths: sorting_paths.clone(),
+                cursor: None,
+            },
+        )
+        .await
+        .expect("could not query entities");
+    let (second_page, _) = api
+        .get_all_entities(
+            2,
+            EntityQuerySorting {
+                paths: sorting_paths,
+                cursor: Some(cursor.expect("cursor should be returned")),
+            },
+        )
+        .await
+        .expect("could not query entities");
+    assert_eq!(
+        first_page
+            .iter()
+            .chain(&second_page)
+            .map(|entity| entity.metadata.record_id.entity_id)
+            .collect::<Vec<_>>(),
+        [charles_id, bob_id, alice_id]
+    );
+}
@@ -1,10 +1,25 @@
-use graph::store::{
-    error::{OntologyTypeIsNotOwned, OntologyVersionDoesNotExist, VersionedUrlAlreadyExists},
-    BaseUrlAlreadyExists,
+use std::collections::HashSet;
+
+use authorization::NoAuthorization;
+use graph::{
+    store::{
+        error::{
+            BreakingOntologyTypeChanges, OntologyTypeIsNotOwned, OntologyVersionDoesNotExist,
+            VersionedUrlAlreadyExists,
+        },
+        ontology::UpdateDataTypesParams,
+        BaseUrlAlreadyExists, DataTypeStore,
+    },
+    subgraph::{
+        edges::{GraphResolveDepths, OutgoingEdgeResolveDepth},
+        identifier::{DataTypeVertexId, EntityTypeVertexId, PropertyTypeVertexId},
+    },
 };
-use type_system::DataType;
+use graph_test_data::{data_type, entity_type, property_type};
+use graph_types::ontology::ProvidedOntologyEditionProvenanceMetadata;
+use type_system::{DataType, EntityType, OntologyTypeChange, OntologyTypeChangeKind, PropertyType};
 
-use crate::DatabaseTestWrapper;
+use crate::{data_type_relationships, DatabaseTestWrapper};
 
 #[tokio::test]
 async fn insert() {
@@ -84,6 +99,66 @@ async fn update() {
     assert_eq!(object_dt_v2, returned_object_dt_v2.schema);
 }
 
+#[tokio::test]
+async fn reject_breaking_update() {
+    let number_dt_v2: DataType = serde_json::from_value(serde_json::json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": "https://blockprotocol.org/@blockprotocol/types/data-type/number/v/2",
+        "title": "Number",
+        "description": "An arithmetical value (in the Real number system)",
+        "type": "number",
+        "minimum": 0,
+    }))
+    .expect("could not parse data type representation");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::NUMBER_V1], [], [])
+        .await
+        .expect("could not seed database");
+
+    let report = api
+        .store
+        .update_data_type(
+            api.account_id,
+            &mut NoAuthorization,
+            None,
+            UpdateDataTypesParams {
+                schema: number_dt_v2.clone(),
+                relationships: data_type_relationships(),
+                conversion: None,
+                reject_breaking_changes: true,
+                provenance: ProvidedOntologyEditionProvenanceMetadata::default(),
+            },
+        )
+        .await
+        .expect_err("could apply breaking update");
+    let breaking_changes = report
+        .downcast_ref::<BreakingOntologyTypeChanges>()
+        .expect("error should be caused by breaking changes");
+    assert_eq!(
+        breaking_changes.changes,
+        [OntologyTypeChange::breaking(
+            OntologyTypeChangeKind::AddedConstraint {
+                constraint: "minimum".to_owned(),
+            }
+        )]
+    );
+
+    // The rejected update was rolled back, so it can be applied without the check.
+    api.update_data_type(number_dt_v2.clone())
+        .await
+        .expect("could not update data type");
+    assert_eq!(
+        api.get_data_type(number_dt_v2.id())
+            .await
+            .expect("could not get data type")
+            .schema,
+        number_dt_v2
+    );
+}
+
 #[tokio::test]
 async fn insert_same_base_url() {
     let object_dt_v1: DataType = serde_json::from_str(graph_test_data::data_type::OBJECT_V1)
@@ -229,3 +304,171 @@ async fn update_external_with_owned() {
         "wrong error, expected `OntologyTypeIsNotOwned`, got {report:?}"
     );
 }
+
+#[tokio::test]
+async fn resolve_incoming_edges() {
+    let text_data_type: DataType =
+        serde_json::from_str(data_type::TEXT_V1).expect("could not parse data type");
+    let text_property_type: PropertyType =
+        serde_json::from_str(property_type::TEXT_V1).expect("could not parse property type");
+    let page_entity_type: EntityType =
+        serde_json::from_str(entity_type::PAGE_V1).expect("could not parse entity type");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let subgraph = api
+        .get_data_type_subgraph(
+            text_data_type.id(),
+            GraphResolveDepths {
+                constrains_values_on: OutgoingEdgeResolveDepth {
+                    outgoing: 0,
+                    incoming: 1,
+                },
+                constrains_properties_on: OutgoingEdgeResolveDepth {
+                    outgoing: 0,
+                    incoming: 1,
+                },
+                ..GraphResolveDepths::default()
+            },
+        )
+        .await
+        .expect("could not get data type");
+
+    assert!(
+        subgraph
+            .vertices
+            .property_types
+            .contains_key(&PropertyTypeVertexId::from(text_property_type.id().clone()))
+    );
+    assert!(
+        subgraph
+            .vertices
+            .entity_types
+            .contains_key(&EntityTypeVertexId::from(page_entity_type.id().clone()))
+    );
+}
+
+#[tokio::test]
+async fn paginate() {
+    let seeded_data_types = [
+        data_type::BOOLEAN_V1,
+        data_type::EMPTY_LIST_V1,
+        data_type::NULL_V1,
+        data_type::NUMBER_V1,
+        data_type::TEXT_V1,
+    ];
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(seeded_data_types, [], [])
+        .await
+        .expect("could not seed database");
+
+    let mut read_data_types = Vec::new();
+    let mut cursor = None;
+    loop {
+        let (page, next_cursor) = api
+            .get_data_types_page(cursor, 2)
+            .await
+            .expect("could not read data types");
+        assert!(page.len() <= 2, "page exceeds the requested limit");
+        read_data_types.extend(page);
+
+        if next_cursor.is_none() {
+            break;
+        }
+        cursor = next_cursor;
+    }
+
+    let unique_data_types = read_data_types.iter().collect::<HashSet<_>>();
+    assert_eq!(
+        unique_data_types.len(),
+        read_data_types.len(),
+        "pages must not overlap"
+    );
+    for data_type in seeded_data_types {
+        let data_type: DataType =
+            serde_json::from_str(data_type).expect("could not parse data type representation");
+        assert!(unique_data_types.contains(&DataTypeVertexId::from(data_type.id().clone())));
+    }
+}
+
+#[tokio::test]
+async fn inherit_constraints() {
+    let number_dt: DataType = serde_json::from_value(serde_json::json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": "https://example.com/data-type/percentage/v/1",
+        "title": "Percentage",
+        "type": "number",
+        "minimum": 0,
+        "maximum": 100,
+    }))
+    .expect("could not parse data type");
+    let narrowed_dt: DataType = serde_json::from_value(serde_json::json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": "https://example.com/data-type/positive-percentage/v/1",
+        "title": "Positive Percentage",
+        "allOf": [{ "$ref": "https://example.com/data-type/percentage/v/1" }],
+        "type": "integer",
+        "minimum": 1,
+    }))
+    .expect("could not parse data type");
+    let widened_dt: DataType = serde_json::from_value(serde_json::json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": "https://example.com/data-type/large-percentage/v/1",
+        "title": "Large Percentage",
+        "allOf": [{ "$ref": "https://example.com/data-type/percentage/v/1" }],
+        "type": "number",
+        "maximum": 1000,
+    }))
+    .expect("could not parse data type");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([], [], [])
+        .await
+        .expect("could not seed database");
+
+    api.create_owned_data_type(number_dt.clone())
+        .await
+        .expect("could not create data type");
+    api.create_owned_data_type(narrowed_dt.clone())
+        .await
+        .expect("could not create data type");
+    _ = api
+        .create_owned_data_type(widened_dt)
+        .await
+        .expect_err("could create data type widening a constraint");
+
+    let subgraph = api
+        .get_data_type_subgraph(
+            narrowed_dt.id(),
+            GraphResolveDepths {
+                inherits_from: OutgoingEdgeResolveDepth {
+                    outgoing: 1,
+                    incoming: 0,
+                },
+                ..GraphResolveDepths::default()
+            },
+        )
+        .await
+        .expect("could not get data type");
+
+    assert!(
+        subgraph
+            .vertices
+            .data_types
+            .contains_key(&DataTypeVertexId::from(number_dt.id().clone()))
+    );
+}
@@ -2,7 +2,8 @@ use graph::store::knowledge::PatchEntityParams;
 use graph_test_data::{data_type, entity, entity_type, property_type};
 use graph_types::knowledge::{
     entity::{EntityId, ProvidedEntityEditionProvenanceMetadata},
-    Property, PropertyConfidence, PropertyObject, PropertyPatchOperation, PropertyPath,
+    Property, PropertyConfidence, PropertyMergeConflict, PropertyObject, PropertyPatchOperation,
+    PropertyPath,
 };
 use pretty_assertions::assert_eq;
 use temporal_versioning::ClosedTemporalBound;
@@ -98,10 +99,13 @@ async fn initial_draft() {
     let updated_entity = api
         .patch_entity(PatchEntityParams {
             entity_id: entity.record_id.entity_id,
+            expected_edition_id: None,
             properties: vec![PropertyPatchOperation::Replace {
                 path: PropertyPath::default(),
                 value: Property::Object(bob()),
                 confidence: None,
+                data_type_id: None,
+                provenance: None,
             }],
             entity_type_ids: vec![],
             archived: None,
@@ -135,10 +139,13 @@ async fn initial_draft() {
     let updated_live_entity = api
         .patch_entity(PatchEntityParams {
             entity_id: updated_entity.record_id.entity_id,
+            expected_edition_id: None,
             properties: vec![PropertyPatchOperation::Replace {
                 path: PropertyPath::default(),
                 value: Property::Object(charles()),
                 confidence: None,
+                data_type_id: None,
+                provenance: None,
             }],
             entity_type_ids: vec![],
             archived: None,
@@ -225,10 +232,13 @@ async fn no_initial_draft() {
         let updated_entity = api
             .patch_entity(PatchEntityParams {
                 entity_id: entity.record_id.entity_id,
+                expected_edition_id: None,
                 properties: vec![PropertyPatchOperation::Replace {
                     path: PropertyPath::default(),
                     value: Property::Object(bob()),
                     confidence: None,
+                    data_type_id: None,
+                    provenance: None,
                 }],
                 entity_type_ids: vec![],
                 archived: None,
@@ -267,10 +277,13 @@ async fn no_initial_draft() {
         let updated_live_entity = api
             .patch_entity(PatchEntityParams {
                 entity_id: updated_entity.record_id.entity_id,
+                expected_edition_id: None,
                 properties: vec![PropertyPatchOperation::Replace {
                     path: PropertyPath::default(),
                     value: Property::Object(charles()),
                     confidence: None,
+                    data_type_id: None,
+                    provenance: None,
                 }],
                 entity_type_ids: vec![],
                 archived: None,
@@ -343,10 +356,13 @@ async fn multiple_drafts() {
         let updated_entity = api
             .patch_entity(PatchEntityParams {
                 entity_id: entity.record_id.entity_id,
+                expected_edition_id: None,
                 properties: vec![PropertyPatchOperation::Replace {
                     path: PropertyPath::default(),
                     value: Property::Object(bob()),
                     confidence: None,
+                    data_type_id: None,
+                    provenance: None,
                 }],
                 entity_type_ids: vec![],
                 archived: None,
@@ -388,10 +404,13 @@ async fn multiple_drafts() {
         let updated_live_entity = api
             .patch_entity(PatchEntityParams {
                 entity_id: draft,
+                expected_edition_id: None,
                 properties: vec![PropertyPatchOperation::Replace {
                     path: PropertyPath::default(),
                     value: Property::Object(charles()),
                     confidence: None,
+                    data_type_id: None,
+                    provenance: None,
                 }],
                 entity_type_ids: vec![],
                 archived: None,
@@ -423,3 +442,169 @@ async fn multiple_drafts() {
         );
     }
 }
+
+fn property_path(property_type: &str) -> PropertyPath<'static> {
+    let mut path = PropertyPath::default();
+    path.push(
+        BaseUrl::new(format!(
+            "https://blockprotocol.org/@alice/types/property-type/{property_type}/"
+        ))
+        .expect("couldn't construct Base URL"),
+    );
+    path
+}
+
+fn patch_property(
+    entity_id: EntityId,
+    property_type: &str,
+    value: serde_json::Value,
+    draft: bool,
+) -> PatchEntityParams {
+    PatchEntityParams {
+        entity_id,
+        expected_edition_id: None,
+        properties: vec![PropertyPatchOperation::Add {
+            path: property_path(property_type),
+            value: Property::Value(value),
+            confidence: None,
+            data_type_id: None,
+            provenance: None,
+        }],
+        entity_type_ids: vec![],
+        archived: None,
+        draft: Some(draft),
+        decision_time: None,
+        confidence: None,
+        provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+    }
+}
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn merge_drafts() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let entity = api
+        .create_entity(
+            alice(),
+            vec![person_entity_type_id()],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity");
+    let entity_id = entity.record_id.entity_id;
+
+    let age_draft = api
+        .patch_entity(patch_property(
+            entity_id,
+            "age",
+            serde_json::json!(30),
+            true,
+        ))
+        .await
+        .expect("could not create draft");
+    let name_draft = api
+        .patch_entity(patch_property(
+            entity_id,
+            "name",
+            serde_json::json!("Bob"),
+            true,
+        ))
+        .await
+        .expect("could not create draft");
+
+    let drafts = api
+        .get_entity_drafts(entity_id)
+        .await
+        .expect("could not read drafts");
+    assert_eq!(drafts.len(), 2);
+    assert!(
+        drafts
+            .iter()
+            .all(|draft| draft.base_edition_id == Some(entity.record_id.edition_id))
+    );
+
+    api.patch_entity(patch_property(
+        name_draft.record_id.entity_id,
+        "name",
+        serde_json::json!("Bob"),
+        false,
+    ))
+    .await
+    .expect("could not publish draft");
+
+    // The live entity changed since the age draft was created, but the changes do not conflict.
+    let diff = api
+        .diff_entity_draft(age_draft.record_id.entity_id)
+        .await
+        .expect("could not diff draft");
+    assert!(diff.conflicts.is_empty());
+    assert_eq!(diff.diff.properties.len(), 1);
+    assert_eq!(*diff.diff.properties[0].path(), property_path("age"));
+
+    api.patch_entity(patch_property(
+        age_draft.record_id.entity_id,
+        "age",
+        serde_json::json!(30),
+        false,
+    ))
+    .await
+    .expect("could not publish draft");
+    assert_eq!(
+        api.get_latest_entity(entity_id)
+            .await
+            .expect("could not read entity")
+            .properties,
+        serde_json::from_value::<PropertyObject>(serde_json::json!({
+            "https://blockprotocol.org/@alice/types/property-type/name/": "Bob",
+            "https://blockprotocol.org/@alice/types/property-type/age/": 30,
+        }))
+        .expect("could not parse properties")
+    );
+
+    // Both the draft and the live entity change the name, so the draft cannot be published.
+    let conflicting_draft = api
+        .patch_entity(patch_property(
+            entity_id,
+            "name",
+            serde_json::json!("Charles"),
+            true,
+        ))
+        .await
+        .expect("could not create draft");
+    api.patch_entity(patch_property(
+        entity_id,
+        "name",
+        serde_json::json!("Alice"),
+        false,
+    ))
+    .await
+    .expect("could not update entity");
+
+    let diff = api
+        .diff_entity_draft(conflicting_draft.record_id.entity_id)
+        .await
+        .expect("could not diff draft");
+    assert_eq!(diff.conflicts, [property_path("name")]);
+
+    let report = api
+        .patch_entity(PatchEntityParams {
+            properties: vec![],
+            ..patch_property(
+                conflicting_draft.record_id.entity_id,
+                "name",
+                serde_json::Value::Null,
+                false,
+            )
+        })
+        .await
+        .expect_err("could publish conflicting draft");
+    let conflict = report
+        .downcast_ref::<PropertyMergeConflict>()
+        .expect("error should be caused by a merge conflict");
+    assert_eq!(conflict.paths, [property_path("name")]);
+}
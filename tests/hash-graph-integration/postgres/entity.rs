@@ -1,13 +1,60 @@
-use graph::store::knowledge::PatchEntityParams;
+use std::collections::{HashMap, HashSet};
+
+use authorization::{
+    schema::{
+        EntityEditorSubject, EntityOwnerSubject, EntityRelationAndSubject, EntitySetting,
+        EntitySettingSubject, EntityViewerSubject, WebOwnerSubject, WebPermission,
+    },
+    NoAuthorization,
+};
+use graph::{
+    store::{
+        account::{InsertAccountIdParams, InsertWebIdParams},
+        error::EntityEditionMismatch,
+        knowledge::{
+            CorrectEntityParams, CountEntitiesParams, CreateEntityParams, DeleteEntitiesParams,
+            DiffEntityParams, DiffEntitySubgraphParams, EntityChangeKind, EntityCountGroup,
+            EntityCountGroupBy, EntityCountGroupKey, EntityEditionSelector, GetEntityChangesParams,
+            MergeEntitiesParams, PatchEntityParams,
+        },
+        query::Filter,
+        AccountStore, EntityStore,
+    },
+    subgraph::{
+        diff::{EdgeDiff, GraphEdge, GraphEdgeKind, GraphElementId, VertexDiff},
+        edges::{GraphResolveDepths, OutgoingEdgeResolveDepth, SharedEdgeKind},
+        identifier::{EntityTypeVertexId, GraphElementVertexId},
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
+            VariableTemporalAxisUnresolved,
+        },
+    },
+};
 use graph_test_data::{data_type, entity, entity_type, property_type};
-use graph_types::knowledge::{
-    entity::ProvidedEntityEditionProvenanceMetadata, Property, PropertyConfidence, PropertyObject,
-    PropertyPatchOperation, PropertyPath,
+use graph_types::{
+    account::AccountId,
+    knowledge::{
+        entity::{
+            ActorType, EntityId, EntityUuid, OriginProvenance, PropertyMergeStrategy,
+            ProvidedEntityEditionProvenanceMetadata, SourceProvenance,
+        },
+        link::LinkData,
+        Property, PropertyConfidence, PropertyDataTypes, PropertyDiff, PropertyObject,
+        PropertyPatchOperation, PropertyPath, PropertyProvenance, PropertyProvenances,
+    },
+    owned_by_id::OwnedById,
 };
-use temporal_versioning::ClosedTemporalBound;
+use serde::Deserialize;
+use serde_json::json;
+use temporal_versioning::{ClosedTemporalBound, LeftClosedTemporalInterval, OpenTemporalBound};
+use time::Duration;
 use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+use uuid::Uuid;
 
-use crate::DatabaseTestWrapper;
+use crate::{
+    decision_time_before_now, restricted_authorization::RestrictedAuthorization,
+    DatabaseTestWrapper,
+};
 
 #[tokio::test]
 async fn insert() {
@@ -63,6 +110,232 @@ async fn insert() {
     assert_eq!(entities[0].properties, person);
 }
 
+#[tokio::test]
+async fn insert_many() {
+    let alice: PropertyObject =
+        serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob: PropertyObject =
+        serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let organization_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/organization/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let results = api
+        .create_entities(vec![
+            (alice.clone(), vec![person_type_id.clone()]),
+            (bob.clone(), vec![organization_type_id]),
+            (bob.clone(), vec![person_type_id]),
+        ])
+        .await
+        .expect("could not create entities");
+    assert_eq!(results.len(), 3);
+
+    let alice_metadata = results[0].as_ref().expect("could not create alice");
+    assert!(
+        results[1].is_err(),
+        "entity with unknown entity type was created"
+    );
+    let bob_metadata = results[2].as_ref().expect("could not create bob");
+
+    let entities = api
+        .get_entities(alice_metadata.record_id.entity_id)
+        .await
+        .expect("could not get entity");
+    assert_eq!(entities.len(), 1);
+    assert_eq!(entities[0].properties, alice);
+
+    let entities = api
+        .get_entities(bob_metadata.record_id.entity_id)
+        .await
+        .expect("could not get entity");
+    assert_eq!(entities.len(), 1);
+    assert_eq!(entities[0].properties, bob);
+}
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn insert_many_with_links() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let friend_of_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let owned_by_id = OwnedById::new(api.account_id.into_uuid());
+    let entity_id = || EntityId {
+        owned_by_id,
+        entity_uuid: EntityUuid::new(Uuid::new_v4()),
+        draft_id: None,
+    };
+    let alice_id = entity_id();
+    let bob_id = entity_id();
+    let charles_id = entity_id();
+
+    let params = |entity_id: EntityId,
+                  properties: PropertyObject,
+                  entity_type_id: &VersionedUrl,
+                  link_data: Option<LinkData>| CreateEntityParams {
+        owned_by_id,
+        entity_uuid: Some(entity_id.entity_uuid),
+        decision_time: None,
+        entity_type_ids: vec![entity_type_id.clone()],
+        properties,
+        property_confidence: PropertyConfidence::default(),
+        property_data_types: PropertyDataTypes::default(),
+        property_provenance: PropertyProvenances::default(),
+        link_data,
+        draft: false,
+        relationships: [],
+        confidence: None,
+        provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+    };
+    let link_data = |left_entity_id: EntityId, right_entity_id: EntityId| LinkData {
+        left_entity_id,
+        right_entity_id,
+        left_entity_confidence: None,
+        right_entity_confidence: None,
+        position: None,
+    };
+
+    // Bob is rejected because of his age, which also rejects the link pointing to him
+    let results = api
+        .store
+        .create_entities(
+            api.account_id,
+            &mut NoAuthorization,
+            None,
+            vec![
+                params(
+                    entity_id(),
+                    PropertyObject::empty(),
+                    &friend_of_type_id,
+                    Some(link_data(alice_id, charles_id)),
+                ),
+                params(
+                    entity_id(),
+                    PropertyObject::empty(),
+                    &friend_of_type_id,
+                    Some(link_data(alice_id, bob_id)),
+                ),
+                params(
+                    alice_id,
+                    serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity"),
+                    &person_type_id,
+                    None,
+                ),
+                params(
+                    bob_id,
+                    serde_json::from_value(json!({
+                        "https://blockprotocol.org/@alice/types/property-type/age/": "thirty"
+                    }))
+                    .expect("could not parse entity"),
+                    &person_type_id,
+                    None,
+                ),
+                params(
+                    charles_id,
+                    serde_json::from_str(entity::PERSON_CHARLES_V1)
+                        .expect("could not parse entity"),
+                    &person_type_id,
+                    None,
+                ),
+            ],
+        )
+        .await
+        .expect("could not create entities");
+    assert_eq!(results.len(), 5);
+
+    let link_metadata = results[0].as_ref().expect("could not create link");
+    assert!(results[1].is_err(), "link to a rejected entity was created");
+    assert!(results[2].is_ok(), "could not create alice");
+    assert!(
+        results[3].is_err(),
+        "entity with invalid properties was created"
+    );
+    assert!(results[4].is_ok(), "could not create charles");
+
+    let links = api
+        .get_entities(link_metadata.record_id.entity_id)
+        .await
+        .expect("could not get link");
+    assert_eq!(links.len(), 1);
+    let link_data = links[0].link_data.as_ref().expect("entity is not a link");
+    assert_eq!(link_data.left_entity_id, alice_id);
+    assert_eq!(link_data.right_entity_id, charles_id);
+
+    assert!(
+        api.get_entities(bob_id)
+            .await
+            .expect("could not query entities")
+            .is_empty()
+    );
+}
+
 #[tokio::test]
 async fn query() {
     let organization: PropertyObject =
@@ -143,10 +416,13 @@ async fn update() {
     let v2_metadata = api
         .patch_entity(PatchEntityParams {
             entity_id: v1_metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: vec![PropertyPatchOperation::Replace {
                 path: PropertyPath::default(),
                 value: Property::Object(page_v2.clone()),
                 confidence: None,
+                data_type_id: None,
+                provenance: None,
             }],
             entity_type_ids: vec![],
             archived: None,
@@ -189,3 +465,1225 @@ async fn update() {
 
     assert_eq!(entity_v2.properties.properties(), page_v2.properties());
 }
+
+#[tokio::test]
+async fn history_and_diff() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: PropertyObject =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database:");
+
+    let v1_metadata = api
+        .create_entity(
+            page_v1.clone(),
+            vec![VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: OntologyTypeVersion::new(1),
+            }],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity");
+    let entity_id = v1_metadata.record_id.entity_id;
+
+    let v2_metadata = api
+        .patch_entity(PatchEntityParams {
+            entity_id,
+            expected_edition_id: None,
+            properties: vec![PropertyPatchOperation::Replace {
+                path: PropertyPath::default(),
+                value: Property::Object(page_v2.clone()),
+                confidence: None,
+                data_type_id: None,
+                provenance: None,
+            }],
+            entity_type_ids: vec![],
+            archived: None,
+            draft: None,
+            decision_time: None,
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+        })
+        .await
+        .expect("could not update entity");
+
+    let history = api
+        .get_entity_history(entity_id)
+        .await
+        .expect("could not read entity history");
+    let edition_ids = history
+        .iter()
+        .map(|metadata| metadata.record_id.edition_id)
+        .collect::<Vec<_>>();
+    assert!(edition_ids.contains(&v1_metadata.record_id.edition_id));
+    assert_eq!(edition_ids.last(), Some(&v2_metadata.record_id.edition_id));
+
+    let diff = api
+        .diff_entity(DiffEntityParams {
+            first_entity_id: entity_id,
+            first_edition: EntityEditionSelector::EditionId(v1_metadata.record_id.edition_id),
+            second_entity_id: entity_id,
+            second_edition: EntityEditionSelector::Timestamp {
+                decision_time: None,
+                transaction_time: None,
+            },
+        })
+        .await
+        .expect("could not diff entity editions");
+    assert_eq!(
+        diff.properties,
+        page_v1
+            .diff(&page_v2, &mut PropertyPath::default())
+            .map(PropertyDiff::into_owned)
+            .collect::<Vec<_>>()
+    );
+    assert!(diff.entity_types.is_empty());
+    assert!(diff.link_data.is_none());
+
+    let diff = api
+        .diff_entity(DiffEntityParams {
+            first_entity_id: entity_id,
+            first_edition: EntityEditionSelector::EditionId(v2_metadata.record_id.edition_id),
+            second_entity_id: entity_id,
+            second_edition: EntityEditionSelector::EditionId(v2_metadata.record_id.edition_id),
+        })
+        .await
+        .expect("could not diff entity editions");
+    assert!(diff.properties.is_empty());
+}
+
+#[tokio::test]
+async fn change_feed() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: PropertyObject =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database:");
+
+    let (_, cursor) = api
+        .get_entity_changes(None)
+        .await
+        .expect("could not read entity changes");
+
+    let v1_metadata = api
+        .create_entity(
+            page_v1,
+            vec![VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: OntologyTypeVersion::new(1),
+            }],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity");
+    let entity_id = v1_metadata.record_id.entity_id;
+
+    let v2_metadata = api
+        .patch_entity(PatchEntityParams {
+            entity_id,
+            expected_edition_id: None,
+            properties: vec![PropertyPatchOperation::Replace {
+                path: PropertyPath::default(),
+                value: Property::Object(page_v2),
+                confidence: None,
+                data_type_id: None,
+                provenance: None,
+            }],
+            entity_type_ids: vec![],
+            archived: None,
+            draft: None,
+            decision_time: None,
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+        })
+        .await
+        .expect("could not update entity");
+
+    let v3_metadata = api
+        .archive_entity(entity_id)
+        .await
+        .expect("could not archive entity");
+
+    let (changes, cursor) = api
+        .get_entity_changes(cursor)
+        .await
+        .expect("could not read entity changes");
+    let changes = changes
+        .into_iter()
+        .filter(|change| change.entity_id == entity_id)
+        .map(|change| {
+            (
+                change.kind,
+                change
+                    .metadata
+                    .map(|metadata| metadata.record_id.edition_id),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        changes,
+        [
+            (
+                EntityChangeKind::Create,
+                Some(v1_metadata.record_id.edition_id)
+            ),
+            (
+                EntityChangeKind::Patch,
+                Some(v2_metadata.record_id.edition_id)
+            ),
+            (
+                EntityChangeKind::Archive,
+                Some(v3_metadata.record_id.edition_id)
+            ),
+        ]
+    );
+
+    let (changes, _) = api
+        .get_entity_changes(cursor)
+        .await
+        .expect("could not read entity changes");
+    assert!(changes.is_empty());
+
+    api.delete_entities(vec![entity_id], false)
+        .await
+        .expect("could not delete entity");
+
+    // Only the deletion is kept after the entity was deleted
+    let (changes, _) = api
+        .get_entity_changes(None)
+        .await
+        .expect("could not read entity changes");
+    let changes = changes
+        .into_iter()
+        .filter(|change| change.entity_id == entity_id)
+        .map(|change| (change.kind, change.metadata))
+        .collect::<Vec<_>>();
+    assert_eq!(changes, [(EntityChangeKind::Delete, None)]);
+}
+
+#[tokio::test]
+async fn change_feed_filter() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_entity_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database:");
+
+    let kept_entity_id = api
+        .create_entity(
+            page_v1.clone(),
+            vec![page_entity_type_id.clone()],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+    let deleted_entity_id = api
+        .create_entity(
+            page_v1,
+            vec![page_entity_type_id],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+    api.delete_entities(vec![deleted_entity_id], false)
+        .await
+        .expect("could not delete entity");
+
+    // Deleted entities cannot be matched against the filter, so their deletion is returned even
+    // though the filter only matches the other entity.
+    let (changes, _) = api
+        .store
+        .get_entity_changes(
+            api.account_id,
+            &NoAuthorization,
+            GetEntityChangesParams {
+                filter: Filter::for_entity_by_entity_id(kept_entity_id),
+                after: None,
+                limit: 100,
+            },
+        )
+        .await
+        .expect("could not read entity changes");
+    let changes = changes
+        .into_iter()
+        .map(|change| (change.kind, change.entity_id))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        changes,
+        [
+            (EntityChangeKind::Create, kept_entity_id),
+            (EntityChangeKind::Delete, deleted_entity_id),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn patch_expected_edition() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: PropertyObject =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database:");
+
+    let v1_metadata = api
+        .create_entity(
+            page_v1.clone(),
+            vec![VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: OntologyTypeVersion::new(1),
+            }],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity");
+    let entity_id = v1_metadata.record_id.entity_id;
+
+    let patch = |properties: PropertyObject| PatchEntityParams {
+        entity_id,
+        expected_edition_id: Some(v1_metadata.record_id.edition_id),
+        properties: vec![PropertyPatchOperation::Replace {
+            path: PropertyPath::default(),
+            value: Property::Object(properties),
+            confidence: None,
+            data_type_id: None,
+            provenance: None,
+        }],
+        entity_type_ids: vec![],
+        archived: None,
+        draft: None,
+        decision_time: None,
+        confidence: None,
+        provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+    };
+
+    let v2_metadata = api
+        .patch_entity(patch(page_v2))
+        .await
+        .expect("could not update entity");
+
+    let report = api
+        .patch_entity(patch(page_v1))
+        .await
+        .expect_err("could update entity with an outdated edition");
+    let mismatch = report
+        .downcast_ref::<EntityEditionMismatch>()
+        .expect("error should be caused by an edition mismatch");
+    assert_eq!(
+        mismatch.expected_edition_id,
+        v1_metadata.record_id.edition_id
+    );
+    assert_eq!(
+        mismatch.current_edition_id,
+        v2_metadata.record_id.edition_id
+    );
+}
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn correct_past_decision_time() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: PropertyObject =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database:");
+
+    // The entity is created one day ago and updated twelve hours ago.
+    let v1_metadata = api
+        .create_entity(
+            page_v1.clone(),
+            vec![VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: OntologyTypeVersion::new(1),
+            }],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity");
+    let entity_id = v1_metadata.record_id.entity_id;
+
+    api.patch_entity(PatchEntityParams {
+        entity_id,
+        expected_edition_id: None,
+        properties: vec![PropertyPatchOperation::Replace {
+            path: PropertyPath::default(),
+            value: Property::Object(page_v2.clone()),
+            confidence: None,
+            data_type_id: None,
+            provenance: None,
+        }],
+        entity_type_ids: vec![],
+        archived: None,
+        draft: None,
+        decision_time: Some(decision_time_before_now(Duration::hours(12))),
+        confidence: None,
+        provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+    })
+    .await
+    .expect("could not update entity");
+
+    // Between 20 and 16 hours ago the entity actually already had the second value.
+    let correction_interval = LeftClosedTemporalInterval::new(
+        ClosedTemporalBound::Inclusive(decision_time_before_now(Duration::hours(20))),
+        OpenTemporalBound::Exclusive(decision_time_before_now(Duration::hours(16))),
+    );
+    let corrected = api
+        .correct_entity(CorrectEntityParams {
+            entity_id,
+            decision_time: correction_interval,
+            properties: vec![PropertyPatchOperation::Replace {
+                path: PropertyPath::default(),
+                value: Property::Object(page_v2.clone()),
+                confidence: None,
+                data_type_id: None,
+                provenance: None,
+            }],
+            provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+        })
+        .await
+        .expect("could not correct entity");
+    assert_eq!(corrected.len(), 1);
+    assert_eq!(
+        corrected[0].temporal_versioning.decision_time,
+        correction_interval
+    );
+    assert_ne!(
+        corrected[0].record_id.edition_id,
+        v1_metadata.record_id.edition_id
+    );
+
+    // The first edition is split around the correction, the second edition is not affected.
+    let mut entities = api
+        .get_entities(entity_id)
+        .await
+        .expect("could not get entities");
+    entities.sort_by_key(|entity| entity.metadata.temporal_versioning.decision_time);
+    let properties = entities
+        .iter()
+        .map(|entity| &entity.properties)
+        .collect::<Vec<_>>();
+    assert_eq!(properties, [&page_v1, &page_v2, &page_v1, &page_v2]);
+    assert_eq!(
+        entities[0].metadata.record_id.edition_id,
+        entities[2].metadata.record_id.edition_id
+    );
+    assert_eq!(
+        entities[1].metadata.record_id.edition_id,
+        corrected[0].record_id.edition_id
+    );
+
+    // The change feed reports the corrected edition even though it was never valid at the current
+    // decision time.
+    let (changes, _) = api
+        .get_entity_changes(None)
+        .await
+        .expect("could not read entity changes");
+    let edition_ids = changes
+        .iter()
+        .filter(|change| change.entity_id == entity_id)
+        .filter_map(|change| change.metadata.as_ref())
+        .map(|metadata| metadata.record_id.edition_id)
+        .collect::<Vec<_>>();
+    assert_eq!(edition_ids.len(), 3);
+    assert_eq!(edition_ids[0], v1_metadata.record_id.edition_id);
+    assert_eq!(edition_ids[2], corrected[0].record_id.edition_id);
+
+    // The previous history is still available in transaction time.
+    let history = api
+        .get_entity_history(entity_id)
+        .await
+        .expect("could not read entity history");
+    assert!(history.iter().any(|metadata| {
+        metadata.record_id.edition_id == v1_metadata.record_id.edition_id
+            && metadata.temporal_versioning.decision_time.start()
+                == v1_metadata.temporal_versioning.decision_time.start()
+            && !matches!(
+                metadata.temporal_versioning.transaction_time.end(),
+                OpenTemporalBound::Unbounded
+            )
+    }));
+}
+
+#[tokio::test]
+async fn edition_provenance() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database:");
+
+    let v1_metadata = api
+        .create_entity(
+            page_v1,
+            vec![VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: OntologyTypeVersion::new(1),
+            }],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity");
+
+    let mut text_path = PropertyPath::default();
+    text_path.push(
+        BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/text/".to_owned())
+            .expect("couldn't construct Base URL"),
+    );
+    let provenance = ProvidedEntityEditionProvenanceMetadata {
+        actor_type: Some(ActorType::Ai),
+        origin: Some(OriginProvenance {
+            workflow_id: "research-entities".to_owned(),
+            workflow_run_id: None,
+        }),
+        sources: vec![SourceProvenance {
+            url: "https://example.com/".parse().expect("invalid URL"),
+        }],
+        note: Some("Rewritten by a summarizer".to_owned()),
+    };
+    let text_provenance = PropertyProvenance {
+        sources: vec![SourceProvenance {
+            url: "https://example.com/#text".parse().expect("invalid URL"),
+        }],
+    };
+
+    let v2_metadata = api
+        .patch_entity(PatchEntityParams {
+            entity_id: v1_metadata.record_id.entity_id,
+            expected_edition_id: None,
+            properties: vec![PropertyPatchOperation::Replace {
+                path: text_path.clone(),
+                value: Property::Value(json!("This page was intentionally left blank")),
+                confidence: None,
+                data_type_id: None,
+                provenance: Some(text_provenance.clone()),
+            }],
+            entity_type_ids: vec![],
+            archived: None,
+            draft: None,
+            decision_time: None,
+            confidence: None,
+            provenance: provenance.clone(),
+        })
+        .await
+        .expect("could not update entity");
+    assert_eq!(v2_metadata.provenance.edition.user_defined, provenance);
+    assert_eq!(
+        v2_metadata.property_provenance.get(&text_path),
+        Some(&text_provenance)
+    );
+
+    let entities = api
+        .get_entities_by_filter(
+            Filter::deserialize(json!({
+                "equal": [
+                    { "path": ["editionProvenance", "actorType"] },
+                    { "parameter": "ai" }
+                ]
+            }))
+            .expect("could not parse filter"),
+        )
+        .await
+        .expect("could not query entities");
+    assert_eq!(entities.len(), 1);
+    assert_eq!(
+        entities[0].metadata.record_id, v2_metadata.record_id,
+        "only the edition with the provided provenance should be returned"
+    );
+    assert_eq!(
+        entities[0].metadata.provenance.edition.user_defined,
+        provenance
+    );
+    assert_eq!(
+        entities[0].metadata.property_provenance,
+        v2_metadata.property_provenance
+    );
+}
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn subgraph_diff() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: PropertyObject =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+    let page_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database:");
+
+    // Both entities are created one day ago, the first one is updated twelve hours ago.
+    let changed_metadata = api
+        .create_entity(
+            page_v1.clone(),
+            vec![page_type_id.clone()],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity");
+    let changed_entity_id = changed_metadata.record_id.entity_id;
+    let unchanged_entity_id = api
+        .create_entity(
+            page_v1.clone(),
+            vec![page_type_id.clone()],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+
+    api.patch_entity(PatchEntityParams {
+        entity_id: changed_entity_id,
+        expected_edition_id: None,
+        properties: vec![PropertyPatchOperation::Replace {
+            path: PropertyPath::default(),
+            value: Property::Object(page_v2.clone()),
+            confidence: None,
+            data_type_id: None,
+            provenance: None,
+        }],
+        entity_type_ids: vec![],
+        archived: None,
+        draft: None,
+        decision_time: Some(decision_time_before_now(Duration::hours(12))),
+        confidence: None,
+        provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+    })
+    .await
+    .expect("could not update entity");
+
+    let temporal_axes = |decision_time| QueryTemporalAxesUnresolved::TransactionTime {
+        pinned: PinnedTemporalAxisUnresolved::new(decision_time),
+        variable: VariableTemporalAxisUnresolved::new(None, None),
+    };
+    let graph_resolve_depths = GraphResolveDepths {
+        is_of_type: OutgoingEdgeResolveDepth {
+            outgoing: 1,
+            incoming: 0,
+        },
+        ..GraphResolveDepths::default()
+    };
+
+    // Before the entities were created, the subgraph is empty.
+    let diff = api
+        .diff_entity_subgraph(DiffEntitySubgraphParams {
+            filter: Filter::All(Vec::new()),
+            graph_resolve_depths,
+            old_temporal_axes: temporal_axes(Some(decision_time_before_now(Duration::days(2)))),
+            new_temporal_axes: temporal_axes(None),
+            include_drafts: false,
+        })
+        .await
+        .expect("could not diff subgraphs");
+    let added_vertices = diff
+        .vertices
+        .iter()
+        .map(|vertex| match vertex {
+            VertexDiff::Added { added } => added.clone(),
+            VertexDiff::Removed { .. } | VertexDiff::Changed { .. } => {
+                panic!("unexpected vertex diff: {vertex:?}")
+            }
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(added_vertices.len(), 3);
+    assert!(
+        added_vertices.contains(&GraphElementVertexId::EntityType(EntityTypeVertexId::from(
+            page_type_id.clone()
+        )))
+    );
+    for entity_id in [changed_entity_id, unchanged_entity_id] {
+        assert!(added_vertices.iter().any(|vertex_id| matches!(
+            vertex_id,
+            GraphElementVertexId::KnowledgeGraph(vertex_id) if vertex_id.base_id == entity_id
+        )));
+        assert!(diff.edges.contains(&EdgeDiff::Added {
+            added: GraphEdge {
+                left_endpoint: GraphElementId::KnowledgeGraph(entity_id),
+                kind: GraphEdgeKind::Shared(SharedEdgeKind::IsOfType),
+                reversed: false,
+                right_endpoint: GraphElementId::Ontology(page_type_id.clone()),
+            },
+        }));
+    }
+    assert!(
+        diff.edges
+            .iter()
+            .all(|edge| matches!(edge, EdgeDiff::Added { .. }))
+    );
+
+    // Only the properties of the updated entity changed since eighteen hours ago.
+    let diff = api
+        .diff_entity_subgraph(DiffEntitySubgraphParams {
+            filter: Filter::All(Vec::new()),
+            graph_resolve_depths,
+            old_temporal_axes: temporal_axes(Some(decision_time_before_now(Duration::hours(18)))),
+            new_temporal_axes: temporal_axes(None),
+            include_drafts: false,
+        })
+        .await
+        .expect("could not diff subgraphs");
+    assert!(diff.edges.is_empty());
+    let [VertexDiff::Changed { old, new, diff }] = diff.vertices.as_slice() else {
+        panic!("unexpected vertex diffs: {:?}", diff.vertices);
+    };
+    assert_eq!(old.base_id, changed_entity_id);
+    assert_eq!(new.base_id, changed_entity_id);
+    assert_eq!(
+        diff.properties,
+        page_v1
+            .diff(&page_v2, &mut PropertyPath::default())
+            .map(PropertyDiff::into_owned)
+            .collect::<Vec<_>>()
+    );
+    assert!(diff.entity_types.is_empty());
+}
+
+#[tokio::test]
+async fn filter_by_property_range() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [entity_type::PERSON_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let name = "https://blockprotocol.org/@alice/types/property-type/name/";
+    let age = "https://blockprotocol.org/@alice/types/property-type/age/";
+
+    let mut entity_ids = Vec::new();
+    for properties in [
+        serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity"),
+        serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity"),
+        serde_json::from_value(json!({ name: "Dave", age: 25 })).expect("could not parse entity"),
+    ] {
+        entity_ids.push(
+            api.create_entity(
+                properties,
+                vec![person_type_id.clone()],
+                None,
+                false,
+                None,
+                PropertyConfidence::default(),
+            )
+            .await
+            .expect("could not create entity")
+            .record_id
+            .entity_id,
+        );
+    }
+    let [alice_id, bob_id, dave_id] = entity_ids[..] else {
+        panic!("expected three entities");
+    };
+
+    for (filter, expected) in [
+        (
+            json!({ "greater": [
+                { "path": ["properties", age] },
+                { "parameter": 30 }
+            ]}),
+            vec![bob_id],
+        ),
+        (
+            json!({ "between": [
+                { "path": ["properties", age] },
+                { "parameter": 20 },
+                { "parameter": 30.5 }
+            ]}),
+            vec![dave_id],
+        ),
+        // Strings are not comparable to numbers, so they don't match instead of failing the query
+        (
+            json!({ "greater": [
+                { "path": ["properties", name] },
+                { "parameter": 30 }
+            ]}),
+            vec![],
+        ),
+        // Strings are compared lexicographically
+        (
+            json!({ "less": [
+                { "path": ["properties", name] },
+                { "parameter": "Bob" }
+            ]}),
+            vec![alice_id],
+        ),
+    ] {
+        let mut filter = Filter::deserialize(filter).expect("could not parse filter");
+        filter
+            .convert_parameters()
+            .expect("could not convert parameters");
+
+        let mut entities = api
+            .get_entities_by_filter(filter)
+            .await
+            .expect("could not query entities")
+            .into_iter()
+            .map(|entity| entity.metadata.record_id.entity_id)
+            .collect::<Vec<_>>();
+        entities.sort_by_key(|entity_id| entity_id.entity_uuid.into_uuid());
+        assert_eq!(entities, expected);
+    }
+}
+
+#[tokio::test]
+async fn delete_requires_web_administrator() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::NAME_V1],
+            [entity_type::SONG_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let song_id = api
+        .create_entity(
+            serde_json::from_str(entity::SONG_V1).expect("could not parse entity"),
+            vec![VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/song/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: OntologyTypeVersion::new(1),
+            }],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+
+    // The actor has full access to the entity but does not administrate its web
+    let _ = api
+        .store
+        .delete_entities(
+            api.account_id,
+            &mut RestrictedAuthorization {
+                denied_web_permissions: vec![WebPermission::ChangePermission],
+                ..RestrictedAuthorization::default()
+            },
+            DeleteEntitiesParams {
+                entity_ids: vec![song_id],
+                cascade_links: false,
+            },
+        )
+        .await
+        .expect_err("could delete entity without administrating the web");
+    assert_eq!(
+        api.get_latest_entity(song_id)
+            .await
+            .expect("could not get entity")
+            .metadata
+            .record_id
+            .entity_id,
+        song_id
+    );
+
+    api.delete_entities(vec![song_id], false)
+        .await
+        .expect("could not delete entity");
+}
+
+#[tokio::test]
+async fn count_entities_by_type() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::NAME_V1],
+            [entity_type::SONG_V1, entity_type::ORGANIZATION_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let song_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/song/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let organization_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/organization/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let mut song_ids = Vec::new();
+    for _ in 0..3 {
+        song_ids.push(
+            api.create_entity(
+                serde_json::from_str(entity::SONG_V1).expect("could not parse entity"),
+                vec![song_type_id.clone()],
+                None,
+                false,
+                None,
+                PropertyConfidence::default(),
+            )
+            .await
+            .expect("could not create entity")
+            .record_id
+            .entity_id,
+        );
+    }
+    api.create_entity(
+        serde_json::from_str(entity::ORGANIZATION_V1).expect("could not parse entity"),
+        vec![organization_type_id.clone()],
+        None,
+        false,
+        None,
+        PropertyConfidence::default(),
+    )
+    .await
+    .expect("could not create entity");
+
+    // One of the songs is not visible to the actor
+    let count = api
+        .store
+        .count_entities(
+            api.account_id,
+            &RestrictedAuthorization {
+                hidden_entities: HashSet::from([song_ids[0].entity_uuid]),
+                ..RestrictedAuthorization::default()
+            },
+            CountEntitiesParams {
+                filter: Filter::All(Vec::new()),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                include_drafts: false,
+                group_by: Some(EntityCountGroupBy::EntityType),
+            },
+        )
+        .await
+        .expect("could not count entities");
+
+    assert_eq!(count.count, 3);
+    assert_eq!(count.groups.len(), 2);
+    assert!(count.groups.contains(&EntityCountGroup {
+        key: EntityCountGroupKey::EntityType(song_type_id),
+        count: 2,
+    }));
+    assert!(count.groups.contains(&EntityCountGroup {
+        key: EntityCountGroupKey::EntityType(organization_type_id),
+        count: 1,
+    }));
+}
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn merge_entities_of_different_webs() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let other_account_id = AccountId::new(Uuid::new_v4());
+    api.store
+        .insert_account_id(
+            other_account_id,
+            &mut NoAuthorization,
+            InsertAccountIdParams {
+                account_id: other_account_id,
+            },
+        )
+        .await
+        .expect("could not insert account id");
+    api.store
+        .insert_web_id(
+            other_account_id,
+            &mut NoAuthorization,
+            InsertWebIdParams {
+                owned_by_id: OwnedById::new(other_account_id.into_uuid()),
+                owner: WebOwnerSubject::Account {
+                    id: other_account_id,
+                },
+            },
+        )
+        .await
+        .expect("could not create web id");
+
+    let survivor_web_id = OwnedById::new(api.account_id.into_uuid());
+    let duplicate_web_id = OwnedById::new(other_account_id.into_uuid());
+    let mut authorization = RestrictedAuthorization::default();
+    let params =
+        |owned_by_id: OwnedById, properties: &str, relationships: Vec<EntityRelationAndSubject>| {
+            CreateEntityParams {
+                owned_by_id,
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: vec![person_type_id.clone()],
+                properties: serde_json::from_str(properties).expect("could not parse entity"),
+                property_confidence: PropertyConfidence::default(),
+                property_data_types: PropertyDataTypes::default(),
+                property_provenance: PropertyProvenances::default(),
+                link_data: None,
+                draft: false,
+                relationships,
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+            }
+        };
+
+    let survivor_params = params(
+        survivor_web_id,
+        entity::PERSON_BOB_V1,
+        vec![EntityRelationAndSubject::Setting {
+            subject: EntitySettingSubject::Setting {
+                id: EntitySetting::AdministratorFromWeb,
+            },
+            level: 0,
+        }],
+    );
+    let duplicate_params = params(
+        duplicate_web_id,
+        entity::PERSON_ALICE_V1,
+        vec![
+            EntityRelationAndSubject::Setting {
+                subject: EntitySettingSubject::Setting {
+                    id: EntitySetting::ViewFromWeb,
+                },
+                level: 0,
+            },
+            EntityRelationAndSubject::Editor {
+                subject: EntityEditorSubject::Account {
+                    id: other_account_id,
+                },
+                level: 0,
+            },
+            EntityRelationAndSubject::Viewer {
+                subject: EntityViewerSubject::Public,
+                level: 0,
+            },
+        ],
+    );
+    let survivor_id = api
+        .store
+        .create_entity(api.account_id, &mut authorization, None, survivor_params)
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+    let duplicate_id = api
+        .store
+        .create_entity(api.account_id, &mut authorization, None, duplicate_params)
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+
+    api.store
+        .merge_entities(
+            api.account_id,
+            &mut authorization,
+            None,
+            MergeEntitiesParams {
+                survivor: survivor_id,
+                duplicate: duplicate_id,
+                default_strategy: PropertyMergeStrategy::PreferNewest,
+                strategies: HashMap::new(),
+                provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+            },
+        )
+        .await
+        .expect("could not merge entities");
+
+    // The survivor keeps its owner and setting, only the instance relations are carried over
+    let relations = &authorization.entity_relations[&survivor_id.entity_uuid];
+    assert_eq!(relations.len(), 4);
+    for relation in [
+        EntityRelationAndSubject::Owner {
+            subject: EntityOwnerSubject::Web {
+                id: survivor_web_id,
+            },
+            level: 0,
+        },
+        EntityRelationAndSubject::Setting {
+            subject: EntitySettingSubject::Setting {
+                id: EntitySetting::AdministratorFromWeb,
+            },
+            level: 0,
+        },
+        EntityRelationAndSubject::Editor {
+            subject: EntityEditorSubject::Account {
+                id: other_account_id,
+            },
+            level: 0,
+        },
+        EntityRelationAndSubject::Viewer {
+            subject: EntityViewerSubject::Public,
+            level: 0,
+        },
+    ] {
+        assert!(
+            relations.contains(&relation),
+            "missing relation {relation:?}"
+        );
+    }
+}
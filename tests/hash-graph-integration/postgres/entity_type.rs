@@ -1,4 +1,6 @@
-use graph_test_data::{data_type, entity_type, property_type};
+use graph::subgraph::edges::{GraphResolveDepths, OutgoingEdgeResolveDepth};
+use graph_test_data::{data_type, entity, entity_type, property_type};
+use graph_types::knowledge::PropertyConfidence;
 use type_system::EntityType;
 
 use crate::DatabaseTestWrapper;
@@ -115,3 +117,60 @@ async fn update() {
     assert_eq!(page_et_v1, returned_page_et_v1.schema);
     assert_eq!(page_et_v2, returned_page_et_v2.schema);
 }
+
+#[tokio::test]
+async fn resolve_draft_instances() {
+    let organization_et: EntityType = serde_json::from_str(entity_type::ORGANIZATION_V1)
+        .expect("could not parse entity type representation");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::NAME_V1],
+            [entity_type::ORGANIZATION_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let draft_id = api
+        .create_entity(
+            serde_json::from_str(entity::ORGANIZATION_V1).expect("could not parse entity"),
+            vec![organization_et.id().clone()],
+            None,
+            true,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+
+    let graph_resolve_depths = GraphResolveDepths {
+        is_of_type: OutgoingEdgeResolveDepth {
+            outgoing: 0,
+            incoming: 1,
+        },
+        ..GraphResolveDepths::default()
+    };
+
+    let subgraph = api
+        .get_entity_type_subgraph(organization_et.id(), graph_resolve_depths, false)
+        .await
+        .expect("could not get entity type");
+    assert!(subgraph.vertices.entities.is_empty());
+
+    let subgraph = api
+        .get_entity_type_subgraph(organization_et.id(), graph_resolve_depths, true)
+        .await
+        .expect("could not get entity type");
+    assert_eq!(subgraph.vertices.entities.len(), 1);
+    assert!(
+        subgraph
+            .vertices
+            .entities
+            .keys()
+            .all(|vertex_id| vertex_id.base_id == draft_id)
+    );
+}
@@ -14,7 +14,9 @@ mod links;
 mod multi_type;
 mod partial_updates;
 mod property_type;
+mod restricted_authorization;
 mod sorting;
+mod units;
 
 use std::{borrow::Cow, str::FromStr};
 
@@ -34,7 +36,14 @@ use graph::{
     ontology::EntityTypeQueryPath,
     store::{
         account::{InsertAccountIdParams, InsertWebIdParams},
-        knowledge::{CreateEntityParams, GetEntityParams, PatchEntityParams},
+        error::DeletionError,
+        knowledge::{
+            CorrectEntityParams, CreateEntityParams, DeleteEntitiesParams, DiffEntityDraftResult,
+            DiffEntityParams, DiffEntityResult, DiffEntitySubgraphParams, EntityChange,
+            EntityChangeCursor, EntityDraft, EntityMigration, GetEntityChangesParams,
+            GetEntityParams, MergeEntitiesParams, MigrateEntitiesParams, MoveLinkParams,
+            PatchEntityParams,
+        },
         ontology::{
             CreateDataTypeParams, CreateEntityTypeParams, CreatePropertyTypeParams,
             GetDataTypesParams, GetEntityTypesParams, GetPropertyTypesParams,
@@ -46,6 +55,7 @@ use graph::{
         PostgresStore, PostgresStorePool, PropertyTypeStore, QueryError, StorePool, UpdateError,
     },
     subgraph::{
+        diff::SubgraphDiff,
         edges::{EdgeDirection, GraphResolveDepths, KnowledgeGraphEdgeKind, SharedEdgeKind},
         identifier::{
             DataTypeVertexId, EntityTypeVertexId, GraphElementVertexId, PropertyTypeVertexId,
@@ -55,6 +65,7 @@ use graph::{
             PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
             VariableTemporalAxisUnresolved,
         },
+        Subgraph,
     },
     Environment,
 };
@@ -64,13 +75,13 @@ use graph_types::{
         entity::{
             Entity, EntityId, EntityMetadata, EntityUuid, ProvidedEntityEditionProvenanceMetadata,
         },
-        link::LinkData,
-        Confidence, PropertyConfidence, PropertyObject,
+        link::{LinkData, LinkPosition},
+        Confidence, PropertyConfidence, PropertyDataTypes, PropertyObject, PropertyProvenances,
     },
     ontology::{
-        DataTypeMetadata, DataTypeWithMetadata, EntityTypeMetadata, EntityTypeWithMetadata,
-        OntologyTypeClassificationMetadata, PropertyTypeMetadata, PropertyTypeWithMetadata,
-        ProvidedOntologyEditionProvenanceMetadata,
+        DataTypeConversion, DataTypeMetadata, DataTypeWithMetadata, EntityTypeMetadata,
+        EntityTypeWithMetadata, OntologyTypeClassificationMetadata, PropertyTypeMetadata,
+        PropertyTypeWithMetadata, ProvidedOntologyEditionProvenanceMetadata,
     },
     owned_by_id::OwnedById,
 };
@@ -223,6 +234,7 @@ impl DatabaseTestWrapper {
                         relationships: data_type_relationships(),
                         conflict_behavior: ConflictBehavior::Skip,
                         provenance: ProvidedOntologyEditionProvenanceMetadata::default(),
+                        conversion: None,
                     }
                 }),
             )
@@ -279,10 +291,14 @@ impl DatabaseTestWrapper {
 fn generate_decision_time() -> Timestamp<DecisionTime> {
     // We cannot use `Timestamp::now` as the decision time must be before the transaction time. As
     // the transaction is started before the time was recorded, this will always fail.
+    decision_time_before_now(Duration::days(1))
+}
+
+fn decision_time_before_now(duration: Duration) -> Timestamp<DecisionTime> {
     Timestamp::from_str(
         &OffsetDateTime::now_utc()
-            .checked_sub(Duration::days(1))
-            .expect("could not subtract a day from the current time")
+            .checked_sub(duration)
+            .expect("could not subtract the duration from the current time")
             .format(&Iso8601::DEFAULT)
             .expect("could not format date to ISO8601"),
     )
@@ -308,6 +324,31 @@ impl DatabaseApi<'_> {
                     relationships: data_type_relationships(),
                     conflict_behavior: ConflictBehavior::Fail,
                     provenance: ProvidedOntologyEditionProvenanceMetadata::default(),
+                    conversion: None,
+                },
+            )
+            .await
+    }
+
+    pub async fn create_unit_data_type(
+        &mut self,
+        data_type: DataType,
+        conversion: DataTypeConversion,
+    ) -> Result<DataTypeMetadata, InsertionError> {
+        self.store
+            .create_data_type(
+                self.account_id,
+                &mut NoAuthorization,
+                None,
+                CreateDataTypeParams {
+                    schema: data_type,
+                    classification: OntologyTypeClassificationMetadata::Owned {
+                        owned_by_id: OwnedById::new(self.account_id.into_uuid()),
+                    },
+                    relationships: data_type_relationships(),
+                    conflict_behavior: ConflictBehavior::Fail,
+                    conversion: Some(conversion),
+                    provenance: ProvidedOntologyEditionProvenanceMetadata::default(),
                 },
             )
             .await
@@ -330,6 +371,7 @@ impl DatabaseApi<'_> {
                     relationships: data_type_relationships(),
                     conflict_behavior: ConflictBehavior::Fail,
                     provenance: ProvidedOntologyEditionProvenanceMetadata::default(),
+                    conversion: None,
                 },
             )
             .await
@@ -340,14 +382,56 @@ impl DatabaseApi<'_> {
         url: &VersionedUrl,
     ) -> Result<DataTypeWithMetadata, QueryError> {
         Ok(self
+            .get_data_type_subgraph(url, GraphResolveDepths::default())
+            .await?
+            .vertices
+            .data_types
+            .remove(&DataTypeVertexId::from(url.clone()))
+            .expect("no data type found"))
+    }
+
+    pub async fn get_data_types_page(
+        &mut self,
+        after: Option<DataTypeVertexId>,
+        limit: usize,
+    ) -> Result<(Vec<DataTypeVertexId>, Option<DataTypeVertexId>), QueryError> {
+        let (subgraph, cursor) = self
             .store
             .get_data_type(
                 self.account_id,
                 &NoAuthorization,
                 GetDataTypesParams {
                     query: StructuralQuery {
-                        filter: Filter::for_versioned_url(url),
+                        filter: Filter::All(Vec::new()),
                         graph_resolve_depths: GraphResolveDepths::default(),
+                        temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                            pinned: PinnedTemporalAxisUnresolved::new(None),
+                            variable: VariableTemporalAxisUnresolved::new(None, None),
+                        },
+                        include_drafts: false,
+                    },
+                    after,
+                    limit: Some(limit),
+                },
+            )
+            .await?;
+
+        Ok((subgraph.vertices.data_types.into_keys().collect(), cursor))
+    }
+
+    pub async fn get_data_type_subgraph(
+        &mut self,
+        url: &VersionedUrl,
+        graph_resolve_depths: GraphResolveDepths,
+    ) -> Result<Subgraph, QueryError> {
+        self.store
+            .get_data_type(
+                self.account_id,
+                &NoAuthorization,
+                GetDataTypesParams {
+                    query: StructuralQuery {
+                        filter: Filter::for_versioned_url(url),
+                        graph_resolve_depths,
                         temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
                             pinned: PinnedTemporalAxisUnresolved::new(None),
                             variable: VariableTemporalAxisUnresolved::new(
@@ -361,11 +445,8 @@ impl DatabaseApi<'_> {
                     after: None,
                 },
             )
-            .await?
-            .vertices
-            .data_types
-            .remove(&DataTypeVertexId::from(url.clone()))
-            .expect("no data type found"))
+            .await
+            .map(|(subgraph, _)| subgraph)
     }
 
     pub async fn update_data_type(
@@ -381,6 +462,8 @@ impl DatabaseApi<'_> {
                     schema,
                     relationships: data_type_relationships(),
                     provenance: ProvidedOntologyEditionProvenanceMetadata::default(),
+                    conversion: None,
+                    reject_breaking_changes: false,
                 },
             )
             .await
@@ -435,6 +518,7 @@ impl DatabaseApi<'_> {
                 },
             )
             .await?
+            .0
             .vertices
             .property_types
             .remove(&PropertyTypeVertexId::from(url.clone()))
@@ -454,6 +538,7 @@ impl DatabaseApi<'_> {
                     schema: property_type,
                     relationships: property_type_relationships(),
                     provenance: ProvidedOntologyEditionProvenanceMetadata::default(),
+                    reject_breaking_changes: false,
                 },
             )
             .await
@@ -487,6 +572,21 @@ impl DatabaseApi<'_> {
         &mut self,
         url: &VersionedUrl,
     ) -> Result<EntityTypeWithMetadata, QueryError> {
+        Ok(self
+            .get_entity_type_subgraph(url, GraphResolveDepths::default(), false)
+            .await?
+            .vertices
+            .entity_types
+            .remove(&EntityTypeVertexId::from(url.clone()))
+            .expect("no entity type found"))
+    }
+
+    pub async fn get_entity_type_subgraph(
+        &mut self,
+        url: &VersionedUrl,
+        graph_resolve_depths: GraphResolveDepths,
+        include_drafts: bool,
+    ) -> Result<Subgraph, QueryError> {
         Ok(self
             .store
             .get_entity_type(
@@ -495,7 +595,7 @@ impl DatabaseApi<'_> {
                 GetEntityTypesParams {
                     query: StructuralQuery {
                         filter: Filter::for_versioned_url(url),
-                        graph_resolve_depths: GraphResolveDepths::default(),
+                        graph_resolve_depths,
                         temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
                             pinned: PinnedTemporalAxisUnresolved::new(None),
                             variable: VariableTemporalAxisUnresolved::new(
@@ -503,17 +603,14 @@ impl DatabaseApi<'_> {
                                 None,
                             ),
                         },
-                        include_drafts: false,
+                        include_drafts,
                     },
                     after: None,
                     limit: None,
                 },
             )
             .await?
-            .vertices
-            .entity_types
-            .remove(&EntityTypeVertexId::from(url.clone()))
-            .expect("no entity type found"))
+            .0)
     }
 
     pub async fn update_entity_type(
@@ -531,6 +628,7 @@ impl DatabaseApi<'_> {
                     label_property: None,
                     relationships: entity_type_relationships(),
                     provenance: ProvidedOntologyEditionProvenanceMetadata::default(),
+                    reject_breaking_changes: false,
                 },
             )
             .await
@@ -557,6 +655,8 @@ impl DatabaseApi<'_> {
                     entity_type_ids,
                     properties,
                     property_confidence,
+                    property_data_types: PropertyDataTypes::default(),
+                    property_provenance: PropertyProvenances::default(),
                     link_data: None,
                     draft,
                     relationships: [],
@@ -567,6 +667,67 @@ impl DatabaseApi<'_> {
             .await
     }
 
+    pub async fn create_entity_with_data_types(
+        &mut self,
+        properties: PropertyObject,
+        entity_type_ids: Vec<VersionedUrl>,
+        property_data_types: PropertyDataTypes<'static>,
+    ) -> Result<EntityMetadata, InsertionError> {
+        self.store
+            .create_entity(
+                self.account_id,
+                &mut NoAuthorization,
+                None,
+                CreateEntityParams {
+                    owned_by_id: OwnedById::new(self.account_id.into_uuid()),
+                    entity_uuid: None,
+                    decision_time: Some(generate_decision_time()),
+                    entity_type_ids,
+                    properties,
+                    property_confidence: PropertyConfidence::default(),
+                    property_data_types,
+                    property_provenance: PropertyProvenances::default(),
+                    link_data: None,
+                    draft: false,
+                    relationships: [],
+                    confidence: None,
+                    provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+                },
+            )
+            .await
+    }
+
+    pub async fn create_entities(
+        &mut self,
+        entities: Vec<(PropertyObject, Vec<VersionedUrl>)>,
+    ) -> Result<Vec<Result<EntityMetadata, InsertionError>>, InsertionError> {
+        let owned_by_id = OwnedById::new(self.account_id.into_uuid());
+        self.store
+            .create_entities(
+                self.account_id,
+                &mut NoAuthorization,
+                None,
+                entities
+                    .into_iter()
+                    .map(|(properties, entity_type_ids)| CreateEntityParams {
+                        owned_by_id,
+                        entity_uuid: None,
+                        decision_time: Some(generate_decision_time()),
+                        entity_type_ids,
+                        properties,
+                        property_confidence: PropertyConfidence::default(),
+                        property_data_types: PropertyDataTypes::default(),
+                        property_provenance: PropertyProvenances::default(),
+                        link_data: None,
+                        draft: false,
+                        relationships: [],
+                        confidence: None,
+                        provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+                    }),
+            )
+            .await
+    }
+
     pub async fn get_entities(&self, entity_id: EntityId) -> Result<Vec<Entity>, QueryError> {
         Ok(self
             .store
@@ -642,6 +803,35 @@ impl DatabaseApi<'_> {
     pub async fn get_entities_by_type(
         &self,
         entity_type_id: &VersionedUrl,
+    ) -> Result<Vec<Entity>, QueryError> {
+        self.get_entities_by_filter(Filter::All(vec![
+            Filter::Equal(
+                Some(FilterExpression::Path(EntityQueryPath::EntityTypeEdge {
+                    edge_kind: SharedEdgeKind::IsOfType,
+                    path: EntityTypeQueryPath::BaseUrl,
+                    inheritance_depth: Some(0),
+                })),
+                Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                    entity_type_id.base_url.as_str(),
+                )))),
+            ),
+            Filter::Equal(
+                Some(FilterExpression::Path(EntityQueryPath::EntityTypeEdge {
+                    edge_kind: SharedEdgeKind::IsOfType,
+                    path: EntityTypeQueryPath::Version,
+                    inheritance_depth: Some(0),
+                })),
+                Some(FilterExpression::Parameter(Parameter::OntologyTypeVersion(
+                    entity_type_id.version,
+                ))),
+            ),
+        ]))
+        .await
+    }
+
+    pub async fn get_entities_by_filter(
+        &self,
+        filter: Filter<'_, Entity>,
     ) -> Result<Vec<Entity>, QueryError> {
         let (mut subgraph, _) = self
             .store
@@ -650,28 +840,7 @@ impl DatabaseApi<'_> {
                 &NoAuthorization,
                 GetEntityParams {
                     query: StructuralQuery {
-                        filter: Filter::All(vec![
-                            Filter::Equal(
-                                Some(FilterExpression::Path(EntityQueryPath::EntityTypeEdge {
-                                    edge_kind: SharedEdgeKind::IsOfType,
-                                    path: EntityTypeQueryPath::BaseUrl,
-                                    inheritance_depth: Some(0),
-                                })),
-                                Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
-                                    entity_type_id.base_url.as_str(),
-                                )))),
-                            ),
-                            Filter::Equal(
-                                Some(FilterExpression::Path(EntityQueryPath::EntityTypeEdge {
-                                    edge_kind: SharedEdgeKind::IsOfType,
-                                    path: EntityTypeQueryPath::Version,
-                                    inheritance_depth: Some(0),
-                                })),
-                                Some(FilterExpression::Parameter(Parameter::OntologyTypeVersion(
-                                    entity_type_id.version,
-                                ))),
-                            ),
-                        ]),
+                        filter,
                         graph_resolve_depths: GraphResolveDepths::default(),
                         temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
                             pinned: PinnedTemporalAxisUnresolved::new(None),
@@ -790,6 +959,39 @@ impl DatabaseApi<'_> {
             .await
     }
 
+    async fn correct_entity(
+        &mut self,
+        params: CorrectEntityParams,
+    ) -> Result<Vec<EntityMetadata>, UpdateError> {
+        self.store
+            .correct_entity(self.account_id, &mut NoAuthorization, None, params)
+            .await
+    }
+
+    async fn merge_entities(
+        &mut self,
+        params: MergeEntitiesParams,
+    ) -> Result<EntityMetadata, UpdateError> {
+        self.store
+            .merge_entities(self.account_id, &mut NoAuthorization, None, params)
+            .await
+    }
+
+    async fn migrate_entities(
+        &mut self,
+        params: MigrateEntitiesParams,
+    ) -> Result<Vec<EntityMigration>, UpdateError> {
+        self.store
+            .migrate_entities(self.account_id, &mut NoAuthorization, None, params)
+            .await
+    }
+
+    async fn move_link(&mut self, params: MoveLinkParams) -> Result<LinkPosition, UpdateError> {
+        self.store
+            .move_link(self.account_id, &mut NoAuthorization, params)
+            .await
+    }
+
     async fn create_link_entity(
         &mut self,
         properties: PropertyObject,
@@ -810,11 +1012,14 @@ impl DatabaseApi<'_> {
                     entity_type_ids,
                     properties,
                     property_confidence: PropertyConfidence::default(),
+                    property_data_types: PropertyDataTypes::default(),
+                    property_provenance: PropertyProvenances::default(),
                     link_data: Some(LinkData {
                         left_entity_id,
                         right_entity_id,
                         left_entity_confidence: None,
                         right_entity_confidence: None,
+                        position: None,
                     }),
                     draft: false,
                     relationships: [],
@@ -986,6 +1191,82 @@ impl DatabaseApi<'_> {
             .collect())
     }
 
+    async fn delete_entities(
+        &mut self,
+        entity_ids: Vec<EntityId>,
+        cascade_links: bool,
+    ) -> Result<Vec<EntityId>, DeletionError> {
+        self.store
+            .delete_entities(
+                self.account_id,
+                &mut NoAuthorization,
+                DeleteEntitiesParams {
+                    entity_ids,
+                    cascade_links,
+                },
+            )
+            .await
+    }
+
+    async fn get_entity_history(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<Vec<EntityMetadata>, QueryError> {
+        self.store
+            .get_entity_history(self.account_id, &NoAuthorization, entity_id)
+            .await
+    }
+
+    async fn diff_entity(
+        &self,
+        params: DiffEntityParams,
+    ) -> Result<DiffEntityResult<'static>, QueryError> {
+        self.store
+            .diff_entity(self.account_id, &NoAuthorization, params)
+            .await
+    }
+
+    async fn diff_entity_subgraph(
+        &self,
+        params: DiffEntitySubgraphParams<'_>,
+    ) -> Result<SubgraphDiff, QueryError> {
+        self.store
+            .diff_entity_subgraph(self.account_id, &NoAuthorization, params)
+            .await
+    }
+
+    async fn get_entity_drafts(&self, entity_id: EntityId) -> Result<Vec<EntityDraft>, QueryError> {
+        self.store
+            .get_entity_drafts(self.account_id, &NoAuthorization, entity_id)
+            .await
+    }
+
+    async fn diff_entity_draft(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<DiffEntityDraftResult<'static>, QueryError> {
+        self.store
+            .diff_entity_draft(self.account_id, &NoAuthorization, entity_id)
+            .await
+    }
+
+    async fn get_entity_changes(
+        &self,
+        after: Option<EntityChangeCursor>,
+    ) -> Result<(Vec<EntityChange>, Option<EntityChangeCursor>), QueryError> {
+        self.store
+            .get_entity_changes(
+                self.account_id,
+                &NoAuthorization,
+                GetEntityChangesParams {
+                    filter: Filter::All(Vec::new()),
+                    after,
+                    limit: 100,
+                },
+            )
+            .await
+    }
+
     async fn archive_entity(&mut self, entity_id: EntityId) -> Result<EntityMetadata, UpdateError> {
         self.store
             .patch_entity(
@@ -994,6 +1275,7 @@ impl DatabaseApi<'_> {
                 None,
                 PatchEntityParams {
                     entity_id,
+                    expected_edition_id: None,
                     decision_time: Some(generate_decision_time()),
                     archived: Some(true),
                     draft: None,
@@ -1,8 +1,40 @@
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    num::NonZeroU32,
+};
+
+use authorization::NoAuthorization;
+use graph::{
+    knowledge::EntityQueryPath,
+    ontology::EntityTypeQueryPath,
+    store::{
+        error::UnpublishedLinkEndpoints,
+        knowledge::{
+            CreateEntityParams, EntityChangeKind, MergeEntitiesParams, MoveLinkParams,
+            PatchEntityParams,
+        },
+        query::{Filter, FilterExpression, LinkDirection, LinkPathFilter, LinkPathHop, Parameter},
+        EntityStore,
+    },
+    subgraph::edges::SharedEdgeKind,
+};
 use graph_test_data::{data_type, entity, entity_type, property_type};
-use graph_types::knowledge::{PropertyConfidence, PropertyObject};
+use graph_types::{
+    knowledge::{
+        entity::{
+            Entity, EntityId, PropertyMergeStrategy, ProvidedEntityEditionProvenanceMetadata,
+        },
+        link::LinkData,
+        Property, PropertyConfidence, PropertyDataTypes, PropertyObject, PropertyPath,
+        PropertyProvenances,
+    },
+    owned_by_id::OwnedById,
+};
+use serde_json::json;
 use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
 
-use crate::DatabaseTestWrapper;
+use crate::{restricted_authorization::RestrictedAuthorization, DatabaseApi, DatabaseTestWrapper};
 
 #[tokio::test]
 async fn insert() {
@@ -220,7 +252,7 @@ async fn get_entity_links() {
 
     let link_datas = links_from_source
         .iter()
-        .map(|entity| entity.link_data.expect("entity is not a link"))
+        .map(|entity| entity.link_data.as_ref().expect("entity is not a link"))
         .collect::<Vec<_>>();
     assert!(
         link_datas
@@ -335,3 +367,1041 @@ async fn remove_link() {
             .is_empty()
     );
 }
+
+#[tokio::test]
+async fn delete_linked_entity() {
+    let alice = serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob = serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let alice_metadata = api
+        .create_entity(
+            alice,
+            vec![person_type_id.clone()],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity");
+
+    let bob_metadata = api
+        .create_entity(
+            bob,
+            vec![person_type_id.clone()],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity");
+
+    let friend_of_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let link_metadata = api
+        .create_link_entity(
+            PropertyObject::empty(),
+            vec![friend_of_type_id],
+            None,
+            alice_metadata.record_id.entity_id,
+            bob_metadata.record_id.entity_id,
+        )
+        .await
+        .expect("could not create link");
+
+    let _ = api
+        .delete_entities(vec![bob_metadata.record_id.entity_id], false)
+        .await
+        .expect_err("could delete linked entity without cascading");
+
+    let deleted_entity_ids = api
+        .delete_entities(vec![bob_metadata.record_id.entity_id], true)
+        .await
+        .expect("could not delete entity");
+    assert_eq!(
+        deleted_entity_ids,
+        [
+            bob_metadata.record_id.entity_id,
+            link_metadata.record_id.entity_id
+        ]
+    );
+
+    assert!(
+        api.get_entities(bob_metadata.record_id.entity_id)
+            .await
+            .expect("could not read entities")
+            .is_empty()
+    );
+    assert!(
+        api.get_latest_entity_links(alice_metadata.record_id.entity_id)
+            .await
+            .expect("could not read links")
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn link_path_filter() {
+    let alice = serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob = serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+    let charles = serde_json::from_str(entity::PERSON_CHARLES_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let friend_link_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let acquaintance_link_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/acquaintance-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let mut entity_ids = Vec::new();
+    for person in [alice, bob, charles] {
+        entity_ids.push(
+            api.create_entity(
+                person,
+                vec![person_type_id.clone()],
+                None,
+                false,
+                None,
+                PropertyConfidence::default(),
+            )
+            .await
+            .expect("could not create entity")
+            .record_id
+            .entity_id,
+        );
+    }
+    let [alice_id, bob_id, charles_id] = entity_ids[..] else {
+        unreachable!("three entities were created");
+    };
+
+    // alice -friend-of-> bob -acquaintance-of-> charles
+    api.create_link_entity(
+        PropertyObject::empty(),
+        vec![friend_link_type_id.clone()],
+        None,
+        alice_id,
+        bob_id,
+    )
+    .await
+    .expect("could not create link");
+    api.create_link_entity(
+        PropertyObject::empty(),
+        vec![acquaintance_link_type_id],
+        None,
+        bob_id,
+        charles_id,
+    )
+    .await
+    .expect("could not create link");
+
+    let reaches_charles = |link_filter: Option<Filter<'static, Entity>>| {
+        Filter::LinkPath(LinkPathFilter {
+            hops: vec![LinkPathHop {
+                direction: LinkDirection::Outgoing,
+                link_filter,
+                entity_filter: None,
+                max_depth: NonZeroU32::new(2).expect("2 is not zero"),
+            }],
+            filter: Some(Box::new(Filter::for_entity_by_entity_id(charles_id))),
+        })
+    };
+
+    let mut entities = api
+        .get_entities_by_filter(reaches_charles(None))
+        .await
+        .expect("could not query entities")
+        .into_iter()
+        .map(|entity| entity.metadata.record_id.entity_id)
+        .collect::<Vec<_>>();
+    entities.sort_by_key(|entity_id| entity_id.entity_uuid.into_uuid());
+    let mut expected = vec![alice_id, bob_id];
+    expected.sort_by_key(|entity_id| entity_id.entity_uuid.into_uuid());
+    assert_eq!(entities, expected);
+
+    // A link path can be combined with a filter on the latest entity type version
+    let mut entities = api
+        .get_entities_by_filter(Filter::All(vec![
+            reaches_charles(None),
+            Filter::Equal(
+                Some(FilterExpression::Path(EntityQueryPath::EntityTypeEdge {
+                    edge_kind: SharedEdgeKind::IsOfType,
+                    path: EntityTypeQueryPath::Version,
+                    inheritance_depth: Some(0),
+                })),
+                Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                    "latest",
+                )))),
+            ),
+        ]))
+        .await
+        .expect("could not query entities")
+        .into_iter()
+        .map(|entity| entity.metadata.record_id.entity_id)
+        .collect::<Vec<_>>();
+    entities.sort_by_key(|entity_id| entity_id.entity_uuid.into_uuid());
+    assert_eq!(entities, expected);
+
+    // Only following `friend-of` links, charles is not reachable
+    let entities = api
+        .get_entities_by_filter(reaches_charles(Some(Filter::Equal(
+            Some(FilterExpression::Path(EntityQueryPath::EntityTypeEdge {
+                edge_kind: SharedEdgeKind::IsOfType,
+                path: EntityTypeQueryPath::BaseUrl,
+                inheritance_depth: Some(0),
+            })),
+            Some(FilterExpression::Parameter(Parameter::Text(Cow::Owned(
+                friend_link_type_id.base_url.to_string(),
+            )))),
+        ))))
+        .await
+        .expect("could not query entities");
+    assert!(entities.is_empty());
+
+    // The path in the opposite direction starts at charles
+    let entities = api
+        .get_entities_by_filter(Filter::LinkPath(LinkPathFilter {
+            hops: vec![LinkPathHop {
+                direction: LinkDirection::Incoming,
+                link_filter: None,
+                entity_filter: None,
+                max_depth: NonZeroU32::new(2).expect("2 is not zero"),
+            }],
+            filter: Some(Box::new(Filter::for_entity_by_entity_id(alice_id))),
+        }))
+        .await
+        .expect("could not query entities");
+    assert_eq!(entities.len(), 2);
+
+    // charles -friend-of-> alice closes a cycle, which is followed until the maximum depth
+    api.create_link_entity(
+        PropertyObject::empty(),
+        vec![friend_link_type_id],
+        None,
+        charles_id,
+        alice_id,
+    )
+    .await
+    .expect("could not create link");
+    let entities = api
+        .get_entities_by_filter(Filter::LinkPath(LinkPathFilter {
+            hops: vec![LinkPathHop {
+                direction: LinkDirection::Outgoing,
+                link_filter: None,
+                entity_filter: None,
+                max_depth: NonZeroU32::new(LinkPathHop::MAX_DEPTH)
+                    .expect("maximum depth is not zero"),
+            }],
+            filter: Some(Box::new(Filter::for_entity_by_entity_id(alice_id))),
+        }))
+        .await
+        .expect("could not query entities");
+    assert_eq!(entities.len(), 3);
+}
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn link_to_draft() {
+    let alice = serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob = serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let friend_of_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let alice_metadata = api
+        .create_entity(
+            alice,
+            vec![person_type_id.clone()],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity");
+
+    let bob_metadata = api
+        .create_entity(
+            bob,
+            vec![person_type_id.clone()],
+            None,
+            true,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity");
+    let bob_id = EntityId {
+        draft_id: None,
+        ..bob_metadata.record_id.entity_id
+    };
+
+    let report = api
+        .create_link_entity(
+            PropertyObject::empty(),
+            vec![friend_of_type_id.clone()],
+            None,
+            alice_metadata.record_id.entity_id,
+            bob_id,
+        )
+        .await
+        .expect_err("could create a published link to a draft entity");
+    let endpoints = report
+        .downcast_ref::<UnpublishedLinkEndpoints>()
+        .expect("the unpublished endpoints should be reported");
+    assert_eq!(endpoints.entity_ids, [bob_id]);
+    assert!(endpoints.link_entity_ids.is_empty());
+
+    let draft_link_id = api
+        .store
+        .create_entity(
+            api.account_id,
+            &mut NoAuthorization,
+            None,
+            CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: vec![friend_of_type_id.clone()],
+                properties: PropertyObject::empty(),
+                property_confidence: PropertyConfidence::default(),
+                property_data_types: PropertyDataTypes::default(),
+                property_provenance: PropertyProvenances::default(),
+                link_data: Some(LinkData {
+                    left_entity_id: alice_metadata.record_id.entity_id,
+                    right_entity_id: bob_id,
+                    left_entity_confidence: None,
+                    right_entity_confidence: None,
+                    position: None,
+                }),
+                draft: true,
+                relationships: [],
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+            },
+        )
+        .await
+        .expect("could not create draft link")
+        .record_id
+        .entity_id;
+    let publish_link = || PatchEntityParams {
+        entity_id: draft_link_id,
+        expected_edition_id: None,
+        properties: vec![],
+        entity_type_ids: vec![],
+        archived: None,
+        draft: Some(false),
+        decision_time: None,
+        confidence: None,
+        provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+    };
+
+    let report = api
+        .patch_entity(publish_link())
+        .await
+        .expect_err("could publish a link to a draft entity");
+    let endpoints = report
+        .downcast_ref::<UnpublishedLinkEndpoints>()
+        .expect("the unpublished endpoints should be reported");
+    assert_eq!(endpoints.entity_ids, [bob_id]);
+    assert_eq!(
+        endpoints.link_entity_ids,
+        [EntityId {
+            draft_id: None,
+            ..draft_link_id
+        }],
+        "the link blocked by the draft should be reported"
+    );
+
+    let published_bob = api
+        .patch_entity(PatchEntityParams {
+            entity_id: bob_metadata.record_id.entity_id,
+            expected_edition_id: None,
+            properties: vec![],
+            entity_type_ids: vec![],
+            archived: None,
+            draft: Some(false),
+            decision_time: None,
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+        })
+        .await
+        .expect("could not publish entity");
+    assert_eq!(published_bob.record_id.entity_id, bob_id);
+
+    api.patch_entity(publish_link())
+        .await
+        .expect("could not publish link");
+
+    api.create_link_entity(
+        PropertyObject::empty(),
+        vec![friend_of_type_id],
+        None,
+        alice_metadata.record_id.entity_id,
+        bob_id,
+    )
+    .await
+    .expect("could not create link");
+}
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn merge_entities() {
+    let alice = serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob = serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+    let robert = serde_json::from_value(json!({
+        "https://blockprotocol.org/@alice/types/property-type/name/": "Robert",
+        "https://blockprotocol.org/@alice/types/property-type/age/": 43,
+    }))
+    .expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let name_path = PropertyPath::from_json_pointer(
+        "/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1name~1",
+    )
+    .expect("could not parse path");
+    let age_path = PropertyPath::from_json_pointer(
+        "/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1age~1",
+    )
+    .expect("could not parse path");
+
+    let alice_id = api
+        .create_entity(
+            alice,
+            vec![person_type_id.clone()],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+    let bob_id = api
+        .create_entity(
+            bob,
+            vec![person_type_id.clone()],
+            None,
+            false,
+            None,
+            PropertyConfidence::new(HashMap::from([(
+                name_path.clone(),
+                serde_json::from_str("0.5").expect("could not parse confidence"),
+            )])),
+        )
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+    let robert_id = api
+        .create_entity(
+            robert,
+            vec![person_type_id.clone()],
+            None,
+            false,
+            None,
+            PropertyConfidence::new(HashMap::from([(
+                name_path.clone(),
+                serde_json::from_str("0.9").expect("could not parse confidence"),
+            )])),
+        )
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+
+    let friend_of_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let acquaintance_of_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/acquaintance-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    api.create_link_entity(
+        PropertyObject::empty(),
+        vec![friend_of_type_id],
+        None,
+        alice_id,
+        bob_id,
+    )
+    .await
+    .expect("could not create link");
+    let repointed_link_metadata = api
+        .create_link_entity(
+            PropertyObject::empty(),
+            vec![acquaintance_of_type_id],
+            None,
+            alice_id,
+            robert_id,
+        )
+        .await
+        .expect("could not create link");
+    let repointed_link_id = repointed_link_metadata.record_id.entity_id;
+
+    let _ = api
+        .merge_entities(MergeEntitiesParams {
+            survivor: bob_id,
+            duplicate: bob_id,
+            default_strategy: PropertyMergeStrategy::PreferNewest,
+            strategies: HashMap::new(),
+            provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+        })
+        .await
+        .expect_err("could merge an entity into itself");
+
+    let duplicate_draft_id = api
+        .patch_entity(PatchEntityParams {
+            entity_id: robert_id,
+            expected_edition_id: None,
+            properties: vec![],
+            entity_type_ids: vec![],
+            archived: None,
+            draft: Some(true),
+            decision_time: None,
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+        })
+        .await
+        .expect("could not create draft")
+        .record_id
+        .entity_id;
+
+    let (_, cursor) = api
+        .get_entity_changes(None)
+        .await
+        .expect("could not read entity changes");
+
+    let merged_metadata = api
+        .merge_entities(MergeEntitiesParams {
+            survivor: bob_id,
+            duplicate: robert_id,
+            default_strategy: PropertyMergeStrategy::PreferHighestConfidence,
+            strategies: HashMap::from([(
+                age_path,
+                PropertyMergeStrategy::Explicit {
+                    value: Property::Value(json!(44)),
+                },
+            )]),
+            provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+        })
+        .await
+        .expect("could not merge entities");
+    assert_eq!(merged_metadata.record_id.entity_id, bob_id);
+    assert_eq!(
+        merged_metadata.property_confidence.get(&name_path),
+        Some(serde_json::from_str("0.9").expect("could not parse confidence"))
+    );
+
+    let merged_entity = api
+        .get_latest_entity(bob_id)
+        .await
+        .expect("could not get entity");
+    assert_eq!(
+        merged_entity.properties,
+        serde_json::from_value::<PropertyObject>(json!({
+            "https://blockprotocol.org/@alice/types/property-type/name/": "Robert",
+            "https://blockprotocol.org/@alice/types/property-type/age/": 44,
+        }))
+        .expect("could not parse entity")
+    );
+
+    let duplicate = api
+        .get_latest_entity(robert_id)
+        .await
+        .expect("could not get entity");
+    assert!(duplicate.metadata.archived);
+    assert_eq!(
+        duplicate.metadata.provenance.edition.merged_into,
+        Some(bob_id)
+    );
+
+    // The draft of the duplicate is archived as well
+    let duplicate_draft = api
+        .get_latest_entity(duplicate_draft_id)
+        .await
+        .expect("could not get entity");
+    assert!(duplicate_draft.metadata.archived);
+    assert_eq!(
+        duplicate_draft.metadata.provenance.edition.merged_into,
+        Some(bob_id)
+    );
+
+    let links = api
+        .get_latest_entity_links(alice_id)
+        .await
+        .expect("could not read links");
+    assert_eq!(links.len(), 2);
+    for link in links {
+        let link_data = link.link_data.expect("entity is not a link");
+        assert_eq!(link_data.left_entity_id, alice_id);
+        assert_eq!(link_data.right_entity_id, bob_id);
+    }
+
+    // The re-pointed link has a new edition, which is reported in the change feed
+    let repointed_link = api
+        .get_latest_entity(repointed_link_id)
+        .await
+        .expect("could not get entity");
+    assert_ne!(
+        repointed_link.metadata.record_id.edition_id,
+        repointed_link_metadata.record_id.edition_id
+    );
+    let (changes, _) = api
+        .get_entity_changes(cursor)
+        .await
+        .expect("could not read entity changes");
+    assert_eq!(
+        changes
+            .into_iter()
+            .map(|change| (
+                change.kind,
+                change.metadata.map(|metadata| metadata.record_id)
+            ))
+            .collect::<Vec<_>>(),
+        [
+            (EntityChangeKind::Patch, Some(merged_metadata.record_id)),
+            (
+                EntityChangeKind::Archive,
+                Some(duplicate.metadata.record_id)
+            ),
+            (
+                EntityChangeKind::Archive,
+                Some(duplicate_draft.metadata.record_id)
+            ),
+            (
+                EntityChangeKind::Patch,
+                Some(repointed_link.metadata.record_id)
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn move_links() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::NAME_V1],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::CONTAINS_V1,
+                entity_type::SONG_V1,
+                entity_type::PLAYLIST_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let playlist_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/playlist/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let song_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/song/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let contains_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/contains/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let playlist_id = api
+        .create_entity(
+            serde_json::from_str(entity::PLAYLIST_V1).expect("could not parse entity"),
+            vec![playlist_type_id],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+
+    let mut link_ids = Vec::new();
+    for _ in 0..3 {
+        let song_id = api
+            .create_entity(
+                serde_json::from_str(entity::SONG_V1).expect("could not parse entity"),
+                vec![song_type_id.clone()],
+                None,
+                false,
+                None,
+                PropertyConfidence::default(),
+            )
+            .await
+            .expect("could not create entity")
+            .record_id
+            .entity_id;
+        link_ids.push(
+            api.create_link_entity(
+                PropertyObject::empty(),
+                vec![contains_type_id.clone()],
+                None,
+                playlist_id,
+                song_id,
+            )
+            .await
+            .expect("could not create link")
+            .record_id
+            .entity_id,
+        );
+    }
+
+    let (_, cursor) = api
+        .get_entity_changes(None)
+        .await
+        .expect("could not read entity changes");
+
+    // Assigning positions rewrites the other links as well, so they have to be updatable.
+    api.store
+        .move_link(
+            api.account_id,
+            &mut RestrictedAuthorization {
+                hidden_entities: HashSet::from([link_ids[2].entity_uuid]),
+                ..RestrictedAuthorization::default()
+            },
+            MoveLinkParams {
+                link_entity_id: link_ids[1],
+                after: Some(link_ids[0]),
+            },
+        )
+        .await
+        .expect_err("could assign a position to a link which cannot be updated");
+    assert!(
+        api.get_latest_entity_links(playlist_id)
+            .await
+            .expect("could not read links")
+            .into_iter()
+            .all(|link| link
+                .link_data
+                .is_some_and(|link_data| link_data.position.is_none())),
+        "no position should be assigned if the move was rejected"
+    );
+
+    // None of the links has a position yet, so positions are assigned when using one as anchor.
+    api.move_link(MoveLinkParams {
+        link_entity_id: link_ids[1],
+        after: Some(link_ids[0]),
+    })
+    .await
+    .expect("could not move link after an unpositioned link");
+
+    let mut unmoved_links = [link_ids[0], link_ids[2]];
+    unmoved_links.sort();
+    let expected_order = if unmoved_links[0] == link_ids[0] {
+        [link_ids[0], link_ids[1], link_ids[2]]
+    } else {
+        [link_ids[2], link_ids[0], link_ids[1]]
+    };
+    assert_eq!(sorted_link_ids(&mut api, playlist_id).await, expected_order);
+
+    for (link_entity_id, after) in [(link_ids[2], None), (link_ids[1], Some(link_ids[2]))] {
+        api.move_link(MoveLinkParams {
+            link_entity_id,
+            after,
+        })
+        .await
+        .expect("could not move link");
+    }
+
+    assert_eq!(
+        sorted_link_ids(&mut api, playlist_id).await,
+        [link_ids[2], link_ids[1], link_ids[0]]
+    );
+
+    let (changes, _) = api
+        .get_entity_changes(cursor)
+        .await
+        .expect("could not read entity changes");
+    assert_eq!(
+        changes
+            .into_iter()
+            .map(|change| (change.kind, change.entity_id))
+            .collect::<Vec<_>>(),
+        [
+            (EntityChangeKind::Move, link_ids[1]),
+            (EntityChangeKind::Move, link_ids[2]),
+            (EntityChangeKind::Move, link_ids[1]),
+        ]
+    );
+
+    api.move_link(MoveLinkParams {
+        link_entity_id: link_ids[0],
+        after: Some(playlist_id),
+    })
+    .await
+    .expect_err("could move link after an entity which is not a sibling");
+}
+
+async fn sorted_link_ids(api: &mut DatabaseApi<'_>, left_entity_id: EntityId) -> Vec<EntityId> {
+    let mut links = api
+        .get_latest_entity_links(left_entity_id)
+        .await
+        .expect("could not read links");
+    // Links without a position are placed last
+    links.sort_by_key(|link| {
+        let position = link
+            .link_data
+            .as_ref()
+            .and_then(|link_data| link_data.position.clone());
+        (position.is_none(), position)
+    });
+    links
+        .into_iter()
+        .map(|link| link.metadata.record_id.entity_id)
+        .collect()
+}
+
+#[tokio::test]
+async fn move_unordered_link() {
+    let alice = serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob = serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let friend_of_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let alice_id = api
+        .create_entity(
+            alice,
+            vec![person_type_id.clone()],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+    let bob_id = api
+        .create_entity(
+            bob,
+            vec![person_type_id],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+
+    let link_id = api
+        .create_link_entity(
+            PropertyObject::empty(),
+            vec![friend_of_type_id],
+            None,
+            alice_id,
+            bob_id,
+        )
+        .await
+        .expect("could not create link")
+        .record_id
+        .entity_id;
+
+    api.move_link(MoveLinkParams {
+        link_entity_id: link_id,
+        after: None,
+    })
+    .await
+    .expect_err("could move link of an unordered link type");
+
+    let link_data = api
+        .get_latest_entity(link_id)
+        .await
+        .expect("could not read link")
+        .link_data
+        .expect("entity is not a link");
+    assert!(link_data.position.is_none());
+}
@@ -1,10 +1,10 @@
 use std::str::FromStr;
 
-use graph::store::knowledge::PatchEntityParams;
+use graph::store::knowledge::{MigrateEntitiesParams, PatchEntityParams};
 use graph_test_data::{data_type, entity, entity_type, property_type};
 use graph_types::knowledge::{
-    entity::{Entity, ProvidedEntityEditionProvenanceMetadata},
-    PropertyConfidence, PropertyObject,
+    entity::{Entity, PropertyMigration, ProvidedEntityEditionProvenanceMetadata},
+    PropertyConfidence, PropertyObject, PropertyPath,
 };
 use pretty_assertions::assert_eq;
 use type_system::url::VersionedUrl;
@@ -103,6 +103,7 @@ async fn initial_person() {
     let updated_entity_metadata = api
         .patch_entity(PatchEntityParams {
             entity_id: entity_metadata.record_id.entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: vec![person_entity_type_id(), org_entity_type_id()],
             properties: vec![],
@@ -179,6 +180,7 @@ async fn create_multi() {
     let updated_entity_metadata = api
         .patch_entity(PatchEntityParams {
             entity_id: entity_metadata.record_id.entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: vec![person_entity_type_id()],
             properties: vec![],
@@ -207,3 +209,105 @@ async fn create_multi() {
         }]
     );
 }
+
+#[tokio::test]
+async fn migrate_entity_type() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let alice_id = api
+        .create_entity(
+            alice(),
+            vec![person_entity_type_id()],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+    let bob_id = api
+        .create_entity(
+            serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity"),
+            vec![person_entity_type_id()],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+
+    // Bob has an age, which is not a property of an organization.
+    let migrations = api
+        .migrate_entities(MigrateEntitiesParams {
+            from: person_entity_type_id(),
+            to: org_entity_type_id(),
+            properties: Vec::new(),
+            dry_run: true,
+            provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+        })
+        .await
+        .expect("could not migrate entities");
+    assert_eq!(migrations.len(), 2);
+    for migration in migrations {
+        if migration.entity_id == alice_id {
+            assert!(matches!(migration.result, Ok(None)));
+        } else {
+            assert_eq!(migration.entity_id, bob_id);
+            let _ = migration
+                .result
+                .expect_err("migrated entity with invalid properties");
+        }
+    }
+    assert_eq!(
+        api.get_latest_entity(alice_id)
+            .await
+            .expect("could not get entity")
+            .metadata
+            .entity_type_ids,
+        [person_entity_type_id()]
+    );
+
+    let migrations = api
+        .migrate_entities(MigrateEntitiesParams {
+            from: person_entity_type_id(),
+            to: org_entity_type_id(),
+            properties: vec![PropertyMigration::Drop {
+                path: PropertyPath::from_json_pointer(
+                    "/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1age~1",
+                )
+                .expect("could not parse path"),
+            }],
+            dry_run: false,
+            provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+        })
+        .await
+        .expect("could not migrate entities");
+    assert_eq!(migrations.len(), 2);
+    for migration in migrations {
+        let metadata = migration
+            .result
+            .expect("could not migrate entity")
+            .expect("entity was not migrated");
+        assert_eq!(metadata.record_id.entity_id, migration.entity_id);
+        assert_eq!(metadata.entity_type_ids, [org_entity_type_id()]);
+    }
+
+    let bob = api
+        .get_latest_entity(bob_id)
+        .await
+        .expect("could not get entity");
+    assert_eq!(bob.metadata.entity_type_ids, [org_entity_type_id()]);
+    assert_eq!(
+        bob.properties,
+        serde_json::from_value::<PropertyObject>(serde_json::json!({
+            "https://blockprotocol.org/@alice/types/property-type/name/": "Bob",
+        }))
+        .expect("could not parse properties")
+    );
+}
@@ -3,8 +3,9 @@ use std::{collections::HashSet, iter::once, str::FromStr};
 use graph::store::knowledge::PatchEntityParams;
 use graph_test_data::{data_type, entity, entity_type, property_type};
 use graph_types::knowledge::{
-    entity::ProvidedEntityEditionProvenanceMetadata, Property, PropertyConfidence, PropertyObject,
-    PropertyPatchOperation, PropertyPathElement,
+    entity::{ProvidedEntityEditionProvenanceMetadata, SourceProvenance},
+    Property, PropertyConfidence, PropertyObject, PropertyPatchOperation, PropertyPath,
+    PropertyPathElement, PropertyProvenance,
 };
 use pretty_assertions::assert_eq;
 use serde_json::json;
@@ -87,12 +88,15 @@ async fn properties_add() {
 
     api.patch_entity(PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: vec![],
         properties: vec![PropertyPatchOperation::Add {
             path: once(PropertyPathElement::from(age_property_type_id())).collect(),
             value: Property::Value(json!(30)),
             confidence: None,
+            data_type_id: None,
+            provenance: None,
         }],
         draft: None,
         archived: None,
@@ -132,6 +136,7 @@ async fn properties_remove() {
 
     api.patch_entity(PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: vec![],
         properties: vec![PropertyPatchOperation::Remove {
@@ -173,12 +178,15 @@ async fn properties_replace() {
 
     api.patch_entity(PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: vec![],
         properties: vec![PropertyPatchOperation::Replace {
             path: once(PropertyPathElement::from(name_property_type_id())).collect(),
             value: Property::Value(json!("Bob")),
             confidence: None,
+            data_type_id: None,
+            provenance: None,
         }],
         draft: None,
         archived: None,
@@ -218,6 +226,7 @@ async fn properties_move() {
     let _ = api
         .patch_entity(PatchEntityParams {
             entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: vec![],
             properties: vec![PropertyPatchOperation::Move {
@@ -240,6 +249,7 @@ async fn properties_move() {
 
     api.patch_entity(PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: vec![],
         properties: vec![
@@ -247,6 +257,8 @@ async fn properties_move() {
                 path: once(PropertyPathElement::from(interests_property_type_id())).collect(),
                 value: Property::Value(json!({})),
                 confidence: None,
+                data_type_id: None,
+                provenance: None,
             },
             PropertyPatchOperation::Move {
                 from: once(PropertyPathElement::from(name_property_type_id())).collect(),
@@ -299,6 +311,7 @@ async fn properties_copy() {
 
     api.patch_entity(PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: vec![],
         properties: vec![
@@ -306,6 +319,8 @@ async fn properties_copy() {
                 path: once(PropertyPathElement::from(interests_property_type_id())).collect(),
                 value: Property::Value(json!({})),
                 confidence: None,
+                data_type_id: None,
+                provenance: None,
             },
             PropertyPatchOperation::Test {
                 path: once(PropertyPathElement::from(interests_property_type_id())).collect(),
@@ -363,6 +378,7 @@ async fn type_ids() {
 
     api.patch_entity(PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: vec![],
         properties: vec![],
@@ -386,6 +402,7 @@ async fn type_ids() {
 
     api.patch_entity(PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: vec![person_entity_type_id(), org_entity_type_id()],
         properties: vec![],
@@ -413,6 +430,7 @@ async fn type_ids() {
 
     api.patch_entity(PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: vec![person_entity_type_id()],
         properties: vec![],
@@ -430,3 +448,82 @@ async fn type_ids() {
         .expect("could not get entity");
     assert_eq!(entity.metadata.entity_type_ids, [person_entity_type_id()],);
 }
+
+#[tokio::test]
+async fn property_provenance() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let entity = api
+        .create_entity(
+            alice(),
+            vec![person_entity_type_id()],
+            None,
+            false,
+            None,
+            PropertyConfidence::default(),
+        )
+        .await
+        .expect("could not create entity");
+    let entity_id = entity.record_id.entity_id;
+
+    let name_path: PropertyPath<'static> =
+        once(PropertyPathElement::from(name_property_type_id())).collect();
+    let name_provenance = PropertyProvenance {
+        sources: vec![SourceProvenance {
+            url: "https://example.com/alice".parse().expect("invalid URL"),
+        }],
+    };
+
+    api.patch_entity(PatchEntityParams {
+        entity_id,
+        expected_edition_id: None,
+        decision_time: None,
+        entity_type_ids: vec![],
+        properties: vec![PropertyPatchOperation::Replace {
+            path: name_path.clone(),
+            value: Property::Value(json!("Alice")),
+            confidence: None,
+            data_type_id: None,
+            provenance: Some(name_provenance.clone()),
+        }],
+        draft: None,
+        archived: None,
+        confidence: None,
+        provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+    })
+    .await
+    .expect("could not patch entity");
+
+    api.patch_entity(PatchEntityParams {
+        entity_id,
+        expected_edition_id: None,
+        decision_time: None,
+        entity_type_ids: vec![],
+        properties: vec![PropertyPatchOperation::Add {
+            path: once(PropertyPathElement::from(age_property_type_id())).collect(),
+            value: Property::Value(json!(30)),
+            confidence: None,
+            data_type_id: None,
+            provenance: None,
+        }],
+        draft: None,
+        archived: None,
+        confidence: None,
+        provenance: ProvidedEntityEditionProvenanceMetadata::default(),
+    })
+    .await
+    .expect("could not patch entity");
+
+    let entity = api
+        .get_latest_entity(entity_id)
+        .await
+        .expect("could not get entity");
+    assert_eq!(entity.properties.properties().len(), 2);
+    assert_eq!(entity.metadata.property_provenance.len(), 1);
+    assert_eq!(
+        entity.metadata.property_provenance.get(&name_path),
+        Some(&name_provenance),
+        "the provenance of the name should be kept when the age is added"
+    );
+}
@@ -0,0 +1,330 @@
+use std::collections::{HashMap, HashSet};
+
+use authorization::{
+    backend::{
+        CheckError, CheckResponse, ModifyRelationError, ModifyRelationshipOperation, ReadError,
+    },
+    schema::{
+        AccountGroupPermission, AccountGroupRelationAndSubject, DataTypeId, DataTypePermission,
+        DataTypeRelationAndSubject, EntityPermission, EntityRelationAndSubject, EntityTypeId,
+        EntityTypePermission, EntityTypeRelationAndSubject, PropertyTypeId, PropertyTypePermission,
+        PropertyTypeRelationAndSubject, WebPermission, WebRelationAndSubject,
+    },
+    zanzibar::{Consistency, Zookie},
+    AuthorizationApi, NoAuthorization,
+};
+use error_stack::Result;
+use graph_types::{
+    account::{AccountGroupId, AccountId},
+    knowledge::entity::{EntityId, EntityUuid},
+    owned_by_id::OwnedById,
+};
+
+/// Authorizes every request like [`NoAuthorization`] except for the denied permissions.
+///
+/// Relations of entities are recorded, so they can be inspected after a request.
+#[derive(Debug, Default, Clone)]
+pub struct RestrictedAuthorization {
+    /// Web permissions which are denied for every web.
+    pub denied_web_permissions: Vec<WebPermission>,
+    /// Entities for which every permission is denied.
+    pub hidden_entities: HashSet<EntityUuid>,
+    /// The relations which were created for each entity.
+    pub entity_relations: HashMap<EntityUuid, Vec<EntityRelationAndSubject>>,
+}
+
+impl AuthorizationApi for RestrictedAuthorization {
+    async fn seed(&mut self) -> Result<Zookie<'static>, ModifyRelationError> {
+        NoAuthorization.seed().await
+    }
+
+    async fn check_account_group_permission(
+        &self,
+        actor: AccountId,
+        permission: AccountGroupPermission,
+        account_group: AccountGroupId,
+        consistency: Consistency<'_>,
+    ) -> Result<CheckResponse, CheckError> {
+        NoAuthorization
+            .check_account_group_permission(actor, permission, account_group, consistency)
+            .await
+    }
+
+    async fn modify_account_group_relations(
+        &mut self,
+        relationships: impl IntoIterator<
+            Item = (
+                ModifyRelationshipOperation,
+                AccountGroupId,
+                AccountGroupRelationAndSubject,
+            ),
+            IntoIter: Send,
+        > + Send,
+    ) -> Result<Zookie<'static>, ModifyRelationError> {
+        NoAuthorization
+            .modify_account_group_relations(relationships)
+            .await
+    }
+
+    async fn check_web_permission(
+        &self,
+        _: AccountId,
+        permission: WebPermission,
+        _: OwnedById,
+        _: Consistency<'_>,
+    ) -> Result<CheckResponse, CheckError> {
+        Ok(CheckResponse {
+            has_permission: !self.denied_web_permissions.contains(&permission),
+            checked_at: Zookie::empty(),
+        })
+    }
+
+    async fn modify_web_relations(
+        &mut self,
+        relationships: impl IntoIterator<
+            Item = (
+                ModifyRelationshipOperation,
+                OwnedById,
+                WebRelationAndSubject,
+            ),
+            IntoIter: Send,
+        > + Send,
+    ) -> Result<Zookie<'static>, ModifyRelationError> {
+        NoAuthorization.modify_web_relations(relationships).await
+    }
+
+    async fn get_web_relations(
+        &self,
+        web: OwnedById,
+        consistency: Consistency<'static>,
+    ) -> Result<Vec<WebRelationAndSubject>, ReadError> {
+        NoAuthorization.get_web_relations(web, consistency).await
+    }
+
+    async fn check_entity_permission(
+        &self,
+        _: AccountId,
+        _: EntityPermission,
+        entity: EntityId,
+        _: Consistency<'_>,
+    ) -> Result<CheckResponse, CheckError> {
+        Ok(CheckResponse {
+            has_permission: !self.hidden_entities.contains(&entity.entity_uuid),
+            checked_at: Zookie::empty(),
+        })
+    }
+
+    async fn check_entities_permission(
+        &self,
+        _: AccountId,
+        _: EntityPermission,
+        entities: impl IntoIterator<Item = EntityId, IntoIter: Send> + Send,
+        _: Consistency<'_>,
+    ) -> Result<(HashMap<EntityUuid, bool>, Zookie<'static>), CheckError> {
+        Ok((
+            entities
+                .into_iter()
+                .map(|entity| {
+                    (
+                        entity.entity_uuid,
+                        !self.hidden_entities.contains(&entity.entity_uuid),
+                    )
+                })
+                .collect(),
+            Zookie::empty(),
+        ))
+    }
+
+    async fn modify_entity_relations(
+        &mut self,
+        relationships: impl IntoIterator<
+            Item = (
+                ModifyRelationshipOperation,
+                EntityId,
+                EntityRelationAndSubject,
+            ),
+            IntoIter: Send,
+        > + Send,
+    ) -> Result<Zookie<'static>, ModifyRelationError> {
+        let relationships = relationships.into_iter().collect::<Vec<_>>();
+        for (operation, entity_id, relation_and_subject) in &relationships {
+            let relations = self
+                .entity_relations
+                .entry(entity_id.entity_uuid)
+                .or_default();
+            match operation {
+                ModifyRelationshipOperation::Touch | ModifyRelationshipOperation::Create => {
+                    if !relations.contains(relation_and_subject) {
+                        relations.push(*relation_and_subject);
+                    }
+                }
+                ModifyRelationshipOperation::Delete => {
+                    relations.retain(|relation| relation != relation_and_subject);
+                }
+            }
+        }
+        NoAuthorization.modify_entity_relations(relationships).await
+    }
+
+    async fn get_entity_relations(
+        &self,
+        entity: EntityId,
+        _: Consistency<'static>,
+    ) -> Result<Vec<EntityRelationAndSubject>, ReadError> {
+        Ok(self
+            .entity_relations
+            .get(&entity.entity_uuid)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn modify_entity_type_relations(
+        &mut self,
+        relationships: impl IntoIterator<
+            Item = (
+                ModifyRelationshipOperation,
+                EntityTypeId,
+                EntityTypeRelationAndSubject,
+            ),
+            IntoIter: Send,
+        > + Send,
+    ) -> Result<Zookie<'static>, ModifyRelationError> {
+        NoAuthorization
+            .modify_entity_type_relations(relationships)
+            .await
+    }
+
+    async fn check_entity_type_permission(
+        &self,
+        actor: AccountId,
+        permission: EntityTypePermission,
+        entity_type: EntityTypeId,
+        consistency: Consistency<'_>,
+    ) -> Result<CheckResponse, CheckError> {
+        NoAuthorization
+            .check_entity_type_permission(actor, permission, entity_type, consistency)
+            .await
+    }
+
+    async fn check_entity_types_permission(
+        &self,
+        actor: AccountId,
+        permission: EntityTypePermission,
+        entity_types: impl IntoIterator<Item = EntityTypeId, IntoIter: Send> + Send,
+        consistency: Consistency<'_>,
+    ) -> Result<(HashMap<EntityTypeId, bool>, Zookie<'static>), CheckError> {
+        NoAuthorization
+            .check_entity_types_permission(actor, permission, entity_types, consistency)
+            .await
+    }
+
+    async fn get_entity_type_relations(
+        &self,
+        entity_type: EntityTypeId,
+        consistency: Consistency<'static>,
+    ) -> Result<Vec<EntityTypeRelationAndSubject>, ReadError> {
+        NoAuthorization
+            .get_entity_type_relations(entity_type, consistency)
+            .await
+    }
+
+    async fn modify_property_type_relations(
+        &mut self,
+        relationships: impl IntoIterator<
+            Item = (
+                ModifyRelationshipOperation,
+                PropertyTypeId,
+                PropertyTypeRelationAndSubject,
+            ),
+            IntoIter: Send,
+        > + Send,
+    ) -> Result<Zookie<'static>, ModifyRelationError> {
+        NoAuthorization
+            .modify_property_type_relations(relationships)
+            .await
+    }
+
+    async fn check_property_type_permission(
+        &self,
+        actor: AccountId,
+        permission: PropertyTypePermission,
+        property_type: PropertyTypeId,
+        consistency: Consistency<'_>,
+    ) -> Result<CheckResponse, CheckError> {
+        NoAuthorization
+            .check_property_type_permission(actor, permission, property_type, consistency)
+            .await
+    }
+
+    async fn check_property_types_permission(
+        &self,
+        actor: AccountId,
+        permission: PropertyTypePermission,
+        property_types: impl IntoIterator<Item = PropertyTypeId, IntoIter: Send> + Send,
+        consistency: Consistency<'_>,
+    ) -> Result<(HashMap<PropertyTypeId, bool>, Zookie<'static>), CheckError> {
+        NoAuthorization
+            .check_property_types_permission(actor, permission, property_types, consistency)
+            .await
+    }
+
+    async fn get_property_type_relations(
+        &self,
+        property_type: PropertyTypeId,
+        consistency: Consistency<'static>,
+    ) -> Result<Vec<PropertyTypeRelationAndSubject>, ReadError> {
+        NoAuthorization
+            .get_property_type_relations(property_type, consistency)
+            .await
+    }
+
+    async fn modify_data_type_relations(
+        &mut self,
+        relationships: impl IntoIterator<
+            Item = (
+                ModifyRelationshipOperation,
+                DataTypeId,
+                DataTypeRelationAndSubject,
+            ),
+            IntoIter: Send,
+        > + Send,
+    ) -> Result<Zookie<'static>, ModifyRelationError> {
+        NoAuthorization
+            .modify_data_type_relations(relationships)
+            .await
+    }
+
+    async fn check_data_type_permission(
+        &self,
+        actor: AccountId,
+        permission: DataTypePermission,
+        data_type: DataTypeId,
+        consistency: Consistency<'_>,
+    ) -> Result<CheckResponse, CheckError> {
+        NoAuthorization
+            .check_data_type_permission(actor, permission, data_type, consistency)
+            .await
+    }
+
+    async fn check_data_types_permission(
+        &self,
+        actor: AccountId,
+        permission: DataTypePermission,
+        data_types: impl IntoIterator<Item = DataTypeId, IntoIter: Send> + Send,
+        consistency: Consistency<'_>,
+    ) -> Result<(HashMap<DataTypeId, bool>, Zookie<'static>), CheckError> {
+        NoAuthorization
+            .check_data_types_permission(actor, permission, data_types, consistency)
+            .await
+    }
+
+    async fn get_data_type_relations(
+        &self,
+        data_type: DataTypeId,
+        consistency: Consistency<'static>,
+    ) -> Result<Vec<DataTypeRelationAndSubject>, ReadError> {
+        NoAuthorization
+            .get_data_type_relations(data_type, consistency)
+            .await
+    }
+}
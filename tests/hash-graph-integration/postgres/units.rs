@@ -0,0 +1,216 @@
+use std::{collections::HashMap, iter::once, str::FromStr};
+
+use graph::{
+    knowledge::EntityQueryPath,
+    store::query::{Filter, FilterExpression, Parameter},
+};
+use graph_types::{
+    knowledge::{PropertyDataTypes, PropertyObject, PropertyPath, PropertyPathElement},
+    ontology::DataTypeConversion,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use type_system::{
+    url::{BaseUrl, VersionedUrl},
+    DataType, EntityType, PropertyType,
+};
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+fn meter_data_type_id() -> VersionedUrl {
+    VersionedUrl::from_str("https://blockprotocol.org/@alice/types/data-type/meter/v/1")
+        .expect("couldn't construct data type id")
+}
+
+fn centimeter_data_type_id() -> VersionedUrl {
+    VersionedUrl::from_str("https://blockprotocol.org/@alice/types/data-type/centimeter/v/1")
+        .expect("couldn't construct data type id")
+}
+
+fn second_data_type_id() -> VersionedUrl {
+    VersionedUrl::from_str("https://blockprotocol.org/@alice/types/data-type/second/v/1")
+        .expect("couldn't construct data type id")
+}
+
+fn height_property_type_id() -> BaseUrl {
+    BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/height/".to_owned())
+        .expect("couldn't construct Base URL")
+}
+
+fn tower_entity_type_id() -> VersionedUrl {
+    VersionedUrl::from_str("https://blockprotocol.org/@alice/types/entity-type/tower/v/1")
+        .expect("couldn't construct entity type id")
+}
+
+fn data_type(id: &VersionedUrl, title: &str) -> DataType {
+    serde_json::from_value(json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": id.to_string(),
+        "title": title,
+        "type": "number"
+    }))
+    .expect("could not parse data type")
+}
+
+async fn seed(database: &mut DatabaseTestWrapper) -> DatabaseApi<'_> {
+    let mut api = database
+        .seed([], [], [])
+        .await
+        .expect("could not seed database");
+
+    api.create_owned_data_type(data_type(&meter_data_type_id(), "Meter"))
+        .await
+        .expect("could not create data type");
+    api.create_unit_data_type(
+        data_type(&centimeter_data_type_id(), "Centimeter"),
+        DataTypeConversion {
+            canonical_unit: meter_data_type_id().base_url,
+            factor: 0.01,
+        },
+    )
+    .await
+    .expect("could not create data type");
+    api.create_owned_data_type(data_type(&second_data_type_id(), "Second"))
+        .await
+        .expect("could not create data type");
+
+    let height: PropertyType = serde_json::from_value(json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+        "kind": "propertyType",
+        "$id": "https://blockprotocol.org/@alice/types/property-type/height/v/1",
+        "title": "Height",
+        "oneOf": [{ "$ref": meter_data_type_id().to_string() }]
+    }))
+    .expect("could not parse property type");
+    api.create_property_type(height)
+        .await
+        .expect("could not create property type");
+
+    let tower: EntityType = serde_json::from_value(json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type",
+        "kind": "entityType",
+        "$id": tower_entity_type_id().to_string(),
+        "type": "object",
+        "title": "Tower",
+        "properties": {
+            height_property_type_id().to_string(): {
+                "$ref": "https://blockprotocol.org/@alice/types/property-type/height/v/1"
+            }
+        }
+    }))
+    .expect("could not parse entity type");
+    api.create_entity_type(tower)
+        .await
+        .expect("could not create entity type");
+
+    api
+}
+
+fn height_path() -> PropertyPath<'static> {
+    once(PropertyPathElement::from(height_property_type_id())).collect()
+}
+
+fn tower(height: f64) -> PropertyObject {
+    serde_json::from_value(json!({ height_property_type_id().to_string(): height }))
+        .expect("could not parse properties")
+}
+
+fn height_data_type(data_type_id: VersionedUrl) -> PropertyDataTypes<'static> {
+    PropertyDataTypes::new(HashMap::from([(height_path(), data_type_id)]))
+}
+
+#[tokio::test]
+async fn declared_unit() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let entity = api
+        .create_entity_with_data_types(
+            tower(180.0),
+            vec![tower_entity_type_id()],
+            height_data_type(centimeter_data_type_id()),
+        )
+        .await
+        .expect("could not create entity");
+
+    assert_eq!(
+        entity.property_data_types,
+        height_data_type(centimeter_data_type_id())
+    );
+
+    let entities = api
+        .get_entities_by_filter(Filter::for_entity_by_entity_id(entity.record_id.entity_id))
+        .await
+        .expect("could not query entities");
+    let [entity] = entities.as_slice() else {
+        panic!("expected exactly one entity");
+    };
+    assert_eq!(entity.properties, tower(180.0));
+    assert_eq!(
+        entity.metadata.property_data_types,
+        height_data_type(centimeter_data_type_id())
+    );
+}
+
+#[tokio::test]
+async fn incompatible_unit() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    api.create_entity_with_data_types(
+        tower(180.0),
+        vec![tower_entity_type_id()],
+        height_data_type(second_data_type_id()),
+    )
+    .await
+    .expect_err("created entity with a value in an incompatible unit");
+}
+
+#[tokio::test]
+async fn filter_normalized_value() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let tall_tower = api
+        .create_entity_with_data_types(
+            tower(180.0),
+            vec![tower_entity_type_id()],
+            height_data_type(centimeter_data_type_id()),
+        )
+        .await
+        .expect("could not create entity");
+    let taller_tower = api
+        .create_entity_with_data_types(
+            tower(2.0),
+            vec![tower_entity_type_id()],
+            height_data_type(meter_data_type_id()),
+        )
+        .await
+        .expect("could not create entity");
+    api.create_entity_with_data_types(
+        tower(120.0),
+        vec![tower_entity_type_id()],
+        height_data_type(centimeter_data_type_id()),
+    )
+    .await
+    .expect("could not create entity");
+
+    let mut entities = api
+        .get_entities_by_filter(Filter::Greater(
+            FilterExpression::Path(EntityQueryPath::NormalizedProperty(height_path())),
+            FilterExpression::Parameter(Parameter::F64(1.5)),
+        ))
+        .await
+        .expect("could not query entities")
+        .into_iter()
+        .map(|entity| entity.metadata.record_id.entity_id)
+        .collect::<Vec<_>>();
+    entities.sort_by_key(|entity_id| entity_id.entity_uuid.into_uuid());
+    let mut expected = vec![
+        tall_tower.record_id.entity_id,
+        taller_tower.record_id.entity_id,
+    ];
+    expected.sort_by_key(|entity_id| entity_id.entity_uuid.into_uuid());
+    assert_eq!(entities, expected);
+}